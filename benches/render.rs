@@ -0,0 +1,28 @@
+use std::time::Instant;
+
+
+// `cargo bench`
+// renders an empty document, README.md, and a document made of 50 copies of it
+fn main() {
+
+    let empty = String::new();
+    let readme = std::fs::read_to_string("README.md").unwrap();
+    let large = vec![readme.as_str(); 50].join("\n\n");
+
+    // the first call loads the default syntaxes and themes, which costs much more than rendering
+    let begin = Instant::now();
+    hmd::render(empty.clone());
+    println!("{:<16} {:>8} bytes  {:>10.2?}", "first render", 0, begin.elapsed());
+
+    for (name, document, iterations) in [("empty", &empty, 50), ("README.md", &readme, 50), ("README.md x 50", &large, 5)] {
+        let begin = Instant::now();
+
+        for _ in 0..iterations {
+            hmd::render(document.clone());
+        }
+
+        let elapsed = begin.elapsed() / iterations;
+        println!("{:<16} {:>8} bytes  {:>10.2?} / render", name, document.len(), elapsed);
+    }
+
+}
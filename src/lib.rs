@@ -1,9 +1,13 @@
 mod markdown;
-mod consts;
 mod utils;
+mod option;
+
+pub use option::{RenderOption, CodeHighlight, LineNumberLayout, TableAlignmentStyle, ElementStyle, ColorScheme};
+pub use markdown::{Diagnostic, Severity, Span, InlineKind, InlineSpan};
 
 use std::fs::File;
-use std::io::{Read, Write};
+use std::io::{BufWriter, Read};
+use std::{fmt, io};
 
 
 pub fn from_file(input: &str, output: &str) {
@@ -12,10 +16,9 @@ pub fn from_file(input: &str, output: &str) {
     let mut st = String::new();
 
     f.read_to_string(&mut st).unwrap();
-    let result = crate::markdown::render(&st).unwrap();
 
-    let mut f = File::create(output).unwrap();
-    f.write_all(result.as_bytes()).unwrap();
+    let mut f = BufWriter::new(File::create(output).unwrap());
+    render_to_writer(&st, &mut f).unwrap();
 }
 
 
@@ -24,6 +27,100 @@ pub fn render(input: String) -> String {
 }
 
 
+pub fn render_with_option(input: String, option: &RenderOption) -> String {
+    crate::markdown::render_with_option(&input, option).unwrap()
+}
+
+
+// diagnostics are sorted by their positions
+// ex) an unclosed code fence, an unterminated `[[tag]]`, an invalid icon argument or a table row with a wrong number of cells
+pub fn render_with_diagnostics(input: &str, option: &RenderOption) -> (String, Vec<Diagnostic>) {
+    crate::markdown::render_with_diagnostics(input, option)
+}
+
+
+// where the inline elements of the document are, sorted by their positions
+// ex) `**bold**` in a list item is an `InlineKind::Bold` whose span covers both `**`s
+pub fn inline_spans(input: &str, option: &RenderOption) -> Vec<InlineSpan> {
+    crate::markdown::inline_spans(input, option)
+}
+
+
+// reports the syntax of gfm that hmd renders differently, along with the diagnostics of `render_with_diagnostics`
+// ex) `* ` bullets, `_emphasis_`, setext headings, raw html, html entities, ``double backticks`` and `~~~` fences
+pub fn lint(input: &str) -> Vec<Diagnostic> {
+    crate::markdown::lint(input)
+}
+
+
+// rewrites gfm into hmd, with warnings for the constructs that hmd cannot express
+// ex) `* ` bullets -> `- `, `_em_` -> `*em*`, `~~~` -> ```, `\` hard line breaks -> two spaces, `&nbsp;` -> `[[blank]]`
+pub fn from_gfm(input: &str) -> (String, Vec<Diagnostic>) {
+    crate::markdown::from_gfm(input)
+}
+
+
+// lowers hmd into gfm, so that the document can be posted where hmd is not available
+// the extensions become inline html, ex) `~_u_~` -> `<u>u</u>`, `[[center]]` -> `<div align="center">`
+pub fn to_gfm(input: &str) -> (String, Vec<Diagnostic>) {
+    crate::markdown::to_gfm(input)
+}
+
+
+// rewrites the document in the canonical layout: aligned tables, normalized list indents and tags, and consistent empty lines
+// the rendered html only differs in whitespaces, and `format(&format(s)) == format(s)`
+pub fn format(input: &str) -> String {
+    crate::markdown::format(input)
+}
+
+
+// the whole document is parsed first, then the rendered blocks are written to `writer` one by one, so the joined html is never built
+// it saves a copy of the output, but it does not start writing before the input is parsed
+// wrap files and sockets with a `BufWriter`
+pub fn render_to_writer<W: io::Write>(input: &str, writer: &mut W) -> io::Result<()> {
+    crate::markdown::render_to_io_writer(input, &RenderOption::default(), writer)
+}
+
+
+pub fn render_to_writer_with_option<W: io::Write>(input: &str, option: &RenderOption, writer: &mut W) -> io::Result<()> {
+    crate::markdown::render_to_io_writer(input, option, writer)
+}
+
+
+// `render_to_writer` for `fmt::Write`, such as `String` and `fmt::Formatter`
+pub fn render_to_fmt_writer<W: fmt::Write>(input: &str, writer: &mut W) -> fmt::Result {
+    crate::markdown::render_to_fmt_writer(input, &RenderOption::default(), writer)
+}
+
+
+pub fn render_to_fmt_writer_with_option<W: fmt::Write>(input: &str, option: &RenderOption, writer: &mut W) -> fmt::Result {
+    crate::markdown::render_to_fmt_writer(input, option, writer)
+}
+
+
+// names of the themes that can be used for `RenderOption::theme` and `syntax_theme_css`
+// the ones loaded with `RenderOption::load_theme_dir` come before the bundled ones
+pub fn syntax_theme_names(option: &RenderOption) -> Vec<String> {
+    crate::markdown::theme_names(option)
+}
+
+
+// a stylesheet for fenced codes rendered with `CodeHighlight::CssClass`
+// `theme` is looked up in the themes loaded with `RenderOption::load_theme_dir` first, then in the bundled ones
+// returns `None` if there's no theme named `theme`
+pub fn syntax_theme_css(theme: &str, option: &RenderOption) -> Option<String> {
+    crate::markdown::theme_css(theme, option)
+}
+
+
+// a stylesheet for the classes in the rendered html, ex) `.box`, `.font_red`, `.align_center` and `.line_no`
+// it does not color fenced codes, use `syntax_theme_css` with `CodeHighlight::CssClass` for them
+// `class_prefix` must be the same as `RenderOption::class_prefix`
+pub fn default_stylesheet(color_scheme: ColorScheme, class_prefix: &str) -> String {
+    crate::markdown::stylesheet(color_scheme, class_prefix)
+}
+
+
 #[cfg(test)]
 mod tests {
 
@@ -38,6 +135,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn header_attribute_test() {
+        let md = "# Title {#custom-id .a .b}\n## *Sub* {.c}\n### Not {attributes}\n\n```rust {#main .wide}\nfn main() {}\n```";
+        let html = "<h1 id=\"custom-id\" class=\"a b\">Title</h1>\n<h2 id=\"emSubem\" class=\"c\"><em>Sub</em></h2>\n<h3 id=\"Notattributes\">Not {attributes}</h3>\n\n<pre id=\"main\" class=\"code wide\"><code>";
+
+        let mut option = crate::RenderOption::default();
+        option.code_highlight = crate::CodeHighlight::CssClass;
+
+        assert!(crate::markdown::render_with_option(md, &option).unwrap().starts_with(html));
+
+        option.heading_ids = false;
+
+        assert_eq!(
+            "<h1 class=\"a b\">Title</h1>\n<h2 class=\"c\"><em>Sub</em></h2>",
+            crate::markdown::render_with_option("# Title {#custom-id .a .b}\n## *Sub* {.c}", &option).unwrap().trim_end_matches('\n')
+        );
+    }
+
     #[test]
     fn thematic_break_test() {
         let md = "---\n---\n***\na".to_string();
@@ -82,6 +197,262 @@ mod tests {
         );
     }
 
+    #[test]
+    fn code_fence_option_test() {
+        let md = "```line_num(1), highlight(2, 4-5), title(src/a.rs)\na\nb\nc\nd\n```\n\n```none, diff\n+a\n-b\n c\n```".to_string();
+        let html = "<div class=\"code_title\">src/a.rs</div><pre><code><span class=\"line_no\">1</span>a\n<span class=\"highlighted_line\"><span class=\"line_no\">2</span>b</span>\n<span class=\"line_no\">3</span>c\n<span class=\"highlighted_line\"><span class=\"line_no\">4</span>d</span></code></pre>\n\n<pre><code><span class=\"diff_added\"><span class=\"diff_marker\">+</span>a</span>\n<span class=\"diff_removed\"><span class=\"diff_marker\">-</span>b</span>\n c</code></pre>";
+
+        assert_eq!(
+            html.trim_end_matches('\n'),
+            crate::markdown::render(&md).unwrap().trim_end_matches('\n')
+        );
+    }
+
+    #[test]
+    fn line_number_test() {
+        use crate::{CodeHighlight, RenderOption};
+
+        // tokens spanning multiple lines should not leak `<span>`s into the other lines
+        let samples = [
+            ("rust", "/* a\nb */\nfn main() {\n    let s = \"x\ny\";\n}"),
+            ("python", "s = \"\"\"a\nb\"\"\"\nx = 1"),
+            ("c", "/*\n * a\n */\nint main() {}"),
+            ("js", "let s = `a\nb`;\nf();"),
+            ("html", "<!-- a\nb -->\n<p>c</p>"),
+            ("none", "a\nb\nc")
+        ];
+
+        for code_highlight in [CodeHighlight::InlineStyle, CodeHighlight::CssClass] {
+            let mut option = RenderOption::default();
+            option.code_highlight = code_highlight;
+
+            for (language, code) in samples.iter() {
+                let md = format!("```{}, line_num(3)\n{}\n```", language, code);
+                let html = crate::markdown::render_with_option(&md, &option).unwrap();
+                let code_begin = html.find("<code>").unwrap() + 6;
+                let code_end = html.rfind("</code>").unwrap();
+                let lines = html[code_begin..code_end].split('\n').collect::<Vec<&str>>();
+
+                assert_eq!(lines.len(), code.split('\n').count(), "{}", language);
+
+                for (ind, ln) in lines.iter().enumerate() {
+                    assert!(ln.starts_with(&format!("<span class=\"line_no\">{}</span>", ind + 3)), "{}: {}", language, ln);
+                    assert_eq!(ln.matches("<span").count(), ln.matches("</span>").count(), "{}: {}", language, ln);
+                }
+
+            }
+
+        }
+
+    }
+
+    #[test]
+    fn line_number_layout_test() {
+        let md = "```line_num(0), highlight(1)\na\nb\n```".to_string();
+        let mut option = crate::RenderOption::default();
+
+        option.line_number_layout = crate::LineNumberLayout::Table;
+        let html = "<pre><code><table class=\"code_table\"><tbody><tr><td class=\"line_no\" data-line-number=\"0\"></td><td class=\"line_content\">a</td></tr><tr class=\"highlighted_line\"><td class=\"line_no\" data-line-number=\"1\"></td><td class=\"line_content\">b</td></tr></tbody></table></code></pre>";
+
+        assert_eq!(
+            html.trim_end_matches('\n'),
+            crate::markdown::render_with_option(&md, &option).unwrap().trim_end_matches('\n')
+        );
+
+        option.line_number_layout = crate::LineNumberLayout::Counter;
+        let html = "<pre><code class=\"line_numbers\" style=\"counter-reset: line_no -1\"><span class=\"line\">a</span>\n<span class=\"line highlighted_line\">b</span></code></pre>";
+
+        assert_eq!(
+            html.trim_end_matches('\n'),
+            crate::markdown::render_with_option(&md, &option).unwrap().trim_end_matches('\n')
+        );
+
+        // the numbers would overflow `i32`
+        for layout in [crate::LineNumberLayout::Inline, crate::LineNumberLayout::Table, crate::LineNumberLayout::Counter] {
+            option.line_number_layout = layout;
+
+            let (html, diagnostics) = crate::render_with_diagnostics("```line_num(2147483647)\na\nb\n```\n\n```line_num(-2147483648)\na\n```", &option);
+
+            assert_eq!(html, "<pre><code>a\nb</code></pre>\n\n<pre><code>a</code></pre>\n");
+            assert_eq!(
+                diagnostics.iter().map(|d| d.to_string()).collect::<Vec<String>>(),
+                vec![
+                    "1:1: warning: `line_num(2147483647)` is out of range, the lines are not numbered",
+                    "6:1: warning: `line_num(-2147483648)` is out of range, the lines are not numbered"
+                ]
+            );
+        }
+
+        // the last line is `i32::MAX`
+        assert!(crate::markdown::render_with_option("```line_num(2147483646)\na\nb\n```", &option).unwrap().contains("counter-reset: line_no 2147483645"));
+    }
+
+    #[test]
+    fn copy_button_test() {
+        let md = "```none, line_num(1)\na\n  &b\n```".to_string();
+        let html = "<div class=\"code_block\" data-language=\"none\"><div class=\"code_header\"><span class=\"code_language\">none</span><button class=\"copy_button\" data-code=\"a\n  &amp;b\" onclick=\"navigator.clipboard.writeText(this.dataset.code)\">Copy</button></div><pre><code><span class=\"line_no\">1</span>a\n<span class=\"line_no\">2</span>  &amp;b</code></pre></div>";
+
+        let mut option = crate::RenderOption::default();
+        option.code_copy_button = true;
+
+        assert_eq!(
+            html.trim_end_matches('\n'),
+            crate::markdown::render_with_option(&md, &option).unwrap().trim_end_matches('\n')
+        );
+    }
+
+    #[test]
+    fn code_highlight_test() {
+        let md = "```rust\n/* a\nb */\n```".to_string();
+        let html = "<pre class=\"code\"><code><span class=\"source rust\"><span class=\"comment block rust\"><span class=\"punctuation definition comment rust\">/*</span> a</span></span>\n<span class=\"source rust\"><span class=\"comment block rust\">b <span class=\"punctuation definition comment rust\">*/</span></span></span></code></pre>";
+
+        let mut option = crate::RenderOption::default();
+        option.code_highlight = crate::CodeHighlight::CssClass;
+
+        assert_eq!(
+            html.trim_end_matches('\n'),
+            crate::markdown::render_with_option(&md, &option).unwrap().trim_end_matches('\n')
+        );
+
+        assert!(crate::syntax_theme_names(&option).contains(&"InspiredGitHub".to_string()));
+        assert!(crate::syntax_theme_css("InspiredGitHub", &option).unwrap().starts_with("/*"));
+        assert!(crate::syntax_theme_css("not a theme", &option).is_none());
+    }
+
+    #[test]
+    fn default_stylesheet_test() {
+        use crate::{ColorScheme, LineNumberLayout, RenderOption};
+
+        let tags = ["aqua", "blue", "green", "lime", "orange", "red", "violet", "white", "yellow", "big", "medium", "small", "left", "center", "right", "box"].iter().map(
+            |tag| format!("[[{}]]a[[/{}]]", tag, tag)
+        ).collect::<Vec<String>>().join(" ");
+        let md = format!("{} `b` :k:\n\n- [ ] c\n- [X] d\n\n| e | f | g |\n|:--|:-:|--:|\n| 1 | 2 | 3 |\n\n```none, line_num(1), highlight(1), diff, title(h)\n+i\n-j\n```", tags);
+
+        let mut option = RenderOption::default();
+        option.code_highlight = crate::CodeHighlight::CssClass;
+        option.code_copy_button = true;
+        option.custom_emoji.insert(String::from("k"), String::from("k.png"));
+
+        for (class_prefix, layout) in [("", LineNumberLayout::Inline), ("", LineNumberLayout::Table), ("hmd-", LineNumberLayout::Counter), ("hmd-", LineNumberLayout::Table)] {
+            option.class_prefix = class_prefix.to_string();
+            option.line_number_layout = layout;
+
+            let html = crate::markdown::render_with_option(&md, &option).unwrap();
+
+            // `code` is styled by `syntax_theme_css`
+            for class in html.split("class=\"").skip(1).flat_map(|attribute| attribute[..attribute.find('"').unwrap()].split(' ')).filter(|class| *class != "code") {
+
+                for color_scheme in [ColorScheme::Light, ColorScheme::Dark] {
+                    let css = crate::default_stylesheet(color_scheme, class_prefix);
+                    let selector = format!(".{}", class);
+
+                    assert!(
+                        css.match_indices(&selector).any(|(index, _)| !css[index + selector.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '-')),
+                        "no rule for `{}`", selector
+                    );
+                }
+
+            }
+
+        }
+
+    }
+
+    #[test]
+    fn class_prefix_test() {
+        let md = "[[box]]\n\n[[red]]a[[/red]]\n\n[[/box]]\n\n```rust, line_num(1)\nfn main() {}\n```\n\n| a |\n|:-:|\n| 1 |";
+        let classes = ["box", "font_red", "line_no", "align_center"];
+
+        let mut option = crate::RenderOption::default();
+        option.class_prefix = String::from("hmd-");
+        let html = crate::markdown::render_with_option(md, &option).unwrap();
+
+        for class in classes {
+            assert!(html.contains(&format!("class=\"hmd-{}\"", class)), "{}", class);
+            assert!(!html.contains(&format!("class=\"{}\"", class)), "{}", class);
+        }
+
+        // the default prefix is empty
+        let html = crate::markdown::render(md).unwrap();
+
+        for class in classes {
+            assert!(html.contains(&format!("class=\"{}\"", class)), "{}", class);
+        }
+
+        assert!(!html.contains("hmd-"));
+    }
+
+    #[test]
+    fn inline_style_test() {
+        let md = "[[red]]a[[/red]] [[big]]b[[/big]]\n\n[[center]]\n\n[[box]]c[[/box]]\n\n[[/center]]\n\n- [ ] d\n- [X] e";
+        let html = "<p><span style=\"color:rgb(192, 32, 32)\">a</span> <span style=\"font-size:1.5em\">b</span></p>\n\n<div style=\"text-align:center\">\n\n<p><div style=\"border:1px solid rgb(128, 128, 128);border-radius:4px;margin:0.5em 0;padding:0.5em 1em\">c</div></p>\n\n</div>\n\n<ul><li><div style=\"border-radius:3px;display:inline-block;height:1em;margin-right:0.4em;position:relative;vertical-align:middle;width:1em;border:2px solid rgb(128, 128, 128)\"></div> d</li><li><div style=\"border-radius:3px;display:inline-block;height:1em;margin-right:0.4em;position:relative;vertical-align:middle;width:1em;background-color:rgb(47, 129, 247);border:2px solid rgb(47, 129, 247)\"><span style=\"border:solid rgb(255, 255, 255);border-width:0 2px 2px 0;height:0.6em;left:0.3em;position:absolute;top:0.05em;transform:rotate(45deg);width:0.3em\"></span></div> e</li></ul>";
+
+        let mut option = crate::RenderOption::default();
+        option.element_style = crate::ElementStyle::InlineStyle;
+
+        assert_eq!(
+            html,
+            crate::markdown::render_with_option(md, &option).unwrap().trim_end_matches('\n')
+        );
+        assert!(!html.contains("class="));
+    }
+
+    #[test]
+    fn custom_syntax_test() {
+        let syntax_dir = std::env::temp_dir().join("hmd_custom_syntax_test");
+        std::fs::create_dir_all(&syntax_dir).unwrap();
+        std::fs::write(
+            syntax_dir.join("dsl.sublime-syntax"),
+            "%YAML 1.2\n---\nname: Dsl\nfile_extensions: [dsl]\nscope: source.dsl\ncontexts:\n  main:\n    - match: '\\bfoo\\b'\n      scope: keyword.dsl\n"
+        ).unwrap();
+
+        let mut option = crate::RenderOption::default();
+        option.code_highlight = crate::CodeHighlight::CssClass;
+        option.load_syntax_dir(&syntax_dir).unwrap();
+        option.language_aliases.insert("mydsl".to_string(), "Dsl".to_string());
+
+        let md = "```mydsl\nfoo bar\n```".to_string();
+        let html = "<pre class=\"code\"><code><span class=\"source dsl\"><span class=\"keyword dsl\">foo</span> bar</span></code></pre>";
+
+        assert_eq!(
+            html.trim_end_matches('\n'),
+            crate::markdown::render_with_option(&md, &option).unwrap().trim_end_matches('\n')
+        );
+
+        assert!(option.load_syntax_dir(syntax_dir.join("not_a_dir")).is_err());
+    }
+
+    #[test]
+    fn custom_theme_test() {
+        let theme_dir = std::env::temp_dir().join("hmd_custom_theme_test");
+        std::fs::create_dir_all(&theme_dir).unwrap();
+        std::fs::write(
+            theme_dir.join("Mine.tmTheme"),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<plist version=\"1.0\"><dict><key>name</key><string>Mine</string><key>settings</key><array><dict><key>settings</key><dict><key>foreground</key><string>#123456</string></dict></dict></array></dict></plist>"
+        ).unwrap();
+
+        let mut option = crate::RenderOption::default();
+
+        assert!(crate::syntax_theme_css("Mine", &option).is_none());
+
+        option.load_theme_dir(&theme_dir).unwrap();
+
+        assert_eq!(crate::syntax_theme_names(&option)[0], "Mine");
+        assert!(crate::syntax_theme_names(&option).contains(&"InspiredGitHub".to_string()));
+        assert!(crate::syntax_theme_css("Mine", &option).unwrap().contains("#123456"));
+
+        // a misspelled theme falls back to the default one, with a warning
+        option.theme = String::from("Mine");
+        assert_eq!(crate::render_with_diagnostics("```rust\nfn main() {}\n```", &option).1, vec![]);
+
+        option.theme = String::from("Mnie");
+        let (html, diagnostics) = crate::render_with_diagnostics("```rust\nfn main() {}\n```", &option);
+
+        assert_eq!(html, crate::render_with_option(String::from("```rust\nfn main() {}\n```"), &crate::RenderOption::default()));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].to_string(), "0:0: warning: no theme named `Mnie`, `base16-eighties.dark` is used instead");
+    }
+
     #[test]
     fn blockquote_test() {
         let md = "> 1\n> 1\n>> 2\n>> 2\n 2\n>> 2\n>>> 3\n\n > 1\n1\n > 1".to_string();
@@ -93,6 +464,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn nested_blockquote_test() {
+        let md = "> # a\n>\n> - b\n> - c\n>\n> ```\n> *d*\n> ```\n>\n> | e |\n> |---|\n>> f\ng\n>\n> h\n\n> i\n>\nj";
+        let html = "<blockquote><h1 id=\"a\">a</h1><ul><li>b</li><li>c</li></ul><pre><code>*d*</code></pre><table><thead><th class=\"align_left\"> e </th></thead><tbody></tbody></table><blockquote>f g </blockquote><p>h</p></blockquote>\n\n<blockquote>i </blockquote>\n<p>j</p>";
+
+        assert_eq!(
+            html,
+            crate::markdown::render(md).unwrap().trim_end_matches('\n')
+        );
+    }
+
+    #[test]
+    fn table_test() {
+        let md = "| a | `b|c` | d \\| e |\n|:--|:-:|--:|\n| 1 | < | 2 |\n| 3 |\nTable: *caption*\n\n| < | f |\n|---|---|";
+        let html = "<table><caption><em>caption</em></caption><thead><th class=\"align_left\"> a </th><th class=\"align_center\"> <code class=\"short\">b|c</code> </th><th class=\"align_right\"> d | e </th></thead><tbody><tr><td class=\"align_left\" colspan=\"2\"> 1 </td><td class=\"align_right\"> 2 </td></tr><tr><td class=\"align_left\"> 3 </td><td class=\"align_center\"></td><td class=\"align_right\"></td></tr></tbody></table>\n\n<table><thead><th class=\"align_left\"> &lt; </th><th class=\"align_left\"> f </th></thead><tbody></tbody></table>";
+        let (rendered, diagnostics) = crate::render_with_diagnostics(md, &crate::RenderOption::default());
+
+        assert_eq!(html, rendered.trim_end_matches('\n'));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].to_string(), "4:1: warning: the table has 3 columns, but this row has 1 cell");
+
+        let (_, diagnostics) = crate::render_with_diagnostics("| a |\n|---|\n| 1 | 2 |", &crate::RenderOption::default());
+        assert_eq!(diagnostics[0].to_string(), "3:1: warning: the table has 1 column, but this row has 2 cells");
+
+        // a non-breaking space is not an indentation, so the line is a paragraph
+        let (gfm, diagnostics) = crate::to_gfm("\u{a0}| a | < |");
+        assert_eq!(gfm, "\u{a0}| a | < |");
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn table_alignment_test() {
+        let md = "| a | < | b |\n|:-:|---|--:|\n| 1 | 2 | 3 |";
+        let html = "<table><colgroup><col style=\"text-align:center\"><col style=\"text-align:left\"><col style=\"text-align:right\"></colgroup><thead><th style=\"text-align:center\" colspan=\"2\"> a </th><th style=\"text-align:right\"> b </th></thead><tbody><tr><td style=\"text-align:center\"> 1 </td><td style=\"text-align:left\"> 2 </td><td style=\"text-align:right\"> 3 </td></tr></tbody></table>";
+
+        let mut option = crate::RenderOption::default();
+        option.table_alignment_style = crate::TableAlignmentStyle::InlineStyle;
+        option.table_colgroup = true;
+
+        assert_eq!(
+            html,
+            crate::markdown::render_with_option(md, &option).unwrap().trim_end_matches('\n')
+        );
+    }
+
+    #[test]
+    fn multi_line_cell_test() {
+        let md = "| a | b |\n|---|:-:|\n| - c | d |\n+| - e |   |\n+|     | f |\n| ``` | g |\n+| *h* |\n+| ``` |\n+| i\n\n+| j |";
+        let html = "<table><thead><th class=\"align_left\"> a </th><th class=\"align_center\"> b </th></thead><tbody><tr><td class=\"align_left\"><ul><li>c</li><li>e</li></ul></td><td class=\"align_center\"><p>d</p><p>f</p></td></tr><tr><td class=\"align_left\"><pre><code>*h*</code></pre></td><td class=\"align_center\">g</td></tr></tbody></table>\n<p>+| i</p>\n\n<p>+| j |</p>";
+        let (rendered, diagnostics) = crate::render_with_diagnostics(md, &crate::RenderOption::default());
+
+        assert_eq!(html, rendered.trim_end_matches('\n'));
+
+        // continued rows may leave the other cells out
+        assert!(diagnostics.is_empty());
+    }
+
     #[test]
     fn codespan_test() {
         let md = "You cannot use HTML character entities (like `&nbsp`, `&lt`, and ...)".to_string();
@@ -115,6 +543,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn definition_list_test() {
+        let md = "Apple\n: A *red* fruit\n: A company\nRust\n: A language\n\nOrange\n\n:   A fruit\n\n    - a\n    - b\n\nJust a paragraph\nmore text\n: not a term\n\n: not a definition";
+        let html = "<dl><dt>Apple</dt><dd><p>A <em>red</em> fruit</p></dd><dd><p>A company</p></dd><dt>Rust</dt><dd><p>A language</p></dd><dt>Orange</dt><dd><p>A fruit</p><ul><li>a</li><li>b</li></ul></dd></dl>\n\n<p>Just a paragraph more text : not a term</p>\n\n<p>: not a definition</p>";
+
+        assert_eq!(
+            html,
+            crate::markdown::render(md).unwrap().trim_end_matches('\n')
+        );
+
+        // the contents move with the marker
+        assert_eq!(
+            crate::format("Orange\n\n:   A fruit\n\n    - a\n      - b"),
+            "Orange\n\n: A fruit\n\n  - a\n    - b\n"
+        );
+    }
+
+    #[test]
+    fn ordered_list_start_test() {
+        let md = "3. a\n4. a\n  c. b\n    iv. c\n      XII. d\n  d. b\n\nx\n\n10. e\n\nv. f\nw. f\n\nic. not a list\n\niiii. not a list";
+        let html = "<ol type=\"1\" start=\"3\"><li>a</li><li>a<ol type=\"a\" start=\"3\"><li>b<ol type=\"i\" start=\"4\"><li>c<ol type=\"I\" start=\"12\"><li>d</li></ol></li></ol></li><li>b</li></ol></li></ol>\n\n<p>x</p>\n\n<ol type=\"1\" start=\"10\"><li>e</li></ol>\n\n<ol type=\"a\" start=\"22\"><li>f</li><li>f</li></ol>\n\n<p>ic. not a list</p>\n\n<p>iiii. not a list</p>";
+
+        assert_eq!(
+            html,
+            crate::markdown::render(md).unwrap().trim_end_matches('\n')
+        );
+
+        // sentences beginning with an initial or a roman-looking word are not lists
+        for md in ["e. g. text", "P. S. note", "A. Smith wrote", "a. lone item", "mix. foo", "dix. foo", "xl. foo", "> e. g. text", "- note\n  A. Smith wrote"] {
+            assert!(!crate::markdown::render(md).unwrap().contains("<ol"), "{}", md);
+        }
+
+        // more subtractions than the value
+        for md in ["iiiiiiv. x", "IIIIIIV. x"] {
+            assert_eq!(crate::markdown::render(md).unwrap().trim_end_matches('\n'), format!("<p>{}</p>", md));
+            assert!(crate::lint(md).is_empty());
+            assert_eq!(crate::format(md), format!("{}\n", md));
+        }
+
+        // a sequence of letters is a list, even in a blockquote
+        assert_eq!(
+            crate::markdown::render("> A. Smith wrote\n> B. Jones replied").unwrap().trim_end_matches('\n'),
+            "<blockquote><ol type=\"A\"><li>Smith wrote</li><li>Jones replied</li></ol></blockquote>"
+        );
+    }
+
+    #[test]
+    fn loose_list_test() {
+        let md = "- a\n  b\n\n  c\n\n  > d\n\n  ```\n  e\n\n  ```\n- f\n\n- g\n  - h\n\n    i\n  j\n\n1. k\n| l |\n|---|";
+        let html = "<ul><li><p>a b</p><p>c</p><blockquote>d </blockquote><pre><code>e\n</code></pre></li><li><p>f</p></li><li><p>g</p><ul><li><p>h</p><p>i j</p></li></ul></li></ul>\n\n<ol type=\"1\"><li>k</li></ol>\n<table><thead><th class=\"align_left\"> l </th></thead><tbody></tbody></table>";
+
+        assert_eq!(
+            html,
+            crate::markdown::render(md).unwrap().trim_end_matches('\n')
+        );
+
+        // the formatter keeps the contents in their items
+        assert_eq!(crate::format(md), "- a\n  b\n\n  c\n\n  > d\n\n  ```\n  e\n\n  ```\n- f\n\n- g\n  - h\n\n    i\n    j\n\n1. k\n\n| l |\n|---|\n");
+
+        // an empty line between items of the same kind makes a single loose list
+        let md = "- a\n\n- b\n\n1. c\n\n   2. d\n\n3. e";
+        let html = "<ul><li><p>a</p></li><li><p>b</p></li></ul>\n\n<ol type=\"1\"><li><p>c</p><ol type=\"1\" start=\"2\"><li>d</li></ol></li><li><p>e</p></li></ol>";
+
+        assert_eq!(html, crate::markdown::render(md).unwrap().trim_end_matches('\n'));
+        assert_eq!(html, crate::markdown::render(&crate::format(md)).unwrap().trim_end_matches('\n'));
+    }
+
     #[test]
     fn inline_test() {
         let md = "*italic* **bold** ~~del~~ ^sup^ ~sub~ ~_underline_~".to_string();
@@ -132,6 +627,331 @@ mod tests {
             html.trim_end_matches('\n'),
             crate::markdown::render(&md).unwrap().trim_end_matches('\n')
         );
+
+        // delimiters overlapping the ones they begin with
+        for md in ["a***b", "~~~a", "~_~)"] {
+            assert_eq!(format!("<p>{}</p>", md), crate::markdown::render(md).unwrap().trim_end_matches('\n'));
+        }
+    }
+
+    #[test]
+    fn emoji_test() {
+        let md = "# 😀 emoji 한글\n\n**👍🏽** ~😀~ [[red]]🎉[[/red]] `😀`\n\n![😀 smile](a.png)".to_string();
+        let html = "<h1 id=\"emoji한글\">😀 emoji 한글</h1>\n\n<p><strong>👍🏽</strong> <sub>😀</sub> <span class=\"font_red\">🎉</span> <code class=\"short\">😀</code></p>\n\n<p><img src=\"a.png\" alt=\"smile\" title=\"smile\"></p>";
+
+        assert_eq!(
+            html.trim_end_matches('\n'),
+            crate::markdown::render(&md).unwrap().trim_end_matches('\n')
+        );
+    }
+
+    #[test]
+    fn emoji_shortcode_test() {
+        let md = "# :tada: a\n\n:smile: `:smile:` [:+1:](https://a.b/:smile:) 10:30:00 :unknown: :shipit: :ferris: \\:smile:\n\n| a |\n|:-:|\n\n```\n:smile:\n```";
+        let html = "<h1 id=\"a\">🎉 a</h1>\n\n<p>😄 <code class=\"short\">:smile:</code> <a href=\"https://a.b/:smile:\">👍</a> 10:30:00 :unknown: 🐿️ <img class=\"emoji\" src=\"/ferris.png\" alt=\":ferris:\"> :smile:</p>\n\n<table><thead><th class=\"align_center\"> a </th></thead><tbody></tbody></table>\n\n<pre><code>:smile:</code></pre>";
+
+        let mut option = crate::RenderOption::default();
+        option.emoji.insert(String::from("shipit"), String::from("🐿️"));
+        option.custom_emoji.insert(String::from("ferris"), String::from("/ferris.png"));
+
+        assert_eq!(
+            html,
+            crate::markdown::render_with_option(md, &option).unwrap().trim_end_matches('\n')
+        );
+    }
+
+    #[test]
+    fn abbreviation_test() {
+        let md = "# HTML\n\nHTML, HTML5 and HTMLs `HTML` [HTML](https://a.b/HTML) *HTML* R&D CSS\n\n```\n*[CSS]: not a definition\n```\n\n*[HTML]: Hyper Text \"Markup\" Language\n*[HTML5]: HTML 5\n*[R&D]: Research & Development";
+        let html = "<h1 id=\"HTML\">HTML</h1>\n\n<p><abbr title=\"Hyper Text &quot;Markup&quot; Language\">HTML</abbr>, <abbr title=\"HTML 5\">HTML5</abbr> and HTMLs <code class=\"short\">HTML</code> <a href=\"https://a.b/HTML\">HTML</a> <em><abbr title=\"Hyper Text &quot;Markup&quot; Language\">HTML</abbr></em> <abbr title=\"Research &amp; Development\">R&amp;D</abbr> <abbr title=\"Cascading Style Sheets\">CSS</abbr></p>\n\n<pre><code>*[CSS]: not a definition</code></pre>";
+
+        let mut option = crate::RenderOption::default();
+        option.abbreviations.insert(String::from("CSS"), String::from("Cascading Style Sheets"));
+
+        assert_eq!(
+            html,
+            crate::markdown::render_with_option(md, &option).unwrap().trim_end_matches('\n')
+        );
+    }
+
+    #[test]
+    fn diagnostic_test() {
+        use crate::{Diagnostic, RenderOption, Severity, Span};
+
+        let md = "| a | b |\n|---|---|\n| 1 | 2 | 3 |\n\n&lt; [[red text\n\n`[[code` [[icon = c, size = big]] [[box]]\n\n```rust\nfn main() {}";
+        let (html, diagnostics) = crate::render_with_diagnostics(md, &RenderOption::default());
+
+        assert_eq!(html, crate::markdown::render(md).unwrap());
+        assert_eq!(diagnostics, vec![
+            Diagnostic {
+                severity: Severity::Warning,
+                span: Span { line: 3, column: 1, offset: 20, len: 13 },
+                message: String::from("the table has 2 columns, but this row has 3 cells")
+            },
+            Diagnostic {
+                severity: Severity::Warning,
+                span: Span { line: 5, column: 6, offset: 40, len: 2 },
+                message: String::from("unterminated tag")
+            },
+            Diagnostic {
+                severity: Severity::Error,
+                span: Span { line: 7, column: 10, offset: 61, len: 24 },
+                message: String::from("icon size should be a number, not `big`")
+            },
+            Diagnostic {
+                severity: Severity::Error,
+                span: Span { line: 9, column: 1, offset: 95, len: 7 },
+                message: String::from("unclosed code fence")
+            }
+        ]);
+
+        assert_eq!(diagnostics[1].to_string(), "5:6: warning: unterminated tag");
+        assert_eq!(&md[61..85], "[[icon = c, size = big]]");
+        assert_eq!(&md[95..102], "```rust");
+    }
+
+    #[test]
+    fn inline_span_test() {
+        use crate::{InlineKind, RenderOption};
+
+        let md = "# `code` {#id}\n\n- *a* **b** ~~c~~ ~_d_~\n- ~e~ ^f^ [[red]]g[[/red]]\n\n> [:smile: *h*](i) ![j](k)\n\n| 한글 *l* | m |\n|---|---|\n\n[[center]]\n\n```\n*n*\n```";
+        let spans = crate::inline_spans(md, &RenderOption::default());
+
+        assert_eq!(
+            spans.iter().map(|s| (s.kind, &md[s.span.offset..s.span.offset + s.span.len])).collect::<Vec<(InlineKind, &str)>>(),
+            vec![
+                (InlineKind::CodeSpan, "`code`"),
+                (InlineKind::Italic, "*a*"),
+                (InlineKind::Bold, "**b**"),
+                (InlineKind::Del, "~~c~~"),
+                (InlineKind::Underline, "~_d_~"),
+                (InlineKind::Subscript, "~e~"),
+                (InlineKind::Superscript, "^f^"),
+                (InlineKind::Tag, "[[red]]"),
+                (InlineKind::Tag, "[[/red]]"),
+                (InlineKind::Link, "[:smile: *h*](i)"),
+                (InlineKind::Emoji, ":smile:"),
+                (InlineKind::Italic, "*h*"),
+                (InlineKind::Image, "![j](k)"),
+                (InlineKind::Italic, "*l*"),
+                (InlineKind::Tag, "[[center]]")
+            ]
+        );
+
+        // columns are counted in characters
+        assert_eq!(spans[13].span.line, 8);
+        assert_eq!(spans[13].span.column, 6);
+
+        // unknown shortcodes and tags are texts
+        assert!(crate::inline_spans("10:30 :unknown: [[nope]]", &RenderOption::default()).is_empty());
+    }
+
+    #[test]
+    fn lint_test() {
+        use crate::Severity;
+
+        let md = "* a\n2) b\n- c\n1. d\n\nTitle\n=====\n\n_em_ __strong__ *em* snake_case ~_underline_~ `_code_`\n\n<div> &lt; </p> <!-- x --> `<b>`\n\n&nbsp; &#60; R&D &amp;\n\n``code`` `code`\n\n~~~\n\n```\n* _a_ <b>\n```\n\n***";
+        let diagnostics = crate::lint(md);
+
+        assert!(diagnostics.iter().all(|d| d.severity == Severity::Warning));
+        assert_eq!(
+            diagnostics.iter().map(|d| &md[d.span.offset..d.span.offset + d.span.len]).collect::<Vec<&str>>(),
+            vec!["*", "2)", "=====", "_em_", "__strong__", "<div>", "&lt;", "</p>", "<!-- x -->", "&nbsp;", "&#60;", "&amp;", "``", "``", "~~~"]
+        );
+        assert_eq!(diagnostics[2].to_string(), "7:1: warning: setext headings are not supported, use `#` instead");
+
+        assert!(crate::lint("- a\n3. b\n\n# Title\n\n*em* `code`\n\n---").is_empty());
+
+        // escaped underbars are not emphases
+        assert!(crate::lint("\\_not em\\_ \\__not strong\\__").is_empty());
+        assert_eq!(crate::lint("\\\\_em_").len(), 1);
+
+        // gfm thematic breaks are not list bullets
+        assert_eq!(
+            crate::lint("* * *\n\n- - -\n\n_ _ _").iter().map(|d| d.to_string()).collect::<Vec<String>>(),
+            vec![
+                "1:1: warning: thematic breaks cannot contain spaces, use `***` instead",
+                "3:1: warning: thematic breaks cannot contain spaces, use `---` instead",
+                "5:1: warning: thematic breaks cannot contain spaces, use `___` instead"
+            ]
+        );
+
+        // lines in blockquotes are linted too
+        let md = "> * a\n> > _em_\n>\n> ```\n> * code\n> ```";
+        assert_eq!(
+            crate::lint(md).iter().map(|d| (d.span.line, d.span.column, &md[d.span.offset..d.span.offset + d.span.len])).collect::<Vec<(usize, usize, &str)>>(),
+            vec![(1, 3, "*"), (2, 5, "_em_")]
+        );
+    }
+
+    #[test]
+    fn from_gfm_test() {
+        let gfm = "Title\n=====\n\n## Closed ##\n\n* _em_ __strong__ snake_case\n+ b\n   3. c\n   4) d\n\nLine\\\nbreak &nbsp; &copy; &lt;x&gt; &#42;\n\n``code`` ``a`b`` ~del~ <https://a.b> <div>\n\n~~~rust\nlet x = 1;\n~~~\n\n    indented\n\n* * *\n> > _x_";
+        let (hmd, diagnostics) = crate::from_gfm(gfm);
+
+        assert_eq!(
+            hmd,
+            "# Title\n\n## Closed\n\n- *em* **strong** snake_case\n- b\n   3. c\n   4. d\n\nLine  \nbreak [[blank]] &copy; <x> \\*\n\n`code` ``a`b`` ~~del~~ [https://a.b](https://a.b) <div>\n\n```rust\nlet x = 1;\n```\n\n```\nindented\n```\n\n***\n>> *x*"
+        );
+        assert_eq!(
+            diagnostics.iter().map(|d| (d.span.line, d.span.column, &gfm[d.span.offset..d.span.offset + d.span.len])).collect::<Vec<(usize, usize, &str)>>(),
+            vec![(12, 14, "&copy;"), (14, 10, "``a`b``"), (14, 38, "<div>")]
+        );
+
+        // already hmd
+        let md = "# Title\n\n- *em* **strong**\n\n1. a\n1. b\n\n```rust\nlet x = 1;\n```";
+        assert_eq!(crate::from_gfm(md), (md.to_string(), vec![]));
+
+        // underbars in urls are not emphases
+        assert_eq!(crate::from_gfm("[doc](https://a.b/_private_/x) _em_").0, "[doc](https://a.b/_private_/x) *em*");
+        assert_eq!(crate::from_gfm("![](/img/__x__.png) __strong__").0, "![](/img/__x__.png) **strong**");
+        assert_eq!(crate::from_gfm("[a](b_(c)_d) _em_").0, "[a](b_(c)_d) *em*");
+        assert_eq!(crate::from_gfm("<https://a.b/_x_>").0, "[https://a.b/_x_](https://a.b/_x_)");
+        assert_eq!(crate::from_gfm("https://a.b/_x_ www.a.b/_y_ _em_").0, "https://a.b/_x_ www.a.b/_y_ *em*");
+
+        // paragraphs that hmd would read as lists
+        let (hmd, _) = crate::from_gfm("e. g. this is text\nP. S. note\n\na. first\nb. second\n\n> iv. roman");
+        assert_eq!(hmd, "e\\. g. this is text\nP\\. S. note\n\na\\. first\nb\\. second\n\n> iv\\. roman");
+        assert!(!crate::markdown::render(&hmd).unwrap().contains("<ol"));
+    }
+
+    #[test]
+    fn to_gfm_test() {
+        let md = "c. alpha\nd. beta\n- ~_under_~ H~2~O E=mc^2^ ~~del~~ snake_case _lit_ <div> &copy; a ~ b \\q `code_x <b>`\n[[red]]red[[/red]] [[blank]]\npara\n---\n* not a list\n2) not a list\n\n[[center]]\ncentered\n[[/center]]\n\n````rust, line_num(3), title(a.rs)\nlet _x = \"<b>\";\n```";
+        let (gfm, diagnostics) = crate::to_gfm(md);
+
+        assert_eq!(
+            gfm,
+            "3. alpha\n4. beta\n- <u>under</u> H<sub>2</sub>O E=mc<sup>2</sup> ~~del~~ snake_case \\_lit\\_ &lt;div> &amp;copy; a \\~ b q `code_x <b>`\n<span class=\"font_red\">red</span> &nbsp;\npara\n\n---\n\\* not a list\n2\\) not a list\n\n<div align=\"center\">\n\ncentered\n\n</div>\n\n```rust\nlet _x = \"<b>\";\n```"
+        );
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].to_string(), "1:1: warning: gfm has no `c.` bullets, it's exported as `3.`");
+
+        // github strips inline svgs, so icons are images, with the name as the alt text
+        let icon = "[[icon = rust, size = 16, color = red]]";
+        let image = crate::to_gfm(icon).0;
+
+        assert!(image.starts_with("<img src=\"data:image/svg+xml,%3Csvg%20") && image.contains("fill=%22rgb(192,%2032,%2032)%22"));
+        assert!(image.ends_with("\" alt=\"rust\" width=\"16\" height=\"16\">"));
+        assert!(crate::to_gfm("a [[icon = rust]] b").0.contains("fill=%22rgb(128,%20128,%20128)%22"));
+
+        let svg = crate::markdown::render(icon).unwrap();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 128 128\" width=\"16\" height=\"16\">"));
+    }
+
+    #[test]
+    fn format_test() {
+        let md = "\n\n#   Title   \ntext  \nmore  \n|a|b|c|\n|:-|:-:|-:|\n|long cell|x|y|\n- item\n    - nested [[ Big ]]x[[/ Big ]]\n        cont  \n      cont2\n    1.   two\n***\n[[ Box ]]\n> quote  \nlazy\n```rust, line_num(3)  \n  code   \n```\n   # not a heading\n[[icon=rust,size=32]] `[[ Big ]]` [[Foo]]\n\n\n\nend  ";
+        let formatted = crate::format(md);
+
+        assert_eq!(
+            formatted,
+            "# Title\n\ntext  \nmore\n\n| a         | b | c |\n|:----------|:-:|--:|\n| long cell | x | y |\n\n- item\n  - nested [[big]]x[[/big]]\n    cont  \n    cont2\n  1. two\n\n---\n\n[[box]]\n\n> quote  \nlazy\n\n```rust, line_num(3)\n  code   \n```\n\n   # not a heading\n[[icon = rust, size = 32]] `[[ Big ]]` [[Foo]]\n\nend\n"
+        );
+
+        // round trips
+        let readme = include_str!("../README.md");
+        let remove_whitespaces = |s: String| s.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+
+        for md in [md, readme] {
+            let formatted = crate::format(md);
+
+            assert_eq!(crate::format(&formatted), formatted);
+            assert_eq!(
+                remove_whitespaces(crate::markdown::render(md).unwrap()),
+                remove_whitespaces(crate::markdown::render(&formatted).unwrap())
+            );
+        }
+
+    }
+
+    #[test]
+    fn format_round_trip_test() {
+
+        // trailing whitespaces that the parser depends on
+        assert_eq!(crate::format("- [ ] "), "- [ ] \n");
+        assert_eq!(crate::format("iv. [x] "), "iv. [x] \n");
+        assert_eq!(crate::format("[[red]]  "), "[[red]]  \n");
+        assert_eq!(crate::format("  *[HT]: x"), "  *[HT]: x\n");
+        assert_eq!(crate::format("```"), "```");
+        assert_eq!(crate::format("# \t"), "# \t\n");
+        assert_eq!(crate::format("-  "), "-  \n");
+
+        // over-indented backticks in items are texts, and under-indented ones are fences
+        for md in ["- a\n    ```\n    x\n    ```\n", "- a\n\t```\n\tx\n", "- [ ] [[  \n\t```\n", "- a\n ```\n x\n ```\n", "some text\n[[foo]]\nmore\n"] {
+            assert_eq!(crate::markdown::render(&crate::format(md)).unwrap(), crate::markdown::render(md).unwrap(), "{:?}", md);
+        }
+
+        let corpus = [
+            "- [ ] ", "iv. [x] ", "1. [x]  ", "- [ ]", "-   [x] a", "- \t[x] \na", "x\n\n- [ ] \n- [x] a", "- a\n  - [ ] \n  -  ",
+            "- a\n    - b\n  - [x] ", "- a\n    - b\n  -  ", "1.  \n2. [ ] \n\n3.  ", "b. x\na. \t\t",
+            "[[red]]  ", "a  \n[[red]]  ", "- a\n  [[red]]  ", "> a\n>   [[red]]  ", "Orange\n\n: a\n  [[red]]  ",
+            "  *[HT]: x", "text\n  *[HT]: x\n\nHT", "- a\n*[HT]: x\n\nHT", "- a\n\n  *[HT]: x\n\nHT",
+            "```", "```rust\na", "```\na\n\n", "a\n```\n", "> ```\n> a", "- a\n  ```\n  b\n",
+            "# \t", "# a #  ", "-  ", "a. \t", "- ", "1. ", "> ", "> - [ ] ", "> -  ", "> # \t", "> [[red]]  ", "> \t\na", "> > \t\na",
+            "a. b.  \n|-|", ": a  \n: b", "a\n\n\n: b", "  a\n\n: b", "1. a  \nx\n\n|-|",
+            "some text\n[[foo]]\nmore", "x  \n[[icon=\\#]]", ":   \n[[icon=\\#]]",
+            "- a\n    ```\n    x\n    ```", "- [ ] [[  \n\t```  \n", "- a\n\t```\n\tx", "- a\n ```\n x\n ```", "Orange\n\n: a\n    ```\n    x\n    ```"
+        ];
+        let remove_whitespaces = |s: String| s.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+
+        for md in corpus {
+            let formatted = crate::format(md);
+
+            assert_eq!(crate::format(&formatted), formatted, "{:?}", md);
+            assert_eq!(
+                remove_whitespaces(crate::markdown::render(md).unwrap()),
+                remove_whitespaces(crate::markdown::render(&formatted).unwrap()),
+                "{:?}", md
+            );
+        }
+
+    }
+
+    #[test]
+    fn writer_test() {
+        use std::fmt;
+
+        let md = "# header\n\n```rust, line_num(1)\nfn main() {}\n```\n\n- a\n- b\n\n> quote\n\n| a | b |\n|---|---|\n| 1 | 2 |".to_string();
+        let html = crate::markdown::render(&md).unwrap();
+
+        let mut bytes = vec![];
+        crate::render_to_writer(&md, &mut bytes).unwrap();
+        assert_eq!(html, String::from_utf8(bytes).unwrap());
+
+        let mut string = String::new();
+        crate::render_to_fmt_writer(&md, &mut string).unwrap();
+        assert_eq!(html, string);
+
+        // the parsed blocks are written one by one
+        struct Blocks(Vec<String>);
+
+        impl fmt::Write for Blocks {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0.push(s.to_string());
+                Ok(())
+            }
+        }
+
+        let mut blocks = Blocks(vec![]);
+        crate::render_to_fmt_writer(&md, &mut blocks).unwrap();
+        assert_eq!(html, blocks.0.concat());
+        assert_eq!(blocks.0.len(), html.matches('\n').count());
+
+        // errors of the writer are returned as they are
+        struct Full;
+
+        impl std::io::Write for Full {
+            fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::new(std::io::ErrorKind::WriteZero, "full"))
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        assert_eq!(crate::render_to_writer(&md, &mut Full).unwrap_err().kind(), std::io::ErrorKind::WriteZero);
     }
 
     #[test]
@@ -0,0 +1,364 @@
+use hmd::{CodeHighlight, ColorScheme, ElementStyle, LineNumberLayout, RenderOption, TableAlignmentStyle};
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::process::exit;
+
+
+const USAGE: &str = "Usage:
+    hmd render <input> <output> [options]
+    hmd lint <input>...
+    hmd from-gfm <input> <output>
+    hmd to-gfm <input> <output>
+    hmd fmt [--check] <input>...
+    hmd theme-css <theme> [--theme-dir <dir>]
+    hmd stylesheet <light|dark> [--class-prefix <prefix>]
+
+Options:
+    --theme <name>          syntax highlighting theme
+    --css-class             highlight fenced codes with CSS classes instead of inline colors
+    --copy-button           add a language label and a copy button to fenced codes
+    --line-numbers <layout> `inline`, `table` or `counter`
+    --syntax-dir <dir>      load `.sublime-syntax` files in <dir>
+    --theme-dir <dir>       load `.tmTheme` files in <dir>
+    --alias <lang>=<syntax> highlight fenced codes of <lang> with <syntax>
+    --table-align <style>   align table cells with `class` or `style` attributes
+    --colgroup              add a `<colgroup>` to tables
+    --inline-styles         style extra tags, task lists and table cells with `style` attributes
+    --class-prefix <prefix> prepend <prefix> to the classes, ex) `hmd-`
+    --no-heading-ids        do not add ids to headings";
+
+
+fn main() {
+
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("render") => render(&args[1..]),
+        Some("lint") => lint(&args[1..]),
+        Some("from-gfm") => convert(&args[1..], "from-gfm", hmd::from_gfm),
+        Some("to-gfm") => convert(&args[1..], "to-gfm", hmd::to_gfm),
+        Some("fmt") => format(&args[1..]),
+        Some("theme-css") => theme_css(&args[1..]),
+        Some("stylesheet") => stylesheet(&args[1..]),
+        _ => Err(String::new())
+    };
+
+    if let Err(e) = result {
+
+        if !e.is_empty() {
+            eprintln!("error: {}\n", e);
+        }
+
+        eprintln!("{}", USAGE);
+        exit(1);
+    }
+
+}
+
+
+fn render(args: &[String]) -> Result<(), String> {
+
+    let mut paths = vec![];
+    let mut option = RenderOption::default();
+    let mut index = 0;
+
+    while index < args.len() {
+
+        match args[index].as_str() {
+            "--css-class" => {
+                option.code_highlight = CodeHighlight::CssClass;
+            }
+            "--copy-button" => {
+                option.code_copy_button = true;
+            }
+            "--line-numbers" => {
+                option.line_number_layout = match get_value(args, index)? {
+                    "inline" => LineNumberLayout::Inline,
+                    "table" => LineNumberLayout::Table,
+                    "counter" => LineNumberLayout::Counter,
+                    layout => {
+                        return Err(format!("unknown line number layout `{}`", layout));
+                    }
+                };
+                index += 1;
+            }
+            "--table-align" => {
+                option.table_alignment_style = match get_value(args, index)? {
+                    "class" => TableAlignmentStyle::CssClass,
+                    "style" => TableAlignmentStyle::InlineStyle,
+                    style => {
+                        return Err(format!("unknown table alignment style `{}`", style));
+                    }
+                };
+                index += 1;
+            }
+            "--colgroup" => {
+                option.table_colgroup = true;
+            }
+            "--inline-styles" => {
+                option.element_style = ElementStyle::InlineStyle;
+                option.table_alignment_style = TableAlignmentStyle::InlineStyle;
+            }
+            "--no-heading-ids" => {
+                option.heading_ids = false;
+            }
+            "--class-prefix" => {
+                option.class_prefix = get_value(args, index)?.to_string();
+                index += 1;
+            }
+            "--theme" => {
+                option.theme = get_value(args, index)?.to_string();
+                index += 1;
+            }
+            "--syntax-dir" => {
+                option.load_syntax_dir(get_value(args, index)?)?;
+                index += 1;
+            }
+            "--theme-dir" => {
+                option.load_theme_dir(get_value(args, index)?)?;
+                index += 1;
+            }
+            "--alias" => {
+                let alias = get_value(args, index)?;
+
+                match alias.split_once('=') {
+                    Some((language, syntax)) => {
+                        option.language_aliases.insert(language.to_string(), syntax.to_string());
+                    }
+                    None => {
+                        return Err(format!("invalid alias `{}`, expected `<lang>=<syntax>`", alias));
+                    }
+                }
+
+                index += 1;
+            }
+            arg if arg.starts_with("--") => {
+                return Err(format!("unknown option `{}`", arg));
+            }
+            arg => {
+                paths.push(arg);
+            }
+        }
+
+        index += 1;
+    }
+
+    if paths.len() != 2 {
+        return Err(String::from("`render` takes an input and an output"));
+    }
+
+    let input = read_file(paths[0])?;
+    let (output, diagnostics) = hmd::render_with_diagnostics(&input, &option);
+
+    // input.md:3:14: warning: unterminated tag
+    for diagnostic in diagnostics.iter() {
+        eprintln!("{}:{}", paths[0], diagnostic);
+    }
+
+    write_file(paths[1], |f| f.write_all(output.as_bytes()))
+}
+
+
+// exits with 1 if anything is found, so that it can be used in CI
+fn lint(args: &[String]) -> Result<(), String> {
+
+    if args.is_empty() {
+        return Err(String::from("`lint` takes at least one input"));
+    }
+
+    let mut found = false;
+
+    for path in args.iter() {
+
+        for diagnostic in hmd::lint(&read_file(path)?).iter() {
+            println!("{}:{}", path, diagnostic);
+            found = true;
+        }
+
+    }
+
+    if found {
+        exit(1);
+    }
+
+    Ok(())
+}
+
+
+// rewrites the files in place
+// with `--check`, it prints the files that are not formatted and exits with 1 instead
+fn format(args: &[String]) -> Result<(), String> {
+
+    let is_check = args.iter().any(|arg| arg == "--check");
+    let paths = args.iter().filter(|arg| *arg != "--check").collect::<Vec<&String>>();
+
+    if paths.is_empty() {
+        return Err(String::from("`fmt` takes at least one input"));
+    }
+
+    let mut found = false;
+
+    for path in paths.into_iter() {
+        let content = read_file(path)?;
+        let formatted = hmd::format(&content);
+
+        if formatted == content {
+            continue;
+        }
+
+        if is_check {
+            println!("{}", path);
+            found = true;
+        }
+
+        else {
+            write_file(path, |f| f.write_all(formatted.as_bytes()))?;
+        }
+
+    }
+
+    if found {
+        exit(1);
+    }
+
+    Ok(())
+}
+
+
+// `from-gfm` and `to-gfm`
+fn convert(args: &[String], command: &str, f: fn(&str) -> (String, Vec<hmd::Diagnostic>)) -> Result<(), String> {
+
+    if args.len() != 2 {
+        return Err(format!("`{}` takes an input and an output", command));
+    }
+
+    let (output, diagnostics) = f(&read_file(&args[0])?);
+
+    for diagnostic in diagnostics.iter() {
+        eprintln!("{}:{}", args[0], diagnostic);
+    }
+
+    write_file(&args[1], |f| f.write_all(output.as_bytes()))
+}
+
+
+// `--theme-dir` loads custom themes, which can be exported as well as the bundled ones
+fn theme_css(args: &[String]) -> Result<(), String> {
+
+    let mut option = RenderOption::default();
+    let mut theme = None;
+    let mut index = 0;
+
+    while index < args.len() {
+
+        match args[index].as_str() {
+            "--theme-dir" => {
+                option.load_theme_dir(get_value(args, index)?)?;
+                index += 1;
+            }
+            arg if arg.starts_with("--") => {
+                return Err(format!("unknown option `{}`", arg));
+            }
+            arg if theme.is_none() => {
+                theme = Some(arg);
+            }
+            _ => {
+                return Err(String::from("`theme-css` takes a theme name"));
+            }
+        }
+
+        index += 1;
+    }
+
+    let theme = theme.ok_or_else(|| String::from("`theme-css` takes a theme name"))?;
+
+    match hmd::syntax_theme_css(theme, &option) {
+        Some(css) => {
+            print!("{}", css);
+            Ok(())
+        }
+        None => Err(format!(
+            "no theme named `{}`, available themes are: {}",
+            theme,
+            hmd::syntax_theme_names(&option).join(", ")
+        ))
+    }
+
+}
+
+
+fn stylesheet(args: &[String]) -> Result<(), String> {
+
+    let mut color_scheme = None;
+    let mut class_prefix = "";
+    let mut index = 0;
+
+    while index < args.len() {
+
+        match args[index].as_str() {
+            "--class-prefix" => {
+                class_prefix = get_value(args, index)?;
+                index += 1;
+            }
+            "light" if color_scheme.is_none() => {
+                color_scheme = Some(ColorScheme::Light);
+            }
+            "dark" if color_scheme.is_none() => {
+                color_scheme = Some(ColorScheme::Dark);
+            }
+            _ => {
+                return Err(String::from("`stylesheet` takes `light` or `dark`"));
+            }
+        }
+
+        index += 1;
+    }
+
+    match color_scheme {
+        Some(color_scheme) => {
+            print!("{}", hmd::default_stylesheet(color_scheme, class_prefix));
+            Ok(())
+        }
+        None => Err(String::from("`stylesheet` takes `light` or `dark`"))
+    }
+
+}
+
+
+fn get_value(args: &[String], index: usize) -> Result<&str, String> {
+
+    match args.get(index + 1) {
+        Some(value) => Ok(value.as_str()),
+        None => Err(format!("`{}` requires a value", args[index]))
+    }
+
+}
+
+
+fn read_file(path: &str) -> Result<String, String> {
+
+    let mut content = String::new();
+
+    File::open(path).and_then(
+        |mut f| f.read_to_string(&mut content)
+    ).map_err(
+        |e| format!("cannot read `{}`: {}", path, e)
+    )?;
+
+    Ok(content)
+}
+
+
+// the output is streamed to the file by `write`
+fn write_file<F: FnOnce(&mut BufWriter<File>) -> io::Result<()>>(path: &str, write: F) -> Result<(), String> {
+
+    File::create(path).and_then(
+        |f| {
+            let mut f = BufWriter::new(f);
+            write(&mut f)?;
+            f.flush()
+        }
+    ).map_err(
+        |e| format!("cannot write `{}`: {}", path, e)
+    )
+}
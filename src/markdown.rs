@@ -1,48 +1,172 @@
 mod line;
+mod abbreviation;
+mod attribute;
 mod predicates;
 mod code_fence;
 mod list;
 mod table;
 mod blockquote;
+mod definition;
 mod paragraph;
 mod inline;
 mod escape;
 mod syntax_highlighter;
 mod icon;
+mod diagnostic;
+mod lint;
+mod from_gfm;
+mod to_gfm;
+mod formatter;
+mod stylesheet;
 
-use escape::*;
 use inline::render_inlines;
-use inline::tag::render_independent_tag;
-use line::{code_to_lines, render_lines};
+use inline::span::collect_inline_spans;
+use inline::tag::{check_tags, render_independent_tag};
+use line::{Line, code_to_lines, render_lines, write_lines};
 use code_fence::merge_code_fence;
 use paragraph::merge_paragraph;
 use table::merge_table;
 use blockquote::merge_blockquote;
 use list::merge_list;
+use definition::merge_definition_list;
+use abbreviation::{Abbreviations, take_abbreviations};
+use syntax_highlighter::{SyntaxHighlighter, has_theme};
+use crate::option::{CodeHighlight, RenderOption, DEFAULT_THEME};
+use std::{fmt, io};
 
+pub use syntax_highlighter::{theme_css, theme_names};
+pub use diagnostic::{Diagnostic, Severity, Span};
+pub use inline::span::{InlineKind, InlineSpan};
+pub use from_gfm::from_gfm;
+pub use to_gfm::to_gfm;
+pub use formatter::format;
+pub use stylesheet::stylesheet;
 
-pub fn render(content: &String) -> Result<String, ()> {
 
-    let syntax_highlighter = self::syntax_highlighter::SyntaxHighlighter::new();
+pub fn render(content: &str) -> Result<String, ()> {
+    render_with_option(content, &RenderOption::default())
+}
 
-    let mut u16_content = content.encode_utf16().collect::<Vec<u16>>();
-    u16_content = escape_htmls(&u16_content);
 
-    let mut lines = code_to_lines(&u16_content);
-    lines = merge_code_fence(&lines, &syntax_highlighter);
-    lines = render_independent_tag(&lines);
-    lines = render_inlines(lines);
+pub fn render_with_option(content: &str, option: &RenderOption) -> Result<String, ()> {
+    Ok(render_lines(&parse(content, option, &mut vec![], None)))
+}
 
-    // `merge_paragraph` must be called after `merge_table`
-    // because unused `TableDelimiter`s must be turned into `Paragraph`s.
-    lines = merge_table(&lines);
-    lines = merge_paragraph(&lines);
 
-    lines = merge_list(&lines);
-    lines = merge_blockquote(&lines);
+pub fn render_with_diagnostics(content: &str, option: &RenderOption) -> (String, Vec<Diagnostic>) {
 
-    let mut result = render_lines(&lines);
-    result = escape_backslashes(&result);
+    let mut diagnostics = vec![];
+    let result = render_lines(&parse(content, option, &mut diagnostics, None));
 
-    Ok(String::from_utf16_lossy(&result))
-}
\ No newline at end of file
+    diagnostics.sort_by_key(|d| d.span.offset);
+
+    (result, diagnostics)
+}
+
+
+// code spans, links, images, emoji, decorations and tags, sorted by their positions
+pub fn inline_spans(content: &str, option: &RenderOption) -> Vec<InlineSpan> {
+
+    let mut spans = vec![];
+    parse(content, option, &mut vec![], Some(&mut spans));
+
+    spans.sort_by_key(|s| s.span.offset);
+
+    spans
+}
+
+
+// the dialect warnings of `lint` and the diagnostics of rendering
+pub fn lint(content: &str) -> Vec<Diagnostic> {
+
+    let (_, mut diagnostics) = render_with_diagnostics(content, &RenderOption::default());
+    diagnostics.append(&mut lint::lint(content));
+
+    diagnostics.sort_by_key(|d| d.span.offset);
+
+    diagnostics
+}
+
+
+pub fn render_to_fmt_writer<W: fmt::Write>(content: &str, option: &RenderOption, writer: &mut W) -> fmt::Result {
+    write_lines(&parse(content, option, &mut vec![], None), writer)
+}
+
+
+pub fn render_to_io_writer<W: io::Write>(content: &str, option: &RenderOption, writer: &mut W) -> io::Result<()> {
+
+    let mut adapter = IoWriter { inner: writer, error: None };
+
+    match write_lines(&parse(content, option, &mut vec![], None), &mut adapter) {
+        Ok(()) => Ok(()),
+
+        // `fmt::Error` doesn't carry the cause, so it's kept in the adapter
+        Err(_) => Err(adapter.error.unwrap_or_else(|| io::Error::other("formatter error")))
+    }
+
+}
+
+
+// `inline_spans` is `None` unless they're asked, walking the inline elements twice is not free
+fn parse(content: &str, option: &RenderOption, diagnostics: &mut Vec<Diagnostic>, inline_spans: Option<&mut Vec<InlineSpan>>) -> Vec<Line> {
+
+    // `CodeHighlight::CssClass` does not use the theme
+    if option.code_highlight == CodeHighlight::InlineStyle && !has_theme(&option.theme, option) {
+        diagnostics.push(Diagnostic::warning(
+            Span::default(),
+            format!("no theme named `{}`, `{}` is used instead", option.theme, DEFAULT_THEME)
+        ));
+    }
+
+    let syntax_highlighter = SyntaxHighlighter::new(option);
+    let mut lines = code_to_lines(content);
+    let abbreviations = take_abbreviations(&mut lines, option);
+
+    parse_blocks(lines, &syntax_highlighter, option, &abbreviations, diagnostics, inline_spans)
+}
+
+
+// contents of list items and blockquotes are parsed recursively
+fn parse_blocks(mut lines: Vec<Line>, syntax_highlighter: &SyntaxHighlighter, option: &RenderOption, abbreviations: &Abbreviations, diagnostics: &mut Vec<Diagnostic>, mut inline_spans: Option<&mut Vec<InlineSpan>>) -> Vec<Line> {
+
+    lines = merge_code_fence(lines, syntax_highlighter, option, diagnostics);
+
+    // definitions, list items, blockquotes and table cells may contain any block, so they're merged before anything is rendered
+    // `merge_definition_list` and `merge_table` turn unused `Definition`s and `TableDelimiter`s into `Paragraph`s
+    lines = merge_definition_list(lines, &mut |definition| parse_blocks(definition, syntax_highlighter, option, abbreviations, diagnostics, inline_spans.as_deref_mut()));
+    lines = merge_list(lines, option, &mut |item| parse_blocks(item, syntax_highlighter, option, abbreviations, diagnostics, inline_spans.as_deref_mut()));
+    lines = merge_blockquote(lines, &mut |quoted| parse_blocks(quoted, syntax_highlighter, option, abbreviations, diagnostics, inline_spans.as_deref_mut()));
+    lines = merge_table(lines, option, &mut |cell, diagnostics| parse_blocks(cell, syntax_highlighter, option, abbreviations, diagnostics, inline_spans.as_deref_mut()), diagnostics);
+
+    // positions of inline elements are lost once they're rendered, so they're found here, on the unrendered contents
+    check_tags(&lines, diagnostics);
+
+    if let Some(inline_spans) = inline_spans {
+        collect_inline_spans(&lines, option, inline_spans);
+    }
+
+    lines = render_independent_tag(lines, option);
+    lines = render_inlines(lines, option, abbreviations);
+
+    merge_paragraph(lines)
+}
+
+
+struct IoWriter<'a, W: io::Write> {
+    inner: &'a mut W,
+    error: Option<io::Error>
+}
+
+
+impl<'a, W: io::Write> fmt::Write for IoWriter<'a, W> {
+
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(
+            |e| {
+                self.error = Some(e);
+                fmt::Error
+            }
+        )
+    }
+
+}
@@ -0,0 +1,172 @@
+use crate::markdown::escape::escape_htmls;
+use crate::markdown::line::{Line, LineType};
+use crate::option::RenderOption;
+
+
+// (term, title), both are html-escaped
+// longer terms come first, so that `HTML5` is not rendered as `HTML` and `5`
+pub type Abbreviations = Vec<(String, String)>;
+
+
+// inside these elements, terms are not abbreviations
+const SKIPPED_TAGS: [&str; 4] = ["a", "abbr", "code", "svg"];
+
+
+// `*[HTML]: Hyper Text Markup Language` -> `Some(("HTML", "Hyper Text Markup Language"))`
+pub fn parse_abbreviation(line: &Line) -> Option<(&str, &str)> {
+
+    if line.indent > 0 || !line.content.starts_with("*[") {
+        return None;
+    }
+
+    let term_end = line.content.find("]:")?;
+    let term = &line.content[2..term_end];
+
+    if term.trim().is_empty() || term.contains('[') {
+        None
+    }
+
+    else {
+        Some((term, line.content[term_end + 2..].trim()))
+    }
+
+}
+
+
+// definitions in the document are removed from `lines`, the ones in fenced codes are not definitions
+// they apply to the whole document, and override `RenderOption::abbreviations`
+pub fn take_abbreviations(lines: &mut Vec<Line>, option: &RenderOption) -> Abbreviations {
+
+    let mut result = option.abbreviations.iter().filter(
+        |(term, _)| !term.is_empty()
+    ).map(
+        |(term, title)| (escape_htmls(term), escape_htmls(title))
+    ).collect::<Abbreviations>();
+
+    let mut is_inside_fence = false;
+
+    lines.retain(
+        |ln| {
+
+            if ln.line_type == LineType::CodeFence {
+                is_inside_fence = !is_inside_fence;
+            }
+
+            if is_inside_fence {
+                return true;
+            }
+
+            match parse_abbreviation(ln) {
+                Some((term, title)) => {
+                    result.retain(|(t, _)| t != term);
+                    result.push((term.to_string(), title.to_string()));
+
+                    false
+                }
+                None => true
+            }
+
+        }
+    );
+
+    result.sort_by_key(|(term, _)| std::cmp::Reverse(term.len()));
+
+    result
+}
+
+
+// `content` is a rendered line, terms are looked up in its texts
+// HTML -> <abbr title="Hyper Text Markup Language">HTML</abbr>
+pub fn render_abbreviations(content: &str, abbreviations: &Abbreviations, buffer: &mut String) {
+
+    let mut skipped_depth = 0;
+    let mut last_index = 0;
+    let mut curr_index = 0;
+
+    while curr_index < content.len() {
+
+        match content.as_bytes()[curr_index] {
+            b'<' => {
+                let tag_end = get_tag_end_index(content, curr_index);
+                let tag = &content[curr_index + 1..tag_end];
+                let name = tag.trim_start_matches('/').split([' ', '>']).next().unwrap_or("");
+
+                if SKIPPED_TAGS.contains(&name) {
+
+                    if tag.starts_with('/') {
+                        skipped_depth -= 1;
+                    }
+
+                    else {
+                        skipped_depth += 1;
+                    }
+
+                }
+
+                curr_index = tag_end + 1;
+                continue;
+            }
+
+            // `&amp;` is not `amp`
+            b'&' => {
+                curr_index += content[curr_index..].find(';').map_or(1, |ind| ind + 1);
+                continue;
+            }
+            _ => {}
+        }
+
+        if skipped_depth == 0 && !is_word_before(content, curr_index) {
+
+            if let Some((term, title)) = abbreviations.iter().find(|(term, _)| is_term_at(content, curr_index, term)) {
+                buffer.push_str(&content[last_index..curr_index]);
+                buffer.push_str(&format!("<abbr title=\"{}\">{}</abbr>", title, term));
+                curr_index += term.len();
+                last_index = curr_index;
+                continue;
+            }
+
+        }
+
+        curr_index += content[curr_index..].chars().next().unwrap().len_utf8();
+    }
+
+    buffer.push_str(&content[last_index..]);
+}
+
+
+// index of the `>`, quoted ones are skipped, ex) `<a href="a>b">`
+fn get_tag_end_index(content: &str, index: usize) -> usize {
+
+    let mut is_quoted = false;
+
+    for (ind, c) in content.bytes().enumerate().skip(index) {
+
+        if c == b'"' {
+            is_quoted = !is_quoted;
+        }
+
+        else if c == b'>' && !is_quoted {
+            return ind;
+        }
+
+    }
+
+    content.len() - 1
+}
+
+
+// a term is a whole word, ex) `HTML` in `HTMLs` is not a term
+fn is_term_at(content: &str, index: usize, term: &str) -> bool {
+
+    content[index..].starts_with(term) && !content[index + term.len()..].chars().next().is_some_and(is_word_character)
+}
+
+
+fn is_word_before(content: &str, index: usize) -> bool {
+    content[..index].chars().next_back().is_some_and(is_word_character)
+}
+
+
+fn is_word_character(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
@@ -0,0 +1,73 @@
+#[derive(Default)]
+pub struct Attributes {
+    pub id: Option<String>,
+    pub classes: Vec<String>
+}
+
+
+impl Attributes {
+
+    // ` id="a" class="b c"`, `classes` come before the ones of the attributes
+    pub fn render(&self, classes: &[&str]) -> String {
+
+        let mut result = String::new();
+
+        if let Some(ref id) = self.id {
+            result.push_str(&format!(" id=\"{}\"", id));
+        }
+
+        let classes = classes.iter().copied().chain(self.classes.iter().map(|class| class.as_str())).collect::<Vec<&str>>();
+
+        if !classes.is_empty() {
+            result.push_str(&format!(" class=\"{}\"", classes.join(" ")));
+        }
+
+        result
+    }
+
+}
+
+
+// `Title {#id .class}` -> `Some((index of the '{', attributes))`
+// `content` must be html-escaped, so that the values don't break the attributes
+// the last id is used when there're many of them, and the braces without `#id` or `.class` are not attributes, ex) `{}`, `{a}`
+pub fn parse_attributes(content: &str) -> Option<(usize, Attributes)> {
+
+    let content = content.trim_end();
+
+    if !content.ends_with('}') {
+        return None;
+    }
+
+    let begin = content.rfind('{')?;
+    let mut result = Attributes::default();
+
+    for token in content[begin + 1..content.len() - 1].split_whitespace() {
+
+        if token.len() < 2 {
+            return None;
+        }
+
+        else if let Some(id) = token.strip_prefix('#') {
+            result.id = Some(id.to_string());
+        }
+
+        else if let Some(class) = token.strip_prefix('.') {
+            result.classes.push(class.to_string());
+        }
+
+        else {
+            return None;
+        }
+
+    }
+
+    if result.id.is_none() && result.classes.is_empty() {
+        None
+    }
+
+    else {
+        Some((begin, result))
+    }
+
+}
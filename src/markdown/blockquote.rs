@@ -1,6 +1,7 @@
-use crate::markdown::line::{Line, LineType};
+use crate::markdown::line::{Line, LineType, render_blocks};
+use crate::markdown::list::demote_stray_bullets;
+use crate::markdown::predicates::get_type;
 use crate::utils::{take_while, drop_while};
-use crate::consts::*;
 
 
 impl Line {
@@ -17,44 +18,47 @@ impl Line {
 }
 
 
-pub fn merge_blockquote(lines: &Vec<Line>) -> Vec<Line> {
+// contents of blockquotes are blocks, `parse_blocks` renders them
+pub fn merge_blockquote(lines: Vec<Line>, parse_blocks: &mut dyn FnMut(Vec<Line>) -> Vec<Line>) -> Vec<Line> {
 
     let mut current_blockquote = vec![];
     let mut result = Vec::with_capacity(lines.len());
 
-    for ln in lines.iter() {
+    for ln in lines.into_iter() {
 
         match ln.line_type {
             LineType::Blockquote(_) => {
                 current_blockquote.push(ln);
             },
-            LineType::Paragraph => {
-
-                if current_blockquote.len() > 0 {
-                    current_blockquote.push(ln);
-                }
-
-                else {
-                    result.push(ln.clone());
-                }
 
+            // laziness, an empty quoted line ends a paragraph
+            // https://github.github.com/gfm/#block-quotes
+            LineType::Paragraph if current_blockquote.last().is_some_and(|last: &Line| !is_empty_blockquote(last)) => {
+                current_blockquote.push(ln);
             },
             _ => {
 
-                if current_blockquote.len() > 0 {
-                    result.push(construct_blockquote(current_blockquote));
-                    current_blockquote = vec![];
+                if !current_blockquote.is_empty() {
+
+                    // `| a |` above `|---|` is the head of a table, not a lazy continuation
+                    let table_head = match current_blockquote.last() {
+                        Some(last) if ln.line_type == LineType::TableDelimiter && last.line_type == LineType::Paragraph => current_blockquote.pop(),
+                        _ => None
+                    };
+
+                    result.push(construct_blockquote(std::mem::take(&mut current_blockquote), parse_blocks));
+                    result.extend(table_head);
                 }
 
-                result.push(ln.clone());
+                result.push(ln);
             }
 
         }
 
     }
 
-    if current_blockquote.len() > 0 {
-        result.push(construct_blockquote(current_blockquote));
+    if !current_blockquote.is_empty() {
+        result.push(construct_blockquote(current_blockquote, parse_blocks));
     }
 
     result
@@ -62,44 +66,64 @@ pub fn merge_blockquote(lines: &Vec<Line>) -> Vec<Line> {
 
 
 pub fn count_blockquote(line: &Line) -> usize {
-    take_while(&line.content, U16_GREATER_THAN).len()
+    take_while(&line.content, '>').len()
 }
 
 
-fn construct_blockquote(lines: Vec<&Line>) -> Line {
+// `>> a`, `> > a` -> `a`
+pub fn get_blockquote_content(content: &str) -> &str {
 
-    let mut current_blockquote_level = 0;
-    let mut content = Vec::with_capacity(lines.len());
+    let mut content = content;
 
-    // laziness
-    // https://github.github.com/gfm/#block-quotes
-    for ln in lines.iter() {
-        let ln_level = ln.get_blockquote_level();
+    while let Some(rest) = content.strip_prefix('>') {
+        content = rest.strip_prefix(' ').unwrap_or(rest);
+    }
 
-        if ln_level > current_blockquote_level {
-            content.push(vec![vec![U16_LESS_THAN, U16_SMALL_B, U16_SMALL_L, U16_SMALL_O, U16_SMALL_C, U16_SMALL_K, U16_SMALL_Q, U16_SMALL_U, U16_SMALL_O, U16_SMALL_T, U16_SMALL_E, U16_GREATER_THAN];ln_level - current_blockquote_level].concat());
-            current_blockquote_level = ln_level;
-        }
+    content
+}
 
-        content.push(drop_while(&drop_while(&ln.content, U16_GREATER_THAN), U16_SPACE));
 
-        if ln.content.len() > 2
-        && ln.content[ln.content.len() - 1] == U16_SPACE
-        && ln.content[ln.content.len() - 2] == U16_SPACE  {
-            content.push(vec![U16_LESS_THAN, U16_SMALL_B, U16_SMALL_R, U16_SPACE, U16_SLASH, U16_GREATER_THAN]);
-        }
+// `>`, `> `
+pub fn is_empty_blockquote(line: &Line) -> bool {
+    matches!(line.line_type, LineType::Blockquote(_)) && drop_while(drop_while(&line.content, '>'), ' ').is_empty()
+}
 
-        else {
-            content.push(vec![U16_SPACE]);
-        }
 
-    }
+fn construct_blockquote(lines: Vec<Line>, parse_blocks: &mut dyn FnMut(Vec<Line>) -> Vec<Line>) -> Line {
 
-    content.push(vec![vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_B, U16_SMALL_L, U16_SMALL_O, U16_SMALL_C, U16_SMALL_K, U16_SMALL_Q, U16_SMALL_U, U16_SMALL_O, U16_SMALL_T, U16_SMALL_E, U16_GREATER_THAN];current_blockquote_level].concat());
+    let span = lines[0].span.join(&lines[lines.len() - 1].span);
+    let mut quoted = lines.into_iter().map(remove_marker).collect::<Vec<Line>>();
+    demote_stray_bullets(&mut quoted);
+
+    let blocks = parse_blocks(quoted);
+
+    let mut content = String::from("<blockquote>");
+
+    // a line of a blockquote has always been followed by a space, ex) `<blockquote>a <blockquote>b </blockquote></blockquote>`
+    render_blocks(&blocks, " ", &mut content);
+    content.push_str("</blockquote>");
 
     Line {
-        content: content.concat(),
+        content,
         indent: 0,
-        line_type: LineType::Blockquote(0)
+        line_type: LineType::RenderedBlockquote,
+        span
     }
-}
\ No newline at end of file
+}
+
+
+// `>> a` -> `> a`, `> a` -> `a`, lazy lines are not changed
+fn remove_marker(line: Line) -> Line {
+
+    if line.get_blockquote_level() == 0 {
+        return line;
+    }
+
+    let content = &line.content[1..];
+    let mut result = Line::from_raw(content.strip_prefix(' ').unwrap_or(content));
+
+    result.span = line.span_of(line.content.len() - result.content.len(), line.content.len());
+    result.line_type = get_type(&result);
+
+    result
+}
@@ -1,77 +1,156 @@
+use crate::markdown::attribute::{Attributes, parse_attributes};
 use crate::markdown::escape::prevent_backslash_escape;
+use crate::markdown::diagnostic::{Diagnostic, Span};
 use crate::markdown::line::{Line, LineType};
 use crate::markdown::syntax_highlighter::SyntaxHighlighter;
-use crate::utils::{drop_while, get_parenthesis_end_index};
-use crate::consts::*;
+use crate::option::{CodeHighlight, LineNumberLayout, RenderOption};
+use crate::utils::drop_while;
 use std::str::FromStr;
 
 
 #[derive(Default)]
 struct CodeFenceOption {
-    language: Option<Vec<u16>>,
+    language: Option<String>,
     line_num: Option<i32>,
+    highlights: Vec<(i32, i32)>,  // inclusive ranges of line numbers
+    diff: bool,
+    title: Option<String>,
+    attributes: Attributes  // of `<pre>`
 }
 
 
-fn parse_code_fence_option(content: &Vec<u16>) -> CodeFenceOption {
+// ```rust, line_num(0), highlight(3, 5-7), diff, title(src/main.rs) {#id .class}
+fn parse_code_fence_option(content: &str) -> CodeFenceOption {
 
-    let mut content = drop_while(content, U16_BACKTICK);
-    content = content.iter().filter(|c| *c != &U16_SPACE).map(|c| *c).collect();
+    let mut content = drop_while(content, '`');
+    let mut result = CodeFenceOption::default();
 
-    let params = content.split(|c| *c == U16_COMMA);
-
-    let mut language = None;
-    let mut line_num = None;
+    if let Some((begin, attributes)) = parse_attributes(content) {
+        content = &content[..begin];
+        result.attributes = attributes;
+    }
+    let mut languages = vec![];
 
-    'outer: for p in params {
-        
-        'inner: for (ind, c) in p.iter().enumerate() {
+    for param in split_params(content) {
+        let param = param.trim();
 
-            if *c == U16_LEFT_PARENTHESIS {
+        match param.find('(') {
+            Some(ind) if param.ends_with(')') => {
+                let args = param[ind + 1..param.len() - 1].trim();
 
-                match get_parenthesis_end_index(&p.to_vec(), ind) {
-                    None => {break 'inner;}
-                    Some(i) => {
-                        match i32::from_str(&String::from_utf16_lossy(&p[ind + 1..i])) {
-                            Err(_) => {break 'inner;}
-                            Ok(n) => {line_num = Some(n); continue 'outer;}
+                match param[..ind].trim() {
+                    "line_num" => {
+                        if let Ok(n) = i32::from_str(args) {
+                            result.line_num = Some(n);
                         }
                     }
+                    "highlight" => {
+                        result.highlights = parse_line_ranges(args);
+                    }
+                    "title" => {
+                        result.title = Some(args.to_string());
+                    }
+                    _ => {}
                 }
 
             }
+            _ => {
+
+                if !param.is_empty() {
+                    languages.push(param.replace(' ', ""));
+                }
 
+            }
         }
 
-        language = Some(p.to_vec());
     }
 
-    CodeFenceOption {
-        language, line_num
+    // `diff` alone is a language, not an option
+    if languages.len() > 1 && languages.iter().any(|l| l == "diff") {
+        result.diff = true;
+        languages.retain(|l| l != "diff");
+    }
+
+    result.language = languages.pop();
+    result
+}
+
+
+// ```rust, line_num(0) -> Some("rust")
+pub fn get_fence_language(content: &str) -> Option<String> {
+    parse_code_fence_option(content).language
+}
+
+
+// splits `content` at commas, except the ones inside parenthesis
+fn split_params(content: &str) -> Vec<&str> {
+
+    let mut result = vec![];
+    let mut depth = 0;
+    let mut last_index = 0;
+
+    for (ind, c) in content.char_indices() {
+
+        match c {
+            '(' => { depth += 1; }
+            ')' => { depth -= 1; }
+            ',' if depth == 0 => {
+                result.push(&content[last_index..ind]);
+                last_index = ind + 1;
+            }
+            _ => {}
+        }
+
     }
+
+    result.push(&content[last_index..]);
+    result
+}
+
+
+// `3, 5-7` -> vec![(3, 3), (5, 7)]
+fn parse_line_ranges(args: &str) -> Vec<(i32, i32)> {
+
+    args.split(',').filter_map(
+        |range| {
+            let range = range.trim();
+
+            match range.split_once('-') {
+                Some((begin, end)) if !begin.is_empty() => match (i32::from_str(begin.trim()), i32::from_str(end.trim())) {
+                    (Ok(begin), Ok(end)) => Some((begin, end)),
+                    _ => None
+                },
+                _ => i32::from_str(range).ok().map(|n| (n, n))
+            }
+
+        }
+    ).collect()
 }
 
 
-pub fn merge_code_fence(lines: &Vec<Line>, syntax_highlighter: &SyntaxHighlighter) -> Vec<Line> {
+pub fn merge_code_fence(lines: Vec<Line>, syntax_highlighter: &SyntaxHighlighter, render_option: &RenderOption, diagnostics: &mut Vec<Diagnostic>) -> Vec<Line> {
 
     let mut curr_fenced_code = vec![];
     let mut is_inside_fence = false;
     let mut result = Vec::with_capacity(lines.len());
     let mut code_fence_option = CodeFenceOption::default();
+    let mut fence_span = Span::default();
 
-    for ln in lines.iter() {
+    for ln in lines.into_iter() {
 
         match ln.line_type {
             LineType::CodeFence => {
 
                 if is_inside_fence {
-                    result.push(construct_code_fence(curr_fenced_code, code_fence_option, syntax_highlighter));
+                    let span = fence_span.join(&ln.span);
+                    result.push(construct_code_fence(curr_fenced_code, code_fence_option, span, syntax_highlighter, render_option, diagnostics));
                     curr_fenced_code = vec![];
                     code_fence_option = CodeFenceOption::default();
                 }
 
                 else {
                     code_fence_option = parse_code_fence_option(&ln.content);
+                    fence_span = ln.span;
                 }
 
                 is_inside_fence = !is_inside_fence;
@@ -79,11 +158,11 @@ pub fn merge_code_fence(lines: &Vec<Line>, syntax_highlighter: &SyntaxHighlighte
             _ => {
 
                 if is_inside_fence {
-                    curr_fenced_code.push(ln.clone());
+                    curr_fenced_code.push(ln);
                 }
 
                 else {
-                    result.push(ln.clone());
+                    result.push(ln);
                 }
 
             }
@@ -91,66 +170,241 @@ pub fn merge_code_fence(lines: &Vec<Line>, syntax_highlighter: &SyntaxHighlighte
 
     }
 
-    if curr_fenced_code.len() > 0 {
-        result.push(construct_code_fence(curr_fenced_code, code_fence_option, syntax_highlighter));
+    // the rest of the document is rendered as a fenced code
+    if is_inside_fence {
+        diagnostics.push(Diagnostic::error(fence_span, String::from("unclosed code fence")));
+    }
+
+    if !curr_fenced_code.is_empty() {
+        let span = fence_span.join(&curr_fenced_code[curr_fenced_code.len() - 1].span);
+        result.push(construct_code_fence(curr_fenced_code, code_fence_option, span, syntax_highlighter, render_option, diagnostics));
     }
 
     result
 }
 
 
-fn construct_code_fence(mut lines: Vec<Line>, mut options: CodeFenceOption, syntax_highlighter: &SyntaxHighlighter) -> Line {
+fn construct_code_fence(mut lines: Vec<Line>, mut options: CodeFenceOption, span: Span, syntax_highlighter: &SyntaxHighlighter, render_option: &RenderOption, diagnostics: &mut Vec<Diagnostic>) -> Line {
 
-    let mut result = Line {
-        content: vec![],
-        indent: 0,
-        line_type: LineType::FencedCode
+    // the numbers of all the lines, and the `counter-reset` of `LineNumberLayout::Counter`, which is `n - 1`, must fit in `i32`
+    if let Some(n) = options.line_num {
+
+        let last = i32::try_from(lines.len().saturating_sub(1)).ok().and_then(|len| n.checked_add(len));
+
+        if n.checked_sub(1).is_none() || last.is_none() {
+            diagnostics.push(Diagnostic::warning(
+                span,
+                format!("`line_num({})` is out of range, the lines are not numbered", n)
+            ));
+            options.line_num = None;
+        }
+
+    }
+
+    // it's still html-escaped, which is exactly what an attribute value needs
+    let raw_code = if render_option.code_copy_button {
+        lines.iter().map(|ln| ln.to_raw()).collect::<Vec<String>>().join("\n")
+    } else {
+        String::new()
     };
 
-    if lines.len() == 0 {
-        return result;
+    // markers are taken before highlighting, so that they don't confuse the highlighter
+    let diff_markers = if options.diff {
+        take_diff_markers(&mut lines)
+    } else {
+        vec![None; lines.len()]
+    };
+
+    if let Some(ref s) = options.language {
+        lines = syntax_highlighter.highlight_syntax(lines, s);
     }
 
-    match options.language {
-        Some(ref s) => {
-            lines = syntax_highlighter.highlight_syntax(lines, &String::from_utf16_lossy(&s));
-        }
-        _ => {}
+    // line numbers are rendered only when `line_num(n)` is given
+    let layout = match options.line_num {
+        Some(_) => render_option.line_number_layout,
+        None => LineNumberLayout::Inline
+    };
+
+    let class_prefix = &render_option.class_prefix;
+    let mut content = String::with_capacity(lines.iter().map(|ln| ln.content.len() * 2).sum());
+
+    // <div class="code_block" data-language="rust"><div class="code_header">...</div>
+    if render_option.code_copy_button {
+        render_code_header(&options.language, &raw_code, class_prefix, &mut content);
     }
 
-    let mut content = Vec::with_capacity(lines.len() * 3);
+    // <div class="code_title">src/main.rs</div>
+    if let Some(ref title) = options.title {
+        content.push_str(&format!("<div class=\"{}code_title\">", class_prefix));
+        content.push_str(&prevent_backslash_escape(title));
+        content.push_str("</div>");
+    }
+
+    // the stylesheets for `CodeHighlight::CssClass` set the colors of `.code`, so it's not prefixed
+    let pre_classes: &[&str] = match render_option.code_highlight {
+        CodeHighlight::InlineStyle => &[],
+        CodeHighlight::CssClass => &["code"]
+    };
+
+    content.push_str(&format!("<pre{}>", options.attributes.render(pre_classes)));
+
+    match layout {
+        LineNumberLayout::Inline => content.push_str("<code>"),
+        LineNumberLayout::Table => content.push_str(&format!("<code><table class=\"{}code_table\"><tbody>", class_prefix)),
+
+        // `.line_numbers > .line::before` renders `counter(line_no)`
+        LineNumberLayout::Counter => content.push_str(&format!(
+            "<code class=\"{}line_numbers\" style=\"counter-reset: line_no {}\">",
+            class_prefix, options.line_num.unwrap() - 1
+        ))
+    }
 
-    // all those messes are to remove a trailing `\n`
     for (ind, ln) in lines.iter().enumerate() {
+        let line_number = options.line_num.map(|n| n + ind as i32);
 
-        if ind + 1 < lines.len() {
-            content.push(render_line_number(&mut options));
+        // `highlight(...)` follows the rendered numbers
+        let line_classes = get_line_classes(&options, line_number.unwrap_or(ind as i32 + 1), diff_markers[ind]);
+
+        if ind > 0 && layout != LineNumberLayout::Table {
+            content.push('\n');
+        }
+
+        render_code_line(&ln.to_raw(), line_number, &line_classes, diff_markers[ind], layout, class_prefix, &mut content);
+    }
+
+    content.push_str(match layout {
+        LineNumberLayout::Table => "</tbody></table></code></pre>",
+        _ => "</code></pre>"
+    });
+
+    if render_option.code_copy_button {
+        content.push_str("</div>");
+    }
+
+    Line {
+        content,
+        indent: 0,
+        line_type: LineType::FencedCode,
+        span
+    }
+}
+
+
+// <div class="code_block" data-language="rust"><div class="code_header"><span class="code_language">rust</span><button class="copy_button" data-code="..." onclick="...">Copy</button></div>
+fn render_code_header(language: &Option<String>, raw_code: &str, class_prefix: &str, buffer: &mut String) {
+
+    match language {
+        Some(language) => {
+            buffer.push_str(&format!(
+                "<div class=\"{}code_block\" data-language=\"{}\"><div class=\"{}code_header\"><span class=\"{}code_language\">{}</span>",
+                class_prefix, language, class_prefix, class_prefix, language
+            ));
         }
+        None => {
+            buffer.push_str(&format!("<div class=\"{}code_block\"><div class=\"{}code_header\">", class_prefix, class_prefix));
+        }
+    }
+
+    buffer.push_str(&format!("<button class=\"{}copy_button\" data-code=\"", class_prefix));
+    buffer.push_str(&prevent_backslash_escape(raw_code));
+    buffer.push_str("\" onclick=\"navigator.clipboard.writeText(this.dataset.code)\">Copy</button></div>");
+}
+
+
+// `+` and `-` at the beginning of lines
+fn take_diff_markers(lines: &mut [Line]) -> Vec<Option<char>> {
+
+    lines.iter_mut().map(
+        |ln| if ln.indent == 0 && (ln.content.starts_with('+') || ln.content.starts_with('-')) {
+            Some(ln.content.remove(0))
+        } else {
+            None
+        }
+    ).collect()
+}
+
+
+fn get_line_classes(options: &CodeFenceOption, line_index: i32, diff_marker: Option<char>) -> Vec<&'static str> {
 
-        content.push(ln.into_raw());
-        content.push(vec![U16_NEWLINE]);
+    let mut result = vec![];
+
+    if options.highlights.iter().any(|(begin, end)| *begin <= line_index && line_index <= *end) {
+        result.push("highlighted_line");
     }
 
-    result.content = content[0..content.len() - 1].concat();
-    result.content = prevent_backslash_escape(&result.content);
+    match diff_marker {
+        Some('+') => { result.push("diff_added"); }
+        Some('-') => { result.push("diff_removed"); }
+        _ => {}
+    }
 
     result
 }
 
 
-// <span class="line_no">0</span>
-fn render_line_number(options: &mut CodeFenceOption) -> Vec<u16> {
-
-    match options.line_num {
-        None => {vec![]}
-        Some(ref mut n) => {
-            *n += 1;
-            vec![
-                vec![U16_LESS_THAN, U16_SMALL_S, U16_SMALL_P, U16_SMALL_A, U16_SMALL_N, U16_SPACE, U16_SMALL_C, U16_SMALL_L, U16_SMALL_A, U16_SMALL_S, U16_SMALL_S, U16_EQUAL, U16_DOUBLE_QUOTE, U16_SMALL_L, U16_SMALL_I, U16_SMALL_N, U16_SMALL_E, U16_UNDERBAR, U16_SMALL_N, U16_SMALL_O, U16_DOUBLE_QUOTE, U16_GREATER_THAN],
-                (*n - 1).to_string().encode_utf16().collect::<Vec<u16>>(),
-                vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_S, U16_SMALL_P, U16_SMALL_A, U16_SMALL_N, U16_GREATER_THAN]
-            ].concat()
+// Inline:  <span class="highlighted_line"><span class="line_no">0</span>code</span>
+// Table:   <tr class="highlighted_line"><td class="line_no" data-line-number="0"></td><td class="line_content">code</td></tr>
+// Counter: <span class="line highlighted_line">code</span>
+// numbers in `Table` and `Counter` are rendered by CSS, so they're not selected with the code
+fn render_code_line(line: &str, line_number: Option<i32>, line_classes: &[&str], diff_marker: Option<char>, layout: LineNumberLayout, class_prefix: &str, buffer: &mut String) {
+
+    let prefixed_classes = line_classes.iter().map(|class| format!("{}{}", class_prefix, class)).collect::<Vec<String>>();
+
+    let class_attribute = if line_classes.is_empty() {
+        String::new()
+    } else {
+        format!(" class=\"{}\"", prefixed_classes.join(" "))
+    };
+
+    match layout {
+        LineNumberLayout::Inline => {
+
+            if !line_classes.is_empty() {
+                buffer.push_str(&format!("<span{}>", class_attribute));
+            }
+
+            render_line_number(line_number, class_prefix, buffer);
+        }
+        LineNumberLayout::Table => {
+            buffer.push_str(&format!(
+                "<tr{}><td class=\"{}line_no\" data-line-number=\"{}\"></td><td class=\"{}line_content\">",
+                class_attribute, class_prefix, line_number.unwrap(), class_prefix
+            ));
+        }
+        LineNumberLayout::Counter => {
+            buffer.push_str(&format!("<span class=\"{}line{}\">", class_prefix, prefixed_classes.iter().map(|class| format!(" {}", class)).collect::<String>()));
         }
     }
 
-}
\ No newline at end of file
+    // <span class="diff_marker">+</span>
+    if let Some(marker) = diff_marker {
+        buffer.push_str(&format!("<span class=\"{}diff_marker\">", class_prefix));
+        buffer.push(marker);
+        buffer.push_str("</span>");
+    }
+
+    buffer.push_str(&prevent_backslash_escape(line));
+
+    match layout {
+        LineNumberLayout::Inline => {
+
+            if !line_classes.is_empty() {
+                buffer.push_str("</span>");
+            }
+
+        }
+        LineNumberLayout::Table => { buffer.push_str("</td></tr>"); }
+        LineNumberLayout::Counter => { buffer.push_str("</span>"); }
+    }
+
+}
+
+
+// <span class="line_no">0</span>
+fn render_line_number(line_number: Option<i32>, class_prefix: &str, buffer: &mut String) {
+
+    if let Some(n) = line_number {
+        buffer.push_str(&format!("<span class=\"{}line_no\">{}</span>", class_prefix, n));
+    }
+
+}
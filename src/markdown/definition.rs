@@ -0,0 +1,233 @@
+use crate::markdown::line::{Line, LineType};
+use crate::markdown::list::demote_stray_bullets;
+use crate::markdown::predicates::get_type;
+
+
+// `:   definition` -> `definition`
+pub fn get_definition_content(content: &str) -> &str {
+    content[1..].trim_start_matches(' ')
+}
+
+
+// a term is a single unindented line right above a definition, an empty line may come between them
+// the last line of a paragraph is not a term, ex) `a`, `b`, `: c` is a paragraph
+// `prev` is the line above `line`, and `lines` are the ones below it
+pub fn is_term<'a>(line: &Line, prev: Option<&Line>, mut lines: impl Iterator<Item = &'a Line>) -> bool {
+
+    line.line_type == LineType::Paragraph && line.indent == 0 && prev.is_none_or(|prev| prev.line_type != LineType::Paragraph) && match lines.next() {
+        Some(next) if next.line_type == LineType::Empty => lines.next().is_some_and(|next| next.line_type == LineType::Definition),
+        Some(next) => next.line_type == LineType::Definition,
+        None => false
+    }
+}
+
+
+// whether a non-empty `line` belongs to the last definition, `content_indent` is the indentation of its contents
+// it works like `is_list_continued`, except that terms and definitions end the last definition
+pub fn is_definition_continued(line: &Line, content_indent: usize, is_after_empty: bool) -> bool {
+
+    match line.line_type {
+        LineType::Definition => line.indent >= content_indent,
+
+        // laziness
+        LineType::Paragraph => !is_after_empty || line.indent >= content_indent,
+        _ => line.indent >= content_indent
+    }
+
+}
+
+
+// term
+// : definition
+// contents of definitions are blocks, `parse_blocks` renders them
+// `Definition`s without terms are `Paragraph`s
+pub fn merge_definition_list(lines: Vec<Line>, parse_blocks: &mut dyn FnMut(Vec<Line>) -> Vec<Line>) -> Vec<Line> {
+
+    let is_terms = (0..lines.len()).map(|ind| is_term(&lines[ind], ind.checked_sub(1).map(|prev| &lines[prev]), lines[ind + 1..].iter())).collect::<Vec<bool>>();
+
+    let mut current_list = CurrentDefinitionList::default();
+    let mut empty_lines = vec![];
+    let mut result = Vec::with_capacity(lines.len());
+
+    for (ind, mut ln) in lines.into_iter().enumerate() {
+
+        if current_list.items.is_empty() {
+
+            if is_terms[ind] {
+                current_list.items.push((ln, vec![]));
+            }
+
+            else {
+
+                if ln.line_type == LineType::Definition {
+                    ln.line_type = LineType::Paragraph;
+                }
+
+                result.push(ln);
+            }
+
+            continue;
+        }
+
+        if ln.line_type == LineType::Empty {
+            empty_lines.push(ln);
+            continue;
+        }
+
+        let is_after_empty = !empty_lines.is_empty();
+
+        // `term`, (an empty line), `: definition`
+        if current_list.items.last().unwrap().1.is_empty() {
+            current_list.is_loose |= is_after_empty;
+            empty_lines.clear();
+            current_list.push_definition(ln);
+        }
+
+        // empty lines between a definition and the next term do not make the list loose
+        else if is_terms[ind] {
+            empty_lines.clear();
+            current_list.items.push((ln, vec![]));
+        }
+
+        else if ln.line_type == LineType::Definition && ln.indent < current_list.content_indent {
+            current_list.is_loose |= is_after_empty;
+            empty_lines.clear();
+            current_list.push_definition(ln);
+        }
+
+        else if is_definition_continued(&ln, current_list.content_indent, is_after_empty) {
+            let definition = current_list.items.last_mut().unwrap().1.last_mut().unwrap();
+            definition.append(&mut empty_lines);
+            definition.push(ln);
+        }
+
+        else {
+            result.push(construct_definition_list(std::mem::take(&mut current_list), parse_blocks));
+            result.append(&mut empty_lines);
+            result.push(ln);
+        }
+
+    }
+
+    if !current_list.items.is_empty() {
+        result.push(construct_definition_list(current_list, parse_blocks));
+    }
+
+    result.append(&mut empty_lines);
+
+    result
+}
+
+
+#[derive(Default)]
+struct CurrentDefinitionList {
+
+    // (term, definitions)
+    items: Vec<(Line, Vec<Vec<Line>>)>,
+
+    // indentation of the contents of the last definition
+    content_indent: usize,
+
+    // an empty line between a term and its definition, or between definitions
+    is_loose: bool
+}
+
+
+impl CurrentDefinitionList {
+
+    fn push_definition(&mut self, line: Line) {
+        self.content_indent = line.indent + line.content.len() - get_definition_content(&line.content).len();
+        self.items.last_mut().unwrap().1.push(vec![line]);
+    }
+
+}
+
+
+fn construct_definition_list(definition_list: CurrentDefinitionList, parse_blocks: &mut dyn FnMut(Vec<Line>) -> Vec<Line>) -> Line {
+
+    let CurrentDefinitionList { items, is_loose, .. } = definition_list;
+
+    let last_definition = items[items.len() - 1].1.last().unwrap();
+    let span = items[0].0.span.join(&last_definition[last_definition.len() - 1].span);
+
+    let items = items.into_iter().map(
+        |(term, definitions)| (
+            parse_blocks(vec![term]),
+            definitions.into_iter().map(|definition| parse_blocks(dedent_definition(definition))).collect::<Vec<Vec<Line>>>()
+        )
+    ).collect::<Vec<(Vec<Line>, Vec<Vec<Line>>)>>();
+
+    let is_loose = is_loose || items.iter().any(
+        |(_, definitions)| definitions.iter().any(|blocks| blocks.iter().any(|block| block.line_type == LineType::Empty))
+    );
+
+    let mut content = String::from("<dl>");
+
+    for (term, definitions) in items.iter() {
+        content.push_str("<dt>");
+
+        for block in term.iter() {
+            content.push_str(&block.content);
+        }
+
+        content.push_str("</dt>");
+
+        for blocks in definitions.iter() {
+            content.push_str("<dd>");
+
+            for block in blocks.iter() {
+
+                if block.line_type == LineType::Paragraph && !is_loose {
+                    content.push_str(&block.content);
+                }
+
+                else {
+                    block.render(&mut content);
+                }
+
+            }
+
+            content.push_str("</dd>");
+        }
+
+    }
+
+    content.push_str("</dl>");
+
+    Line {
+        line_type: LineType::RenderedDefinitionList,
+        content,
+        indent: 0,
+        span
+    }
+}
+
+
+// the marker is removed from the first line, and the contents of the definition are dedented
+fn dedent_definition(mut definition: Vec<Line>) -> Vec<Line> {
+
+    let first = &definition[0];
+    let marker_len = first.content.len() - get_definition_content(&first.content).len();
+    let content_indent = first.indent + marker_len;
+
+    // the first line is always a paragraph, ex) `: # not a heading`
+    definition[0] = Line {
+        content: get_definition_content(&first.content).to_string(),
+        indent: 0,
+        line_type: LineType::Paragraph,
+        span: first.span_of(marker_len, first.content.len())
+    };
+
+    for ln in definition[1..].iter_mut() {
+
+        if ln.line_type != LineType::Empty {
+            ln.indent -= ln.indent.min(content_indent);
+            ln.line_type = get_type(ln);
+        }
+
+    }
+
+    demote_stray_bullets(&mut definition);
+
+    definition
+}
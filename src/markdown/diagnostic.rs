@@ -0,0 +1,83 @@
+use std::fmt;
+
+
+// a range of the source document, blocks carry their spans and `inline_spans` locates the inline elements
+// it's `Span::default()`, whose `line` is 0, when the diagnostic is about the whole document, ex) an unknown theme
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Span {
+    // 1-based
+    pub line: usize,
+    // 1-based, counted in characters, a tab is a single character
+    pub column: usize,
+    // byte offset from the beginning of the document
+    pub offset: usize,
+    // in bytes
+    pub len: usize
+}
+
+
+impl Span {
+
+    // from the beginning of `self` to the end of `other`
+    pub fn join(&self, other: &Span) -> Span {
+        Span {
+            len: (other.offset + other.len).max(self.offset + self.len) - self.offset,
+            ..*self
+        }
+    }
+
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Severity {
+    // the output is very likely to be broken
+    Error,
+    // the output is valid, but it might not be what the writer intended
+    Warning
+}
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub span: Span,
+    pub message: String
+}
+
+
+impl Diagnostic {
+
+    pub fn error(span: Span, message: String) -> Diagnostic {
+        Diagnostic { severity: Severity::Error, span, message }
+    }
+
+    pub fn warning(span: Span, message: String) -> Diagnostic {
+        Diagnostic { severity: Severity::Warning, span, message }
+    }
+
+}
+
+
+impl fmt::Display for Severity {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning")
+        }
+
+    }
+
+}
+
+
+// 3:14: warning: unterminated tag
+impl fmt::Display for Diagnostic {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}: {}", self.span.line, self.span.column, self.severity, self.message)
+    }
+
+}
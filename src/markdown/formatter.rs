@@ -0,0 +1,805 @@
+use crate::markdown::abbreviation::parse_abbreviation;
+use crate::markdown::blockquote::{get_blockquote_content, is_empty_blockquote};
+use crate::markdown::definition::{get_definition_content, is_definition_continued, is_term};
+use crate::markdown::inline::tag::{is_tag, is_valid_tag};
+use crate::markdown::line::{Line, LineType};
+use crate::markdown::list::{demote_stray_bullets, is_list, is_list_continued, is_same_kind, is_task_list, parse_ordered_bullet};
+use crate::markdown::predicates::get_type;
+use crate::markdown::table::{raw_row_to_cells, is_continued_row, is_valid_table_row};
+use crate::utils::get_bracket_end_index;
+
+/*
+Canonical layout of hmd documents. `format(format(s)) == format(s)`, and the rendered html only differs in whitespaces.
+
+- a single empty line between blocks, and none at the beginning and the end of the document
+- headings, thematic breaks, fenced codes, tables and independent tags are surrounded by empty lines
+- table columns are padded and aligned, and captions stay below their tables
+- nested lists are indented by 2 spaces per level, and the contents of items move with their bullets
+- `:   definition` -> `: definition`, and the contents of definitions move with their markers
+- `[[ Big ]]` -> `[[big]]`, `[[icon=rust,size=32]]` -> `[[icon = rust, size = 32]]`
+- trailing spaces are removed, except the ones of hard line breaks and the ones the parser depends on, ex) `- [ ] ` and `-  `
+- fenced codes that are not closed are kept as they are till the end of the document
+*/
+
+
+pub fn format(content: &str) -> String {
+
+    let lines = classify(content);
+    let mut formatter = Formatter {
+        result: Vec::with_capacity(lines.len()),
+        is_prev_standalone: false,
+        is_inside_blockquote: false,
+        is_inside_quote_fence: false,
+        is_unclosed_fence: false,
+        is_inside_definition_list: false
+    };
+    let mut index = 0;
+
+    while index < lines.len() {
+        index = formatter.format_block(&lines, index);
+    }
+
+    // the empty lines at the end are codes, and a newline would add another one
+    if formatter.is_unclosed_fence {
+        return formatter.result.join("\n");
+    }
+
+    while formatter.result.last().is_some_and(|ln| ln.is_empty()) {
+        formatter.result.pop();
+    }
+
+    let mut result = formatter.result.join("\n");
+
+    if !result.is_empty() {
+        result.push('\n');
+    }
+
+    result
+}
+
+
+struct Formatter {
+    result: Vec<String>,
+
+    // headings, thematic breaks, fenced codes, tables and independent tags
+    is_prev_standalone: bool,
+
+    // paragraphs are lazy continuations of blockquotes
+    is_inside_blockquote: bool,
+
+    // lazy lines are in the fence until the blockquote ends
+    is_inside_quote_fence: bool,
+
+    // the document ends in a fenced code
+    is_unclosed_fence: bool,
+
+    // the last block is a term or a definition, `Definition`s without terms are paragraphs, the same as `merge_definition_list`
+    is_inside_definition_list: bool
+}
+
+
+impl Formatter {
+
+    // returns the index of the next block
+    fn format_block(&mut self, lines: &[(&str, Line)], index: usize) -> usize {
+
+        let (raw, ln) = &lines[index];
+        let is_definition = ln.line_type == LineType::Definition && self.is_inside_definition_list;
+
+        if ln.line_type != LineType::Empty {
+            self.is_inside_definition_list = is_definition || is_term(ln, index.checked_sub(1).map(|ind| &lines[ind].1), lines[index + 1..].iter().map(|(_, ln)| ln));
+        }
+
+        if ln.line_type != LineType::Paragraph {
+            self.is_inside_blockquote = false;
+        }
+
+        let is_quote_fence = match ln.line_type {
+            LineType::Blockquote(_) => get_blockquote_content(&ln.content).starts_with("```"),
+            LineType::Paragraph => false,
+            _ => {
+                self.is_inside_quote_fence = false;
+                false
+            }
+        };
+
+        // codes in blockquotes are kept as they are
+        if self.is_inside_quote_fence || is_quote_fence {
+            self.is_inside_blockquote = true;
+            self.is_inside_quote_fence ^= is_quote_fence;
+            self.push_block(vec![if is_quote_fence { trim_line_end(raw) } else { raw.to_string() }], false);
+
+            return index + 1;
+        }
+
+        match ln.line_type {
+            LineType::Empty => {
+
+                // `a`, ``, ``, `: b` is not a definition list, but `a`, ``, `: b` is
+                if self.result.last().is_some_and(|ln| !ln.is_empty())
+                    || lines.get(index + 1).is_some_and(|(_, next)| next.line_type == LineType::Definition) {
+                    self.result.push(String::new());
+                }
+
+                index + 1
+            }
+            LineType::CodeFence => {
+                let mut end_index = index + 1;
+
+                while end_index < lines.len() && lines[end_index].1.line_type == LineType::FencedCode {
+                    end_index += 1;
+                }
+
+                let mut block = vec![trim_line_end(raw)];
+                block.extend(lines[index + 1..end_index].iter().map(|(raw, _)| raw.to_string()));
+
+                // the closing fence
+                if end_index < lines.len() {
+                    block.push(trim_line_end(lines[end_index].0));
+                    end_index += 1;
+                }
+
+                else {
+                    self.is_unclosed_fence = true;
+                }
+
+                self.push_block(block, true);
+
+                end_index
+            }
+            LineType::Header => {
+                let sharps = ln.content.bytes().take_while(|c| *c == b'#').count();
+
+                let header = format!("{} {}", &ln.content[..sharps], normalize_tags(ln.content[sharps..].trim()));
+
+                // `# \t` is a heading, but `# ` is not
+                self.push_block(vec![if is_equivalent(raw, &header) { header } else { trim_line_end(raw) }], true);
+
+                index + 1
+            }
+            LineType::ThematicBreak => {
+                self.push_block(vec![String::from("---")], true);
+
+                index + 1
+            }
+            // unknown tags are in paragraphs, the same as `render_independent_tag`
+            LineType::Tag if is_valid_tag(&ln.content, 0) => {
+                self.push_block(vec![normalize_tags(ln.content.trim_end())], true);
+
+                index + 1
+            }
+            LineType::UnorderedList | LineType::OrderedList => self.format_list(lines, index),
+            LineType::Definition if is_definition => self.format_definition(lines, index),
+            _ if is_table_begin(lines, index) => self.format_table(lines, index),
+            LineType::Blockquote(_) => {
+                self.is_inside_blockquote = true;
+
+                let content = format_hard_break(&ln.content, true);
+                self.push_block(vec![if is_equivalent(&ln.content, &content) { normalize_tags(&content) } else { trim_line_end(&ln.content) }], false);
+
+                index + 1
+            }
+            _ => {
+                let has_hard_break = self.is_inside_blockquote || is_paragraph_continued(lines, index);
+                let content = format_hard_break(&ln.content, has_hard_break);
+
+                // an indented line above a definition is not a term, but it would be without the indentation
+                let dedented = Line { indent: 0, ..ln.clone() };
+                let indent = if is_term(&dedented, index.checked_sub(1).map(|ind| &lines[ind].1), lines[index + 1..].iter().map(|(_, ln)| ln)) { ln.indent } else { 0 };
+
+                self.push_block(vec![reindent(raw, &normalize_tags(&content), indent, &ln.line_type)], false);
+
+                index + 1
+            }
+        }
+
+    }
+
+    fn format_list(&mut self, lines: &[(&str, Line)], index: usize) -> usize {
+
+        // original indents of the nested lists
+        let mut indents: Vec<usize> = vec![];
+
+        // (original, formatted) indents of the contents of the last item of each nested list
+        let mut content_indents: Vec<(usize, usize)> = vec![];
+
+        // `- a`, `    - b`, `  - c`: `c` begins another nested list, which cannot be expressed with normalized indents
+        let mut is_irregular = false;
+
+        // (original indent of the fence, original indent of the contents, formatted indent of the contents) of a fence in an item
+        let mut item_fence: Option<(usize, usize, usize)> = None;
+
+        // whether each line is in a fence of an item
+        let mut is_code = vec![];
+
+        let mut block = vec![];
+        let mut end_index = index;
+
+        while end_index < lines.len() {
+            let (raw, ln) = &lines[end_index];
+            let is_after_empty = end_index > index && lines[end_index - 1].1.line_type == LineType::Empty;
+
+            if ln.line_type == LineType::Empty {
+
+                // the list goes on only if the next non-empty line is in it, the same as `merge_list`
+                match (end_index..lines.len()).find(|ind| lines[*ind].1.line_type != LineType::Empty) {
+                    Some(next) if is_same_kind(&lines[index].1, &lines[next].1) || is_list_continued(&lines[next].1, indents[0], content_indents[0].0, true) => {}
+                    _ => {
+                        break;
+                    }
+                }
+
+                if item_fence.is_some() || block.last().is_some_and(|ln: &String| !ln.is_empty()) {
+                    block.push(String::new());
+                }
+
+                is_code.push(item_fence.is_some());
+
+                end_index += 1;
+                continue;
+            }
+
+            // sibling bullets of the same kind are still in the list after an empty line
+            let is_bullet = is_list(ln)
+                && (indents.is_empty() || !is_after_empty || ln.indent > indents[0] || is_same_kind(&lines[index].1, ln));
+
+            if !is_bullet && !is_list_continued(ln, indents[0], content_indents[0].0, is_after_empty) {
+                break;
+            }
+
+            if let Some((fence_indent, original, formatted)) = item_fence {
+
+                if ln.content.starts_with("```") && ln.indent <= fence_indent {
+                    item_fence = None;
+                }
+
+                block.push(shift_indent(ln, original, formatted));
+                is_code.push(true);
+                end_index += 1;
+                continue;
+            }
+
+            if is_bullet {
+                let depth = indents.len();
+
+                while indents.last().is_some_and(|indent| *indent > ln.indent) {
+                    indents.pop();
+                }
+
+                if indents.last().is_none_or(|indent| *indent < ln.indent) {
+                    indents.push(ln.indent);
+                    is_irregular |= indents.len() <= depth;
+                }
+
+                let level = indents.len() - 1;
+                let bullet_len = match parse_ordered_bullet(&ln.content) {
+                    Some((_, _, bullet_len)) if ln.line_type == LineType::OrderedList => bullet_len + 1,
+                    _ => 1
+                };
+
+                // the first line of an item is a paragraph, which may end with a hard line break
+                let content = format_hard_break(ln.content[bullet_len..].trim_start(), is_paragraph_continued(lines, end_index));
+
+                let item = format!("{}{} {}", " ".repeat(level * 2), &ln.content[..bullet_len], normalize_tags(&content));
+
+                // empty items and empty task items keep their trailing spaces, ex) `-  `, `- [ ] `
+                block.push(if is_equivalent(raw, &item) { item } else { format!("{}{}", " ".repeat(level * 2), trim_line_end(&ln.content)) });
+
+                content_indents.truncate(level);
+                content_indents.push((ln.indent + bullet_len + 1, level * 2 + bullet_len + 1));
+            }
+
+            // `| a |` above `|---|` begins a table, unless both are in the item
+            else if ln.indent < content_indents[0].0 && is_table_begin(lines, end_index)
+                && !is_list_continued(&lines[end_index + 1].1, indents[0], content_indents[0].0, false) {
+                break;
+            }
+
+            else {
+
+                // the deepest item whose contents are not less indented than the line, the lazy ones go to the last item
+                let deepest = content_indents.iter().rev().find(|(original, _)| *original <= ln.indent);
+                let (original, formatted) = match deepest {
+                    Some(indents) if is_after_empty || ln.line_type != LineType::Paragraph => *indents,
+                    _ => (ln.indent, content_indents[content_indents.len() - 1].1)
+                };
+
+                // the indentation in the item, `dedent_item` removes all of it from the lines less indented than the contents
+                let dedented = deepest.map_or(0, |(original, _)| ln.indent - original);
+
+                if ln.content.starts_with("```") && dedented == 0 {
+                    item_fence = Some((ln.indent, original, formatted));
+                    block.push(trim_line_end(&shift_indent(ln, original, formatted)));
+                }
+
+                else if ln.line_type == LineType::Paragraph {
+                    let content = format_hard_break(&ln.content, is_paragraph_continued(lines, end_index));
+                    let indent = if ln.indent >= original { ln.indent - original + formatted } else { formatted };
+
+                    // an over-indented "```" is a paragraph in the item, but it would be a fence at the indentation of the contents
+                    let is_retyped = get_type(&Line { indent: dedented, ..ln.clone() }) != get_type(&Line { indent: indent - formatted, ..ln.clone() });
+
+                    block.push(reindent(raw, &normalize_tags(&content), if is_retyped { ln.indent } else { indent }, &ln.line_type));
+                }
+
+                else {
+                    block.push(trim_line_end(&shift_indent(ln, original, formatted)));
+                }
+
+            }
+
+            is_code.push(false);
+            end_index += 1;
+        }
+
+        if is_irregular {
+            block = (index..end_index).zip(is_code.iter()).map(
+                |(ind, is_code)| {
+                    let (raw, ln) = &lines[ind];
+
+                    if *is_code {
+                        raw.to_string()
+                    }
+
+                    else {
+                        let line = format_hard_break(raw, ln.line_type == LineType::Paragraph && is_paragraph_continued(lines, ind));
+
+                        if is_equivalent(raw, &line) { normalize_tags(&line) } else { trim_line_end(raw) }
+                    }
+
+                }
+            ).collect();
+        }
+
+        self.push_block(block, false);
+
+        end_index
+    }
+
+    fn format_definition(&mut self, lines: &[(&str, Line)], index: usize) -> usize {
+
+        let (_, ln) = &lines[index];
+
+        // original indent of the contents, they're indented by 2 spaces after formatted
+        let content_indent = ln.indent + ln.content.len() - get_definition_content(&ln.content).len();
+
+        // original indent of a fence in the definition
+        let mut fence_indent = None;
+
+        let content = format_hard_break(get_definition_content(&ln.content), is_paragraph_continued(lines, index));
+        let mut block = vec![format!(": {}", normalize_tags(&content))];
+        let mut end_index = index + 1;
+
+        while end_index < lines.len() {
+            let (raw, ln) = &lines[end_index];
+            let is_after_empty = lines[end_index - 1].1.line_type == LineType::Empty;
+
+            if ln.line_type == LineType::Empty {
+
+                // the definition goes on only if the next non-empty line is in it, the same as `merge_definition_list`
+                match (end_index..lines.len()).find(|ind| lines[*ind].1.line_type != LineType::Empty) {
+                    Some(next) if fence_indent.is_some() || is_definition_continued(&lines[next].1, content_indent, true) => {}
+                    _ => {
+                        break;
+                    }
+                }
+
+                block.push(String::new());
+                end_index += 1;
+                continue;
+            }
+
+            if let Some(indent) = fence_indent {
+
+                if ln.content.starts_with("```") && ln.indent <= indent {
+                    fence_indent = None;
+                }
+
+                block.push(shift_indent(ln, content_indent, 2));
+                end_index += 1;
+                continue;
+            }
+
+            if is_term(ln, Some(&lines[end_index - 1].1), lines[end_index + 1..].iter().map(|(_, ln)| ln))
+                || !is_definition_continued(ln, content_indent, is_after_empty) {
+                break;
+            }
+
+            if ln.content.starts_with("```") {
+                fence_indent = Some(ln.indent);
+                block.push(trim_line_end(&shift_indent(ln, content_indent, 2)));
+            }
+
+            else if ln.line_type == LineType::Paragraph {
+                let content = format_hard_break(&ln.content, is_paragraph_continued(lines, end_index));
+                let indent = if ln.indent >= content_indent { ln.indent - content_indent + 2 } else { 2 };
+
+                block.push(reindent(raw, &normalize_tags(&content), indent, &ln.line_type));
+            }
+
+            else {
+                block.push(trim_line_end(&shift_indent(ln, content_indent, 2)));
+            }
+
+            end_index += 1;
+        }
+
+        self.push_block(block, false);
+
+        end_index
+    }
+
+    fn format_table(&mut self, lines: &[(&str, Line)], index: usize) -> usize {
+
+        let mut end_index = index + 2;
+
+        while end_index < lines.len() && (is_valid_table_row(&lines[end_index].1) || (is_continued_row(&lines[end_index].1) && end_index > index + 2)) {
+            end_index += 1;
+        }
+
+        // (left colon, right colon), cells are not trimmed like `parse_column_alignment`: `| :-- |` is not aligned
+        let alignments = raw_row_to_cells(&lines[index + 1].1.content).iter().map(
+            |cell| (cell.len() > 1 && cell.starts_with(':'), cell.len() > 1 && cell.ends_with(':'))
+        ).collect::<Vec<(bool, bool)>>();
+
+        let table_rows = lines[index..end_index].iter().enumerate().filter(|(ind, _)| *ind != 1).map(|(_, (_, ln))| ln).collect::<Vec<&Line>>();
+
+        // (is_continued, is_multi_line, cells)
+        let mut rows = Vec::with_capacity(table_rows.len());
+        let mut row_begin = 0;
+
+        while row_begin < table_rows.len() {
+            let row_end = table_rows[row_begin + 1..].iter().position(|ln| !is_continued_row(ln)).map_or(table_rows.len(), |ind| row_begin + 1 + ind);
+            rows.extend(format_row_cells(&table_rows[row_begin..row_end]));
+            row_begin = row_end;
+        }
+
+        let mut widths = vec![1; alignments.len()];
+
+        for (_, _, row) in rows.iter() {
+
+            for (ind, cell) in row.iter().enumerate() {
+
+                if ind < widths.len() {
+                    widths[ind] = widths[ind].max(cell.chars().count());
+                }
+
+            }
+
+        }
+
+        let mut block = Vec::with_capacity(end_index - index);
+
+        for (ind, (is_continued, is_multi_line, row)) in rows.iter().enumerate() {
+
+            // cells of multi-line rows are not aligned, so that their indentations are kept
+            let row = format_table_row(row, &widths, if *is_multi_line { &[] } else { &alignments });
+
+            if *is_continued {
+                block.push(format!("+{}", row));
+            }
+
+            else {
+                block.push(row);
+            }
+
+            // |:---|:---:|---:|
+            if ind == 0 {
+                let delimiter = widths.iter().zip(alignments.iter()).map(
+                    |(width, (left, right))| format!(
+                        "{}{}{}",
+                        if *left { ":" } else { "-" },
+                        "-".repeat(*width),
+                        if *right { ":" } else { "-" }
+                    )
+                ).collect::<Vec<String>>();
+
+                block.push(format!("|{}|", delimiter.join("|")));
+            }
+
+        }
+
+        // `Table: caption`
+        if end_index < lines.len() && lines[end_index].1.line_type == LineType::Paragraph && lines[end_index].1.content.starts_with("Table: ") {
+            block.push(format!("Table: {}", normalize_tags(lines[end_index].1.content["Table:".len()..].trim())));
+            end_index += 1;
+        }
+
+        self.push_block(block, true);
+
+        end_index
+    }
+
+    fn push_block(&mut self, block: Vec<String>, is_standalone: bool) {
+
+        if self.result.last().is_some_and(|ln| !ln.is_empty()) && (is_standalone || self.is_prev_standalone) {
+            self.result.push(String::new());
+        }
+
+        self.result.extend(block);
+        self.is_prev_standalone = is_standalone;
+    }
+
+}
+
+
+// lines in code fences are `FencedCode`s
+fn classify(content: &str) -> Vec<(&str, Line)> {
+
+    let mut is_inside_fence = false;
+    let raws = content.split('\n').collect::<Vec<&str>>();
+
+    let mut lines = raws.iter().map(
+        |raw| {
+            let mut ln = Line::from_raw(raw);
+            ln.line_type = get_type(&ln);
+
+            if ln.line_type == LineType::CodeFence {
+                is_inside_fence = !is_inside_fence;
+            }
+
+            else if is_inside_fence {
+                ln.line_type = LineType::FencedCode;
+            }
+
+            ln
+        }
+    ).collect::<Vec<Line>>();
+
+    demote_stray_bullets(&mut lines);
+
+    raws.into_iter().zip(lines).collect()
+}
+
+
+// the original indentation is kept if the new one changes the type of the line, ex) an indented `# not a heading`
+fn reindent(raw: &str, content: &str, indent: usize, line_type: &LineType) -> String {
+
+    let result = format!("{}{}", " ".repeat(indent), content);
+
+    if &get_type(&Line::from_raw(&result)) == line_type && is_equivalent(raw, &result) {
+        return result;
+    }
+
+    let result = format!("{}{}", &raw[..raw.len() - raw.trim_start_matches([' ', '\t']).len()], content);
+
+    if is_equivalent(raw, &result) {
+        result
+    }
+
+    else {
+        raw.to_string()
+    }
+
+}
+
+
+// trailing whitespaces are kept if the parser reads the line differently without them, ex) `-  ` is an empty item, but `-` is a paragraph
+fn trim_line_end(raw: &str) -> String {
+
+    let trimmed = raw.trim_end();
+
+    if is_equivalent(raw, trimmed) {
+        trimmed.to_string()
+    }
+
+    else {
+        raw.to_string()
+    }
+
+}
+
+
+// whether the parser reads `formatted` the same as `original`: their types, abbreviation definitions and checkboxes, also in blockquotes
+// the types are compared without the indentations as well, because the contents of items and definitions are dedented before parsed
+// ex) `[[red]]  ` is a paragraph, but `[[red]]` is an independent tag
+fn is_equivalent(original: &str, formatted: &str) -> bool {
+
+    let (mut original, mut formatted) = (Line::from_raw(original), Line::from_raw(formatted));
+    original.line_type = get_type(&original);
+    formatted.line_type = get_type(&formatted);
+
+    let dedented_type = |line: &Line| get_type(&Line { indent: 0, ..line.clone() });
+
+    if original.line_type != formatted.line_type
+        || dedented_type(&original) != dedented_type(&formatted)
+        || parse_abbreviation(&original).is_some() != parse_abbreviation(&formatted).is_some()
+        || get_task_box(&original) != get_task_box(&formatted) {
+        return false;
+    }
+
+    // `> \t` is not an empty line of the blockquote, so the next line may be lazy, and neither is `> > \t` of the inner one
+    match original.line_type {
+        LineType::Blockquote(_) => is_empty_blockquote(&original) == is_empty_blockquote(&formatted)
+            && is_equivalent(remove_marker(&original.content), remove_marker(&formatted.content)),
+        _ => true
+    }
+
+}
+
+
+// `> > a` -> `> a`, the same as `blockquote::remove_marker`
+fn remove_marker(content: &str) -> &str {
+
+    let content = &content[1..];
+
+    content.strip_prefix(' ').unwrap_or(content)
+}
+
+
+// (whether the item is a task, whether it begins with a checkbox)
+// `- [ ] ` is a task, and so is `- [x]` if the next line continues it, but `-   [ ] ` is not
+fn get_task_box(line: &Line) -> (bool, bool) {
+
+    let bullet_len = match line.line_type {
+        LineType::UnorderedList => 1,
+        LineType::OrderedList => match parse_ordered_bullet(&line.content) {
+            Some((_, _, bullet_len)) => bullet_len + 1,
+            None => {
+                return (false, false);
+            }
+        },
+        _ => {
+            return (false, false);
+        }
+    };
+
+    let content = line.content.get(bullet_len + 1..).unwrap_or("");
+
+    (is_task_list(content), content.get(..3).is_some_and(|checkbox| is_task_list(&format!("{} ", checkbox))))
+}
+
+
+// the contents of an item move from `original` to `formatted` with the bullet, keeping the relative indentation
+fn shift_indent(line: &Line, original: usize, formatted: usize) -> String {
+
+    let indent = if line.indent >= original { line.indent - original + formatted } else { line.indent.min(formatted) };
+
+    format!("{}{}", " ".repeat(indent), line.content)
+}
+
+
+// 2 trailing spaces are a hard line break, the others are removed
+fn format_hard_break(content: &str, has_hard_break: bool) -> String {
+
+    let trimmed = content.trim_end();
+
+    if has_hard_break && content.len() > 2 && content.ends_with("  ") && !trimmed.is_empty() {
+        format!("{}  ", trimmed)
+    }
+
+    else {
+        trimmed.to_string()
+    }
+
+}
+
+
+// `[[ Big ]]` -> `[[big]]`
+fn normalize_tags(content: &str) -> String {
+
+    let mut result = String::with_capacity(content.len());
+    let mut is_inside_code = false;
+    let mut last_index = 0;
+    let mut curr_index = 0;
+
+    while curr_index < content.len() {
+
+        if content.as_bytes()[curr_index] == b'`' {
+            is_inside_code = !is_inside_code;
+        }
+
+        else if !is_inside_code && is_tag(content, curr_index) && is_valid_tag(content, curr_index) {
+            let end_index = get_bracket_end_index(content, curr_index).unwrap();
+            let name = content[curr_index + 2..end_index - 1].chars().filter(|c| *c != ' ').collect::<String>().to_lowercase();
+
+            result.push_str(&content[last_index..curr_index]);
+            result.push_str("[[");
+
+            // `[[icon = rust, size = 32]]`
+            if name.starts_with("icon=") {
+                result.push_str(&name.split(',').map(|arg| arg.replace('=', " = ")).collect::<Vec<String>>().join(", "));
+            }
+
+            else {
+                result.push_str(&name);
+            }
+
+            result.push_str("]]");
+
+            last_index = end_index + 1;
+            curr_index = last_index;
+            continue;
+        }
+
+        curr_index += 1;
+    }
+
+    result.push_str(&content[last_index..]);
+    result
+}
+
+
+// whether `lines[index]` and the next line are merged into a paragraph
+// definitions without terms, delimiters without table heads and unknown tags are paragraphs
+fn is_paragraph_continued(lines: &[(&str, Line)], index: usize) -> bool {
+    lines.get(index + 1).is_some_and(
+        |(_, next)| match next.line_type {
+            LineType::Paragraph | LineType::Definition | LineType::TableDelimiter => true,
+            LineType::Tag => !is_valid_tag(&next.content, 0),
+            _ => false
+        }
+    ) && !is_table_begin(lines, index + 1)
+}
+
+
+// the same condition as `merge_table`
+fn is_table_begin(lines: &[(&str, Line)], index: usize) -> bool {
+
+    index + 1 < lines.len()
+    && is_valid_table_row(&lines[index].1)
+    && lines[index + 1].1.line_type == LineType::TableDelimiter
+    && raw_row_to_cells(&lines[index].1.content).len() == raw_row_to_cells(&lines[index + 1].1.content).len()
+}
+
+
+// `rows[0]` is a row, and `rows[1..]` are its continuations
+// cells of multi-line rows are dedented, but tags are not normalized because they may be in fenced codes
+fn format_row_cells(rows: &[&Line]) -> Vec<(bool, bool, Vec<String>)> {
+
+    if rows.len() == 1 {
+        return vec![(false, false, raw_row_to_cells(&rows[0].content).iter().map(|cell| normalize_tags(cell.trim())).collect())];
+    }
+
+    let mut cells = rows.iter().map(
+        |ln| {
+            let content = if is_continued_row(ln) { &ln.content[1..] } else { &ln.content[..] };
+
+            raw_row_to_cells(content).iter().map(|cell| cell.trim_end().to_string()).collect::<Vec<String>>()
+        }
+    ).collect::<Vec<Vec<String>>>();
+
+    let columns = cells.iter().map(Vec::len).max().unwrap_or(0);
+
+    for column in 0..columns {
+        let indent = cells.iter().filter_map(|row| row.get(column)).filter(|cell| !cell.is_empty()).map(
+            |cell| cell.len() - cell.trim_start_matches(' ').len()
+        ).min().unwrap_or(0);
+
+        for row in cells.iter_mut() {
+
+            if let Some(cell) = row.get_mut(column) {
+                *cell = cell[indent.min(cell.len())..].to_string();
+            }
+
+        }
+
+    }
+
+    cells.into_iter().enumerate().map(|(ind, row)| (ind > 0, true, row)).collect()
+}
+
+
+// `| left   |  center  |   right |`
+fn format_table_row(cells: &[String], widths: &[usize], alignments: &[(bool, bool)]) -> String {
+
+    let mut result = String::from("|");
+
+    for (ind, cell) in cells.iter().enumerate() {
+        let padding = widths.get(ind).map_or(0, |width| width.saturating_sub(cell.chars().count()));
+
+        let left_padding = match alignments.get(ind) {
+            Some((true, true)) => padding / 2,
+            Some((false, true)) => padding,
+            _ => 0
+        };
+
+        result.push(' ');
+        result.push_str(&" ".repeat(left_padding));
+        result.push_str(cell);
+        result.push_str(&" ".repeat(padding - left_padding));
+        result.push_str(" |");
+    }
+
+    result
+}
@@ -0,0 +1,991 @@
+use crate::markdown::diagnostic::{Diagnostic, Span};
+use crate::markdown::list::parse_ordered_bullet;
+
+/*
+Rewrites gfm into hmd, line by line. See `Things that are missing` and `Things that are different` in README.md.
+Constructs that hmd cannot express are left as they are, with a warning.
+
+- `*`, `+` bullets -> `-`
+- `3)` bullets -> `3.`
+- `_em_`, `__strong__` -> `*em*`, `**strong**`
+- `~del~` -> `~~del~~`
+- ``code`` -> `code`
+- `~~~` and indented fences, indented code blocks -> ```
+- setext headings, `# closed #` headings -> `# heading`
+- `\` hard line breaks -> two spaces
+- `&nbsp;` -> `[[blank]]`, `&lt;`, `&#60;` -> `<`
+- `<https://autolink>` -> `[https://autolink](https://autolink)`
+- `e. g.` at the beginning of a paragraph -> `e\. g.`, it would be a list in hmd
+*/
+
+
+pub fn from_gfm(content: &str) -> (String, Vec<Diagnostic>) {
+
+    let mut converter = Converter::new(content);
+    let mut index = 0;
+
+    while index < converter.lines.len() {
+        index = converter.convert_block(index);
+    }
+
+    converter.diagnostics.sort_by_key(|d| d.span.offset);
+
+    (converter.result.join("\n"), converter.diagnostics)
+}
+
+
+struct Converter<'a> {
+    lines: Vec<&'a str>,
+
+    // byte offset of each line in the source
+    offsets: Vec<usize>,
+
+    result: Vec<String>,
+    diagnostics: Vec<Diagnostic>,
+
+    // indented lines in lists are not code blocks
+    is_in_list: bool,
+    is_in_quote_fence: bool
+}
+
+
+impl<'a> Converter<'a> {
+
+    fn new(content: &'a str) -> Converter<'a> {
+
+        let lines = content.split('\n').collect::<Vec<&str>>();
+        let mut offsets = Vec::with_capacity(lines.len());
+        let mut offset = 0;
+
+        for ln in lines.iter() {
+            offsets.push(offset);
+            offset += ln.len() + 1;
+        }
+
+        Converter {
+            result: Vec::with_capacity(lines.len()),
+            lines,
+            offsets,
+            diagnostics: vec![],
+            is_in_list: false,
+            is_in_quote_fence: false
+        }
+    }
+
+    // returns the index of the next block
+    fn convert_block(&mut self, index: usize) -> usize {
+
+        let ln = self.lines[index];
+        let (indent, indent_len) = measure_indent(ln);
+        let body = &ln[indent_len..];
+
+        if is_blank(ln) {
+            self.result.push(ln.to_string());
+            return index + 1;
+        }
+
+        if indent < 4 && body.starts_with('>') {
+            self.convert_blockquote(index);
+            return index + 1;
+        }
+
+        // gfm closes a fence when its blockquote ends
+        self.is_in_quote_fence = false;
+
+        if indent < 4 && is_opening_fence(body) {
+            return self.convert_fence(index);
+        }
+
+        if indent >= 4 && !self.is_in_list && (index == 0 || is_blank(self.lines[index - 1])) {
+            return self.convert_indented_code(index);
+        }
+
+        if let Some(end) = self.find_setext_heading(index) {
+            self.convert_setext_heading(index, end);
+            return end + 1;
+        }
+
+        if indent == 0 && !is_list_item(body) {
+            self.is_in_list = false;
+        }
+
+        let converted = self.convert_line(index, indent_len, ln[..indent_len].to_string());
+        self.result.push(converted);
+
+        index + 1
+    }
+
+    fn convert_fence(&mut self, index: usize) -> usize {
+
+        let (indent, indent_len) = measure_indent(self.lines[index]);
+        let fence = &self.lines[index][indent_len..];
+        let marker = fence.as_bytes()[0];
+        let fence_len = fence.bytes().take_while(|c| *c == marker).count();
+
+        self.result.push(format!("```{}", &fence[fence_len..]));
+
+        for curr_index in index + 1..self.lines.len() {
+            let ln = self.lines[curr_index];
+
+            if is_closing_fence(ln, marker, fence_len) {
+                self.result.push(String::from("```"));
+                return curr_index + 1;
+            }
+
+            // gfm removes the indentation of the fence from its contents
+            let code = remove_indent(ln, indent);
+            self.check_code(curr_index, code);
+            self.result.push(code.to_string());
+        }
+
+        // gfm closes it at the end of the document
+        self.result.push(String::from("```"));
+
+        self.lines.len()
+    }
+
+    fn convert_indented_code(&mut self, index: usize) -> usize {
+
+        let mut end_index = index;
+
+        // blank lines between indented lines belong to the block
+        for curr_index in index..self.lines.len() {
+            let ln = self.lines[curr_index];
+
+            if is_blank(ln) {
+                continue;
+            }
+
+            if measure_indent(ln).0 < 4 {
+                break;
+            }
+
+            end_index = curr_index;
+        }
+
+        self.result.push(String::from("```"));
+
+        for curr_index in index..end_index + 1 {
+            let code = remove_indent(self.lines[curr_index], 4);
+            self.check_code(curr_index, code);
+            self.result.push(code.to_string());
+        }
+
+        self.result.push(String::from("```"));
+
+        end_index + 1
+    }
+
+    // a line beginning with backticks always closes a code fence in hmd
+    fn check_code(&mut self, index: usize, code: &str) {
+
+        if code.starts_with("```") {
+            let begin = self.lines[index].len() - code.len();
+
+            self.diagnostics.push(Diagnostic::warning(
+                self.span(index, begin, begin + 3),
+                String::from("hmd cannot express a code line beginning with 3 backticks, it closes the code fence")
+            ));
+        }
+
+    }
+
+    fn convert_blockquote(&mut self, index: usize) {
+
+        let ln = self.lines[index];
+        let (depth, begin) = measure_blockquote(ln);
+        let body = ln[begin..].trim_end();
+        let prefix = format!("{} ", ">".repeat(depth));
+
+        if body.is_empty() {
+            self.result.push(ln.to_string());
+        }
+
+        else if self.is_in_quote_fence {
+
+            if body.starts_with("```") || body.starts_with("~~~") {
+                self.result.push(format!("{}```", prefix));
+                self.is_in_quote_fence = false;
+            }
+
+            else {
+                self.result.push(format!("{}{}", prefix, &ln[begin..]));
+            }
+
+        }
+
+        else if body.starts_with("```") || body.starts_with("~~~") {
+            let fence_len = body.len() - body.trim_start_matches(body.as_bytes()[0] as char).len();
+
+            self.result.push(format!("{}```{}", prefix, &body[fence_len..]));
+            self.is_in_quote_fence = true;
+        }
+
+        else {
+            let converted = self.convert_line(index, begin, prefix);
+            self.result.push(converted);
+        }
+
+    }
+
+    // the last line of a paragraph, if the paragraph is a setext heading
+    fn find_setext_heading(&self, index: usize) -> Option<usize> {
+
+        if index > 0 && is_paragraph_line(self.lines[index - 1]) {
+            return None;
+        }
+
+        let mut end_index = index;
+
+        while end_index < self.lines.len() && is_paragraph_line(self.lines[end_index]) {
+            end_index += 1;
+        }
+
+        if end_index > index && end_index < self.lines.len() && setext_level(self.lines[end_index]).is_some() {
+            Some(end_index)
+        }
+
+        else {
+            None
+        }
+
+    }
+
+    // `end` is the underline
+    fn convert_setext_heading(&mut self, index: usize, end: usize) {
+
+        let mut heading = "#".repeat(setext_level(self.lines[end]).unwrap());
+
+        // gfm joins the lines into a single heading
+        for curr_index in index..end {
+            let ln = self.lines[curr_index];
+
+            heading.push(' ');
+            self.convert_inlines(curr_index, measure_indent(ln).1, ln.trim_end().len(), &mut heading);
+        }
+
+        self.result.push(heading);
+        self.is_in_list = false;
+    }
+
+    // `begin` is where the content begins, after indents and `>`s
+    fn convert_line(&mut self, index: usize, begin: usize, mut result: String) -> String {
+
+        let ln = self.lines[index];
+        let content = &ln[begin..];
+
+        if is_thematic_break(content) {
+            result.push_str(&content.replace([' ', '\t'], ""));
+            return result;
+        }
+
+        if let Some(level) = atx_level(content) {
+
+            // hmd headings are not indented
+            if result.trim().is_empty() {
+                result.clear();
+            }
+
+            let text = content[level..].trim();
+            let closing = text.trim_end_matches('#');
+
+            // `# heading #`
+            let text = if closing.is_empty() || closing.ends_with([' ', '\t']) { closing.trim_end() } else { text };
+            let text_begin = if text.is_empty() { ln.len() } else { ln.len() - ln[begin + level..].trim_start().len() };
+
+            result.push_str(&"#".repeat(level));
+            result.push(' ');
+            self.convert_inlines(index, text_begin, text_begin + text.len(), &mut result);
+
+            return result;
+        }
+
+        let mut text_begin = begin;
+
+        if let Some(bullet_len) = unordered_bullet_len(content) {
+            result.push_str("- ");
+            text_begin += bullet_len;
+            self.is_in_list = true;
+        }
+
+        // the first number is the start of the list in both, and the others are ignored
+        else if let Some(bullet_len) = ordered_bullet_len(content) {
+            result.push_str(&content[..bullet_len - 2]);
+            result.push_str(". ");
+            text_begin += bullet_len;
+            self.is_in_list = true;
+        }
+
+        // `e. g. text` and `iv. text` are paragraphs in gfm, but they may be lists in hmd
+        else if let Some((_, _, bullet_len)) = parse_ordered_bullet(content) {
+            result.push_str(&content[..bullet_len]);
+            result.push('\\');
+            text_begin += bullet_len;
+        }
+
+        let mut text_end = ln.len();
+        let backslashes = ln.len() - ln.trim_end_matches('\\').len();
+        let is_hard_break = backslashes % 2 == 1 && index + 1 < self.lines.len() && !is_blank(self.lines[index + 1]);
+
+        if is_hard_break {
+            text_end -= 1;
+        }
+
+        // `- ` without contents
+        if text_begin < text_end {
+            self.convert_inlines(index, text_begin, text_end, &mut result);
+        }
+
+        if is_hard_break {
+            result.push_str("  ");
+        }
+
+        result
+    }
+
+    fn convert_inlines(&mut self, index: usize, begin: usize, end: usize, buffer: &mut String) {
+
+        // `_` and `*` have the same length, so the indexes are still the ones of the source
+        let content = replace_underbar_emphasis(&self.lines[index][begin..end]);
+        let bytes = content.as_bytes();
+        let mut is_inside_del = false;
+        let mut curr_index = 0;
+
+        while curr_index < bytes.len() {
+
+            curr_index = match bytes[curr_index] {
+                b'\\' => convert_backslash(&content, curr_index, buffer),
+                b'`' => self.convert_code_span(index, begin, &content, curr_index, buffer),
+                b'&' => self.convert_entity(index, begin, &content, curr_index, buffer),
+                b'<' => self.convert_angle_bracket(index, begin, &content, curr_index, buffer),
+                b'~' => convert_tilde(&content, curr_index, &mut is_inside_del, buffer),
+                _ => {
+                    let c = content[curr_index..].chars().next().unwrap();
+                    buffer.push(c);
+
+                    curr_index + c.len_utf8()
+                }
+            };
+
+        }
+
+    }
+
+    fn convert_code_span(&mut self, index: usize, begin: usize, content: &str, curr_index: usize, buffer: &mut String) -> usize {
+
+        let bytes = content.as_bytes();
+        let run = count_run(bytes, curr_index);
+
+        let end_index = match get_code_span_end(bytes, curr_index) {
+            Some(end_index) => end_index,
+
+            // a single backtick would begin a code span in hmd
+            None => {
+                buffer.push_str(&"\\`".repeat(run));
+                return curr_index + run;
+            }
+        };
+
+        let mut code = content[curr_index + run..end_index - run].to_string();
+
+        // gfm needs `\|` for pipes in tables even in code spans, but hmd doesn't
+        if self.lines[index].trim_start().starts_with('|') {
+            code = code.replace("\\|", "|");
+        }
+
+        if run == 1 {
+            buffer.push('`');
+            buffer.push_str(&code);
+            buffer.push('`');
+        }
+
+        else if code.contains('`') {
+            self.diagnostics.push(Diagnostic::warning(
+                self.span(index, begin + curr_index, begin + end_index),
+                String::from("hmd cannot express backticks inside a code span")
+            ));
+            buffer.push_str(&content[curr_index..end_index]);
+        }
+
+        else {
+            buffer.push('`');
+            buffer.push_str(&code);
+            buffer.push('`');
+        }
+
+        end_index
+    }
+
+    fn convert_entity(&mut self, index: usize, begin: usize, content: &str, curr_index: usize, buffer: &mut String) -> usize {
+
+        let end_index = match get_entity_end(content.as_bytes(), curr_index) {
+            Some(end_index) => end_index,
+            None => {
+                buffer.push('&');
+                return curr_index + 1;
+            }
+        };
+
+        let entity = &content[curr_index + 1..end_index - 1];
+
+        let decoded = match entity {
+            "nbsp" => {
+                buffer.push_str("[[blank]]");
+                return end_index;
+            }
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ if entity.starts_with("#x") || entity.starts_with("#X") => u32::from_str_radix(&entity[2..], 16).ok().and_then(char::from_u32),
+            _ if entity.starts_with('#') => entity[1..].parse::<u32>().ok().and_then(char::from_u32),
+            _ => None
+        };
+
+        match decoded {
+            Some(c) if c != '\0' => {
+
+                if "\\`*_~^[]|#".contains(c) {
+                    buffer.push('\\');
+                }
+
+                buffer.push(c);
+            }
+            _ => {
+                self.diagnostics.push(Diagnostic::warning(
+                    self.span(index, begin + curr_index, begin + end_index),
+                    format!("hmd cannot express html entity `&{};`, type the character as it is", entity)
+                ));
+                buffer.push_str(&content[curr_index..end_index]);
+            }
+        }
+
+        end_index
+    }
+
+    // autolinks and raw html
+    fn convert_angle_bracket(&mut self, index: usize, begin: usize, content: &str, curr_index: usize, buffer: &mut String) -> usize {
+
+        let rest = &content[curr_index + 1..];
+
+        if let Some(link_end) = get_autolink_end(rest) {
+            let link = &rest[..link_end];
+            let destination = if is_scheme(link) { link.to_string() } else { format!("mailto:{}", link) };
+
+            buffer.push_str(&format!("[{}]({})", link, destination));
+
+            return curr_index + link_end + 2;
+        }
+
+        let is_html = match rest.as_bytes().first() {
+            Some(c) => c.is_ascii_alphabetic() || *c == b'/' || *c == b'!' || *c == b'?',
+            None => false
+        };
+
+        if is_html {
+            let end_index = match rest.find('>') {
+                Some(i) => curr_index + i + 2,
+                None => curr_index + 1
+            };
+
+            self.diagnostics.push(Diagnostic::warning(
+                self.span(index, begin + curr_index, begin + end_index),
+                String::from("hmd cannot express raw html, it's rendered as a text")
+            ));
+        }
+
+        buffer.push('<');
+
+        curr_index + 1
+    }
+
+    fn span(&self, index: usize, begin: usize, end: usize) -> Span {
+
+        let ln = self.lines[index];
+
+        Span {
+            line: index + 1,
+            column: ln[..begin].chars().count() + 1,
+            offset: self.offsets[index] + begin,
+            len: end - begin
+        }
+    }
+
+}
+
+
+// `\q` is `\q` in gfm, but `q` in hmd
+fn convert_backslash(content: &str, curr_index: usize, buffer: &mut String) -> usize {
+
+    match content.as_bytes().get(curr_index + 1) {
+        Some(c) if c.is_ascii_punctuation() => {
+            buffer.push('\\');
+            buffer.push(*c as char);
+
+            curr_index + 2
+        }
+        _ => {
+            buffer.push_str("\\\\");
+
+            curr_index + 1
+        }
+    }
+
+}
+
+
+// a single tilde is a strikethrough in gfm, and a subscript in hmd
+fn convert_tilde(content: &str, curr_index: usize, is_inside_del: &mut bool, buffer: &mut String) -> usize {
+
+    let bytes = content.as_bytes();
+    let run = count_run(bytes, curr_index);
+
+    if run == 1 {
+
+        // ~del~ ends
+        if *is_inside_del && curr_index > 0 && bytes[curr_index - 1] != b' ' {
+            buffer.push_str("~~");
+            *is_inside_del = false;
+            return curr_index + 1;
+        }
+
+        // ~del~ begins
+        let is_opening = !*is_inside_del && curr_index + 1 < bytes.len() && bytes[curr_index + 1] != b' ' && (curr_index + 2..bytes.len()).any(
+            |i| bytes[i] == b'~' && bytes[i - 1] != b'~' && bytes[i - 1] != b' ' && bytes.get(i + 1) != Some(&b'~')
+        );
+
+        if is_opening {
+            buffer.push_str("~~");
+            *is_inside_del = true;
+            return curr_index + 1;
+        }
+
+    }
+
+    buffer.push_str(&content[curr_index..curr_index + run]);
+
+    curr_index + run
+}
+
+
+fn replace_underbar_emphasis(content: &str) -> String {
+
+    let mut bytes = content.as_bytes().to_vec();
+    let is_literal = find_literals(content);
+    let mut curr_index = 0;
+
+    while curr_index < bytes.len() {
+
+        if is_literal[curr_index] || bytes[curr_index] != b'_' {
+            curr_index += 1;
+            continue;
+        }
+
+        let run = count_run(&bytes, curr_index);
+        let is_opening = (curr_index == 0 || !bytes[curr_index - 1].is_ascii_alphanumeric())
+            && curr_index + run < bytes.len() && !bytes[curr_index + run].is_ascii_whitespace();
+
+        // the closing `_`s: not after a space, and not followed by an alphanumeric
+        let closing = if is_opening {
+            (curr_index + run + 1..bytes.len()).find(
+                |i| bytes[*i] == b'_' && !is_literal[*i] && !bytes[*i - 1].is_ascii_whitespace() && bytes[*i - 1] != b'_'
+                && count_run(&bytes, *i) == run
+                && match bytes.get(*i + run) {
+                    None => true,
+                    Some(c) => !c.is_ascii_alphanumeric()
+                }
+            )
+        } else { None };
+
+        if let Some(closing) = closing {
+
+            for i in 0..run {
+                bytes[curr_index + i] = b'*';
+                bytes[closing + i] = b'*';
+            }
+
+            curr_index = closing + run;
+        }
+
+        else {
+            curr_index += run;
+        }
+
+    }
+
+    // only ascii characters are replaced
+    String::from_utf8(bytes).unwrap()
+}
+
+
+// `_`s in these are not emphases: code spans, escapes, link destinations `](...)`, autolinks `<...>` and bare urls
+fn find_literals(content: &str) -> Vec<bool> {
+
+    let bytes = content.as_bytes();
+    let mut result = vec![false; bytes.len()];
+    let mut curr_index = 0;
+
+    while curr_index < bytes.len() {
+
+        let literal_end = match bytes[curr_index] {
+            b'`' => Some(get_code_span_end(bytes, curr_index).unwrap_or(curr_index + count_run(bytes, curr_index))),
+            b'\\' => Some((curr_index + 2).min(bytes.len())),
+            b']' if bytes.get(curr_index + 1) == Some(&b'(') => get_destination_end(bytes, curr_index + 1),
+            b'<' => get_autolink_end(&content[curr_index + 1..]).map(|link_end| curr_index + link_end + 2),
+            _ if is_bare_url(bytes, curr_index) => Some(curr_index + bytes[curr_index..].iter().take_while(|c| !c.is_ascii_whitespace() && **c != b'<').count()),
+            _ => None
+        };
+
+        match literal_end {
+            Some(end_index) => {
+                result[curr_index..end_index].fill(true);
+                curr_index = end_index;
+            }
+            None => {
+                curr_index += 1;
+            }
+        }
+
+    }
+
+    result
+}
+
+
+// `index` is at `(`, returns the index after the matching `)`
+fn get_destination_end(bytes: &[u8], index: usize) -> Option<usize> {
+
+    let mut depth = 0;
+    let mut curr_index = index;
+
+    while curr_index < bytes.len() {
+
+        match bytes[curr_index] {
+            b'\\' => {
+                curr_index += 1;
+            }
+            b'(' => {
+                depth += 1;
+            }
+            b')' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(curr_index + 1);
+                }
+
+            }
+            _ => {}
+        }
+
+        curr_index += 1;
+    }
+
+    None
+}
+
+
+// `https://a.b/_c_`, `www.a.b/_c_`, gfm links them without `<>`
+fn is_bare_url(bytes: &[u8], index: usize) -> bool {
+
+    let rest = &bytes[index..];
+    let is_word_begin = index == 0 || !bytes[index - 1].is_ascii_alphanumeric();
+
+    is_word_begin && (rest.starts_with(b"http://") || rest.starts_with(b"https://") || rest.starts_with(b"www."))
+}
+
+
+// the index after the closing backticks
+fn get_code_span_end(bytes: &[u8], index: usize) -> Option<usize> {
+
+    let run = count_run(bytes, index);
+    let mut curr_index = index + run;
+
+    while curr_index < bytes.len() {
+
+        if bytes[curr_index] == b'`' {
+            let closing_run = count_run(bytes, curr_index);
+
+            if closing_run == run {
+                return Some(curr_index + run);
+            }
+
+            curr_index += closing_run;
+        }
+
+        else {
+            curr_index += 1;
+        }
+
+    }
+
+    None
+}
+
+
+// the index after `;`
+fn get_entity_end(bytes: &[u8], index: usize) -> Option<usize> {
+
+    let (name_begin, is_valid): (usize, fn(&u8) -> bool) = match (bytes.get(index + 1), bytes.get(index + 2)) {
+        (Some(b'#'), Some(b'x')) | (Some(b'#'), Some(b'X')) => (index + 3, u8::is_ascii_hexdigit),
+        (Some(b'#'), _) => (index + 2, u8::is_ascii_digit),
+        (Some(c), _) if c.is_ascii_alphabetic() => (index + 1, u8::is_ascii_alphanumeric),
+        _ => { return None; }
+    };
+
+    let name_len = bytes[name_begin..].iter().take_while(|c| is_valid(c)).count();
+
+    if name_len > 0 && name_len < 32 && bytes.get(name_begin + name_len) == Some(&b';') {
+        Some(name_begin + name_len + 1)
+    }
+
+    else {
+        None
+    }
+
+}
+
+
+// `rest` is after `<`, returns the length of the link
+fn get_autolink_end(rest: &str) -> Option<usize> {
+
+    let link_end = rest.find('>')?;
+    let link = &rest[..link_end];
+
+    if link.is_empty() || link.contains([' ', '<']) {
+        return None;
+    }
+
+    let is_email = link.contains('@') && link.bytes().all(
+        |c| c.is_ascii_alphanumeric() || b"@.-_+".contains(&c)
+    );
+
+    if is_scheme(link) || is_email {
+        Some(link_end)
+    }
+
+    else {
+        None
+    }
+
+}
+
+
+// `https:`, `mailto:`
+fn is_scheme(link: &str) -> bool {
+
+    match link.split_once(':') {
+        Some((scheme, _)) => scheme.len() > 1 && scheme.len() < 33
+            && scheme.as_bytes()[0].is_ascii_alphabetic()
+            && scheme.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'+' || c == b'.' || c == b'-'),
+        None => false
+    }
+
+}
+
+
+fn count_run(bytes: &[u8], index: usize) -> usize {
+    bytes[index..].iter().take_while(|c| **c == bytes[index]).count()
+}
+
+
+// (width, byte length), a tab is 4 spaces
+fn measure_indent(line: &str) -> (usize, usize) {
+
+    let mut width = 0;
+    let mut len = 0;
+
+    for c in line.bytes() {
+
+        if c == b' ' {
+            width += 1;
+        }
+
+        else if c == b'\t' {
+            width += 4;
+        }
+
+        else {
+            break;
+        }
+
+        len += 1;
+    }
+
+    (width, len)
+}
+
+
+// removes at most `width` columns of indentation
+fn remove_indent(line: &str, width: usize) -> &str {
+
+    let mut removed = 0;
+    let mut index = 0;
+
+    for c in line.bytes() {
+
+        if removed >= width || (c != b' ' && c != b'\t') {
+            break;
+        }
+
+        removed += if c == b'\t' { 4 } else { 1 };
+        index += 1;
+    }
+
+    &line[index..]
+}
+
+
+// (number of `>`s, byte length of the markers)
+fn measure_blockquote(line: &str) -> (usize, usize) {
+
+    let bytes = line.as_bytes();
+    let mut depth = 0;
+    let mut index = 0;
+
+    loop {
+        let spaces = bytes[index..].iter().take(3).take_while(|c| **c == b' ').count();
+
+        if bytes.get(index + spaces) != Some(&b'>') {
+            break;
+        }
+
+        depth += 1;
+        index += spaces + 1;
+
+        if bytes.get(index) == Some(&b' ') {
+            index += 1;
+        }
+
+    }
+
+    (depth, index)
+}
+
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+
+fn is_opening_fence(content: &str) -> bool {
+
+    (content.starts_with("```") && !content.trim_start_matches('`').contains('`')) || content.starts_with("~~~")
+}
+
+
+fn is_closing_fence(line: &str, marker: u8, fence_len: usize) -> bool {
+
+    let (indent, indent_len) = measure_indent(line);
+    let content = line[indent_len..].trim_end();
+
+    indent < 4 && content.len() >= fence_len && content.bytes().all(|c| c == marker)
+}
+
+
+// `* * *`
+fn is_thematic_break(content: &str) -> bool {
+
+    let marker = match content.bytes().next() {
+        Some(c) if c == b'*' || c == b'-' || c == b'_' => c,
+        _ => { return false; }
+    };
+
+    content.bytes().filter(|c| *c == marker).count() > 2
+    && content.bytes().all(|c| c == marker || c == b' ' || c == b'\t')
+}
+
+
+fn atx_level(content: &str) -> Option<usize> {
+
+    let level = content.bytes().take_while(|c| *c == b'#').count();
+
+    if level > 0 && level < 7 && (content.len() == level || content[level..].starts_with([' ', '\t'])) {
+        Some(level)
+    }
+
+    else {
+        None
+    }
+
+}
+
+
+fn setext_level(line: &str) -> Option<usize> {
+
+    let (indent, indent_len) = measure_indent(line);
+    let content = line[indent_len..].trim_end();
+
+    if indent > 3 || content.is_empty() {
+        None
+    }
+
+    else if content.bytes().all(|c| c == b'=') {
+        Some(1)
+    }
+
+    else if content.bytes().all(|c| c == b'-') {
+        Some(2)
+    }
+
+    else {
+        None
+    }
+
+}
+
+
+// `* `, `+ `, `- `
+fn unordered_bullet_len(content: &str) -> Option<usize> {
+
+    let bytes = content.as_bytes();
+
+    if bytes.len() > 1 && (bytes[0] == b'*' || bytes[0] == b'+' || bytes[0] == b'-') && (bytes[1] == b' ' || bytes[1] == b'\t') {
+        Some(2)
+    }
+
+    else {
+        None
+    }
+
+}
+
+
+// `12. `, `3) `
+fn ordered_bullet_len(content: &str) -> Option<usize> {
+
+    let bytes = content.as_bytes();
+    let digits = bytes.iter().take_while(|c| c.is_ascii_digit()).count();
+
+    if digits > 0 && digits < 10 && bytes.len() > digits + 1
+        && (bytes[digits] == b'.' || bytes[digits] == b')')
+        && (bytes[digits + 1] == b' ' || bytes[digits + 1] == b'\t') {
+        Some(digits + 2)
+    }
+
+    else {
+        None
+    }
+
+}
+
+
+fn is_list_item(content: &str) -> bool {
+    unordered_bullet_len(content).is_some() || ordered_bullet_len(content).is_some()
+}
+
+
+fn is_paragraph_line(line: &str) -> bool {
+
+    let (indent, indent_len) = measure_indent(line);
+    let content = &line[indent_len..];
+
+    !is_blank(line) && indent < 4
+    && !content.starts_with('>')
+    && !is_opening_fence(content)
+    && !is_thematic_break(content)
+    && atx_level(content).is_none()
+    && setext_level(line).is_none()
+    && !is_list_item(content)
+}
@@ -5,26 +5,73 @@ use std::collections::HashMap;
 
 
 lazy_static! {
-    static ref ICONS: HashMap<Vec<u16>, Vec<u16>> = self::render::data();
+    static ref ICONS: HashMap<&'static str, &'static str> = self::render::data();
 }
 
 
-pub fn get_icon(name: &Vec<u16>, size: usize, r: u8, g: u8, b: u8) -> Option<Vec<u16>> {
+// `color` is `None` to follow the color of the text
+pub fn get_icon(name: &str, size: usize, color: Option<(u8, u8, u8)>) -> Option<String> {
+
+    ICONS.get(name).map(|s| self::render::format(s, size, color))
+}
+
+
+// github strips inline `<svg>`s, so `to_gfm` embeds the icon as an image, and the alt text is shown if the image is stripped too
+// an image does not inherit the color of the text, so `color` is required
+pub fn get_icon_image(name: &str, size: usize, color: (u8, u8, u8)) -> Option<String> {
+
+    let svg = get_icon(name, size, Some(color))?;
+    let mut uri = String::with_capacity(svg.len() * 2);
+
+    for c in svg.bytes() {
+
+        if c.is_ascii_alphanumeric() || b"-_.~/:;=,!*'()".contains(&c) {
+            uri.push(c as char);
+        }
+
+        else {
+            uri.push_str(&format!("%{:02X}", c));
+        }
 
-    match ICONS.get(name) {
-        Some(s) => Some(self::render::format(s, size, r, g, b)),
-        _ => None
     }
 
+    Some(format!("<img src=\"data:image/svg+xml,{}\" alt=\"{}\" width=\"{}\" height=\"{}\">", uri, name, size, size))
+}
+
+
+pub fn is_icon_name(name: &str) -> bool {
+    ICONS.contains_key(name)
 }
 
 
 #[cfg(test)]
 mod tests {
 
+    use super::{ICONS, get_icon, get_icon_image};
+
     #[test]
     fn icon_test() {
-        panic!("{}", String::from_utf16_lossy(&crate::markdown::icon::get_icon(&vec![99], 20, 0, 0, 0).unwrap()));
+        let icon = get_icon("c", 20, None).unwrap();
+
+        assert!(icon.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 128 128\" width=\"20\" height=\"20\">"));
+        assert!(icon.contains("fill=\"currentColor\"") && icon.ends_with("</svg>"));
+        assert!(get_icon("c", 20, Some((192, 32, 32))).unwrap().contains("fill=\"rgb(192, 32, 32)\""));
+        assert_eq!(get_icon("no such icon", 20, None), None);
+
+        // svg attributes are case-sensitive
+        for name in ICONS.keys() {
+            let icon = get_icon(name, 24, None).unwrap();
+
+            assert!(icon.contains(" viewBox=\"") && !icon.contains("viewbox") && !icon.contains("  "), "{}", name);
+        }
+
+        let image = get_icon_image("rust", 16, (0, 0, 0)).unwrap();
+
+        assert!(image.starts_with("<img src=\"data:image/svg+xml,%3Csvg%20xmlns=%22http://www.w3.org/2000/svg%22%20viewBox="));
+        assert!(image.ends_with("%3C/svg%3E\" alt=\"rust\" width=\"16\" height=\"16\">"));
+
+        let uri = &image[10..image.find("\" alt").unwrap()];
+        assert!(!uri.contains(['<', '>', '"', '#', ' ']));
     }
 
-}
\ No newline at end of file
+}
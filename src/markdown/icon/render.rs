@@ -2,158 +2,147 @@ use std::collections::HashMap;
 
 
 // this file is auto-generated by a Python file!
-pub fn data() -> HashMap<Vec<u16>, Vec<u16>> {
+pub fn data() -> HashMap<&'static str, &'static str> {
 
     let mut result = HashMap::with_capacity(100);
 
     // 
-result.insert(into_v16("alerttriangle"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 97, 108, 101, 114, 116, 45, 116, 114, 105, 97, 110, 103, 108, 101, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 57, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 50, 46, 53, 54, 32, 49, 54, 46, 51, 76, 49, 52, 46, 56, 57, 32, 51, 46, 53, 56, 97, 51, 46, 52, 51, 32, 51, 46, 52, 51, 32, 48, 32, 48, 32, 48, 45, 53, 46, 55, 56, 32, 48, 76, 49, 46, 52, 52, 32, 49, 54, 46, 51, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 46, 48, 53, 32, 51, 65, 51, 46, 51, 55, 32, 51, 46, 51, 55, 32, 48, 32, 48, 32, 48, 32, 52, 46, 51, 51, 32, 50, 49, 104, 49, 53, 46, 51, 52, 97, 51, 46, 51, 55, 32, 51, 46, 51, 55, 32, 48, 32, 48, 32, 48, 32, 50, 46, 57, 52, 45, 49, 46, 54, 54, 32, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 46, 48, 53, 45, 51, 46, 48, 52, 122, 77, 49, 50, 32, 49, 55, 97, 49, 32, 49, 32, 48, 32, 49, 32, 49, 32, 49, 45, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 49, 32, 49, 122, 109, 49, 45, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 50, 32, 48, 86, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 50, 32, 48, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("arrowback"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 97, 114, 114, 111, 119, 45, 98, 97, 99, 107, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 57, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 57, 32, 49, 49, 72, 55, 46, 49, 52, 108, 51, 46, 54, 51, 45, 52, 46, 51, 54, 97, 49, 32, 49, 32, 48, 32, 49, 32, 48, 45, 49, 46, 53, 52, 45, 49, 46, 50, 56, 108, 45, 53, 32, 54, 97, 49, 46, 49, 57, 32, 49, 46, 49, 57, 32, 48, 32, 48, 32, 48, 45, 46, 48, 57, 46, 49, 53, 99, 48, 32, 46, 48, 53, 32, 48, 32, 46, 48, 56, 45, 46, 48, 55, 46, 49, 51, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 52, 32, 49, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 48, 55, 46, 51, 54, 99, 48, 32, 46, 48, 53, 32, 48, 32, 46, 48, 56, 46, 48, 55, 46, 49, 51, 97, 49, 46, 49, 57, 32, 49, 46, 49, 57, 32, 48, 32, 48, 32, 48, 32, 46, 48, 57, 46, 49, 53, 108, 53, 32, 54, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 48, 32, 49, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 54, 52, 45, 46, 50, 51, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 49, 51, 45, 49, 46, 52, 49, 76, 55, 46, 49, 52, 32, 49, 51, 72, 49, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("arrowdown"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 97, 114, 114, 111, 119, 45, 100, 111, 119, 110, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 56, 46, 55, 55, 32, 49, 51, 46, 51, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 49, 45, 46, 49, 51, 76, 49, 51, 32, 49, 54, 46, 56, 54, 86, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 50, 32, 48, 118, 49, 49, 46, 56, 54, 108, 45, 52, 46, 51, 54, 45, 51, 46, 54, 51, 97, 49, 32, 49, 32, 48, 32, 49, 32, 48, 45, 49, 46, 50, 56, 32, 49, 46, 53, 52, 108, 54, 32, 53, 32, 46, 49, 53, 46, 48, 57, 46, 49, 51, 46, 48, 55, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 50, 32, 48, 108, 46, 49, 51, 45, 46, 48, 55, 46, 49, 53, 45, 46, 48, 57, 32, 54, 45, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 49, 51, 45, 49, 46, 52, 49, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("arrowforward"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 97, 114, 114, 111, 119, 45, 102, 111, 114, 119, 97, 114, 100, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 45, 57, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 53, 32, 49, 51, 104, 49, 49, 46, 56, 54, 108, 45, 51, 46, 54, 51, 32, 52, 46, 51, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 53, 52, 32, 49, 46, 50, 56, 108, 53, 45, 54, 97, 49, 46, 49, 57, 32, 49, 46, 49, 57, 32, 48, 32, 48, 32, 48, 32, 46, 48, 57, 45, 46, 49, 53, 99, 48, 45, 46, 48, 53, 46, 48, 53, 45, 46, 48, 56, 46, 48, 55, 45, 46, 49, 51, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 50, 48, 32, 49, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 48, 55, 45, 46, 51, 54, 99, 48, 45, 46, 48, 53, 45, 46, 48, 53, 45, 46, 48, 56, 45, 46, 48, 55, 45, 46, 49, 51, 97, 49, 46, 49, 57, 32, 49, 46, 49, 57, 32, 48, 32, 48, 32, 48, 45, 46, 48, 57, 45, 46, 49, 53, 108, 45, 53, 45, 54, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 52, 32, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 54, 52, 46, 50, 51, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 49, 51, 32, 49, 46, 52, 49, 76, 49, 54, 46, 56, 54, 32, 49, 49, 72, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 32, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("arrowheaddown"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 97, 114, 114, 111, 119, 104, 101, 97, 100, 45, 100, 111, 119, 110, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 55, 46, 51, 55, 32, 49, 50, 46, 51, 57, 76, 49, 50, 32, 49, 54, 46, 55, 49, 108, 45, 53, 46, 51, 54, 45, 52, 46, 52, 56, 97, 49, 32, 49, 32, 48, 32, 49, 32, 48, 45, 49, 46, 50, 56, 32, 49, 46, 53, 52, 108, 54, 32, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 50, 55, 32, 48, 108, 54, 45, 52, 46, 56, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 49, 53, 45, 49, 46, 52, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 49, 45, 46, 49, 52, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 49, 46, 51, 54, 32, 49, 49, 46, 55, 55, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 50, 55, 32, 48, 108, 54, 45, 52, 46, 56, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 49, 53, 45, 49, 46, 52, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 49, 45, 46, 49, 53, 76, 49, 50, 32, 57, 46, 55, 49, 32, 54, 46, 54, 52, 32, 53, 46, 50, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 50, 56, 32, 49, 46, 53, 52, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("arrowheadleft"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 97, 114, 114, 111, 119, 104, 101, 97, 100, 45, 108, 101, 102, 116, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 57, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 49, 46, 54, 52, 32, 53, 46, 50, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 49, 46, 49, 51, 108, 45, 53, 32, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 32, 49, 46, 50, 55, 108, 52, 46, 56, 51, 32, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 56, 46, 51, 55, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 56, 45, 49, 46, 54, 51, 76, 55, 46, 50, 57, 32, 49, 50, 108, 52, 46, 52, 56, 45, 53, 46, 51, 55, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 49, 51, 45, 49, 46, 52, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 52, 46, 50, 57, 32, 49, 50, 108, 52, 46, 52, 56, 45, 53, 46, 51, 55, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 53, 52, 45, 49, 46, 50, 56, 108, 45, 53, 32, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 32, 49, 46, 50, 55, 108, 52, 46, 56, 51, 32, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 56, 46, 51, 55, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 56, 45, 49, 46, 54, 51, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("arrowheadright"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 97, 114, 114, 111, 119, 104, 101, 97, 100, 45, 114, 105, 103, 104, 116, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 45, 57, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 56, 46, 55, 56, 32, 49, 49, 46, 51, 55, 108, 45, 52, 46, 55, 56, 45, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 49, 45, 46, 49, 53, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 49, 53, 32, 49, 46, 52, 49, 76, 49, 54, 46, 55, 49, 32, 49, 50, 108, 45, 52, 46, 52, 56, 32, 53, 46, 51, 55, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 49, 51, 32, 49, 46, 52, 49, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 51, 32, 49, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 55, 45, 46, 51, 54, 108, 53, 45, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 48, 49, 45, 49, 46, 50, 55, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 55, 32, 53, 46, 51, 55, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 54, 49, 32, 49, 46, 50, 54, 76, 57, 46, 55, 49, 32, 49, 50, 108, 45, 52, 46, 52, 56, 32, 53, 46, 51, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 49, 51, 32, 49, 46, 52, 49, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 54, 32, 49, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 55, 45, 46, 51, 54, 108, 53, 45, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 46, 50, 55, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("arrowheadup"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 97, 114, 114, 111, 119, 104, 101, 97, 100, 45, 117, 112, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 49, 56, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 54, 46, 54, 51, 32, 49, 49, 46, 54, 49, 76, 49, 50, 32, 55, 46, 50, 57, 108, 53, 46, 51, 55, 32, 52, 46, 52, 56, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 56, 32, 49, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 55, 45, 46, 51, 54, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 49, 51, 45, 49, 46, 52, 49, 108, 45, 54, 45, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 50, 55, 32, 48, 108, 45, 54, 32, 52, 46, 56, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 49, 53, 32, 49, 46, 52, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 49, 46, 49, 52, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 46, 54, 52, 32, 49, 50, 46, 50, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 50, 55, 32, 48, 108, 45, 54, 32, 52, 46, 56, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 49, 53, 32, 49, 46, 52, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 49, 46, 49, 53, 76, 49, 50, 32, 49, 52, 46, 50, 57, 108, 53, 46, 51, 55, 32, 52, 46, 52, 56, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 56, 32, 49, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 55, 45, 46, 51, 54, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 49, 51, 45, 49, 46, 52, 49, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("arrowupward"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 97, 114, 114, 111, 119, 45, 117, 112, 119, 97, 114, 100, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 49, 56, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 53, 46, 50, 51, 32, 49, 48, 46, 54, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 49, 46, 49, 51, 76, 49, 49, 32, 55, 46, 49, 52, 86, 49, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 50, 32, 48, 86, 55, 46, 49, 52, 108, 52, 46, 51, 54, 32, 51, 46, 54, 51, 97, 49, 32, 49, 32, 48, 32, 49, 32, 48, 32, 49, 46, 50, 56, 45, 49, 46, 53, 52, 108, 45, 54, 45, 53, 45, 46, 49, 53, 45, 46, 48, 57, 45, 46, 49, 51, 45, 46, 48, 55, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 55, 50, 32, 48, 108, 45, 46, 49, 51, 46, 48, 55, 45, 46, 49, 53, 46, 48, 57, 45, 54, 32, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 49, 51, 32, 49, 46, 52, 49, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("at"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 97, 116, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 51, 32, 50, 97, 49, 48, 32, 49, 48, 32, 48, 32, 48, 32, 48, 45, 53, 32, 49, 57, 46, 49, 32, 49, 48, 46, 49, 53, 32, 49, 48, 46, 49, 53, 32, 48, 32, 48, 32, 48, 32, 52, 32, 46, 57, 32, 49, 48, 32, 49, 48, 32, 48, 32, 48, 32, 48, 32, 54, 46, 48, 56, 45, 50, 46, 48, 54, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 49, 57, 45, 49, 46, 52, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 49, 45, 46, 49, 57, 65, 56, 32, 56, 32, 48, 32, 49, 32, 49, 32, 49, 50, 46, 55, 55, 32, 52, 32, 56, 46, 49, 55, 32, 56, 46, 49, 55, 32, 48, 32, 48, 32, 49, 32, 50, 48, 32, 49, 50, 46, 50, 50, 118, 46, 54, 56, 97, 49, 46, 55, 49, 32, 49, 46, 55, 49, 32, 48, 32, 48, 32, 49, 45, 49, 46, 55, 56, 32, 49, 46, 55, 32, 49, 46, 56, 50, 32, 49, 46, 56, 50, 32, 48, 32, 48, 32, 49, 45, 49, 46, 54, 50, 45, 49, 46, 56, 56, 86, 56, 46, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 45, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 32, 46, 56, 55, 32, 53, 32, 53, 32, 48, 32, 48, 32, 48, 45, 51, 46, 52, 52, 45, 49, 46, 51, 54, 65, 53, 46, 48, 57, 32, 53, 46, 48, 57, 32, 48, 32, 49, 32, 48, 32, 49, 53, 46, 51, 49, 32, 49, 53, 97, 51, 46, 54, 32, 51, 46, 54, 32, 48, 32, 48, 32, 48, 32, 53, 46, 53, 53, 46, 54, 49, 65, 51, 46, 54, 55, 32, 51, 46, 54, 55, 32, 48, 32, 48, 32, 48, 32, 50, 50, 32, 49, 50, 46, 57, 118, 45, 46, 54, 56, 65, 49, 48, 46, 50, 32, 49, 48, 46, 50, 32, 48, 32, 48, 32, 48, 32, 49, 51, 32, 50, 122, 109, 45, 49, 46, 56, 50, 32, 49, 51, 46, 48, 57, 65, 51, 46, 48, 57, 32, 51, 46, 48, 57, 32, 48, 32, 49, 32, 49, 32, 49, 52, 46, 50, 55, 32, 49, 50, 97, 51, 46, 49, 32, 51, 46, 49, 32, 48, 32, 48, 32, 49, 45, 51, 46, 48, 57, 32, 51, 46, 48, 57, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("attach"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 97, 116, 116, 97, 99, 104, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 57, 46, 50, 57, 32, 50, 49, 97, 54, 46, 50, 51, 32, 54, 46, 50, 51, 32, 48, 32, 48, 32, 49, 45, 52, 46, 52, 51, 45, 49, 46, 56, 56, 32, 54, 32, 54, 32, 48, 32, 48, 32, 49, 45, 46, 50, 50, 45, 56, 46, 52, 57, 76, 49, 50, 32, 51, 46, 50, 65, 52, 46, 49, 49, 32, 52, 46, 49, 49, 32, 48, 32, 48, 32, 49, 32, 49, 53, 32, 50, 97, 52, 46, 52, 56, 32, 52, 46, 52, 56, 32, 48, 32, 48, 32, 49, 32, 51, 46, 49, 57, 32, 49, 46, 51, 53, 32, 52, 46, 51, 54, 32, 52, 46, 51, 54, 32, 48, 32, 48, 32, 49, 32, 46, 49, 53, 32, 54, 46, 49, 51, 108, 45, 55, 46, 52, 32, 55, 46, 52, 51, 97, 50, 46, 53, 52, 32, 50, 46, 53, 52, 32, 48, 32, 48, 32, 49, 45, 49, 46, 56, 49, 46, 55, 53, 32, 50, 46, 55, 50, 32, 50, 46, 55, 50, 32, 48, 32, 48, 32, 49, 45, 49, 46, 57, 53, 45, 46, 56, 50, 32, 50, 46, 54, 56, 32, 50, 46, 54, 56, 32, 48, 32, 48, 32, 49, 45, 46, 48, 56, 45, 51, 46, 55, 55, 108, 54, 46, 56, 51, 45, 54, 46, 56, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 49, 46, 51, 55, 32, 49, 46, 52, 49, 108, 45, 54, 46, 56, 51, 32, 54, 46, 56, 54, 97, 46, 54, 56, 46, 54, 56, 32, 48, 32, 48, 32, 48, 32, 46, 48, 56, 46, 57, 53, 46, 55, 56, 46, 55, 56, 32, 48, 32, 48, 32, 48, 32, 46, 53, 51, 46, 50, 51, 46, 53, 54, 46, 53, 54, 32, 48, 32, 48, 32, 48, 32, 46, 52, 45, 46, 49, 54, 108, 55, 46, 51, 57, 45, 55, 46, 52, 51, 97, 50, 46, 51, 54, 32, 50, 46, 51, 54, 32, 48, 32, 48, 32, 48, 45, 46, 49, 53, 45, 51, 46, 51, 49, 32, 50, 46, 51, 56, 32, 50, 46, 51, 56, 32, 48, 32, 48, 32, 48, 45, 51, 46, 50, 55, 45, 46, 49, 53, 76, 54, 46, 48, 54, 32, 49, 50, 97, 52, 32, 52, 32, 48, 32, 48, 32, 48, 32, 46, 50, 50, 32, 53, 46, 54, 55, 32, 52, 46, 50, 50, 32, 52, 46, 50, 50, 32, 48, 32, 48, 32, 48, 32, 51, 32, 49, 46, 50, 57, 32, 51, 46, 54, 55, 32, 51, 46, 54, 55, 32, 48, 32, 48, 32, 48, 32, 50, 46, 54, 49, 45, 49, 46, 48, 54, 108, 55, 46, 51, 57, 45, 55, 46, 52, 51, 97, 49, 32, 49, 32, 48, 32, 49, 32, 49, 32, 49, 46, 52, 50, 32, 49, 46, 52, 49, 108, 45, 55, 46, 51, 57, 32, 55, 46, 52, 51, 65, 53, 46, 54, 53, 32, 53, 46, 54, 53, 32, 48, 32, 48, 32, 49, 32, 57, 46, 50, 57, 32, 50, 49, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("beer"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 51, 57, 50, 44, 50, 48, 56, 72, 51, 54, 56, 118, 45, 53, 46, 55, 52, 65, 54, 51, 46, 57, 51, 44, 54, 51, 46, 57, 51, 44, 48, 44, 48, 44, 48, 44, 51, 50, 49, 46, 54, 53, 44, 57, 54, 97, 49, 49, 49, 44, 49, 49, 49, 44, 48, 44, 48, 44, 48, 45, 50, 55, 46, 53, 57, 45, 52, 55, 46, 50, 57, 65, 49, 48, 56, 46, 54, 50, 44, 49, 48, 56, 46, 54, 50, 44, 48, 44, 48, 44, 48, 44, 50, 49, 54, 44, 49, 54, 99, 45, 50, 57, 46, 57, 49, 44, 48, 45, 53, 55, 46, 55, 56, 44, 49, 50, 46, 50, 56, 45, 55, 57, 44, 51, 52, 46, 54, 56, 97, 53, 54, 44, 53, 54, 44, 48, 44, 48, 44, 48, 45, 54, 55, 46, 53, 49, 44, 55, 55, 46, 53, 52, 65, 54, 51, 46, 57, 49, 44, 54, 51, 46, 57, 49, 44, 48, 44, 48, 44, 48, 44, 56, 48, 44, 50, 51, 49, 46, 51, 57, 86, 52, 52, 48, 97, 53, 54, 46, 48, 54, 44, 53, 54, 46, 48, 54, 44, 48, 44, 48, 44, 48, 44, 53, 54, 44, 53, 54, 72, 51, 49, 50, 97, 53, 54, 46, 48, 54, 44, 53, 54, 46, 48, 54, 44, 48, 44, 48, 44, 48, 44, 53, 54, 45, 53, 54, 118, 45, 56, 104, 50, 52, 97, 55, 50, 46, 48, 56, 44, 55, 50, 46, 48, 56, 44, 48, 44, 48, 44, 48, 44, 55, 50, 45, 55, 50, 86, 50, 56, 48, 65, 55, 50, 46, 48, 56, 44, 55, 50, 46, 48, 56, 44, 48, 44, 48, 44, 48, 44, 51, 57, 50, 44, 50, 48, 56, 90, 77, 49, 55, 54, 44, 52, 49, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 45, 51, 50, 44, 48, 86, 50, 53, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 51, 50, 44, 48, 90, 109, 54, 52, 44, 48, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 45, 51, 50, 44, 48, 86, 50, 53, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 51, 50, 44, 48, 90, 109, 54, 52, 44, 48, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 45, 51, 50, 44, 48, 86, 50, 53, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 51, 50, 44, 48, 90, 109, 49, 54, 45, 50, 50, 52, 99, 45, 56, 46, 51, 51, 44, 48, 45, 50, 48, 46, 53, 53, 45, 53, 46, 49, 56, 45, 50, 54, 46, 54, 57, 45, 49, 49, 46, 51, 49, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 50, 56, 50, 44, 49, 55, 54, 72, 49, 54, 48, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 45, 49, 53, 44, 49, 48, 46, 53, 51, 67, 49, 51, 56, 46, 49, 55, 44, 50, 48, 53, 46, 50, 49, 44, 49, 50, 49, 46, 52, 44, 50, 48, 56, 44, 49, 49, 50, 44, 50, 48, 56, 97, 51, 50, 44, 51, 50, 44, 48, 44, 48, 44, 49, 44, 48, 45, 54, 52, 99, 46, 48, 57, 44, 48, 44, 57, 46, 49, 50, 46, 51, 52, 44, 49, 54, 46, 52, 44, 53, 46, 56, 97, 49, 54, 44, 49, 54, 44, 48, 44, 49, 44, 48, 44, 49, 57, 46, 50, 45, 50, 53, 46, 54, 65, 54, 51, 46, 54, 57, 44, 54, 51, 46, 54, 57, 44, 48, 44, 48, 44, 48, 44, 49, 49, 50, 44, 49, 49, 50, 97, 54, 51, 46, 53, 53, 44, 54, 51, 46, 53, 53, 44, 48, 44, 48, 44, 48, 45, 49, 52, 44, 49, 46, 53, 55, 65, 50, 52, 44, 50, 52, 44, 48, 44, 48, 44, 49, 44, 49, 50, 48, 44, 56, 48, 97, 50, 51, 46, 55, 56, 44, 50, 51, 46, 55, 56, 44, 48, 44, 48, 44, 49, 44, 49, 57, 46, 51, 56, 44, 57, 46, 56, 52, 44, 53, 49, 46, 51, 53, 44, 53, 49, 46, 51, 53, 44, 48, 44, 48, 44, 49, 44, 52, 46, 55, 49, 44, 55, 46, 57, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 55, 54, 44, 57, 54, 99, 48, 45, 54, 46, 55, 55, 45, 51, 46, 54, 49, 45, 49, 53, 46, 49, 55, 45, 49, 48, 46, 55, 54, 45, 50, 53, 45, 46, 52, 54, 45, 46, 54, 51, 45, 49, 45, 49, 46, 50, 53, 45, 49, 46, 52, 53, 45, 49, 46, 56, 54, 67, 49, 55, 56, 46, 51, 57, 44, 53, 53, 46, 52, 52, 44, 49, 57, 54, 46, 54, 52, 44, 52, 56, 44, 50, 49, 54, 44, 52, 56, 97, 55, 54, 46, 56, 54, 44, 55, 54, 46, 56, 54, 44, 48, 44, 48, 44, 49, 44, 53, 53, 46, 50, 51, 44, 50, 51, 46, 49, 56, 65, 56, 48, 46, 50, 44, 56, 48, 46, 50, 44, 48, 44, 48, 44, 49, 44, 50, 57, 50, 46, 54, 49, 44, 49, 52, 50, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 50, 46, 55, 51, 44, 49, 56, 46, 55, 49, 44, 49, 54, 46, 50, 57, 44, 49, 54, 46, 50, 57, 44, 48, 44, 48, 44, 48, 44, 51, 44, 46, 50, 56, 44, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 53, 46, 55, 45, 49, 51, 65, 49, 49, 49, 46, 55, 56, 44, 49, 49, 49, 46, 55, 56, 44, 48, 44, 48, 44, 48, 44, 51, 50, 54, 44, 49, 50, 56, 46, 53, 55, 44, 51, 50, 44, 51, 50, 44, 48, 44, 48, 44, 49, 44, 51, 50, 48, 44, 49, 57, 50, 90, 77, 52, 51, 50, 44, 51, 54, 48, 97, 52, 48, 44, 52, 48, 44, 48, 44, 48, 44, 49, 45, 52, 48, 44, 52, 48, 72, 51, 54, 56, 86, 50, 52, 48, 104, 50, 52, 97, 52, 48, 44, 52, 48, 44, 48, 44, 48, 44, 49, 44, 52, 48, 44, 52, 48, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("bell"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 98, 101, 108, 108, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 48, 46, 53, 50, 32, 49, 53, 46, 50, 49, 108, 45, 49, 46, 56, 45, 49, 46, 56, 49, 86, 56, 46, 57, 52, 97, 54, 46, 56, 54, 32, 54, 46, 56, 54, 32, 48, 32, 48, 32, 48, 45, 53, 46, 56, 50, 45, 54, 46, 56, 56, 32, 54, 46, 55, 52, 32, 54, 46, 55, 52, 32, 48, 32, 48, 32, 48, 45, 55, 46, 54, 50, 32, 54, 46, 54, 55, 118, 52, 46, 54, 55, 108, 45, 49, 46, 56, 32, 49, 46, 56, 49, 65, 49, 46, 54, 52, 32, 49, 46, 54, 52, 32, 48, 32, 48, 32, 48, 32, 52, 46, 54, 52, 32, 49, 56, 72, 56, 118, 46, 51, 52, 65, 51, 46, 56, 52, 32, 51, 46, 56, 52, 32, 48, 32, 48, 32, 48, 32, 49, 50, 32, 50, 50, 97, 51, 46, 56, 52, 32, 51, 46, 56, 52, 32, 48, 32, 48, 32, 48, 32, 52, 45, 51, 46, 54, 54, 86, 49, 56, 104, 51, 46, 51, 54, 97, 49, 46, 54, 52, 32, 49, 46, 54, 52, 32, 48, 32, 48, 32, 48, 32, 49, 46, 49, 54, 45, 50, 46, 55, 57, 122, 77, 49, 52, 32, 49, 56, 46, 51, 52, 65, 49, 46, 56, 56, 32, 49, 46, 56, 56, 32, 48, 32, 48, 32, 49, 32, 49, 50, 32, 50, 48, 97, 49, 46, 56, 56, 32, 49, 46, 56, 56, 32, 48, 32, 48, 32, 49, 45, 50, 45, 49, 46, 54, 54, 86, 49, 56, 104, 52, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("belloff"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 98, 101, 108, 108, 45, 111, 102, 102, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 53, 46, 56, 56, 32, 49, 56, 46, 55, 49, 108, 45, 46, 53, 57, 45, 46, 53, 57, 76, 49, 52, 32, 49, 54, 46, 55, 56, 108, 45, 46, 48, 55, 45, 46, 48, 55, 76, 54, 46, 53, 56, 32, 57, 46, 52, 32, 53, 46, 51, 49, 32, 56, 46, 49, 52, 97, 53, 46, 54, 56, 32, 53, 46, 54, 56, 32, 48, 32, 48, 32, 48, 32, 48, 32, 46, 53, 57, 118, 52, 46, 54, 55, 108, 45, 49, 46, 56, 32, 49, 46, 56, 49, 65, 49, 46, 54, 52, 32, 49, 46, 54, 52, 32, 48, 32, 48, 32, 48, 32, 52, 46, 54, 52, 32, 49, 56, 72, 56, 118, 46, 51, 52, 65, 51, 46, 56, 52, 32, 51, 46, 56, 52, 32, 48, 32, 48, 32, 48, 32, 49, 50, 32, 50, 50, 97, 51, 46, 56, 56, 32, 51, 46, 56, 56, 32, 48, 32, 48, 32, 48, 32, 52, 45, 51, 46, 50, 50, 122, 77, 49, 52, 32, 49, 56, 46, 51, 52, 65, 49, 46, 56, 56, 32, 49, 46, 56, 56, 32, 48, 32, 48, 32, 49, 32, 49, 50, 32, 50, 48, 97, 49, 46, 56, 56, 32, 49, 46, 56, 56, 32, 48, 32, 48, 32, 49, 45, 50, 45, 49, 46, 54, 54, 86, 49, 56, 104, 52, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 55, 46, 49, 51, 32, 52, 46, 51, 108, 49, 46, 52, 54, 32, 49, 46, 52, 54, 32, 57, 46, 53, 51, 32, 57, 46, 53, 51, 32, 50, 32, 50, 32, 46, 51, 49, 46, 51, 97, 49, 46, 53, 56, 32, 49, 46, 53, 56, 32, 48, 32, 48, 32, 48, 32, 46, 52, 53, 45, 46, 54, 32, 49, 46, 54, 50, 32, 49, 46, 54, 50, 32, 48, 32, 48, 32, 48, 45, 46, 51, 53, 45, 49, 46, 55, 56, 108, 45, 49, 46, 56, 45, 49, 46, 56, 49, 86, 56, 46, 57, 52, 97, 54, 46, 56, 54, 32, 54, 46, 56, 54, 32, 48, 32, 48, 32, 48, 45, 53, 46, 56, 51, 45, 54, 46, 56, 56, 32, 54, 46, 55, 49, 32, 54, 46, 55, 49, 32, 48, 32, 48, 32, 48, 45, 53, 46, 51, 50, 32, 49, 46, 54, 49, 32, 54, 46, 56, 56, 32, 54, 46, 56, 56, 32, 48, 32, 48, 32, 48, 45, 46, 53, 56, 46, 53, 52, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 48, 46, 55, 49, 32, 49, 57, 46, 50, 57, 76, 49, 57, 46, 52, 49, 32, 49, 56, 108, 45, 50, 45, 50, 45, 57, 46, 53, 50, 45, 57, 46, 53, 51, 76, 54, 46, 52, 50, 32, 53, 32, 52, 46, 55, 49, 32, 51, 46, 50, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 32, 49, 46, 52, 50, 76, 53, 46, 53, 51, 32, 55, 108, 49, 46, 55, 53, 32, 49, 46, 55, 32, 55, 46, 51, 49, 32, 55, 46, 51, 46, 48, 55, 46, 48, 55, 76, 49, 54, 32, 49, 55, 46, 52, 49, 108, 46, 53, 57, 46, 53, 57, 32, 50, 46, 55, 32, 50, 46, 55, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 50, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("bug"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 54, 51, 46, 53, 53, 44, 50, 55, 50, 46, 49, 51, 72, 52, 48, 48, 118, 45, 52, 56, 46, 50, 113, 48, 45, 52, 46, 51, 50, 45, 46, 50, 55, 45, 56, 46, 52, 55, 99, 50, 57, 46, 53, 55, 45, 50, 55, 46, 56, 56, 44, 51, 50, 46, 50, 53, 45, 54, 52, 46, 54, 51, 44, 51, 50, 46, 50, 55, 45, 49, 48, 51, 44, 48, 45, 56, 46, 54, 49, 45, 54, 46, 54, 52, 45, 49, 54, 45, 49, 53, 46, 50, 53, 45, 49, 54, 46, 52, 49, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 52, 48, 48, 44, 49, 49, 50, 99, 48, 44, 50, 56, 45, 49, 46, 56, 54, 44, 52, 56, 46, 49, 53, 45, 57, 46, 57, 44, 54, 51, 46, 56, 52, 45, 49, 57, 46, 50, 50, 45, 52, 49, 46, 49, 53, 45, 54, 53, 46, 55, 56, 45, 54, 51, 46, 57, 49, 45, 49, 51, 52, 46, 49, 45, 54, 51, 46, 57, 49, 45, 51, 57, 46, 56, 44, 48, 45, 55, 52, 46, 49, 57, 44, 57, 46, 49, 51, 45, 57, 57, 46, 52, 51, 44, 50, 54, 46, 51, 57, 45, 49, 52, 46, 57, 44, 49, 48, 46, 49, 57, 45, 50, 54, 46, 50, 44, 50, 50, 46, 57, 49, 45, 51, 51, 46, 55, 44, 51, 55, 46, 55, 50, 67, 49, 49, 52, 44, 49, 54, 48, 46, 54, 53, 44, 49, 49, 50, 44, 49, 52, 49, 44, 49, 49, 50, 44, 49, 49, 50, 46, 52, 54, 99, 48, 45, 56, 46, 54, 49, 45, 54, 46, 54, 45, 49, 54, 45, 49, 53, 46, 50, 45, 49, 54, 46, 52, 52, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 56, 48, 44, 49, 49, 50, 99, 48, 44, 51, 55, 46, 54, 51, 44, 50, 46, 54, 49, 44, 55, 51, 46, 55, 51, 44, 51, 50, 46, 52, 52, 44, 49, 48, 49, 46, 54, 51, 113, 45, 46, 52, 51, 44, 53, 46, 48, 54, 45, 46, 52, 52, 44, 49, 48, 46, 51, 118, 52, 56, 46, 50, 72, 52, 56, 46, 52, 53, 99, 45, 56, 46, 54, 49, 44, 48, 45, 49, 54, 44, 54, 46, 54, 50, 45, 49, 54, 46, 52, 51, 44, 49, 53, 46, 50, 51, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 54, 44, 49, 54, 46, 55, 55, 104, 54, 52, 86, 51, 50, 48, 97, 49, 52, 51, 46, 51, 50, 44, 49, 52, 51, 46, 51, 50, 44, 48, 44, 48, 44, 48, 44, 49, 48, 46, 51, 57, 44, 53, 51, 46, 54, 57, 67, 57, 54, 46, 55, 52, 44, 51, 57, 54, 46, 54, 52, 44, 56, 48, 46, 49, 56, 44, 52, 50, 50, 44, 56, 48, 44, 52, 54, 51, 46, 51, 52, 99, 48, 44, 56, 46, 55, 52, 44, 54, 46, 54, 50, 44, 49, 54, 46, 51, 44, 49, 53, 46, 51, 54, 44, 49, 54, 46, 54, 53, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 49, 50, 44, 52, 54, 52, 99, 48, 45, 50, 55, 46, 54, 54, 44, 57, 46, 49, 45, 52, 52, 46, 55, 49, 44, 50, 54, 46, 49, 55, 45, 54, 49, 46, 51, 50, 65, 49, 52, 52, 46, 51, 55, 44, 49, 52, 52, 46, 51, 55, 44, 48, 44, 48, 44, 48, 44, 50, 50, 48, 44, 52, 53, 57, 46, 52, 50, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 50, 48, 45, 49, 53, 46, 52, 57, 86, 49, 57, 50, 46, 52, 53, 99, 48, 45, 56, 46, 54, 49, 44, 54, 46, 54, 50, 45, 49, 54, 44, 49, 53, 46, 50, 51, 45, 49, 54, 46, 52, 51, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 50, 55, 50, 44, 49, 57, 50, 86, 52, 52, 51, 46, 57, 51, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 50, 48, 44, 49, 53, 46, 52, 57, 44, 49, 52, 52, 46, 52, 44, 49, 52, 52, 46, 52, 44, 48, 44, 48, 44, 48, 44, 56, 49, 46, 56, 50, 45, 53, 54, 46, 55, 52, 99, 49, 55, 44, 49, 54, 46, 53, 52, 44, 50, 54, 46, 48, 57, 44, 51, 51, 46, 53, 50, 44, 50, 54, 46, 49, 55, 44, 54, 48, 46, 57, 53, 65, 49, 54, 46, 50, 55, 44, 49, 54, 46, 50, 55, 44, 48, 44, 48, 44, 48, 44, 52, 49, 53, 46, 48, 57, 44, 52, 56, 48, 44, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 52, 51, 50, 44, 52, 54, 52, 99, 48, 45, 52, 49, 46, 54, 56, 45, 49, 54, 46, 54, 45, 54, 55, 46, 50, 51, 45, 52, 50, 46, 51, 57, 45, 57, 48, 46, 51, 49, 65, 49, 52, 51, 46, 51, 50, 44, 49, 52, 51, 46, 51, 50, 44, 48, 44, 48, 44, 48, 44, 52, 48, 48, 44, 51, 50, 48, 86, 51, 48, 52, 46, 49, 51, 104, 54, 52, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 54, 45, 49, 54, 46, 55, 55, 67, 52, 55, 57, 46, 53, 56, 44, 50, 55, 56, 46, 55, 53, 44, 52, 55, 50, 46, 49, 54, 44, 50, 55, 50, 46, 49, 51, 44, 52, 54, 51, 46, 53, 53, 44, 50, 55, 50, 46, 49, 51, 90, 34, 47, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 51, 50, 49, 46, 51, 57, 44, 49, 48, 52, 108, 46, 51, 50, 46, 48, 57, 99, 49, 51, 46, 53, 55, 44, 51, 46, 56, 44, 50, 53, 46, 48, 55, 45, 49, 48, 46, 53, 53, 44, 49, 56, 46, 50, 45, 50, 50, 46, 56, 53, 65, 57, 53, 46, 56, 54, 44, 57, 53, 46, 56, 54, 44, 48, 44, 48, 44, 48, 44, 50, 53, 54, 46, 50, 49, 44, 51, 50, 104, 45, 46, 52, 50, 65, 57, 53, 46, 56, 55, 44, 57, 53, 46, 56, 55, 44, 48, 44, 48, 44, 48, 44, 49, 55, 49, 46, 54, 44, 56, 50, 46, 49, 51, 99, 45, 54, 46, 56, 52, 44, 49, 50, 46, 53, 56, 44, 53, 46, 49, 52, 44, 50, 55, 44, 49, 56, 46, 56, 52, 44, 50, 50, 46, 56, 54, 44, 49, 57, 46, 55, 49, 45, 54, 44, 52, 49, 46, 55, 57, 45, 57, 46, 48, 54, 44, 54, 53, 46, 53, 54, 45, 57, 46, 48, 54, 67, 50, 56, 48, 46, 48, 57, 44, 57, 53, 46, 57, 51, 44, 51, 48, 50, 46, 48, 57, 44, 57, 56, 46, 54, 53, 44, 51, 50, 49, 46, 51, 57, 44, 49, 48, 52, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("bulb"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 98, 117, 108, 98, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 49, 56, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 55, 97, 53, 32, 53, 32, 48, 32, 48, 32, 48, 45, 51, 32, 57, 118, 52, 97, 50, 32, 50, 32, 48, 32, 48, 32, 48, 32, 50, 32, 50, 104, 50, 97, 50, 32, 50, 32, 48, 32, 48, 32, 48, 32, 50, 45, 50, 118, 45, 52, 97, 53, 32, 53, 32, 48, 32, 48, 32, 48, 45, 51, 45, 57, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 45, 49, 86, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 50, 32, 48, 118, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 32, 49, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 49, 32, 49, 49, 104, 45, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 32, 50, 104, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 50, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 53, 32, 49, 49, 72, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 32, 50, 104, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 50, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 55, 46, 54, 54, 32, 54, 46, 52, 50, 76, 54, 46, 50, 50, 32, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 51, 57, 32, 49, 46, 52, 55, 108, 49, 46, 52, 52, 32, 49, 46, 51, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 51, 46, 50, 56, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 50, 45, 46, 51, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 48, 54, 45, 49, 46, 52, 49, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 57, 46, 49, 57, 32, 53, 46, 48, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 49, 32, 48, 108, 45, 49, 46, 52, 52, 32, 49, 46, 51, 55, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 50, 46, 51, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 54, 57, 45, 46, 50, 56, 108, 49, 46, 52, 52, 45, 49, 46, 51, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("c"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 49, 50, 56, 32, 49, 50, 56, 34, 32, 61441, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 49, 49, 55, 46, 53, 32, 51, 51, 46, 53, 108, 46, 51, 45, 46, 50, 99, 45, 46, 54, 45, 49, 46, 49, 45, 49, 46, 53, 45, 50, 46, 49, 45, 50, 46, 52, 45, 50, 46, 54, 76, 54, 55, 46, 49, 32, 50, 46, 57, 99, 45, 46, 56, 45, 46, 53, 45, 49, 46, 57, 45, 46, 55, 45, 51, 46, 49, 45, 46, 55, 45, 49, 46, 50, 32, 48, 45, 50, 46, 51, 46, 51, 45, 51, 46, 49, 46, 55, 108, 45, 52, 56, 32, 50, 55, 46, 57, 99, 45, 49, 46, 55, 32, 49, 45, 50, 46, 57, 32, 51, 46, 53, 45, 50, 46, 57, 32, 53, 46, 52, 118, 53, 53, 46, 55, 99, 48, 32, 49, 46, 49, 46, 50, 32, 50, 46, 51, 46, 57, 32, 51, 46, 52, 108, 45, 46, 50, 46, 49, 99, 46, 53, 46, 56, 32, 49, 46, 50, 32, 49, 46, 53, 32, 49, 46, 57, 32, 49, 46, 57, 108, 52, 56, 46, 50, 32, 50, 55, 46, 57, 99, 46, 56, 46, 53, 32, 49, 46, 57, 46, 55, 32, 51, 46, 49, 46, 55, 32, 49, 46, 50, 32, 48, 32, 50, 46, 51, 45, 46, 51, 32, 51, 46, 49, 45, 46, 55, 108, 52, 56, 45, 50, 55, 46, 57, 99, 49, 46, 55, 45, 49, 32, 50, 46, 57, 45, 51, 46, 53, 32, 50, 46, 57, 45, 53, 46, 52, 86, 51, 54, 46, 49, 99, 46, 49, 45, 46, 56, 32, 48, 45, 49, 46, 55, 45, 46, 52, 45, 50, 46, 54, 122, 77, 54, 52, 32, 56, 56, 46, 53, 99, 57, 46, 49, 32, 48, 32, 49, 55, 46, 49, 45, 53, 32, 50, 49, 46, 51, 45, 49, 50, 46, 52, 108, 49, 50, 46, 57, 32, 55, 46, 54, 99, 45, 54, 46, 56, 32, 49, 49, 46, 56, 45, 49, 57, 46, 54, 32, 49, 57, 46, 56, 45, 51, 52, 46, 50, 32, 49, 57, 46, 56, 45, 50, 49, 46, 56, 32, 48, 45, 51, 57, 46, 53, 45, 49, 55, 46, 55, 45, 51, 57, 46, 53, 45, 51, 57, 46, 53, 83, 52, 50, 46, 50, 32, 50, 52, 46, 53, 32, 54, 52, 32, 50, 52, 46, 53, 99, 49, 52, 46, 55, 32, 48, 32, 50, 55, 46, 53, 32, 56, 46, 49, 32, 51, 52, 46, 51, 32, 50, 48, 108, 45, 49, 51, 32, 55, 46, 53, 67, 56, 49, 46, 49, 32, 52, 52, 46, 53, 32, 55, 51, 46, 49, 32, 51, 57, 46, 53, 32, 54, 52, 32, 51, 57, 46, 53, 99, 45, 49, 51, 46, 53, 32, 48, 45, 50, 52, 46, 53, 32, 49, 49, 45, 50, 52, 46, 53, 32, 50, 52, 46, 53, 115, 49, 49, 32, 50, 52, 46, 53, 32, 50, 52, 46, 53, 32, 50, 52, 46, 53, 122, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("car"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 52, 55, 46, 54, 56, 44, 50, 50, 48, 46, 55, 56, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 45, 49, 45, 51, 46, 48, 56, 108, 45, 51, 55, 46, 55, 56, 45, 56, 56, 46, 49, 54, 67, 52, 48, 48, 46, 49, 57, 44, 49, 48, 57, 46, 49, 55, 44, 51, 55, 57, 44, 57, 54, 44, 51, 53, 52, 46, 56, 57, 44, 57, 54, 72, 49, 53, 55, 46, 49, 49, 99, 45, 50, 52, 46, 48, 57, 44, 48, 45, 52, 53, 46, 51, 44, 49, 51, 46, 49, 55, 45, 53, 52, 44, 51, 51, 46, 53, 52, 76, 54, 53, 46, 50, 57, 44, 50, 49, 55, 46, 55, 65, 49, 53, 46, 55, 50, 44, 49, 53, 46, 55, 50, 44, 48, 44, 48, 44, 48, 44, 54, 52, 44, 50, 50, 52, 86, 52, 48, 48, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 54, 44, 49, 54, 104, 51, 50, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 54, 45, 49, 54, 86, 51, 56, 52, 72, 51, 56, 52, 118, 49, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 54, 44, 49, 54, 104, 51, 50, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 54, 45, 49, 54, 86, 50, 50, 52, 65, 49, 54, 46, 49, 53, 44, 49, 54, 46, 49, 53, 44, 48, 44, 48, 44, 48, 44, 52, 52, 55, 46, 54, 56, 44, 50, 50, 48, 46, 55, 56, 90, 77, 49, 52, 52, 44, 51, 50, 48, 97, 51, 50, 44, 51, 50, 44, 48, 44, 49, 44, 49, 44, 51, 50, 45, 51, 50, 65, 51, 50, 44, 51, 50, 44, 48, 44, 48, 44, 49, 44, 49, 52, 52, 44, 51, 50, 48, 90, 109, 50, 50, 52, 44, 48, 97, 51, 50, 44, 51, 50, 44, 48, 44, 49, 44, 49, 44, 51, 50, 45, 51, 50, 65, 51, 50, 44, 51, 50, 44, 48, 44, 48, 44, 49, 44, 51, 54, 56, 44, 51, 50, 48, 90, 77, 49, 48, 52, 46, 50, 54, 44, 50, 48, 56, 108, 50, 56, 46, 50, 51, 45, 54, 53, 46, 56, 53, 67, 49, 51, 54, 46, 49, 49, 44, 49, 51, 51, 46, 54, 57, 44, 49, 52, 54, 44, 49, 50, 56, 44, 49, 53, 55, 46, 49, 49, 44, 49, 50, 56, 72, 51, 53, 52, 46, 56, 57, 99, 49, 49, 46, 49, 44, 48, 44, 50, 49, 44, 53, 46, 54, 57, 44, 50, 52, 46, 54, 50, 44, 49, 52, 46, 49, 53, 76, 52, 48, 55, 46, 55, 52, 44, 50, 48, 56, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("cart"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 99, 105, 114, 99, 108, 101, 32, 61442, 32, 99, 120, 61, 34, 49, 55, 54, 34, 32, 99, 121, 61, 34, 52, 49, 54, 34, 32, 114, 61, 34, 51, 50, 34, 47, 62, 60, 99, 105, 114, 99, 108, 101, 32, 61442, 32, 99, 120, 61, 34, 52, 48, 48, 34, 32, 99, 121, 61, 34, 52, 49, 54, 34, 32, 114, 61, 34, 51, 50, 34, 47, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 53, 54, 46, 56, 44, 49, 50, 48, 46, 55, 56, 65, 50, 51, 46, 57, 50, 44, 50, 51, 46, 57, 50, 44, 48, 44, 48, 44, 48, 44, 52, 51, 56, 46, 50, 52, 44, 49, 49, 50, 72, 49, 51, 51, 46, 56, 57, 108, 45, 54, 46, 49, 51, 45, 51, 52, 46, 55, 56, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 49, 50, 44, 54, 52, 72, 52, 56, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 48, 44, 51, 50, 72, 57, 56, 46, 53, 56, 108, 52, 53, 46, 54, 54, 44, 50, 53, 56, 46, 55, 56, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 54, 48, 44, 51, 54, 56, 72, 52, 49, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 48, 45, 51, 50, 72, 49, 55, 51, 46, 52, 50, 108, 45, 53, 46, 54, 52, 45, 51, 50, 72, 52, 48, 57, 46, 52, 52, 65, 50, 52, 46, 48, 55, 44, 50, 52, 46, 48, 55, 44, 48, 44, 48, 44, 48, 44, 52, 51, 51, 44, 50, 56, 52, 46, 55, 49, 108, 50, 56, 46, 56, 45, 49, 52, 52, 65, 50, 52, 44, 50, 52, 44, 48, 44, 48, 44, 48, 44, 52, 53, 54, 46, 56, 44, 49, 50, 48, 46, 55, 56, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("chip"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 51, 53, 50, 44, 49, 50, 56, 72, 49, 54, 48, 97, 51, 50, 44, 51, 50, 44, 48, 44, 48, 44, 48, 45, 51, 50, 44, 51, 50, 86, 51, 53, 50, 97, 51, 50, 44, 51, 50, 44, 48, 44, 48, 44, 48, 44, 51, 50, 44, 51, 50, 72, 51, 53, 50, 97, 51, 50, 44, 51, 50, 44, 48, 44, 48, 44, 48, 44, 51, 50, 45, 51, 50, 86, 49, 54, 48, 65, 51, 50, 44, 51, 50, 44, 48, 44, 48, 44, 48, 44, 51, 53, 50, 44, 49, 50, 56, 90, 109, 48, 44, 50, 49, 54, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 45, 56, 44, 56, 72, 49, 54, 56, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 45, 56, 45, 56, 86, 49, 54, 56, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 56, 45, 56, 72, 51, 52, 52, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 56, 44, 56, 90, 34, 32, 115, 116, 121, 108, 101, 61, 34, 102, 105, 108, 108, 58, 110, 111, 110, 101, 34, 47, 62, 60, 114, 101, 99, 116, 32, 61442, 32, 120, 61, 34, 49, 54, 48, 34, 32, 121, 61, 34, 49, 54, 48, 34, 32, 119, 105, 100, 116, 104, 61, 34, 49, 57, 50, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 49, 57, 50, 34, 32, 114, 120, 61, 34, 56, 34, 32, 114, 121, 61, 34, 56, 34, 47, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 54, 52, 44, 49, 57, 50, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 48, 45, 51, 50, 72, 52, 52, 56, 86, 49, 50, 56, 97, 54, 52, 46, 48, 55, 44, 54, 52, 46, 48, 55, 44, 48, 44, 48, 44, 48, 45, 54, 52, 45, 54, 52, 72, 51, 53, 50, 86, 52, 56, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 45, 51, 50, 44, 48, 86, 54, 52, 72, 50, 55, 50, 86, 52, 56, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 45, 51, 50, 44, 48, 86, 54, 52, 72, 49, 57, 50, 86, 52, 56, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 45, 51, 50, 44, 48, 86, 54, 52, 72, 49, 50, 56, 97, 54, 52, 46, 48, 55, 44, 54, 52, 46, 48, 55, 44, 48, 44, 48, 44, 48, 45, 54, 52, 44, 54, 52, 118, 51, 50, 72, 52, 56, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 48, 44, 51, 50, 72, 54, 52, 118, 52, 56, 72, 52, 56, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 48, 44, 51, 50, 72, 54, 52, 118, 52, 56, 72, 52, 56, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 48, 44, 51, 50, 72, 54, 52, 118, 51, 50, 97, 54, 52, 46, 48, 55, 44, 54, 52, 46, 48, 55, 44, 48, 44, 48, 44, 48, 44, 54, 52, 44, 54, 52, 104, 51, 50, 118, 49, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 51, 50, 44, 48, 86, 52, 52, 56, 104, 52, 56, 118, 49, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 51, 50, 44, 48, 86, 52, 52, 56, 104, 52, 56, 118, 49, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 51, 50, 44, 48, 86, 52, 52, 56, 104, 51, 50, 97, 54, 52, 46, 48, 55, 44, 54, 52, 46, 48, 55, 44, 48, 44, 48, 44, 48, 44, 54, 52, 45, 54, 52, 86, 51, 53, 50, 104, 49, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 48, 45, 51, 50, 72, 52, 52, 56, 86, 50, 55, 50, 104, 49, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 48, 45, 51, 50, 72, 52, 52, 56, 86, 49, 57, 50, 90, 77, 51, 56, 52, 44, 51, 53, 50, 97, 51, 50, 44, 51, 50, 44, 48, 44, 48, 44, 49, 45, 51, 50, 44, 51, 50, 72, 49, 54, 48, 97, 51, 50, 44, 51, 50, 44, 48, 44, 48, 44, 49, 45, 51, 50, 45, 51, 50, 86, 49, 54, 48, 97, 51, 50, 44, 51, 50, 44, 48, 44, 48, 44, 49, 44, 51, 50, 45, 51, 50, 72, 51, 53, 50, 97, 51, 50, 44, 51, 50, 44, 48, 44, 48, 44, 49, 44, 51, 50, 44, 51, 50, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("close"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 99, 108, 111, 115, 101, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 49, 56, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 51, 46, 52, 49, 32, 49, 50, 108, 52, 46, 51, 45, 52, 46, 50, 57, 97, 49, 32, 49, 32, 48, 32, 49, 32, 48, 45, 49, 46, 52, 50, 45, 49, 46, 52, 50, 76, 49, 50, 32, 49, 48, 46, 53, 57, 108, 45, 52, 46, 50, 57, 45, 52, 46, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 32, 49, 46, 52, 50, 108, 52, 46, 51, 32, 52, 46, 50, 57, 45, 52, 46, 51, 32, 52, 46, 50, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 50, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 50, 32, 48, 108, 52, 46, 50, 57, 45, 52, 46, 51, 32, 52, 46, 50, 57, 32, 52, 46, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 50, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("cpp"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 49, 50, 56, 32, 49, 50, 56, 34, 32, 61441, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 49, 49, 55, 46, 53, 32, 51, 51, 46, 53, 108, 46, 51, 45, 46, 50, 99, 45, 46, 54, 45, 49, 46, 49, 45, 49, 46, 53, 45, 50, 46, 49, 45, 50, 46, 52, 45, 50, 46, 54, 76, 54, 55, 46, 49, 32, 50, 46, 57, 99, 45, 46, 56, 45, 46, 53, 45, 49, 46, 57, 45, 46, 55, 45, 51, 46, 49, 45, 46, 55, 45, 49, 46, 50, 32, 48, 45, 50, 46, 51, 46, 51, 45, 51, 46, 49, 46, 55, 108, 45, 52, 56, 32, 50, 55, 46, 57, 99, 45, 49, 46, 55, 32, 49, 45, 50, 46, 57, 32, 51, 46, 53, 45, 50, 46, 57, 32, 53, 46, 52, 118, 53, 53, 46, 55, 99, 48, 32, 49, 46, 49, 46, 50, 32, 50, 46, 51, 46, 57, 32, 51, 46, 52, 108, 45, 46, 50, 46, 49, 99, 46, 53, 46, 56, 32, 49, 46, 50, 32, 49, 46, 53, 32, 49, 46, 57, 32, 49, 46, 57, 108, 52, 56, 46, 50, 32, 50, 55, 46, 57, 99, 46, 56, 46, 53, 32, 49, 46, 57, 46, 55, 32, 51, 46, 49, 46, 55, 32, 49, 46, 50, 32, 48, 32, 50, 46, 51, 45, 46, 51, 32, 51, 46, 49, 45, 46, 55, 108, 52, 56, 45, 50, 55, 46, 57, 99, 49, 46, 55, 45, 49, 32, 50, 46, 57, 45, 51, 46, 53, 32, 50, 46, 57, 45, 53, 46, 52, 86, 51, 54, 46, 49, 99, 46, 49, 45, 46, 56, 32, 48, 45, 49, 46, 55, 45, 46, 52, 45, 50, 46, 54, 122, 77, 56, 50, 32, 54, 54, 118, 45, 52, 104, 53, 118, 45, 53, 104, 53, 118, 53, 104, 53, 118, 52, 104, 45, 53, 118, 53, 104, 45, 53, 118, 45, 53, 104, 45, 53, 122, 109, 51, 46, 51, 45, 49, 52, 67, 56, 49, 46, 49, 32, 52, 52, 46, 53, 32, 55, 51, 46, 49, 32, 51, 57, 46, 53, 32, 54, 52, 32, 51, 57, 46, 53, 99, 45, 49, 51, 46, 53, 32, 48, 45, 50, 52, 46, 53, 32, 49, 49, 45, 50, 52, 46, 53, 32, 50, 52, 46, 53, 115, 49, 49, 32, 50, 52, 46, 53, 32, 50, 52, 46, 53, 32, 50, 52, 46, 53, 99, 57, 46, 49, 32, 48, 32, 49, 55, 46, 49, 45, 53, 32, 50, 49, 46, 51, 45, 49, 50, 46, 52, 108, 49, 50, 46, 57, 32, 55, 46, 54, 99, 45, 54, 46, 56, 32, 49, 49, 46, 56, 45, 49, 57, 46, 54, 32, 49, 57, 46, 56, 45, 51, 52, 46, 50, 32, 49, 57, 46, 56, 45, 50, 49, 46, 56, 32, 48, 45, 51, 57, 46, 53, 45, 49, 55, 46, 55, 45, 51, 57, 46, 53, 45, 51, 57, 46, 53, 83, 52, 50, 46, 50, 32, 50, 52, 46, 53, 32, 54, 52, 32, 50, 52, 46, 53, 99, 49, 52, 46, 55, 32, 48, 32, 50, 55, 46, 53, 32, 56, 46, 49, 32, 51, 52, 46, 51, 32, 50, 48, 108, 45, 49, 51, 32, 55, 46, 53, 122, 77, 49, 49, 53, 32, 54, 54, 104, 45, 53, 118, 53, 104, 45, 52, 118, 45, 53, 104, 45, 54, 118, 45, 52, 104, 54, 118, 45, 53, 104, 52, 118, 53, 104, 53, 118, 52, 122, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("css"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 49, 50, 56, 32, 49, 50, 56, 34, 32, 61441, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 56, 46, 55, 54, 32, 49, 108, 49, 48, 46, 48, 53, 53, 32, 49, 49, 50, 46, 56, 56, 51, 32, 52, 53, 46, 49, 49, 56, 32, 49, 50, 46, 53, 56, 32, 52, 53, 46, 50, 52, 52, 45, 49, 50, 46, 54, 50, 54, 76, 49, 49, 57, 46, 50, 52, 32, 49, 72, 56, 46, 55, 54, 122, 109, 56, 57, 46, 53, 57, 49, 32, 50, 53, 46, 56, 54, 50, 108, 45, 51, 46, 51, 52, 55, 32, 51, 55, 46, 54, 48, 53, 46, 48, 49, 46, 50, 48, 51, 45, 46, 48, 49, 52, 46, 52, 54, 55, 118, 45, 46, 48, 48, 52, 108, 45, 50, 46, 51, 55, 56, 32, 50, 54, 46, 50, 57, 52, 45, 46, 50, 54, 50, 32, 50, 46, 51, 51, 54, 76, 54, 52, 32, 49, 48, 49, 46, 54, 48, 55, 118, 46, 48, 48, 49, 108, 45, 46, 48, 50, 50, 46, 48, 49, 57, 45, 50, 56, 46, 51, 49, 49, 45, 55, 46, 56, 56, 56, 76, 51, 51, 46, 55, 53, 32, 55, 50, 104, 49, 51, 46, 56, 56, 51, 108, 46, 57, 56, 53, 32, 49, 49, 46, 48, 53, 52, 32, 49, 53, 46, 51, 56, 54, 32, 52, 46, 49, 55, 45, 46, 48, 48, 52, 46, 48, 48, 56, 118, 45, 46, 48, 48, 50, 108, 49, 53, 46, 52, 52, 51, 45, 52, 46, 50, 50, 57, 76, 56, 49, 46, 48, 55, 53, 32, 54, 53, 72, 52, 56, 46, 55, 57, 50, 108, 45, 46, 50, 55, 55, 45, 51, 46, 48, 52, 51, 45, 46, 54, 51, 49, 45, 55, 46, 49, 50, 57, 76, 52, 55, 46, 53, 53, 51, 32, 53, 49, 104, 51, 52, 46, 55, 52, 57, 108, 49, 46, 50, 54, 52, 45, 49, 52, 72, 51, 48, 46, 54, 52, 108, 45, 46, 50, 55, 55, 45, 51, 46, 48, 52, 49, 45, 46, 54, 51, 45, 55, 46, 49, 51, 49, 76, 50, 57, 46, 52, 48, 49, 32, 50, 51, 104, 54, 57, 46, 50, 56, 49, 108, 45, 46, 51, 51, 49, 32, 51, 46, 56, 54, 50, 122, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("electricity"), vec![60, 115, 118, 103, 32, 61440, 32, 101, 110, 97, 98, 108, 101, 45, 98, 97, 99, 107, 103, 114, 111, 117, 110, 100, 61, 34, 110, 101, 119, 32, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 32, 61442, 62, 60, 103, 62, 60, 114, 101, 99, 116, 32, 102, 105, 108, 108, 61, 34, 110, 111, 110, 101, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 47, 62, 60, 47, 103, 62, 60, 103, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 52, 46, 54, 57, 44, 50, 46, 50, 49, 76, 52, 46, 51, 51, 44, 49, 49, 46, 52, 57, 99, 45, 48, 46, 54, 52, 44, 48, 46, 53, 56, 45, 48, 46, 50, 56, 44, 49, 46, 54, 53, 44, 48, 46, 53, 56, 44, 49, 46, 55, 51, 76, 49, 51, 44, 49, 52, 108, 45, 52, 46, 56, 53, 44, 54, 46, 55, 54, 99, 45, 48, 46, 50, 50, 44, 48, 46, 51, 49, 45, 48, 46, 49, 57, 44, 48, 46, 55, 52, 44, 48, 46, 48, 56, 44, 49, 46, 48, 49, 104, 48, 32, 99, 48, 46, 51, 44, 48, 46, 51, 44, 48, 46, 55, 55, 44, 48, 46, 51, 49, 44, 49, 46, 48, 56, 44, 48, 46, 48, 50, 108, 49, 48, 46, 51, 54, 45, 57, 46, 50, 56, 99, 48, 46, 54, 52, 45, 48, 46, 53, 56, 44, 48, 46, 50, 56, 45, 49, 46, 54, 53, 45, 48, 46, 53, 56, 45, 49, 46, 55, 51, 76, 49, 49, 44, 49, 48, 108, 52, 46, 56, 53, 45, 54, 46, 55, 54, 99, 48, 46, 50, 50, 45, 48, 46, 51, 49, 44, 48, 46, 49, 57, 45, 48, 46, 55, 52, 45, 48, 46, 48, 56, 45, 49, 46, 48, 49, 108, 48, 44, 48, 32, 67, 49, 53, 46, 52, 55, 44, 49, 46, 57, 51, 44, 49, 53, 44, 49, 46, 57, 50, 44, 49, 52, 46, 54, 57, 44, 50, 46, 50, 49, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("eye"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 101, 121, 101, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 99, 105, 114, 99, 108, 101, 32, 99, 120, 61, 34, 49, 50, 34, 32, 99, 121, 61, 34, 49, 50, 34, 32, 114, 61, 34, 49, 46, 53, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 49, 46, 56, 55, 32, 49, 49, 46, 53, 99, 45, 46, 54, 52, 45, 49, 46, 49, 49, 45, 52, 46, 49, 54, 45, 54, 46, 54, 56, 45, 49, 48, 46, 49, 52, 45, 54, 46, 53, 45, 53, 46, 53, 51, 46, 49, 52, 45, 56, 46, 55, 51, 32, 53, 45, 57, 46, 54, 32, 54, 46, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 32, 49, 99, 46, 54, 51, 32, 49, 46, 48, 57, 32, 52, 32, 54, 46, 53, 32, 57, 46, 56, 57, 32, 54, 46, 53, 104, 46, 50, 53, 99, 53, 46, 53, 51, 45, 46, 49, 52, 32, 56, 46, 55, 52, 45, 53, 32, 57, 46, 54, 45, 54, 46, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 122, 109, 45, 57, 46, 56, 55, 32, 52, 97, 51, 46, 53, 32, 51, 46, 53, 32, 48, 32, 49, 32, 49, 32, 51, 46, 53, 45, 51, 46, 53, 32, 51, 46, 53, 32, 51, 46, 53, 32, 48, 32, 48, 32, 49, 45, 51, 46, 53, 32, 51, 46, 53, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("eyeoff"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 101, 121, 101, 45, 111, 102, 102, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 99, 105, 114, 99, 108, 101, 32, 99, 120, 61, 34, 49, 50, 34, 32, 99, 121, 61, 34, 49, 50, 34, 32, 114, 61, 34, 49, 46, 53, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 53, 46, 50, 57, 32, 49, 56, 46, 49, 50, 76, 49, 52, 32, 49, 54, 46, 55, 56, 108, 45, 46, 48, 55, 45, 46, 48, 55, 45, 49, 46, 50, 55, 45, 49, 46, 50, 55, 97, 52, 46, 48, 55, 32, 52, 46, 48, 55, 32, 48, 32, 48, 32, 49, 45, 46, 54, 49, 46, 48, 54, 65, 51, 46, 53, 32, 51, 46, 53, 32, 48, 32, 48, 32, 49, 32, 56, 46, 53, 32, 49, 50, 97, 52, 46, 48, 55, 32, 52, 46, 48, 55, 32, 48, 32, 48, 32, 49, 32, 46, 48, 54, 45, 46, 54, 49, 108, 45, 50, 45, 50, 76, 53, 32, 55, 46, 56, 55, 97, 49, 53, 46, 56, 57, 32, 49, 53, 46, 56, 57, 32, 48, 32, 48, 32, 48, 45, 50, 46, 56, 55, 32, 51, 46, 54, 51, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 32, 49, 99, 46, 54, 51, 32, 49, 46, 48, 57, 32, 52, 32, 54, 46, 53, 32, 57, 46, 56, 57, 32, 54, 46, 53, 104, 46, 50, 53, 97, 57, 46, 52, 56, 32, 57, 46, 52, 56, 32, 48, 32, 48, 32, 48, 32, 51, 46, 50, 51, 45, 46, 54, 55, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 56, 46, 53, 57, 32, 53, 46, 55, 54, 108, 50, 46, 56, 32, 50, 46, 56, 65, 52, 46, 48, 55, 32, 52, 46, 48, 55, 32, 48, 32, 48, 32, 49, 32, 49, 50, 32, 56, 46, 53, 97, 51, 46, 53, 32, 51, 46, 53, 32, 48, 32, 48, 32, 49, 32, 51, 46, 53, 32, 51, 46, 53, 32, 52, 46, 48, 55, 32, 52, 46, 48, 55, 32, 48, 32, 48, 32, 49, 45, 46, 48, 54, 46, 54, 49, 108, 50, 46, 54, 56, 32, 50, 46, 54, 56, 46, 56, 52, 46, 56, 52, 97, 49, 53, 46, 56, 57, 32, 49, 53, 46, 56, 57, 32, 48, 32, 48, 32, 48, 32, 50, 46, 57, 49, 45, 51, 46, 54, 51, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 99, 45, 46, 54, 52, 45, 49, 46, 49, 49, 45, 52, 46, 49, 54, 45, 54, 46, 54, 56, 45, 49, 48, 46, 49, 52, 45, 54, 46, 53, 97, 57, 46, 52, 56, 32, 57, 46, 52, 56, 32, 48, 32, 48, 32, 48, 45, 51, 46, 50, 51, 46, 54, 55, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 48, 46, 55, 49, 32, 49, 57, 46, 50, 57, 76, 49, 57, 46, 52, 49, 32, 49, 56, 108, 45, 50, 45, 50, 45, 57, 46, 53, 50, 45, 57, 46, 53, 51, 76, 54, 46, 52, 50, 32, 53, 32, 52, 46, 55, 49, 32, 51, 46, 50, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 32, 49, 46, 52, 50, 76, 53, 46, 53, 51, 32, 55, 108, 49, 46, 55, 53, 32, 49, 46, 55, 32, 55, 46, 51, 49, 32, 55, 46, 51, 46, 48, 55, 46, 48, 55, 76, 49, 54, 32, 49, 55, 46, 52, 49, 108, 46, 53, 57, 46, 53, 57, 32, 50, 46, 55, 32, 50, 46, 55, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 50, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("facebook"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 102, 97, 99, 101, 98, 111, 111, 107, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 49, 56, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 55, 32, 51, 46, 53, 97, 46, 53, 46, 53, 32, 48, 32, 48, 32, 48, 45, 46, 53, 45, 46, 53, 72, 49, 52, 97, 52, 46, 55, 55, 32, 52, 46, 55, 55, 32, 48, 32, 48, 32, 48, 45, 53, 32, 52, 46, 53, 118, 50, 46, 55, 72, 54, 46, 53, 97, 46, 53, 46, 53, 32, 48, 32, 48, 32, 48, 45, 46, 53, 46, 53, 118, 50, 46, 54, 97, 46, 53, 46, 53, 32, 48, 32, 48, 32, 48, 32, 46, 53, 46, 53, 72, 57, 118, 54, 46, 55, 97, 46, 53, 46, 53, 32, 48, 32, 48, 32, 48, 32, 46, 53, 46, 53, 104, 51, 97, 46, 53, 46, 53, 32, 48, 32, 48, 32, 48, 32, 46, 53, 45, 46, 53, 118, 45, 54, 46, 55, 104, 50, 46, 54, 50, 97, 46, 53, 46, 53, 32, 48, 32, 48, 32, 48, 32, 46, 52, 57, 45, 46, 51, 55, 108, 46, 55, 50, 45, 50, 46, 54, 97, 46, 53, 46, 53, 32, 48, 32, 48, 32, 48, 45, 46, 52, 56, 45, 46, 54, 51, 72, 49, 51, 86, 55, 46, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 49, 45, 46, 57, 104, 50, 46, 53, 97, 46, 53, 46, 53, 32, 48, 32, 48, 32, 48, 32, 46, 53, 45, 46, 53, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("fish"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 32, 61441, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 53, 49, 50, 44, 50, 53, 54, 99, 48, 45, 49, 54, 46, 53, 52, 45, 49, 52, 46, 50, 55, 45, 52, 54, 46, 55, 54, 45, 52, 53, 46, 54, 49, 45, 55, 52, 97, 50, 48, 55, 46, 48, 54, 44, 50, 48, 55, 46, 48, 54, 44, 48, 44, 48, 44, 48, 45, 54, 48, 46, 50, 56, 45, 51, 54, 46, 49, 50, 44, 51, 46, 49, 53, 44, 51, 46, 49, 53, 44, 48, 44, 48, 44, 48, 45, 51, 46, 57, 51, 44, 49, 46, 53, 54, 99, 45, 46, 49, 53, 46, 50, 57, 45, 46, 51, 46, 53, 55, 45, 46, 52, 55, 46, 56, 54, 108, 45, 57, 46, 53, 57, 44, 49, 53, 46, 57, 97, 49, 56, 51, 46, 50, 52, 44, 49, 56, 51, 46, 50, 52, 44, 48, 44, 48, 44, 48, 44, 46, 48, 55, 44, 49, 56, 51, 46, 55, 56, 108, 46, 50, 51, 46, 51, 57, 44, 56, 46, 55, 52, 44, 49, 54, 97, 52, 44, 52, 44, 48, 44, 48, 44, 48, 44, 52, 46, 57, 52, 44, 49, 46, 56, 50, 67, 52, 55, 57, 46, 54, 51, 44, 51, 51, 55, 46, 52, 50, 44, 53, 49, 50, 44, 50, 56, 49, 46, 52, 57, 44, 53, 49, 50, 44, 50, 53, 54, 90, 109, 45, 57, 51, 46, 57, 50, 45, 46, 49, 52, 97, 49, 54, 44, 49, 54, 44, 48, 44, 49, 44, 49, 44, 49, 51, 46, 55, 57, 45, 49, 51, 46, 55, 57, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 52, 49, 56, 46, 48, 56, 44, 50, 53, 53, 46, 56, 54, 90, 34, 47, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 51, 51, 53, 46, 52, 53, 44, 50, 53, 54, 97, 50, 49, 52, 46, 56, 44, 50, 49, 52, 46, 56, 44, 48, 44, 48, 44, 49, 44, 50, 57, 46, 48, 56, 45, 49, 48, 56, 108, 46, 49, 50, 45, 46, 50, 49, 44, 52, 46, 54, 50, 45, 55, 46, 54, 55, 97, 52, 44, 52, 44, 48, 44, 48, 44, 48, 45, 50, 46, 53, 57, 45, 54, 44, 50, 56, 52, 46, 50, 57, 44, 50, 56, 52, 46, 50, 57, 44, 48, 44, 48, 44, 48, 45, 51, 57, 46, 50, 54, 45, 53, 46, 51, 57, 44, 55, 46, 57, 52, 44, 55, 46, 57, 52, 44, 48, 44, 48, 44, 49, 45, 52, 46, 50, 57, 45, 49, 46, 54, 99, 45, 49, 57, 46, 50, 56, 45, 49, 52, 46, 54, 54, 45, 53, 55, 46, 53, 45, 52, 48, 46, 51, 45, 57, 54, 46, 52, 54, 45, 52, 54, 46, 56, 57, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 45, 49, 56, 44, 50, 48, 46, 49, 56, 108, 49, 48, 46, 54, 50, 44, 51, 55, 46, 49, 55, 97, 52, 44, 52, 44, 48, 44, 48, 44, 49, 45, 50, 46, 52, 50, 44, 52, 46, 56, 52, 99, 45, 51, 54, 46, 56, 53, 44, 49, 51, 46, 54, 57, 45, 54, 56, 46, 53, 57, 44, 51, 56, 46, 55, 53, 45, 57, 49, 46, 55, 52, 44, 53, 55, 46, 56, 53, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 45, 49, 48, 46, 48, 54, 46, 48, 54, 113, 45, 52, 46, 55, 50, 45, 51, 46, 55, 53, 45, 57, 46, 54, 57, 45, 55, 46, 51, 57, 67, 54, 53, 46, 55, 52, 44, 49, 54, 52, 44, 49, 57, 46, 49, 55, 44, 49, 54, 48, 46, 49, 57, 44, 49, 55, 46, 50, 49, 44, 49, 54, 48, 46, 48, 53, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 46, 51, 56, 44, 49, 55, 57, 46, 52, 53, 99, 46, 52, 50, 44, 49, 46, 57, 51, 44, 57, 46, 49, 57, 44, 52, 48, 46, 54, 57, 44, 51, 49, 46, 55, 44, 55, 49, 46, 54, 49, 97, 56, 46, 48, 57, 44, 56, 46, 48, 57, 44, 48, 44, 48, 44, 49, 44, 48, 44, 57, 46, 53, 53, 67, 57, 46, 53, 55, 44, 50, 57, 49, 46, 53, 50, 46, 56, 44, 51, 51, 48, 46, 50, 57, 46, 51, 56, 44, 51, 51, 50, 46, 50, 50, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 54, 46, 56, 51, 44, 49, 57, 46, 52, 99, 50, 45, 46, 49, 52, 44, 52, 56, 46, 53, 51, 45, 52, 44, 56, 56, 46, 49, 50, 45, 51, 50, 46, 56, 56, 113, 52, 46, 56, 53, 45, 51, 46, 53, 54, 44, 57, 46, 52, 55, 45, 55, 46, 50, 50, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 49, 48, 46, 48, 54, 46, 48, 55, 99, 50, 51, 46, 50, 53, 44, 49, 57, 46, 49, 57, 44, 53, 53, 46, 48, 53, 44, 52, 52, 46, 50, 56, 44, 57, 50, 44, 53, 56, 97, 52, 44, 52, 44, 48, 44, 48, 44, 49, 44, 50, 46, 52, 50, 44, 52, 46, 56, 51, 76, 50, 48, 56, 46, 54, 50, 44, 52, 49, 49, 46, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 56, 44, 50, 48, 46, 49, 56, 99, 49, 55, 46, 49, 54, 45, 50, 46, 57, 44, 53, 49, 46, 56, 56, 45, 49, 50, 46, 56, 54, 44, 57, 54, 46, 48, 53, 45, 52, 54, 46, 56, 51, 97, 56, 46, 49, 53, 44, 56, 46, 49, 53, 44, 48, 44, 48, 44, 49, 44, 52, 46, 51, 54, 45, 49, 46, 54, 53, 65, 50, 56, 55, 46, 51, 54, 44, 50, 56, 55, 46, 51, 54, 44, 48, 44, 48, 44, 48, 44, 51, 54, 54, 46, 50, 53, 44, 51, 55, 56, 97, 52, 44, 52, 44, 48, 44, 48, 44, 48, 44, 50, 46, 54, 57, 45, 53, 46, 56, 51, 108, 45, 52, 46, 53, 49, 45, 56, 46, 50, 57, 65, 50, 49, 52, 46, 56, 49, 44, 50, 49, 52, 46, 56, 49, 44, 48, 44, 48, 44, 49, 44, 51, 51, 53, 46, 52, 53, 44, 50, 53, 54, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("folder"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 48, 56, 44, 57, 54, 72, 50, 53, 50, 46, 49, 49, 97, 50, 51, 46, 56, 57, 44, 50, 51, 46, 56, 57, 44, 48, 44, 48, 44, 49, 45, 49, 51, 46, 51, 49, 45, 52, 76, 50, 49, 49, 44, 55, 51, 46, 52, 49, 65, 53, 53, 46, 55, 55, 44, 53, 53, 46, 55, 55, 44, 48, 44, 48, 44, 48, 44, 49, 55, 57, 46, 56, 57, 44, 54, 52, 72, 49, 48, 52, 97, 53, 54, 46, 48, 54, 44, 53, 54, 46, 48, 54, 44, 48, 44, 48, 44, 48, 45, 53, 54, 44, 53, 54, 118, 50, 52, 72, 52, 54, 52, 67, 52, 54, 52, 44, 49, 49, 51, 46, 49, 50, 44, 52, 51, 56, 46, 56, 56, 44, 57, 54, 44, 52, 48, 56, 44, 57, 54, 90, 34, 47, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 50, 51, 46, 55, 53, 44, 52, 52, 56, 72, 56, 56, 46, 50, 53, 97, 53, 54, 44, 53, 54, 44, 48, 44, 48, 44, 49, 45, 53, 53, 46, 57, 51, 45, 53, 53, 46, 49, 53, 76, 49, 54, 46, 49, 56, 44, 50, 50, 56, 46, 49, 49, 108, 48, 45, 46, 50, 56, 65, 52, 56, 44, 52, 56, 44, 48, 44, 48, 44, 49, 44, 54, 52, 44, 49, 55, 54, 104, 51, 56, 52, 46, 49, 97, 52, 56, 44, 52, 56, 44, 48, 44, 48, 44, 49, 44, 52, 55, 46, 56, 44, 53, 49, 46, 56, 51, 108, 48, 44, 46, 50, 56, 76, 52, 55, 57, 46, 54, 56, 44, 51, 57, 50, 46, 56, 53, 65, 53, 54, 44, 53, 54, 44, 48, 44, 48, 44, 49, 44, 52, 50, 51, 46, 55, 53, 44, 52, 52, 56, 90, 77, 52, 55, 57, 46, 57, 44, 50, 50, 54, 46, 53, 53, 104, 48, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("game"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 56, 51, 46, 49, 51, 44, 50, 52, 53, 46, 51, 56, 67, 52, 54, 49, 46, 57, 50, 44, 49, 52, 57, 46, 52, 57, 44, 52, 51, 48, 44, 57, 56, 46, 51, 49, 44, 51, 56, 50, 46, 54, 53, 44, 56, 52, 46, 51, 51, 65, 49, 48, 55, 46, 49, 51, 44, 49, 48, 55, 46, 49, 51, 44, 48, 44, 48, 44, 48, 44, 51, 53, 50, 44, 56, 48, 99, 45, 49, 51, 46, 55, 49, 44, 48, 45, 50, 53, 46, 54, 53, 44, 51, 46, 51, 52, 45, 51, 56, 46, 50, 56, 44, 54, 46, 56, 56, 67, 50, 57, 56, 46, 53, 44, 57, 49, 46, 49, 53, 44, 50, 56, 49, 46, 50, 49, 44, 57, 54, 44, 50, 53, 54, 44, 57, 54, 115, 45, 52, 50, 46, 53, 49, 45, 52, 46, 56, 52, 45, 53, 55, 46, 55, 54, 45, 57, 46, 49, 49, 67, 49, 56, 53, 46, 54, 44, 56, 51, 46, 51, 52, 44, 49, 55, 51, 46, 54, 55, 44, 56, 48, 44, 49, 54, 48, 44, 56, 48, 97, 49, 49, 53, 46, 55, 52, 44, 49, 49, 53, 46, 55, 52, 44, 48, 44, 48, 44, 48, 45, 51, 49, 46, 55, 51, 44, 52, 46, 51, 50, 99, 45, 52, 55, 46, 49, 44, 49, 51, 46, 57, 50, 45, 55, 57, 44, 54, 53, 46, 48, 56, 45, 49, 48, 48, 46, 53, 50, 44, 49, 54, 49, 67, 52, 46, 54, 49, 44, 51, 52, 56, 46, 53, 52, 44, 49, 54, 44, 52, 49, 51, 46, 55, 49, 44, 53, 57, 46, 54, 57, 44, 52, 50, 56, 46, 56, 51, 97, 53, 54, 46, 54, 50, 44, 53, 54, 46, 54, 50, 44, 48, 44, 48, 44, 48, 44, 49, 56, 46, 54, 52, 44, 51, 46, 50, 50, 99, 50, 57, 46, 57, 51, 44, 48, 44, 53, 51, 46, 57, 51, 45, 50, 52, 46, 57, 51, 44, 55, 48, 46, 51, 51, 45, 52, 53, 46, 51, 52, 44, 49, 56, 46, 53, 51, 45, 50, 51, 46, 49, 44, 52, 48, 46, 50, 50, 45, 51, 52, 46, 56, 50, 44, 49, 48, 55, 46, 51, 52, 45, 51, 52, 46, 56, 50, 44, 53, 57, 46, 57, 53, 44, 48, 44, 56, 52, 46, 55, 54, 44, 56, 46, 49, 51, 44, 49, 48, 54, 46, 49, 57, 44, 51, 52, 46, 56, 50, 44, 49, 51, 46, 52, 55, 44, 49, 54, 46, 55, 56, 44, 50, 54, 46, 50, 44, 50, 56, 46, 53, 50, 44, 51, 56, 46, 57, 44, 51, 53, 46, 57, 49, 44, 49, 54, 46, 56, 57, 44, 57, 46, 56, 50, 44, 51, 51, 46, 55, 55, 44, 49, 50, 44, 53, 48, 46, 49, 54, 44, 54, 46, 51, 55, 44, 50, 53, 46, 56, 50, 45, 56, 46, 56, 49, 44, 52, 48, 46, 54, 50, 45, 51, 50, 46, 49, 44, 52, 52, 45, 54, 57, 46, 50, 52, 67, 52, 57, 55, 46, 56, 50, 44, 51, 51, 49, 46, 50, 55, 44, 52, 57, 51, 46, 56, 54, 44, 50, 57, 51, 46, 56, 54, 44, 52, 56, 51, 46, 49, 51, 44, 50, 52, 53, 46, 51, 56, 90, 77, 50, 48, 56, 44, 50, 52, 48, 72, 49, 55, 54, 118, 51, 50, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 45, 51, 50, 44, 48, 86, 50, 52, 48, 72, 49, 49, 50, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 48, 45, 51, 50, 104, 51, 50, 86, 49, 55, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 51, 50, 44, 48, 118, 51, 50, 104, 51, 50, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 48, 44, 51, 50, 90, 109, 56, 52, 44, 52, 97, 50, 48, 44, 50, 48, 44, 48, 44, 49, 44, 49, 44, 50, 48, 45, 50, 48, 65, 50, 48, 44, 50, 48, 44, 48, 44, 48, 44, 49, 44, 50, 57, 50, 44, 50, 52, 52, 90, 109, 52, 52, 44, 52, 52, 97, 50, 48, 44, 50, 48, 44, 48, 44, 49, 44, 49, 44, 50, 48, 45, 49, 57, 46, 57, 53, 65, 50, 48, 44, 50, 48, 44, 48, 44, 48, 44, 49, 44, 51, 51, 54, 44, 50, 56, 56, 90, 109, 48, 45, 56, 56, 97, 50, 48, 44, 50, 48, 44, 48, 44, 49, 44, 49, 44, 50, 48, 45, 50, 48, 65, 50, 48, 44, 50, 48, 44, 48, 44, 48, 44, 49, 44, 51, 51, 54, 44, 50, 48, 48, 90, 109, 52, 52, 44, 52, 52, 97, 50, 48, 44, 50, 48, 44, 48, 44, 49, 44, 49, 44, 50, 48, 45, 50, 48, 65, 50, 48, 44, 50, 48, 44, 48, 44, 48, 44, 49, 44, 51, 56, 48, 44, 50, 52, 52, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("gift"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 103, 105, 102, 116, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 49, 56, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 52, 46, 54, 52, 32, 49, 53, 46, 50, 55, 118, 52, 46, 56, 50, 97, 46, 57, 50, 46, 57, 50, 32, 48, 32, 48, 32, 48, 32, 46, 57, 50, 46, 57, 49, 104, 53, 46, 54, 50, 118, 45, 53, 46, 55, 51, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 46, 56, 50, 32, 50, 49, 104, 53, 46, 54, 50, 97, 46, 57, 50, 46, 57, 50, 32, 48, 32, 48, 32, 48, 32, 46, 57, 50, 45, 46, 57, 49, 118, 45, 52, 46, 56, 50, 104, 45, 54, 46, 53, 52, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 48, 46, 49, 32, 55, 46, 48, 57, 104, 45, 49, 46, 56, 52, 97, 50, 46, 56, 50, 32, 50, 46, 56, 50, 32, 48, 32, 48, 32, 48, 32, 46, 50, 57, 45, 49, 46, 50, 51, 65, 50, 46, 56, 55, 32, 50, 46, 56, 55, 32, 48, 32, 48, 32, 48, 32, 49, 53, 46, 54, 56, 32, 51, 32, 52, 46, 50, 49, 32, 52, 46, 50, 49, 32, 48, 32, 48, 32, 48, 32, 49, 50, 32, 53, 46, 53, 55, 32, 52, 46, 50, 49, 32, 52, 46, 50, 49, 32, 48, 32, 48, 32, 48, 32, 56, 46, 51, 50, 32, 51, 97, 50, 46, 56, 55, 32, 50, 46, 56, 55, 32, 48, 32, 48, 32, 48, 45, 50, 46, 56, 55, 32, 50, 46, 56, 54, 32, 50, 46, 56, 50, 32, 50, 46, 56, 50, 32, 48, 32, 48, 32, 48, 32, 46, 50, 57, 32, 49, 46, 50, 51, 72, 51, 46, 57, 99, 45, 46, 53, 32, 48, 45, 46, 57, 46, 53, 57, 45, 46, 57, 32, 49, 46, 51, 49, 118, 51, 46, 57, 51, 99, 48, 32, 46, 55, 50, 46, 52, 32, 49, 46, 51, 49, 46, 57, 32, 49, 46, 51, 49, 104, 55, 46, 50, 56, 86, 55, 46, 48, 57, 104, 49, 46, 54, 52, 118, 54, 46, 53, 53, 104, 55, 46, 50, 56, 99, 46, 53, 32, 48, 32, 46, 57, 45, 46, 53, 57, 46, 57, 45, 49, 46, 51, 49, 86, 56, 46, 52, 99, 48, 45, 46, 55, 50, 45, 46, 52, 45, 49, 46, 51, 49, 45, 46, 57, 45, 49, 46, 51, 49, 122, 109, 45, 49, 49, 46, 55, 56, 32, 48, 97, 49, 46, 50, 51, 32, 49, 46, 50, 51, 32, 48, 32, 49, 32, 49, 32, 48, 45, 50, 46, 52, 53, 99, 49, 46, 52, 32, 48, 32, 50, 46, 49, 57, 32, 49, 46, 52, 52, 32, 50, 46, 53, 56, 32, 50, 46, 52, 53, 122, 109, 55, 46, 51, 54, 32, 48, 72, 49, 51, 46, 49, 99, 46, 51, 57, 45, 49, 32, 49, 46, 49, 56, 45, 50, 46, 52, 53, 32, 50, 46, 53, 56, 45, 50, 46, 52, 53, 97, 49, 46, 50, 51, 32, 49, 46, 50, 51, 32, 48, 32, 49, 32, 49, 32, 48, 32, 50, 46, 52, 53, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("github"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 49, 56, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 49, 65, 49, 48, 46, 56, 57, 32, 49, 48, 46, 56, 57, 32, 48, 32, 48, 32, 48, 32, 49, 32, 49, 49, 46, 55, 55, 32, 49, 48, 46, 55, 57, 32, 49, 48, 46, 55, 57, 32, 48, 32, 48, 32, 48, 32, 56, 46, 53, 50, 32, 50, 50, 99, 46, 53, 53, 46, 49, 46, 55, 53, 45, 46, 50, 51, 46, 55, 53, 45, 46, 53, 50, 118, 45, 49, 46, 56, 51, 99, 45, 51, 46, 48, 54, 46, 54, 53, 45, 51, 46, 55, 49, 45, 49, 46, 52, 52, 45, 51, 46, 55, 49, 45, 49, 46, 52, 52, 97, 50, 46, 56, 54, 32, 50, 46, 56, 54, 32, 48, 32, 48, 32, 48, 45, 49, 46, 50, 50, 45, 49, 46, 53, 56, 99, 45, 49, 45, 46, 54, 54, 46, 48, 56, 45, 46, 54, 53, 46, 48, 56, 45, 46, 54, 53, 97, 50, 46, 51, 49, 32, 50, 46, 51, 49, 32, 48, 32, 48, 32, 49, 32, 49, 46, 54, 56, 32, 49, 46, 49, 49, 32, 50, 46, 51, 55, 32, 50, 46, 51, 55, 32, 48, 32, 48, 32, 48, 32, 51, 46, 50, 46, 56, 57, 32, 50, 46, 51, 51, 32, 50, 46, 51, 51, 32, 48, 32, 48, 32, 49, 32, 46, 55, 45, 49, 46, 52, 52, 99, 45, 50, 46, 52, 52, 45, 46, 50, 55, 45, 53, 45, 49, 46, 49, 57, 45, 53, 45, 53, 46, 51, 50, 97, 52, 46, 49, 53, 32, 52, 46, 49, 53, 32, 48, 32, 48, 32, 49, 32, 49, 46, 49, 49, 45, 50, 46, 57, 49, 32, 51, 46, 55, 56, 32, 51, 46, 55, 56, 32, 48, 32, 48, 32, 49, 32, 46, 49, 49, 45, 50, 46, 56, 52, 115, 46, 57, 51, 45, 46, 50, 57, 32, 51, 32, 49, 46, 49, 97, 49, 48, 46, 54, 56, 32, 49, 48, 46, 54, 56, 32, 48, 32, 48, 32, 49, 32, 53, 46, 53, 32, 48, 99, 50, 46, 49, 45, 49, 46, 51, 57, 32, 51, 45, 49, 46, 49, 32, 51, 45, 49, 46, 49, 97, 51, 46, 55, 56, 32, 51, 46, 55, 56, 32, 48, 32, 48, 32, 49, 32, 46, 49, 49, 32, 50, 46, 56, 52, 65, 52, 46, 49, 53, 32, 52, 46, 49, 53, 32, 48, 32, 48, 32, 49, 32, 49, 57, 32, 49, 49, 46, 50, 99, 48, 32, 52, 46, 49, 52, 45, 50, 46, 53, 56, 32, 53, 46, 48, 53, 45, 53, 32, 53, 46, 51, 50, 97, 50, 46, 53, 32, 50, 46, 53, 32, 48, 32, 48, 32, 49, 32, 46, 55, 53, 32, 50, 118, 50, 46, 57, 53, 99, 48, 32, 46, 51, 53, 46, 50, 46, 54, 51, 46, 55, 53, 46, 53, 50, 65, 49, 48, 46, 56, 32, 49, 48, 46, 56, 32, 48, 32, 48, 32, 48, 32, 50, 51, 32, 49, 49, 46, 55, 55, 32, 49, 48, 46, 56, 57, 32, 49, 48, 46, 56, 57, 32, 48, 32, 48, 32, 48, 32, 49, 50, 32, 49, 34, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 103, 105, 116, 104, 117, 98, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("glasses"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 54, 52, 44, 49, 56, 52, 72, 52, 53, 51, 46, 49, 97, 55, 56, 46, 55, 50, 44, 55, 56, 46, 55, 50, 44, 48, 44, 48, 44, 48, 45, 49, 54, 45, 55, 46, 49, 56, 67, 52, 49, 57, 46, 53, 44, 49, 55, 49, 44, 51, 57, 54, 46, 50, 54, 44, 49, 54, 56, 44, 51, 54, 56, 44, 49, 54, 56, 115, 45, 53, 49, 46, 53, 44, 51, 45, 54, 57, 46, 48, 54, 44, 56, 46, 56, 50, 99, 45, 49, 52, 46, 48, 54, 44, 52, 46, 54, 57, 45, 50, 48, 46, 50, 53, 44, 57, 46, 56, 54, 45, 50, 50, 46, 50, 53, 44, 49, 49, 46, 56, 55, 104, 48, 97, 52, 55, 46, 57, 52, 44, 52, 55, 46, 57, 52, 44, 48, 44, 48, 44, 48, 45, 52, 49, 46, 51, 54, 44, 48, 104, 48, 99, 45, 50, 45, 50, 45, 56, 46, 49, 57, 45, 55, 46, 49, 56, 45, 50, 50, 46, 50, 53, 45, 49, 49, 46, 56, 55, 67, 49, 57, 53, 46, 53, 44, 49, 55, 49, 44, 49, 55, 50, 46, 50, 54, 44, 49, 54, 56, 44, 49, 52, 52, 44, 49, 54, 56, 115, 45, 53, 49, 46, 53, 44, 51, 45, 54, 57, 46, 48, 54, 44, 56, 46, 56, 50, 97, 55, 56, 46, 55, 50, 44, 55, 56, 46, 55, 50, 44, 48, 44, 48, 44, 48, 45, 49, 54, 44, 55, 46, 49, 56, 72, 52, 56, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 48, 44, 51, 50, 104, 46, 49, 55, 99, 49, 44, 52, 53, 46, 52, 54, 44, 54, 46, 52, 52, 44, 55, 50, 46, 55, 56, 44, 49, 56, 46, 49, 49, 44, 57, 50, 46, 50, 51, 97, 54, 54, 46, 55, 56, 44, 54, 54, 46, 55, 56, 44, 48, 44, 48, 44, 48, 44, 51, 49, 46, 57, 50, 44, 50, 56, 99, 49, 50, 46, 50, 51, 44, 53, 46, 50, 52, 44, 50, 55, 46, 50, 50, 44, 55, 46, 55, 57, 44, 52, 53, 46, 56, 44, 55, 46, 55, 57, 44, 50, 52, 46, 49, 53, 44, 48, 44, 53, 56, 46, 52, 56, 45, 51, 46, 55, 49, 44, 55, 55, 46, 55, 50, 45, 51, 53, 46, 55, 55, 44, 57, 46, 54, 56, 45, 49, 54, 46, 49, 52, 44, 49, 53, 46, 48, 57, 45, 51, 55, 46, 54, 57, 44, 49, 55, 46, 50, 49, 45, 55, 48, 46, 53, 50, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 50, 52, 48, 44, 50, 51, 50, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 51, 50, 44, 48, 44, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 49, 46, 48, 55, 44, 53, 46, 55, 49, 99, 50, 46, 49, 50, 44, 51, 50, 46, 56, 51, 44, 55, 46, 53, 51, 44, 53, 52, 46, 51, 56, 44, 49, 55, 46, 50, 49, 44, 55, 48, 46, 53, 50, 97, 54, 54, 46, 55, 56, 44, 54, 54, 46, 55, 56, 44, 48, 44, 48, 44, 48, 44, 51, 49, 46, 57, 50, 44, 50, 56, 99, 49, 50, 46, 50, 51, 44, 53, 46, 50, 52, 44, 50, 55, 46, 50, 50, 44, 55, 46, 55, 57, 44, 52, 53, 46, 56, 44, 55, 46, 55, 57, 44, 50, 52, 46, 49, 53, 44, 48, 44, 53, 56, 46, 52, 56, 45, 51, 46, 55, 49, 44, 55, 55, 46, 55, 50, 45, 51, 53, 46, 55, 55, 44, 49, 49, 46, 54, 55, 45, 49, 57, 46, 52, 53, 44, 49, 55, 46, 49, 51, 45, 52, 54, 46, 55, 55, 44, 49, 56, 46, 49, 49, 45, 57, 50, 46, 50, 51, 72, 52, 54, 52, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 48, 45, 51, 50, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("google"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 49, 50, 56, 32, 49, 50, 56, 34, 32, 61441, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 52, 46, 53, 57, 32, 52, 46, 50, 49, 97, 54, 51, 46, 50, 56, 32, 54, 51, 46, 50, 56, 32, 48, 32, 48, 48, 52, 46, 51, 51, 32, 49, 50, 48, 46, 57, 32, 54, 55, 46, 54, 32, 54, 55, 46, 54, 32, 48, 32, 48, 48, 51, 50, 46, 51, 54, 46, 51, 53, 32, 53, 55, 46, 49, 51, 32, 53, 55, 46, 49, 51, 32, 48, 32, 48, 48, 50, 53, 46, 57, 45, 49, 51, 46, 52, 54, 32, 53, 55, 46, 52, 52, 32, 53, 55, 46, 52, 52, 32, 48, 32, 48, 48, 49, 54, 45, 50, 54, 46, 50, 54, 32, 55, 52, 46, 51, 51, 32, 55, 52, 46, 51, 51, 32, 48, 32, 48, 48, 49, 46, 54, 49, 45, 51, 51, 46, 53, 56, 72, 54, 53, 46, 50, 55, 118, 50, 52, 46, 54, 57, 104, 51, 52, 46, 52, 55, 97, 50, 57, 46, 55, 50, 32, 50, 57, 46, 55, 50, 32, 48, 32, 48, 49, 45, 49, 50, 46, 54, 54, 32, 49, 57, 46, 53, 50, 32, 51, 54, 46, 49, 54, 32, 51, 54, 46, 49, 54, 32, 48, 32, 48, 49, 45, 49, 51, 46, 57, 51, 32, 53, 46, 53, 32, 52, 49, 46, 50, 57, 32, 52, 49, 46, 50, 57, 32, 48, 32, 48, 49, 45, 49, 53, 46, 49, 32, 48, 65, 51, 55, 46, 49, 54, 32, 51, 55, 46, 49, 54, 32, 48, 32, 48, 49, 52, 52, 32, 57, 53, 46, 55, 52, 97, 51, 57, 46, 51, 32, 51, 57, 46, 51, 32, 48, 32, 48, 49, 45, 49, 52, 46, 53, 45, 49, 57, 46, 52, 50, 32, 51, 56, 46, 51, 49, 32, 51, 56, 46, 51, 49, 32, 48, 32, 48, 49, 48, 45, 50, 52, 46, 54, 51, 32, 51, 57, 46, 50, 53, 32, 51, 57, 46, 50, 53, 32, 48, 32, 48, 49, 57, 46, 49, 56, 45, 49, 52, 46, 57, 49, 65, 51, 55, 46, 49, 55, 32, 51, 55, 46, 49, 55, 32, 48, 32, 48, 49, 55, 54, 46, 49, 51, 32, 50, 55, 97, 51, 52, 46, 50, 56, 32, 51, 52, 46, 50, 56, 32, 48, 32, 48, 49, 49, 51, 46, 54, 52, 32, 56, 113, 53, 46, 56, 51, 45, 53, 46, 56, 32, 49, 49, 46, 54, 52, 45, 49, 49, 46, 54, 51, 99, 50, 45, 50, 46, 48, 57, 32, 52, 46, 49, 56, 45, 52, 46, 48, 56, 32, 54, 46, 49, 53, 45, 54, 46, 50, 50, 65, 54, 49, 46, 50, 50, 32, 54, 49, 46, 50, 50, 32, 48, 32, 48, 48, 56, 55, 46, 50, 32, 52, 46, 53, 57, 97, 54, 52, 32, 54, 52, 32, 48, 32, 48, 48, 45, 52, 50, 46, 54, 49, 45, 46, 51, 56, 122, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("happy"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 49, 52, 46, 51, 57, 44, 57, 55, 46, 54, 49, 65, 50, 50, 52, 44, 50, 50, 52, 44, 48, 44, 49, 44, 48, 44, 57, 55, 46, 54, 49, 44, 52, 49, 52, 46, 51, 57, 44, 50, 50, 52, 44, 50, 50, 52, 44, 48, 44, 49, 44, 48, 44, 52, 49, 52, 46, 51, 57, 44, 57, 55, 46, 54, 49, 90, 77, 49, 56, 52, 44, 50, 48, 56, 97, 50, 52, 44, 50, 52, 44, 48, 44, 49, 44, 49, 45, 50, 52, 44, 50, 52, 65, 50, 51, 46, 57, 52, 44, 50, 51, 46, 57, 52, 44, 48, 44, 48, 44, 49, 44, 49, 56, 52, 44, 50, 48, 56, 90, 77, 51, 53, 49, 46, 54, 55, 44, 51, 49, 52, 46, 49, 55, 99, 45, 49, 50, 44, 52, 48, 46, 51, 45, 53, 48, 46, 50, 44, 54, 57, 46, 56, 51, 45, 57, 53, 46, 54, 50, 44, 54, 57, 46, 56, 51, 115, 45, 56, 51, 46, 54, 50, 45, 50, 57, 46, 53, 51, 45, 57, 53, 46, 55, 50, 45, 54, 57, 46, 56, 51, 65, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 49, 54, 56, 46, 49, 54, 44, 51, 48, 52, 72, 51, 52, 51, 46, 56, 53, 65, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 51, 53, 49, 46, 54, 55, 44, 51, 49, 52, 46, 49, 55, 90, 77, 51, 50, 56, 44, 50, 53, 54, 97, 50, 52, 44, 50, 52, 44, 48, 44, 49, 44, 49, 44, 50, 52, 45, 50, 52, 65, 50, 51, 46, 57, 52, 44, 50, 51, 46, 57, 52, 44, 48, 44, 48, 44, 49, 44, 51, 50, 56, 44, 50, 53, 54, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("hash"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 104, 97, 115, 104, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 49, 56, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 48, 32, 49, 52, 104, 45, 52, 46, 51, 108, 46, 55, 51, 45, 52, 72, 50, 48, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 50, 104, 45, 51, 46, 50, 49, 108, 46, 54, 57, 45, 51, 46, 56, 49, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 54, 46, 54, 52, 32, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 50, 50, 46, 56, 50, 76, 49, 52, 46, 54, 55, 32, 56, 104, 45, 51, 46, 56, 56, 108, 46, 54, 57, 45, 51, 46, 56, 49, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 48, 46, 54, 52, 32, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 50, 50, 46, 56, 50, 76, 56, 46, 54, 55, 32, 56, 72, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 32, 50, 104, 52, 46, 51, 108, 45, 46, 55, 51, 32, 52, 72, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 32, 50, 104, 51, 46, 50, 49, 108, 45, 46, 54, 57, 32, 51, 46, 56, 49, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 55, 46, 51, 54, 32, 50, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 50, 50, 45, 46, 56, 50, 76, 57, 46, 51, 51, 32, 49, 54, 104, 51, 46, 56, 56, 108, 45, 46, 54, 57, 32, 51, 46, 56, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 56, 52, 32, 49, 46, 49, 57, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 50, 50, 45, 46, 56, 50, 108, 46, 55, 53, 45, 52, 46, 49, 56, 72, 50, 48, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 50, 122, 77, 57, 46, 55, 32, 49, 52, 108, 46, 55, 51, 45, 52, 104, 51, 46, 56, 55, 108, 45, 46, 55, 51, 32, 52, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("heart"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 104, 101, 97, 114, 116, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 50, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 46, 55, 49, 45, 46, 50, 57, 108, 45, 55, 46, 55, 55, 45, 55, 46, 55, 56, 97, 53, 46, 50, 54, 32, 53, 46, 50, 54, 32, 48, 32, 48, 32, 49, 32, 48, 45, 55, 46, 52, 32, 53, 46, 50, 52, 32, 53, 46, 50, 52, 32, 48, 32, 48, 32, 49, 32, 55, 46, 52, 32, 48, 76, 49, 50, 32, 54, 46, 54, 49, 108, 49, 46, 48, 56, 45, 49, 46, 48, 56, 97, 53, 46, 50, 52, 32, 53, 46, 50, 52, 32, 48, 32, 48, 32, 49, 32, 55, 46, 52, 32, 48, 32, 53, 46, 50, 54, 32, 53, 46, 50, 54, 32, 48, 32, 48, 32, 49, 32, 48, 32, 55, 46, 52, 108, 45, 55, 46, 55, 55, 32, 55, 46, 55, 56, 65, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 49, 50, 32, 50, 49, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("home"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 104, 111, 109, 101, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 114, 101, 99, 116, 32, 120, 61, 34, 49, 48, 34, 32, 121, 61, 34, 49, 52, 34, 32, 119, 105, 100, 116, 104, 61, 34, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 55, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 48, 46, 52, 50, 32, 49, 48, 46, 49, 56, 76, 49, 50, 46, 55, 49, 32, 50, 46, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 32, 48, 108, 45, 55, 46, 55, 49, 32, 55, 46, 56, 57, 65, 50, 32, 50, 32, 48, 32, 48, 32, 48, 32, 51, 32, 49, 49, 46, 54, 50, 86, 50, 48, 97, 50, 32, 50, 32, 48, 32, 48, 32, 48, 32, 49, 46, 56, 57, 32, 50, 72, 56, 118, 45, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 49, 45, 49, 104, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 49, 32, 49, 118, 57, 104, 51, 46, 49, 49, 65, 50, 32, 50, 32, 48, 32, 48, 32, 48, 32, 50, 49, 32, 50, 48, 118, 45, 56, 46, 51, 56, 97, 50, 46, 48, 55, 32, 50, 46, 48, 55, 32, 48, 32, 48, 32, 48, 45, 46, 53, 56, 45, 49, 46, 52, 52, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("html"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 49, 50, 56, 32, 49, 50, 56, 34, 32, 61441, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 57, 46, 48, 51, 50, 32, 50, 108, 49, 48, 46, 48, 48, 53, 32, 49, 49, 50, 46, 48, 57, 51, 32, 52, 52, 46, 56, 57, 54, 32, 49, 50, 46, 52, 48, 49, 32, 52, 53, 46, 48, 50, 45, 49, 50, 46, 51, 56, 55, 76, 49, 49, 56, 46, 57, 54, 56, 32, 50, 72, 57, 46, 48, 51, 50, 122, 109, 56, 57, 46, 49, 50, 54, 32, 50, 54, 46, 53, 51, 57, 108, 45, 46, 54, 50, 55, 32, 55, 46, 49, 55, 50, 76, 57, 55, 46, 50, 53, 53, 32, 51, 57, 72, 52, 52, 46, 53, 57, 108, 49, 46, 50, 53, 55, 32, 49, 52, 104, 53, 48, 46, 49, 53, 54, 108, 45, 46, 51, 51, 54, 32, 51, 46, 52, 55, 49, 45, 51, 46, 50, 51, 51, 32, 51, 54, 46, 49, 49, 57, 45, 46, 50, 51, 56, 32, 50, 46, 50, 55, 76, 54, 52, 32, 49, 48, 50, 46, 54, 48, 57, 118, 46, 48, 48, 50, 108, 45, 46, 48, 51, 52, 46, 48, 49, 56, 45, 50, 56, 46, 49, 55, 55, 45, 55, 46, 52, 50, 51, 76, 51, 51, 46, 56, 55, 54, 32, 55, 52, 104, 49, 51, 46, 56, 49, 53, 108, 46, 57, 55, 57, 32, 49, 48, 46, 57, 49, 57, 76, 54, 51, 46, 57, 53, 55, 32, 56, 57, 72, 54, 52, 118, 45, 46, 53, 52, 54, 108, 49, 53, 46, 51, 53, 53, 45, 51, 46, 56, 55, 53, 76, 56, 48, 46, 57, 53, 57, 32, 54, 55, 72, 51, 51, 46, 50, 54, 49, 108, 45, 51, 46, 51, 56, 51, 45, 51, 56, 46, 49, 49, 55, 76, 50, 57, 46, 53, 52, 57, 32, 50, 53, 104, 54, 56, 46, 57, 51, 57, 108, 45, 46, 51, 51, 32, 51, 46, 53, 51, 57, 122, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("image"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 105, 109, 97, 103, 101, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 56, 32, 51, 72, 54, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 51, 32, 51, 118, 49, 50, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 51, 32, 51, 104, 49, 50, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 51, 45, 51, 86, 54, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 51, 45, 51, 122, 77, 54, 32, 53, 104, 49, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 49, 32, 49, 118, 56, 46, 51, 54, 108, 45, 51, 46, 50, 45, 50, 46, 55, 51, 97, 50, 46, 55, 55, 32, 50, 46, 55, 55, 32, 48, 32, 48, 32, 48, 45, 51, 46, 53, 50, 32, 48, 76, 53, 32, 49, 55, 46, 55, 86, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 49, 45, 49, 122, 34, 32, 61442, 47, 62, 60, 99, 105, 114, 99, 108, 101, 32, 99, 120, 61, 34, 56, 34, 32, 99, 121, 61, 34, 56, 46, 53, 34, 32, 114, 61, 34, 49, 46, 53, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("info"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 105, 110, 102, 111, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 49, 56, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 50, 97, 49, 48, 32, 49, 48, 32, 48, 32, 49, 32, 48, 32, 49, 48, 32, 49, 48, 65, 49, 48, 32, 49, 48, 32, 48, 32, 48, 32, 48, 32, 49, 50, 32, 50, 122, 109, 49, 32, 49, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 50, 32, 48, 118, 45, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 50, 32, 48, 122, 109, 45, 49, 45, 55, 97, 49, 32, 49, 32, 48, 32, 49, 32, 49, 32, 49, 45, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 49, 32, 49, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("instagram"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 51, 52, 57, 46, 51, 51, 44, 54, 57, 46, 51, 51, 97, 57, 51, 46, 54, 50, 44, 57, 51, 46, 54, 50, 44, 48, 44, 48, 44, 49, 44, 57, 51, 46, 51, 52, 44, 57, 51, 46, 51, 52, 86, 51, 52, 57, 46, 51, 51, 97, 57, 51, 46, 54, 50, 44, 57, 51, 46, 54, 50, 44, 48, 44, 48, 44, 49, 45, 57, 51, 46, 51, 52, 44, 57, 51, 46, 51, 52, 72, 49, 54, 50, 46, 54, 55, 97, 57, 51, 46, 54, 50, 44, 57, 51, 46, 54, 50, 44, 48, 44, 48, 44, 49, 45, 57, 51, 46, 51, 52, 45, 57, 51, 46, 51, 52, 86, 49, 54, 50, 46, 54, 55, 97, 57, 51, 46, 54, 50, 44, 57, 51, 46, 54, 50, 44, 48, 44, 48, 44, 49, 44, 57, 51, 46, 51, 52, 45, 57, 51, 46, 51, 52, 72, 51, 52, 57, 46, 51, 51, 109, 48, 45, 51, 55, 46, 51, 51, 72, 49, 54, 50, 46, 54, 55, 67, 57, 48, 46, 56, 44, 51, 50, 44, 51, 50, 44, 57, 48, 46, 56, 44, 51, 50, 44, 49, 54, 50, 46, 54, 55, 86, 51, 52, 57, 46, 51, 51, 67, 51, 50, 44, 52, 50, 49, 46, 50, 44, 57, 48, 46, 56, 44, 52, 56, 48, 44, 49, 54, 50, 46, 54, 55, 44, 52, 56, 48, 72, 51, 52, 57, 46, 51, 51, 67, 52, 50, 49, 46, 50, 44, 52, 56, 48, 44, 52, 56, 48, 44, 52, 50, 49, 46, 50, 44, 52, 56, 48, 44, 51, 52, 57, 46, 51, 51, 86, 49, 54, 50, 46, 54, 55, 67, 52, 56, 48, 44, 57, 48, 46, 56, 44, 52, 50, 49, 46, 50, 44, 51, 50, 44, 51, 52, 57, 46, 51, 51, 44, 51, 50, 90, 34, 47, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 51, 55, 55, 46, 51, 51, 44, 49, 54, 50, 46, 54, 55, 97, 50, 56, 44, 50, 56, 44, 48, 44, 49, 44, 49, 44, 50, 56, 45, 50, 56, 65, 50, 55, 46, 57, 52, 44, 50, 55, 46, 57, 52, 44, 48, 44, 48, 44, 49, 44, 51, 55, 55, 46, 51, 51, 44, 49, 54, 50, 46, 54, 55, 90, 34, 47, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 50, 53, 54, 44, 49, 56, 49, 46, 51, 51, 65, 55, 52, 46, 54, 55, 44, 55, 52, 46, 54, 55, 44, 48, 44, 49, 44, 49, 44, 49, 56, 49, 46, 51, 51, 44, 50, 53, 54, 44, 55, 52, 46, 55, 53, 44, 55, 52, 46, 55, 53, 44, 48, 44, 48, 44, 49, 44, 50, 53, 54, 44, 49, 56, 49, 46, 51, 51, 77, 50, 53, 54, 44, 49, 52, 52, 65, 49, 49, 50, 44, 49, 49, 50, 44, 48, 44, 49, 44, 48, 44, 51, 54, 56, 44, 50, 53, 54, 44, 49, 49, 50, 44, 49, 49, 50, 44, 48, 44, 48, 44, 48, 44, 50, 53, 54, 44, 49, 52, 52, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("javascript"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 49, 50, 56, 32, 49, 50, 56, 34, 32, 61441, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 50, 32, 49, 118, 49, 50, 53, 104, 49, 50, 53, 86, 49, 72, 50, 122, 109, 54, 54, 46, 49, 49, 57, 32, 49, 48, 54, 46, 53, 49, 51, 99, 45, 49, 46, 56, 52, 53, 32, 51, 46, 55, 52, 57, 45, 53, 46, 51, 54, 55, 32, 54, 46, 50, 49, 50, 45, 57, 46, 52, 52, 56, 32, 55, 46, 52, 48, 49, 45, 54, 46, 50, 55, 49, 32, 49, 46, 52, 52, 45, 49, 50, 46, 50, 54, 57, 46, 54, 49, 57, 45, 49, 54, 46, 55, 51, 49, 45, 50, 46, 48, 53, 57, 45, 50, 46, 57, 56, 54, 45, 49, 46, 56, 51, 50, 45, 53, 46, 51, 49, 56, 45, 52, 46, 54, 53, 50, 45, 54, 46, 57, 48, 49, 45, 55, 46, 57, 48, 49, 108, 57, 46, 53, 50, 45, 53, 46, 56, 51, 99, 46, 48, 56, 51, 46, 48, 51, 53, 46, 51, 51, 51, 46, 52, 56, 55, 46, 54, 54, 55, 32, 49, 46, 48, 55, 49, 32, 49, 46, 50, 49, 52, 32, 50, 46, 48, 51, 52, 32, 50, 46, 50, 54, 49, 32, 51, 46, 52, 55, 52, 32, 52, 46, 51, 49, 57, 32, 52, 46, 52, 56, 53, 32, 50, 46, 48, 50, 50, 46, 54, 57, 32, 54, 46, 52, 54, 49, 32, 49, 46, 49, 51, 49, 32, 56, 46, 49, 55, 53, 45, 50, 46, 52, 50, 55, 32, 49, 46, 48, 52, 55, 45, 49, 46, 56, 49, 46, 55, 49, 52, 45, 55, 46, 54, 50, 56, 46, 55, 49, 52, 45, 49, 52, 46, 48, 54, 53, 67, 53, 56, 46, 52, 51, 51, 32, 55, 56, 46, 48, 55, 51, 32, 53, 56, 46, 52, 56, 32, 54, 56, 32, 53, 56, 46, 52, 56, 32, 53, 56, 104, 49, 49, 46, 55, 48, 57, 99, 48, 32, 49, 49, 32, 46, 48, 54, 32, 50, 49, 46, 52, 49, 56, 32, 48, 32, 51, 50, 46, 49, 53, 50, 46, 48, 50, 53, 32, 54, 46, 53, 56, 46, 53, 57, 54, 32, 49, 50, 46, 52, 52, 54, 45, 50, 46, 48, 55, 32, 49, 55, 46, 51, 54, 49, 122, 109, 52, 56, 46, 53, 55, 52, 45, 51, 46, 51, 48, 56, 99, 45, 52, 46, 48, 55, 32, 49, 51, 46, 57, 50, 50, 45, 50, 54, 46, 55, 54, 50, 32, 49, 52, 46, 51, 55, 52, 45, 51, 53, 46, 56, 51, 32, 53, 46, 49, 55, 54, 45, 49, 46, 57, 49, 54, 45, 50, 46, 49, 54, 53, 45, 51, 46, 49, 49, 55, 45, 51, 46, 50, 57, 54, 45, 52, 46, 50, 54, 45, 53, 46, 55, 57, 53, 32, 52, 46, 56, 49, 57, 45, 50, 46, 55, 55, 50, 32, 52, 46, 56, 49, 57, 45, 50, 46, 55, 55, 50, 32, 57, 46, 53, 48, 56, 45, 53, 46, 52, 56, 53, 32, 50, 46, 53, 52, 55, 32, 51, 46, 57, 49, 53, 32, 52, 46, 57, 48, 50, 32, 54, 46, 48, 54, 56, 32, 57, 46, 49, 51, 57, 32, 54, 46, 57, 52, 57, 32, 53, 46, 55, 52, 56, 46, 55, 48, 50, 32, 49, 49, 46, 53, 51, 49, 45, 49, 46, 50, 55, 51, 32, 49, 48, 46, 50, 51, 52, 45, 55, 46, 51, 55, 56, 45, 49, 46, 51, 51, 51, 45, 52, 46, 57, 56, 54, 45, 49, 49, 46, 55, 55, 45, 54, 46, 49, 57, 57, 45, 49, 56, 46, 56, 55, 51, 45, 49, 49, 46, 53, 51, 49, 45, 55, 46, 50, 49, 49, 45, 52, 46, 56, 52, 51, 45, 56, 46, 57, 48, 49, 45, 49, 54, 46, 54, 49, 49, 45, 50, 46, 57, 55, 53, 45, 50, 51, 46, 51, 51, 53, 32, 49, 46, 57, 55, 53, 45, 50, 46, 52, 56, 55, 32, 53, 46, 51, 52, 51, 45, 52, 46, 51, 52, 51, 32, 56, 46, 56, 55, 55, 45, 53, 46, 50, 51, 53, 108, 51, 46, 54, 56, 56, 45, 46, 52, 55, 55, 99, 55, 46, 48, 56, 49, 45, 46, 49, 52, 51, 32, 49, 49, 46, 53, 48, 55, 32, 49, 46, 55, 50, 55, 32, 49, 52, 46, 55, 53, 54, 32, 53, 46, 51, 53, 53, 46, 57, 48, 52, 46, 57, 49, 54, 32, 49, 46, 54, 52, 50, 32, 49, 46, 57, 48, 52, 32, 51, 46, 48, 50, 50, 32, 52, 46, 48, 52, 53, 45, 51, 46, 55, 55, 50, 32, 50, 46, 52, 48, 52, 45, 51, 46, 55, 54, 32, 50, 46, 51, 56, 49, 45, 57, 46, 49, 54, 51, 32, 53, 46, 56, 55, 57, 45, 49, 46, 49, 53, 52, 45, 50, 46, 52, 56, 54, 45, 51, 46, 48, 54, 57, 45, 52, 46, 48, 52, 54, 45, 53, 46, 48, 57, 51, 45, 52, 46, 55, 50, 52, 45, 51, 46, 49, 52, 50, 45, 46, 57, 53, 50, 45, 55, 46, 49, 48, 52, 46, 48, 56, 51, 45, 55, 46, 57, 50, 54, 32, 51, 46, 52, 48, 51, 45, 46, 50, 56, 53, 32, 49, 46, 48, 50, 51, 45, 46, 50, 50, 54, 32, 49, 46, 57, 55, 53, 46, 50, 50, 55, 32, 51, 46, 54, 54, 53, 32, 49, 46, 50, 55, 51, 32, 50, 46, 57, 48, 51, 32, 53, 46, 53, 52, 53, 32, 52, 46, 49, 54, 53, 32, 57, 46, 51, 55, 55, 32, 53, 46, 57, 50, 54, 32, 49, 49, 46, 48, 51, 49, 32, 52, 46, 52, 55, 52, 32, 49, 52, 46, 55, 53, 54, 32, 57, 46, 50, 55, 49, 32, 49, 53, 46, 54, 55, 50, 32, 49, 52, 46, 57, 56, 49, 46, 56, 56, 50, 32, 52, 46, 57, 49, 54, 45, 46, 50, 49, 51, 32, 56, 46, 49, 48, 53, 45, 46, 51, 56, 32, 56, 46, 53, 56, 49, 122, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("key"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 50, 49, 56, 46, 49, 44, 49, 54, 55, 46, 49, 55, 99, 48, 44, 49, 51, 44, 48, 44, 50, 53, 46, 54, 44, 52, 46, 49, 44, 51, 55, 46, 52, 45, 52, 51, 46, 49, 44, 53, 48, 46, 54, 45, 49, 53, 54, 46, 57, 44, 49, 56, 52, 46, 51, 45, 49, 54, 55, 46, 53, 44, 49, 57, 52, 46, 53, 97, 50, 48, 46, 49, 55, 44, 50, 48, 46, 49, 55, 44, 48, 44, 48, 44, 48, 45, 54, 46, 55, 44, 49, 53, 99, 48, 44, 56, 46, 53, 44, 53, 46, 50, 44, 49, 54, 46, 55, 44, 57, 46, 54, 44, 50, 49, 46, 51, 44, 54, 46, 54, 44, 54, 46, 57, 44, 51, 52, 46, 56, 44, 51, 51, 44, 52, 48, 44, 50, 56, 44, 49, 53, 46, 52, 45, 49, 53, 44, 49, 56, 46, 53, 45, 49, 57, 44, 50, 52, 46, 56, 45, 50, 53, 46, 50, 44, 57, 46, 53, 45, 57, 46, 51, 45, 49, 45, 50, 56, 46, 51, 44, 50, 46, 51, 45, 51, 54, 115, 54, 46, 56, 45, 57, 46, 50, 44, 49, 50, 46, 53, 45, 49, 48, 46, 52, 44, 49, 53, 46, 56, 44, 50, 46, 57, 44, 50, 51, 46, 55, 44, 51, 99, 56, 46, 51, 46, 49, 44, 49, 50, 46, 56, 45, 51, 46, 52, 44, 49, 57, 45, 57, 46, 50, 44, 53, 45, 52, 46, 54, 44, 56, 46, 54, 45, 56, 46, 57, 44, 56, 46, 55, 45, 49, 53, 46, 54, 46, 50, 45, 57, 45, 49, 50, 46, 56, 45, 50, 48, 46, 57, 45, 51, 46, 49, 45, 51, 48, 46, 52, 115, 50, 51, 46, 55, 44, 54, 46, 50, 44, 51, 52, 44, 53, 44, 50, 50, 46, 56, 45, 49, 53, 46, 53, 44, 50, 52, 46, 49, 45, 50, 49, 46, 54, 45, 49, 49, 46, 55, 45, 50, 49, 46, 56, 45, 57, 46, 55, 45, 51, 48, 46, 55, 99, 46, 55, 45, 51, 44, 54, 46, 56, 45, 49, 48, 44, 49, 49, 46, 52, 45, 49, 49, 115, 50, 53, 44, 54, 46, 57, 44, 50, 57, 46, 54, 44, 53, 46, 57, 99, 53, 46, 54, 45, 49, 46, 50, 44, 49, 50, 46, 49, 45, 55, 46, 49, 44, 49, 55, 46, 52, 45, 49, 48, 46, 52, 44, 49, 53, 46, 53, 44, 54, 46, 55, 44, 50, 57, 46, 54, 44, 57, 46, 52, 44, 52, 55, 46, 55, 44, 57, 46, 52, 44, 54, 56, 46, 53, 44, 48, 44, 49, 50, 52, 45, 53, 51, 46, 52, 44, 49, 50, 52, 45, 49, 49, 57, 46, 50, 83, 52, 48, 56, 46, 53, 44, 52, 56, 44, 51, 52, 48, 44, 52, 56, 44, 50, 49, 56, 46, 49, 44, 49, 48, 49, 46, 51, 55, 44, 50, 49, 56, 46, 49, 44, 49, 54, 55, 46, 49, 55, 90, 77, 52, 48, 48, 44, 49, 52, 52, 97, 51, 50, 44, 51, 50, 44, 48, 44, 49, 44, 49, 45, 51, 50, 45, 51, 50, 65, 51, 50, 44, 51, 50, 44, 48, 44, 48, 44, 49, 44, 52, 48, 48, 44, 49, 52, 52, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("list"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 108, 105, 115, 116, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 49, 56, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 99, 105, 114, 99, 108, 101, 32, 99, 120, 61, 34, 52, 34, 32, 99, 121, 61, 34, 55, 34, 32, 114, 61, 34, 49, 34, 32, 61442, 47, 62, 60, 99, 105, 114, 99, 108, 101, 32, 99, 120, 61, 34, 52, 34, 32, 99, 121, 61, 34, 49, 50, 34, 32, 114, 61, 34, 49, 34, 32, 61442, 47, 62, 60, 99, 105, 114, 99, 108, 101, 32, 99, 120, 61, 34, 52, 34, 32, 99, 121, 61, 34, 49, 55, 34, 32, 114, 61, 34, 49, 34, 32, 61442, 47, 62, 60, 114, 101, 99, 116, 32, 120, 61, 34, 55, 34, 32, 121, 61, 34, 49, 49, 34, 32, 119, 105, 100, 116, 104, 61, 34, 49, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 34, 32, 114, 120, 61, 34, 46, 57, 52, 34, 32, 114, 121, 61, 34, 46, 57, 52, 34, 32, 61442, 47, 62, 60, 114, 101, 99, 116, 32, 120, 61, 34, 55, 34, 32, 121, 61, 34, 49, 54, 34, 32, 119, 105, 100, 116, 104, 61, 34, 49, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 34, 32, 114, 120, 61, 34, 46, 57, 52, 34, 32, 114, 121, 61, 34, 46, 57, 52, 34, 32, 61442, 47, 62, 60, 114, 101, 99, 116, 32, 120, 61, 34, 55, 34, 32, 121, 61, 34, 54, 34, 32, 119, 105, 100, 116, 104, 61, 34, 49, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 34, 32, 114, 120, 61, 34, 46, 57, 52, 34, 32, 114, 121, 61, 34, 46, 57, 52, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("lock"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 108, 111, 99, 107, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 99, 105, 114, 99, 108, 101, 32, 99, 120, 61, 34, 49, 50, 34, 32, 99, 121, 61, 34, 49, 53, 34, 32, 114, 61, 34, 49, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 55, 32, 56, 104, 45, 49, 86, 54, 46, 49, 49, 97, 52, 32, 52, 32, 48, 32, 49, 32, 48, 45, 56, 32, 48, 86, 56, 72, 55, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 51, 32, 51, 118, 56, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 51, 32, 51, 104, 49, 48, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 51, 45, 51, 118, 45, 56, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 51, 45, 51, 122, 109, 45, 55, 45, 49, 46, 56, 57, 65, 50, 46, 48, 54, 32, 50, 46, 48, 54, 32, 48, 32, 48, 32, 49, 32, 49, 50, 32, 52, 97, 50, 46, 48, 54, 32, 50, 46, 48, 54, 32, 48, 32, 48, 32, 49, 32, 50, 32, 50, 46, 49, 49, 86, 56, 104, 45, 52, 122, 77, 49, 50, 32, 49, 56, 97, 51, 32, 51, 32, 48, 32, 49, 32, 49, 32, 51, 45, 51, 32, 51, 32, 51, 32, 48, 32, 48, 32, 49, 45, 51, 32, 51, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("maximize"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 109, 97, 120, 105, 109, 105, 122, 101, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 48, 46, 55, 49, 32, 49, 57, 46, 50, 57, 108, 45, 51, 46, 52, 45, 51, 46, 51, 57, 65, 55, 46, 57, 50, 32, 55, 46, 57, 50, 32, 48, 32, 48, 32, 48, 32, 49, 57, 32, 49, 49, 97, 56, 32, 56, 32, 48, 32, 49, 32, 48, 45, 56, 32, 56, 32, 55, 46, 57, 50, 32, 55, 46, 57, 50, 32, 48, 32, 48, 32, 48, 32, 52, 46, 57, 45, 49, 46, 54, 57, 108, 51, 46, 51, 57, 32, 51, 46, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 50, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 122, 77, 49, 51, 32, 49, 50, 104, 45, 49, 118, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 50, 32, 48, 118, 45, 49, 72, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 48, 45, 50, 104, 49, 86, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 50, 32, 48, 118, 49, 104, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 48, 32, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("menu"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 109, 101, 110, 117, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 49, 56, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 114, 101, 99, 116, 32, 61442, 32, 120, 61, 34, 51, 34, 32, 121, 61, 34, 49, 49, 34, 32, 119, 105, 100, 116, 104, 61, 34, 49, 56, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 34, 32, 114, 120, 61, 34, 46, 57, 53, 34, 32, 114, 121, 61, 34, 46, 57, 53, 34, 47, 62, 60, 114, 101, 99, 116, 32, 61442, 32, 120, 61, 34, 51, 34, 32, 121, 61, 34, 49, 54, 34, 32, 119, 105, 100, 116, 104, 61, 34, 49, 56, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 34, 32, 114, 120, 61, 34, 46, 57, 53, 34, 32, 114, 121, 61, 34, 46, 57, 53, 34, 47, 62, 60, 114, 101, 99, 116, 32, 61442, 32, 120, 61, 34, 51, 34, 32, 121, 61, 34, 54, 34, 32, 119, 105, 100, 116, 104, 61, 34, 49, 56, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 34, 32, 114, 120, 61, 34, 46, 57, 53, 34, 32, 114, 121, 61, 34, 46, 57, 53, 34, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("messagesquare"), vec![60, 115, 118, 103, 32, 61440, 34, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 109, 101, 115, 115, 97, 103, 101, 45, 115, 113, 117, 97, 114, 101, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 57, 32, 51, 72, 53, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 51, 32, 51, 118, 49, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 53, 49, 46, 56, 55, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 51, 32, 50, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 53, 49, 45, 46, 49, 52, 76, 56, 32, 49, 57, 46, 49, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 46, 53, 53, 45, 46, 49, 52, 72, 49, 57, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 51, 45, 51, 86, 54, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 51, 45, 51, 122, 77, 56, 32, 49, 50, 97, 49, 32, 49, 32, 48, 32, 49, 32, 49, 32, 49, 45, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 49, 32, 49, 122, 109, 52, 32, 48, 97, 49, 32, 49, 32, 48, 32, 49, 32, 49, 32, 49, 45, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 49, 32, 49, 122, 109, 52, 32, 48, 97, 49, 32, 49, 32, 48, 32, 49, 32, 49, 32, 49, 45, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 49, 32, 49, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("mic"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 109, 105, 99, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 49, 53, 97, 52, 32, 52, 32, 48, 32, 48, 32, 48, 32, 52, 45, 52, 86, 54, 97, 52, 32, 52, 32, 48, 32, 48, 32, 48, 45, 56, 32, 48, 118, 53, 97, 52, 32, 52, 32, 48, 32, 48, 32, 48, 32, 52, 32, 52, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 57, 32, 49, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 50, 32, 48, 32, 53, 32, 53, 32, 48, 32, 48, 32, 49, 45, 49, 48, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 50, 32, 48, 32, 55, 32, 55, 32, 48, 32, 48, 32, 48, 32, 54, 32, 54, 46, 57, 50, 86, 50, 48, 72, 56, 46, 56, 57, 97, 46, 56, 57, 46, 56, 57, 32, 48, 32, 48, 32, 48, 45, 46, 56, 57, 46, 56, 57, 118, 46, 50, 50, 97, 46, 56, 57, 46, 56, 57, 32, 48, 32, 48, 32, 48, 32, 46, 56, 57, 46, 56, 57, 104, 54, 46, 50, 50, 97, 46, 56, 57, 46, 56, 57, 32, 48, 32, 48, 32, 48, 32, 46, 56, 57, 45, 46, 56, 57, 118, 45, 46, 50, 50, 97, 46, 56, 57, 46, 56, 57, 32, 48, 32, 48, 32, 48, 45, 46, 56, 57, 45, 46, 56, 57, 72, 49, 51, 118, 45, 50, 46, 48, 56, 65, 55, 32, 55, 32, 48, 32, 48, 32, 48, 32, 49, 57, 32, 49, 49, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("micoff"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 109, 105, 99, 45, 111, 102, 102, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 53, 46, 53, 56, 32, 49, 50, 46, 55, 53, 65, 52, 32, 52, 32, 48, 32, 48, 32, 48, 32, 49, 54, 32, 49, 49, 86, 54, 97, 52, 32, 52, 32, 48, 32, 48, 32, 48, 45, 55, 46, 57, 50, 45, 46, 55, 53, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 57, 32, 49, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 50, 32, 48, 32, 52, 46, 56, 54, 32, 52, 46, 56, 54, 32, 48, 32, 48, 32, 49, 45, 46, 54, 57, 32, 50, 46, 52, 56, 76, 49, 55, 46, 55, 56, 32, 49, 53, 65, 55, 32, 55, 32, 48, 32, 48, 32, 48, 32, 49, 57, 32, 49, 49, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 49, 53, 104, 46, 49, 54, 76, 56, 32, 49, 48, 46, 56, 51, 86, 49, 49, 97, 52, 32, 52, 32, 48, 32, 48, 32, 48, 32, 52, 32, 52, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 48, 46, 55, 49, 32, 49, 57, 46, 50, 57, 108, 45, 49, 54, 45, 49, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 32, 49, 46, 52, 50, 108, 49, 54, 32, 49, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 50, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 53, 32, 50, 48, 104, 45, 50, 118, 45, 50, 46, 48, 56, 97, 55, 32, 55, 32, 48, 32, 48, 32, 48, 32, 49, 46, 54, 53, 45, 46, 52, 52, 108, 45, 49, 46, 54, 45, 49, 46, 54, 65, 52, 46, 53, 55, 32, 52, 46, 53, 55, 32, 48, 32, 48, 32, 49, 32, 49, 50, 32, 49, 54, 97, 53, 32, 53, 32, 48, 32, 48, 32, 49, 45, 53, 45, 53, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 50, 32, 48, 32, 55, 32, 55, 32, 48, 32, 48, 32, 48, 32, 54, 32, 54, 46, 57, 50, 86, 50, 48, 72, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 32, 50, 104, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("minimize"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 109, 105, 110, 105, 109, 105, 122, 101, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 48, 46, 55, 49, 32, 49, 57, 46, 50, 57, 108, 45, 51, 46, 52, 45, 51, 46, 51, 57, 65, 55, 46, 57, 50, 32, 55, 46, 57, 50, 32, 48, 32, 48, 32, 48, 32, 49, 57, 32, 49, 49, 97, 56, 32, 56, 32, 48, 32, 49, 32, 48, 45, 56, 32, 56, 32, 55, 46, 57, 50, 32, 55, 46, 57, 50, 32, 48, 32, 48, 32, 48, 32, 52, 46, 57, 45, 49, 46, 54, 57, 108, 51, 46, 51, 57, 32, 51, 46, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 50, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 122, 77, 49, 51, 32, 49, 50, 72, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 48, 45, 50, 104, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 48, 32, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("music"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 109, 117, 115, 105, 99, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 57, 32, 49, 53, 86, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 51, 56, 45, 46, 55, 56, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 56, 52, 45, 46, 50, 108, 45, 57, 32, 50, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 56, 32, 54, 118, 56, 46, 51, 52, 97, 51, 46, 52, 57, 32, 51, 46, 52, 57, 32, 48, 32, 49, 32, 48, 32, 50, 32, 51, 46, 49, 56, 32, 52, 46, 51, 54, 32, 52, 46, 51, 54, 32, 48, 32, 48, 32, 48, 32, 48, 45, 46, 53, 50, 86, 54, 46, 56, 108, 55, 45, 49, 46, 53, 53, 118, 55, 46, 48, 57, 97, 51, 46, 52, 57, 32, 51, 46, 52, 57, 32, 48, 32, 49, 32, 48, 32, 50, 32, 51, 46, 49, 55, 32, 52, 46, 53, 55, 32, 52, 46, 53, 55, 32, 48, 32, 48, 32, 48, 32, 48, 45, 46, 53, 49, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("navigation"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 110, 97, 118, 105, 103, 97, 116, 105, 111, 110, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 48, 32, 50, 48, 97, 46, 57, 52, 46, 57, 52, 32, 48, 32, 48, 32, 49, 45, 46, 53, 53, 45, 46, 49, 55, 108, 45, 54, 46, 57, 45, 52, 46, 53, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 49, 32, 48, 108, 45, 54, 46, 57, 32, 52, 46, 53, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 49, 46, 52, 52, 45, 49, 46, 50, 56, 108, 56, 45, 49, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 49, 46, 55, 56, 32, 48, 108, 56, 32, 49, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 46, 50, 51, 32, 49, 46, 50, 65, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 50, 48, 32, 50, 48, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("nuclear"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 50, 53, 56, 46, 57, 44, 52, 56, 67, 49, 52, 49, 46, 57, 50, 44, 52, 54, 46, 52, 50, 44, 52, 54, 46, 52, 50, 44, 49, 52, 49, 46, 57, 50, 44, 52, 56, 44, 50, 53, 56, 46, 57, 44, 52, 57, 46, 53, 54, 44, 51, 55, 49, 46, 48, 57, 44, 49, 52, 48, 46, 57, 49, 44, 52, 54, 50, 46, 52, 52, 44, 50, 53, 51, 46, 49, 44, 52, 54, 52, 99, 49, 49, 55, 44, 49, 46, 54, 44, 50, 49, 50, 46, 52, 56, 45, 57, 51, 46, 57, 44, 50, 49, 48, 46, 56, 56, 45, 50, 49, 48, 46, 56, 56, 67, 52, 54, 50, 46, 52, 52, 44, 49, 52, 48, 46, 57, 49, 44, 51, 55, 49, 46, 48, 57, 44, 52, 57, 46, 53, 54, 44, 50, 53, 56, 46, 57, 44, 52, 56, 90, 77, 52, 50, 57, 44, 50, 51, 57, 46, 57, 50, 108, 45, 57, 51, 46, 48, 56, 45, 46, 49, 97, 50, 44, 50, 44, 48, 44, 48, 44, 49, 45, 49, 46, 57, 53, 45, 49, 46, 53, 55, 44, 56, 48, 46, 48, 56, 44, 56, 48, 46, 48, 56, 44, 48, 44, 48, 44, 48, 45, 50, 55, 46, 52, 52, 45, 52, 52, 46, 49, 55, 44, 50, 44, 50, 44, 48, 44, 48, 44, 49, 45, 46, 53, 52, 45, 50, 46, 52, 51, 108, 52, 49, 46, 51, 50, 45, 56, 51, 46, 52, 51, 97, 50, 44, 50, 44, 48, 44, 48, 44, 49, 44, 50, 46, 56, 55, 45, 46, 56, 49, 65, 49, 55, 54, 46, 50, 44, 49, 55, 54, 46, 50, 44, 48, 44, 48, 44, 49, 44, 52, 51, 49, 44, 50, 51, 55, 46, 55, 49, 44, 50, 44, 50, 44, 48, 44, 48, 44, 49, 44, 52, 50, 57, 44, 50, 51, 57, 46, 57, 50, 90, 77, 50, 48, 56, 46, 50, 44, 50, 54, 48, 46, 51, 56, 97, 52, 56, 44, 52, 56, 44, 48, 44, 49, 44, 49, 44, 52, 51, 46, 52, 50, 44, 52, 51, 46, 52, 50, 65, 52, 56, 44, 52, 56, 44, 48, 44, 48, 44, 49, 44, 50, 48, 56, 46, 50, 44, 50, 54, 48, 46, 51, 56, 90, 77, 49, 54, 52, 46, 54, 53, 44, 49, 48, 56, 46, 50, 50, 44, 50, 48, 54, 44, 49, 57, 49, 46, 54, 53, 97, 50, 44, 50, 44, 48, 44, 48, 44, 49, 45, 46, 53, 52, 44, 50, 46, 52, 51, 65, 56, 48, 46, 48, 56, 44, 56, 48, 46, 48, 56, 44, 48, 44, 48, 44, 48, 44, 49, 55, 56, 44, 50, 51, 56, 46, 50, 53, 97, 50, 44, 50, 44, 48, 44, 48, 44, 49, 45, 50, 44, 49, 46, 53, 55, 108, 45, 57, 51, 46, 48, 56, 46, 49, 97, 50, 44, 50, 44, 48, 44, 48, 44, 49, 45, 50, 45, 50, 46, 50, 49, 44, 49, 55, 54, 46, 50, 44, 49, 55, 54, 46, 50, 44, 48, 44, 48, 44, 49, 44, 56, 48, 46, 56, 50, 45, 49, 51, 48, 46, 51, 65, 50, 44, 50, 44, 48, 44, 48, 44, 49, 44, 49, 54, 52, 46, 54, 53, 44, 49, 48, 56, 46, 50, 50, 90, 109, 45, 46, 51, 55, 44, 50, 57, 53, 46, 51, 52, 44, 53, 54, 46, 51, 49, 45, 55, 52, 46, 48, 57, 97, 50, 44, 50, 44, 48, 44, 48, 44, 49, 44, 50, 46, 52, 51, 45, 46, 54, 44, 55, 57, 46, 56, 52, 44, 55, 57, 46, 56, 52, 44, 48, 44, 48, 44, 48, 44, 54, 54, 44, 48, 44, 50, 44, 50, 44, 48, 44, 48, 44, 49, 44, 50, 46, 52, 51, 46, 54, 108, 53, 54, 46, 51, 49, 44, 55, 52, 46, 48, 57, 97, 50, 44, 50, 44, 48, 44, 48, 44, 49, 45, 46, 53, 52, 44, 50, 46, 57, 50, 44, 49, 55, 53, 46, 54, 53, 44, 49, 55, 53, 46, 54, 53, 44, 48, 44, 48, 44, 49, 45, 49, 56, 50, 46, 51, 54, 44, 48, 65, 50, 44, 50, 44, 48, 44, 48, 44, 49, 44, 49, 54, 52, 46, 50, 56, 44, 52, 48, 51, 46, 53, 54, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("options"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 111, 112, 116, 105, 111, 110, 115, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 55, 32, 49, 52, 46, 49, 56, 86, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 50, 32, 48, 118, 49, 49, 46, 49, 56, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 48, 32, 53, 46, 54, 52, 86, 50, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 50, 32, 48, 118, 45, 49, 46, 49, 56, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 48, 45, 53, 46, 54, 52, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 49, 32, 49, 51, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 50, 45, 50, 46, 56, 50, 86, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 50, 32, 48, 118, 55, 46, 49, 56, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 48, 32, 53, 46, 54, 52, 86, 50, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 50, 32, 48, 118, 45, 53, 46, 49, 56, 65, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 50, 49, 32, 49, 51, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 53, 32, 53, 97, 51, 32, 51, 32, 48, 32, 49, 32, 48, 45, 52, 32, 50, 46, 56, 50, 86, 50, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 50, 32, 48, 86, 55, 46, 56, 50, 65, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 49, 53, 32, 53, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("pen"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 32, 61442, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 48, 32, 48, 104, 50, 52, 118, 50, 52, 72, 48, 122, 34, 32, 102, 105, 108, 108, 61, 34, 110, 111, 110, 101, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 51, 32, 49, 55, 46, 50, 53, 86, 50, 49, 104, 51, 46, 55, 53, 76, 49, 55, 46, 56, 49, 32, 57, 46, 57, 52, 108, 45, 51, 46, 55, 53, 45, 51, 46, 55, 53, 76, 51, 32, 49, 55, 46, 50, 53, 122, 77, 50, 48, 46, 55, 49, 32, 55, 46, 48, 52, 99, 46, 51, 57, 45, 46, 51, 57, 46, 51, 57, 45, 49, 46, 48, 50, 32, 48, 45, 49, 46, 52, 49, 108, 45, 50, 46, 51, 52, 45, 50, 46, 51, 52, 99, 45, 46, 51, 57, 45, 46, 51, 57, 45, 49, 46, 48, 50, 45, 46, 51, 57, 45, 49, 46, 52, 49, 32, 48, 108, 45, 49, 46, 56, 51, 32, 49, 46, 56, 51, 32, 51, 46, 55, 53, 32, 51, 46, 55, 53, 32, 49, 46, 56, 51, 45, 49, 46, 56, 51, 122, 34, 32, 61442, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("people"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 51, 51, 54, 44, 50, 53, 54, 99, 45, 50, 48, 46, 53, 54, 44, 48, 45, 52, 48, 46, 52, 52, 45, 57, 46, 49, 56, 45, 53, 54, 45, 50, 53, 46, 56, 52, 45, 49, 53, 46, 49, 51, 45, 49, 54, 46, 50, 53, 45, 50, 52, 46, 51, 55, 45, 51, 55, 46, 57, 50, 45, 50, 54, 45, 54, 49, 45, 49, 46, 55, 52, 45, 50, 52, 46, 54, 50, 44, 53, 46, 55, 55, 45, 52, 55, 46, 50, 54, 44, 50, 49, 46, 49, 52, 45, 54, 51, 46, 55, 54, 83, 51, 49, 50, 44, 56, 48, 44, 51, 51, 54, 44, 56, 48, 99, 50, 51, 46, 56, 51, 44, 48, 44, 52, 53, 46, 51, 56, 44, 57, 46, 48, 54, 44, 54, 48, 46, 55, 44, 50, 53, 46, 53, 50, 44, 49, 53, 46, 52, 55, 44, 49, 54, 46, 54, 50, 44, 50, 51, 44, 51, 57, 46, 50, 50, 44, 50, 49, 46, 50, 54, 44, 54, 51, 46, 54, 51, 104, 48, 99, 45, 49, 46, 54, 55, 44, 50, 51, 46, 49, 49, 45, 49, 48, 46, 57, 44, 52, 52, 46, 55, 55, 45, 50, 54, 44, 54, 49, 67, 51, 55, 54, 46, 52, 52, 44, 50, 52, 54, 46, 56, 50, 44, 51, 53, 54, 46, 53, 55, 44, 50, 53, 54, 44, 51, 51, 54, 44, 50, 53, 54, 90, 109, 54, 54, 45, 56, 56, 104, 48, 90, 34, 47, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 54, 55, 46, 56, 51, 44, 52, 51, 50, 72, 50, 48, 52, 46, 49, 56, 97, 50, 55, 46, 55, 49, 44, 50, 55, 46, 55, 49, 44, 48, 44, 48, 44, 49, 45, 50, 50, 45, 49, 48, 46, 54, 55, 44, 51, 48, 46, 50, 50, 44, 51, 48, 46, 50, 50, 44, 48, 44, 48, 44, 49, 45, 53, 46, 50, 54, 45, 50, 53, 46, 55, 57, 99, 56, 46, 52, 50, 45, 51, 51, 46, 56, 49, 44, 50, 57, 46, 50, 56, 45, 54, 49, 46, 56, 53, 44, 54, 48, 46, 51, 50, 45, 56, 49, 46, 48, 56, 67, 50, 54, 52, 46, 55, 57, 44, 50, 57, 55, 46, 52, 44, 50, 57, 57, 46, 56, 54, 44, 50, 56, 56, 44, 51, 51, 54, 44, 50, 56, 56, 99, 51, 54, 46, 56, 53, 44, 48, 44, 55, 49, 44, 57, 44, 57, 56, 46, 55, 49, 44, 50, 54, 46, 48, 53, 44, 51, 49, 46, 49, 49, 44, 49, 57, 46, 49, 51, 44, 53, 50, 44, 52, 55, 46, 51, 51, 44, 54, 48, 46, 51, 56, 44, 56, 49, 46, 53, 53, 97, 51, 48, 46, 50, 55, 44, 51, 48, 46, 50, 55, 44, 48, 44, 48, 44, 49, 45, 53, 46, 51, 50, 44, 50, 53, 46, 55, 56, 65, 50, 55, 46, 54, 56, 44, 50, 55, 46, 54, 56, 44, 48, 44, 48, 44, 49, 44, 52, 54, 55, 46, 56, 51, 44, 52, 51, 50, 90, 34, 47, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 49, 52, 55, 44, 50, 54, 48, 99, 45, 51, 53, 46, 49, 57, 44, 48, 45, 54, 54, 46, 49, 51, 45, 51, 50, 46, 55, 50, 45, 54, 57, 45, 55, 50, 46, 57, 51, 67, 55, 54, 46, 53, 56, 44, 49, 54, 54, 46, 52, 55, 44, 56, 51, 44, 49, 52, 55, 46, 52, 50, 44, 57, 54, 44, 49, 51, 51, 46, 52, 53, 44, 49, 48, 56, 46, 56, 54, 44, 49, 49, 57, 46, 54, 50, 44, 49, 50, 55, 44, 49, 49, 50, 44, 49, 52, 55, 44, 49, 49, 50, 115, 51, 56, 44, 55, 46, 54, 54, 44, 53, 48, 46, 57, 51, 44, 50, 49, 46, 53, 55, 99, 49, 51, 46, 49, 44, 49, 52, 46, 48, 56, 44, 49, 57, 46, 53, 44, 51, 51, 46, 48, 57, 44, 49, 56, 44, 53, 51, 46, 53, 50, 67, 50, 49, 51, 46, 48, 54, 44, 50, 50, 55, 46, 50, 57, 44, 49, 56, 50, 46, 49, 51, 44, 50, 54, 48, 44, 49, 52, 55, 44, 50, 54, 48, 90, 34, 47, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 50, 49, 50, 46, 54, 54, 44, 50, 57, 49, 46, 52, 53, 99, 45, 49, 55, 46, 53, 57, 45, 56, 46, 54, 45, 52, 48, 46, 52, 50, 45, 49, 50, 46, 57, 45, 54, 53, 46, 54, 53, 45, 49, 50, 46, 57, 45, 50, 57, 46, 52, 54, 44, 48, 45, 53, 56, 46, 48, 55, 44, 55, 46, 54, 56, 45, 56, 48, 46, 53, 55, 44, 50, 49, 46, 54, 50, 67, 52, 48, 46, 57, 51, 44, 51, 49, 54, 44, 50, 51, 46, 55, 55, 44, 51, 51, 57, 46, 48, 53, 44, 49, 54, 46, 56, 52, 44, 51, 54, 54, 46, 56, 56, 97, 50, 55, 46, 51, 57, 44, 50, 55, 46, 51, 57, 44, 48, 44, 48, 44, 48, 44, 52, 46, 55, 57, 44, 50, 51, 46, 51, 54, 65, 50, 53, 46, 51, 50, 44, 50, 53, 46, 51, 50, 44, 48, 44, 48, 44, 48, 44, 52, 49, 46, 55, 50, 44, 52, 48, 48, 104, 49, 49, 49, 97, 56, 44, 56, 44, 48, 44, 48, 44, 48, 44, 55, 46, 56, 55, 45, 54, 46, 53, 55, 99, 46, 49, 49, 45, 46, 54, 51, 46, 50, 53, 45, 49, 46, 50, 54, 46, 52, 49, 45, 49, 46, 56, 56, 44, 56, 46, 52, 56, 45, 51, 52, 46, 48, 54, 44, 50, 56, 46, 51, 53, 45, 54, 50, 46, 56, 52, 44, 53, 55, 46, 55, 49, 45, 56, 51, 46, 56, 50, 97, 56, 44, 56, 44, 48, 44, 48, 44, 48, 45, 46, 54, 51, 45, 49, 51, 46, 51, 57, 67, 50, 49, 54, 46, 53, 49, 44, 50, 57, 51, 46, 52, 50, 44, 50, 49, 52, 46, 55, 49, 44, 50, 57, 50, 46, 52, 53, 44, 50, 49, 50, 46, 54, 54, 44, 50, 57, 49, 46, 52, 53, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("person"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 112, 101, 114, 115, 111, 110, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 49, 49, 97, 52, 32, 52, 32, 48, 32, 49, 32, 48, 45, 52, 45, 52, 32, 52, 32, 52, 32, 48, 32, 48, 32, 48, 32, 52, 32, 52, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 56, 32, 50, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 45, 49, 32, 55, 32, 55, 32, 48, 32, 48, 32, 48, 45, 49, 52, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 32, 49, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("phone"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 112, 104, 111, 110, 101, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 55, 46, 52, 32, 50, 50, 65, 49, 53, 46, 52, 50, 32, 49, 53, 46, 52, 50, 32, 48, 32, 48, 32, 49, 32, 50, 32, 54, 46, 54, 32, 52, 46, 54, 32, 52, 46, 54, 32, 48, 32, 48, 32, 49, 32, 54, 46, 54, 32, 50, 97, 51, 46, 57, 52, 32, 51, 46, 57, 52, 32, 48, 32, 48, 32, 49, 32, 46, 55, 55, 46, 48, 55, 32, 51, 46, 55, 57, 32, 51, 46, 55, 57, 32, 48, 32, 48, 32, 49, 32, 46, 55, 50, 46, 49, 56, 32, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 46, 54, 53, 46, 55, 53, 108, 49, 46, 51, 55, 32, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 46, 50, 54, 46, 57, 50, 99, 45, 46, 49, 51, 46, 49, 52, 45, 46, 49, 52, 46, 49, 53, 45, 49, 46, 51, 55, 46, 55, 57, 97, 57, 46, 57, 49, 32, 57, 46, 57, 49, 32, 48, 32, 48, 32, 48, 32, 52, 46, 56, 55, 32, 52, 46, 56, 57, 99, 46, 54, 53, 45, 49, 46, 50, 52, 46, 54, 54, 45, 49, 46, 50, 53, 46, 56, 45, 49, 46, 51, 56, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 46, 57, 50, 45, 46, 50, 54, 108, 54, 32, 49, 46, 51, 55, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 46, 55, 50, 46, 54, 53, 32, 52, 46, 51, 52, 32, 52, 46, 51, 52, 32, 48, 32, 48, 32, 49, 32, 46, 49, 57, 46, 55, 51, 32, 52, 46, 55, 55, 32, 52, 46, 55, 55, 32, 48, 32, 48, 32, 49, 32, 46, 48, 54, 46, 55, 54, 65, 52, 46, 54, 32, 52, 46, 54, 32, 48, 32, 48, 32, 49, 32, 49, 55, 46, 52, 32, 50, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("pin"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 112, 105, 110, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 99, 105, 114, 99, 108, 101, 32, 99, 120, 61, 34, 49, 50, 34, 32, 99, 121, 61, 34, 57, 46, 53, 34, 32, 114, 61, 34, 49, 46, 53, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 50, 97, 56, 32, 56, 32, 48, 32, 48, 32, 48, 45, 56, 32, 55, 46, 57, 50, 99, 48, 32, 53, 46, 52, 56, 32, 55, 46, 48, 53, 32, 49, 49, 46, 53, 56, 32, 55, 46, 51, 53, 32, 49, 49, 46, 56, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 51, 32, 48, 67, 49, 51, 32, 50, 49, 46, 53, 32, 50, 48, 32, 49, 53, 46, 52, 32, 50, 48, 32, 57, 46, 57, 50, 65, 56, 32, 56, 32, 48, 32, 48, 32, 48, 32, 49, 50, 32, 50, 122, 109, 48, 32, 49, 49, 97, 51, 46, 53, 32, 51, 46, 53, 32, 48, 32, 49, 32, 49, 32, 51, 46, 53, 45, 51, 46, 53, 65, 51, 46, 53, 32, 51, 46, 53, 32, 48, 32, 48, 32, 49, 32, 49, 50, 32, 49, 51, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("power"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 112, 111, 119, 101, 114, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 49, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 45, 49, 86, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 50, 32, 48, 118, 49, 48, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 32, 49, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 54, 46, 53, 57, 32, 51, 46, 49, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 57, 50, 32, 49, 46, 55, 56, 32, 56, 32, 56, 32, 48, 32, 49, 32, 49, 45, 55, 46, 51, 52, 32, 48, 32, 49, 32, 49, 32, 48, 32, 49, 32, 48, 45, 46, 57, 50, 45, 49, 46, 55, 56, 32, 49, 48, 32, 49, 48, 32, 48, 32, 49, 32, 48, 32, 57, 46, 49, 56, 32, 48, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("python"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 49, 50, 56, 32, 49, 50, 56, 34, 32, 61441, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 57, 46, 51, 51, 32, 54, 50, 104, 50, 57, 46, 49, 53, 57, 67, 56, 54, 46, 54, 48, 54, 32, 54, 50, 32, 57, 51, 32, 53, 53, 46, 49, 51, 50, 32, 57, 51, 32, 52, 54, 46, 57, 56, 49, 86, 49, 57, 46, 49, 56, 51, 99, 48, 45, 55, 46, 57, 49, 50, 45, 54, 46, 54, 51, 50, 45, 49, 51, 46, 56, 53, 54, 45, 49, 52, 46, 53, 53, 53, 45, 49, 53, 46, 49, 55, 54, 45, 53, 46, 48, 49, 52, 45, 46, 56, 51, 53, 45, 49, 48, 46, 49, 57, 53, 45, 49, 46, 50, 49, 53, 45, 49, 53, 46, 49, 56, 55, 45, 49, 46, 49, 57, 49, 45, 52, 46, 57, 57, 46, 48, 50, 51, 45, 57, 46, 54, 49, 50, 46, 52, 52, 56, 45, 49, 51, 46, 56, 48, 53, 32, 49, 46, 49, 57, 49, 67, 51, 55, 46, 48, 57, 56, 32, 54, 46, 49, 56, 56, 32, 51, 53, 32, 49, 48, 46, 55, 53, 56, 32, 51, 53, 32, 49, 57, 46, 49, 56, 51, 86, 51, 48, 104, 50, 57, 118, 52, 72, 50, 51, 46, 55, 55, 54, 99, 45, 56, 46, 52, 56, 52, 32, 48, 45, 49, 53, 46, 57, 49, 52, 32, 53, 46, 49, 48, 56, 45, 49, 56, 46, 50, 51, 55, 32, 49, 52, 46, 56, 49, 49, 45, 50, 46, 54, 56, 49, 32, 49, 49, 46, 49, 50, 45, 50, 46, 56, 32, 49, 55, 46, 57, 49, 57, 32, 48, 32, 50, 57, 46, 53, 51, 67, 55, 46, 54, 49, 52, 32, 56, 54, 46, 57, 56, 51, 32, 49, 50, 46, 53, 54, 57, 32, 57, 51, 32, 50, 49, 46, 48, 53, 52, 32, 57, 51, 72, 51, 49, 86, 55, 57, 46, 57, 53, 50, 67, 51, 49, 32, 55, 48, 46, 51, 49, 53, 32, 51, 57, 46, 52, 50, 56, 32, 54, 50, 32, 52, 57, 46, 51, 51, 32, 54, 50, 122, 109, 45, 49, 46, 56, 51, 56, 45, 51, 57, 46, 49, 49, 99, 45, 51, 46, 48, 50, 54, 32, 48, 45, 53, 46, 52, 55, 56, 45, 50, 46, 52, 55, 57, 45, 53, 46, 52, 55, 56, 45, 53, 46, 53, 52, 53, 32, 48, 45, 51, 46, 48, 55, 57, 32, 50, 46, 52, 53, 49, 45, 53, 46, 53, 56, 49, 32, 53, 46, 52, 55, 56, 45, 53, 46, 53, 56, 49, 32, 51, 46, 48, 49, 53, 32, 48, 32, 53, 46, 52, 55, 57, 32, 50, 46, 53, 48, 50, 32, 53, 46, 52, 55, 57, 32, 53, 46, 53, 56, 49, 45, 46, 48, 48, 49, 32, 51, 46, 48, 54, 54, 45, 50, 46, 52, 54, 53, 32, 53, 46, 53, 52, 53, 45, 53, 46, 52, 55, 57, 32, 53, 46, 53, 52, 53, 122, 109, 55, 52, 46, 55, 56, 57, 32, 50, 53, 46, 57, 50, 49, 67, 49, 50, 48, 46, 49, 56, 51, 32, 52, 48, 46, 51, 54, 51, 32, 49, 49, 54, 46, 49, 55, 56, 32, 51, 52, 32, 49, 48, 55, 46, 54, 56, 50, 32, 51, 52, 72, 57, 55, 118, 49, 50, 46, 57, 56, 49, 67, 57, 55, 32, 53, 55, 46, 48, 51, 49, 32, 56, 56, 46, 50, 48, 54, 32, 54, 53, 32, 55, 56, 46, 52, 56, 57, 32, 54, 53, 72, 52, 57, 46, 51, 51, 67, 52, 49, 46, 51, 52, 50, 32, 54, 53, 32, 51, 53, 32, 55, 50, 46, 51, 50, 54, 32, 51, 53, 32, 56, 48, 46, 51, 50, 54, 118, 50, 55, 46, 56, 99, 48, 32, 55, 46, 57, 49, 32, 54, 46, 55, 52, 53, 32, 49, 50, 46, 53, 54, 52, 32, 49, 52, 46, 52, 54, 50, 32, 49, 52, 46, 56, 51, 52, 32, 57, 46, 50, 52, 50, 32, 50, 46, 55, 49, 55, 32, 49, 55, 46, 57, 57, 52, 32, 51, 46, 50, 48, 56, 32, 50, 57, 46, 48, 53, 49, 32, 48, 67, 56, 53, 46, 56, 54, 50, 32, 49, 50, 48, 46, 56, 51, 49, 32, 57, 51, 32, 49, 49, 54, 46, 53, 52, 57, 32, 57, 51, 32, 49, 48, 56, 46, 49, 50, 54, 86, 57, 55, 72, 54, 52, 118, 45, 52, 104, 52, 51, 46, 54, 56, 50, 99, 56, 46, 52, 56, 52, 32, 48, 32, 49, 49, 46, 54, 52, 55, 45, 53, 46, 55, 55, 54, 32, 49, 52, 46, 53, 57, 57, 45, 49, 52, 46, 54, 54, 32, 51, 46, 48, 52, 55, 45, 57, 46, 49, 52, 53, 32, 50, 46, 57, 49, 54, 45, 49, 55, 46, 55, 57, 57, 32, 48, 45, 50, 57, 46, 53, 50, 57, 122, 109, 45, 52, 49, 46, 57, 53, 53, 32, 53, 53, 46, 54, 48, 54, 99, 51, 46, 48, 50, 55, 32, 48, 32, 53, 46, 52, 55, 57, 32, 50, 46, 52, 55, 57, 32, 53, 46, 52, 55, 57, 32, 53, 46, 53, 52, 55, 32, 48, 32, 51, 46, 48, 55, 54, 45, 50, 46, 52, 53, 49, 32, 53, 46, 53, 55, 57, 45, 53, 46, 52, 55, 57, 32, 53, 46, 53, 55, 57, 45, 51, 46, 48, 49, 53, 32, 48, 45, 53, 46, 52, 55, 56, 45, 50, 46, 53, 48, 50, 45, 53, 46, 52, 55, 56, 45, 53, 46, 53, 55, 57, 32, 48, 45, 51, 46, 48, 54, 56, 32, 50, 46, 52, 54, 51, 45, 53, 46, 53, 52, 55, 32, 53, 46, 52, 55, 56, 45, 53, 46, 53, 52, 55, 122, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("question"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 50, 53, 54, 44, 54, 52, 67, 49, 53, 48, 44, 54, 52, 44, 54, 52, 44, 49, 53, 48, 44, 54, 52, 44, 50, 53, 54, 115, 56, 54, 44, 49, 57, 50, 44, 49, 57, 50, 44, 49, 57, 50, 44, 49, 57, 50, 45, 56, 54, 44, 49, 57, 50, 45, 49, 57, 50, 83, 51, 54, 50, 44, 54, 52, 44, 50, 53, 54, 44, 54, 52, 90, 109, 45, 54, 44, 51, 48, 52, 97, 50, 48, 44, 50, 48, 44, 48, 44, 49, 44, 49, 44, 50, 48, 45, 50, 48, 65, 50, 48, 44, 50, 48, 44, 48, 44, 48, 44, 49, 44, 50, 53, 48, 44, 51, 54, 56, 90, 109, 51, 51, 46, 52, 52, 45, 49, 48, 50, 67, 50, 54, 55, 46, 50, 51, 44, 50, 55, 54, 46, 56, 56, 44, 50, 54, 53, 44, 50, 56, 54, 46, 56, 53, 44, 50, 54, 53, 44, 50, 57, 54, 97, 49, 52, 44, 49, 52, 44, 48, 44, 48, 44, 49, 45, 50, 56, 44, 48, 99, 48, 45, 50, 49, 46, 57, 49, 44, 49, 48, 46, 48, 56, 45, 51, 57, 46, 51, 51, 44, 51, 48, 46, 56, 50, 45, 53, 51, 46, 50, 54, 67, 50, 56, 55, 46, 49, 44, 50, 50, 57, 46, 56, 44, 50, 57, 56, 44, 50, 50, 49, 46, 54, 44, 50, 57, 56, 44, 50, 48, 51, 46, 53, 55, 99, 48, 45, 49, 50, 46, 50, 54, 45, 55, 45, 50, 49, 46, 53, 55, 45, 50, 49, 46, 52, 57, 45, 50, 56, 46, 52, 54, 45, 51, 46, 52, 49, 45, 49, 46, 54, 50, 45, 49, 49, 45, 51, 46, 50, 45, 50, 48, 46, 51, 52, 45, 51, 46, 48, 57, 45, 49, 49, 46, 55, 50, 46, 49, 53, 45, 50, 48, 46, 56, 50, 44, 50, 46, 57, 53, 45, 50, 55, 46, 56, 51, 44, 56, 46, 53, 57, 67, 50, 49, 53, 46, 49, 50, 44, 49, 57, 49, 46, 50, 53, 44, 50, 49, 52, 44, 50, 48, 50, 46, 56, 51, 44, 50, 49, 52, 44, 50, 48, 51, 97, 49, 52, 44, 49, 52, 44, 48, 44, 49, 44, 49, 45, 50, 56, 45, 49, 46, 51, 53, 99, 46, 49, 49, 45, 50, 46, 52, 51, 44, 49, 46, 56, 45, 50, 52, 46, 51, 50, 44, 50, 52, 46, 55, 55, 45, 52, 50, 46, 56, 44, 49, 49, 46, 57, 49, 45, 57, 46, 53, 56, 44, 50, 55, 46, 48, 54, 45, 49, 52, 46, 53, 54, 44, 52, 53, 45, 49, 52, 46, 55, 56, 44, 49, 50, 46, 55, 45, 46, 49, 53, 44, 50, 52, 46, 54, 51, 44, 50, 44, 51, 50, 46, 55, 50, 44, 53, 46, 56, 50, 67, 51, 49, 50, 46, 55, 44, 49, 54, 49, 46, 51, 52, 44, 51, 50, 54, 44, 49, 56, 48, 46, 52, 51, 44, 51, 50, 54, 44, 50, 48, 51, 46, 53, 55, 44, 51, 50, 54, 44, 50, 51, 55, 46, 52, 44, 51, 48, 51, 46, 51, 57, 44, 50, 53, 50, 46, 53, 57, 44, 50, 56, 51, 46, 52, 52, 44, 50, 54, 54, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("refresh"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 114, 101, 102, 114, 101, 115, 104, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 48, 46, 51, 32, 49, 51, 46, 52, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 50, 53, 46, 54, 53, 65, 55, 46, 49, 52, 32, 55, 46, 49, 52, 32, 48, 32, 48, 32, 49, 32, 49, 50, 46, 49, 56, 32, 49, 57, 32, 55, 46, 49, 32, 55, 46, 49, 32, 48, 32, 48, 32, 49, 32, 53, 32, 49, 50, 97, 55, 46, 49, 32, 55, 46, 49, 32, 48, 32, 48, 32, 49, 32, 55, 46, 49, 56, 45, 55, 32, 55, 46, 50, 54, 32, 55, 46, 50, 54, 32, 48, 32, 48, 32, 49, 32, 52, 46, 54, 53, 32, 49, 46, 54, 55, 108, 45, 50, 46, 49, 55, 45, 46, 51, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 49, 53, 46, 56, 51, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 56, 51, 32, 49, 46, 49, 53, 108, 52, 46, 50, 52, 46, 55, 104, 46, 49, 55, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 51, 52, 45, 46, 48, 54, 46, 51, 51, 46, 51, 51, 32, 48, 32, 48, 32, 48, 32, 46, 49, 45, 46, 48, 54, 46, 55, 56, 46, 55, 56, 32, 48, 32, 48, 32, 48, 32, 46, 50, 45, 46, 49, 49, 108, 46, 48, 57, 45, 46, 49, 49, 99, 48, 45, 46, 48, 53, 46, 48, 57, 45, 46, 48, 57, 46, 49, 51, 45, 46, 49, 53, 115, 48, 45, 46, 49, 46, 48, 53, 45, 46, 49, 52, 97, 49, 46, 51, 52, 32, 49, 46, 51, 52, 32, 48, 32, 48, 32, 48, 32, 46, 48, 55, 45, 46, 49, 56, 108, 46, 55, 53, 45, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 50, 45, 46, 51, 56, 108, 45, 46, 50, 55, 32, 49, 46, 52, 53, 65, 57, 46, 50, 49, 32, 57, 46, 50, 49, 32, 48, 32, 48, 32, 48, 32, 49, 50, 46, 49, 56, 32, 51, 32, 57, 46, 49, 32, 57, 46, 49, 32, 48, 32, 48, 32, 48, 32, 51, 32, 49, 50, 97, 57, 46, 49, 32, 57, 46, 49, 32, 48, 32, 48, 32, 48, 32, 57, 46, 49, 56, 32, 57, 65, 57, 46, 49, 50, 32, 57, 46, 49, 50, 32, 48, 32, 48, 32, 48, 32, 50, 49, 32, 49, 52, 46, 54, 56, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 55, 45, 49, 46, 50, 53, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("rust"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 98, 111, 120, 61, 34, 48, 32, 48, 32, 49, 50, 56, 32, 49, 50, 56, 34, 32, 61441, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 54, 50, 46, 50, 55, 49, 32, 49, 48, 46, 56, 56, 99, 45, 46, 49, 56, 57, 46, 49, 49, 45, 46, 57, 56, 50, 32, 49, 46, 50, 52, 56, 45, 49, 46, 55, 54, 51, 32, 50, 46, 53, 50, 57, 45, 49, 46, 57, 54, 32, 51, 46, 50, 49, 55, 45, 49, 46, 57, 56, 50, 32, 51, 46, 50, 49, 57, 45, 52, 46, 54, 49, 53, 46, 52, 52, 56, 45, 49, 46, 55, 49, 51, 45, 49, 46, 56, 48, 50, 45, 50, 46, 49, 50, 55, 45, 50, 46, 49, 51, 50, 45, 50, 46, 54, 55, 57, 45, 50, 46, 49, 50, 56, 45, 46, 51, 53, 57, 46, 48, 48, 50, 45, 46, 56, 49, 50, 46, 49, 50, 52, 45, 49, 46, 48, 48, 56, 46, 50, 55, 49, 45, 46, 49, 57, 53, 46, 49, 52, 55, 45, 46, 55, 52, 56, 32, 49, 46, 51, 49, 55, 45, 49, 46, 50, 50, 56, 32, 50, 46, 54, 45, 49, 46, 48, 57, 57, 32, 50, 46, 57, 51, 57, 45, 49, 46, 49, 53, 50, 32, 51, 46, 48, 51, 52, 45, 49, 46, 55, 54, 49, 32, 51, 46, 49, 53, 49, 45, 46, 51, 55, 53, 46, 48, 55, 49, 45, 49, 46, 48, 57, 55, 45, 46, 51, 51, 49, 45, 50, 46, 56, 50, 56, 45, 49, 46, 53, 55, 52, 45, 49, 46, 50, 55, 56, 45, 46, 57, 49, 57, 45, 50, 46, 53, 51, 50, 45, 49, 46, 54, 55, 45, 50, 46, 55, 56, 54, 45, 49, 46, 54, 55, 45, 49, 46, 48, 53, 52, 32, 48, 45, 49, 46, 51, 53, 49, 46, 53, 55, 54, 45, 49, 46, 56, 53, 51, 32, 51, 46, 53, 57, 51, 45, 46, 54, 51, 56, 32, 51, 46, 56, 51, 54, 45, 46, 54, 49, 54, 32, 51, 46, 56, 50, 51, 45, 52, 46, 48, 55, 52, 32, 50, 46, 50, 53, 50, 45, 49, 46, 51, 57, 54, 45, 46, 54, 51, 51, 45, 50, 46, 55, 50, 45, 49, 46, 49, 53, 50, 45, 50, 46, 57, 52, 51, 45, 49, 46, 49, 53, 50, 45, 46, 50, 50, 51, 32, 48, 45, 46, 54, 52, 54, 46, 50, 52, 45, 46, 57, 51, 57, 46, 53, 51, 51, 45, 46, 53, 51, 50, 46, 53, 51, 51, 45, 46, 53, 51, 51, 46, 53, 51, 53, 45, 46, 51, 56, 56, 32, 51, 46, 52, 54, 56, 108, 46, 49, 52, 54, 32, 50, 46, 57, 51, 54, 45, 46, 53, 53, 53, 46, 50, 57, 55, 99, 45, 46, 52, 57, 50, 46, 50, 54, 51, 45, 46, 56, 51, 49, 46, 50, 51, 49, 45, 51, 46, 48, 48, 57, 45, 46, 50, 56, 52, 45, 50, 46, 56, 52, 51, 45, 46, 54, 55, 49, 45, 51, 46, 52, 52, 51, 45, 46, 54, 53, 51, 45, 52, 46, 48, 49, 57, 46, 49, 50, 50, 108, 45, 46, 52, 50, 49, 46, 53, 54, 54, 46, 53, 54, 53, 32, 50, 46, 52, 50, 49, 99, 46, 51, 49, 32, 49, 46, 51, 51, 49, 46, 54, 48, 57, 32, 50, 46, 54, 49, 51, 46, 54, 54, 53, 32, 50, 46, 56, 52, 56, 46, 48, 53, 53, 46, 50, 51, 52, 45, 46, 48, 52, 46, 54, 48, 57, 45, 46, 50, 49, 50, 46, 56, 51, 50, 45, 46, 50, 56, 52, 46, 51, 54, 55, 45, 46, 53, 56, 54, 46, 52, 45, 51, 46, 50, 49, 55, 46, 51, 54, 45, 52, 46, 52, 53, 51, 45, 46, 48, 55, 45, 52, 46, 55, 48, 54, 46, 51, 49, 50, 45, 50, 46, 56, 54, 54, 32, 52, 46, 51, 50, 56, 46, 53, 56, 53, 32, 49, 46, 50, 55, 53, 32, 49, 46, 48, 54, 52, 32, 50, 46, 52, 51, 51, 32, 49, 46, 48, 54, 52, 32, 50, 46, 53, 55, 50, 32, 48, 32, 46, 55, 51, 52, 45, 46, 53, 56, 53, 32, 49, 46, 48, 48, 49, 45, 51, 46, 48, 57, 56, 32, 49, 46, 52, 49, 49, 45, 49, 46, 52, 48, 54, 46, 50, 50, 57, 45, 50, 46, 54, 50, 56, 46, 52, 49, 55, 45, 50, 46, 55, 49, 54, 46, 52, 49, 55, 45, 46, 48, 56, 56, 32, 48, 45, 46, 51, 53, 50, 46, 49, 57, 50, 45, 46, 53, 56, 54, 46, 52, 50, 54, 45, 46, 55, 54, 53, 46, 55, 54, 53, 45, 46, 53, 52, 56, 32, 49, 46, 52, 56, 51, 32, 49, 46, 49, 56, 55, 32, 51, 46, 57, 51, 50, 32, 50, 46, 49, 54, 49, 32, 51, 46, 48, 53, 32, 50, 46, 49, 53, 55, 32, 51, 46, 48, 54, 49, 45, 49, 46, 52, 49, 51, 32, 52, 46, 52, 50, 55, 45, 52, 46, 48, 54, 32, 49, 46, 53, 53, 51, 45, 52, 46, 49, 52, 50, 32, 49, 46, 57, 51, 54, 45, 49, 46, 48, 53, 49, 32, 52, 46, 56, 54, 56, 32, 50, 46, 56, 55, 57, 32, 50, 46, 55, 51, 32, 50, 46, 56, 56, 50, 32, 50, 46, 54, 57, 45, 46, 51, 55, 55, 32, 52, 46, 55, 51, 57, 45, 50, 46, 52, 54, 57, 32, 49, 46, 53, 53, 49, 45, 50, 46, 53, 48, 55, 32, 49, 46, 53, 56, 56, 45, 50, 46, 53, 55, 32, 50, 46, 52, 50, 57, 45, 46, 48, 55, 54, 32, 49, 46, 48, 50, 51, 45, 46, 48, 53, 56, 32, 49, 46, 48, 52, 49, 32, 50, 46, 56, 57, 32, 50, 46, 56, 52, 50, 32, 50, 46, 57, 49, 53, 32, 49, 46, 55, 56, 32, 50, 46, 57, 49, 53, 32, 49, 46, 56, 51, 52, 46, 48, 53, 52, 32, 52, 46, 53, 52, 49, 45, 51, 46, 48, 55, 55, 32, 50, 46, 57, 49, 45, 50, 46, 57, 56, 50, 32, 51, 46, 51, 51, 53, 32, 49, 46, 48, 56, 49, 32, 52, 46, 56, 54, 56, 32, 51, 46, 53, 53, 32, 49, 46, 51, 51, 57, 32, 51, 46, 53, 53, 53, 32, 49, 46, 51, 53, 53, 32, 49, 46, 51, 57, 32, 52, 46, 52, 48, 53, 45, 49, 46, 50, 50, 55, 32, 49, 46, 55, 50, 57, 45, 49, 46, 54, 49, 56, 32, 50, 46, 52, 52, 57, 45, 49, 46, 54, 49, 56, 32, 50, 46, 57, 56, 51, 32, 48, 32, 46, 57, 57, 57, 46, 53, 50, 32, 49, 46, 50, 53, 52, 32, 51, 46, 54, 50, 55, 32, 49, 46, 55, 55, 54, 32, 50, 46, 54, 49, 55, 46, 52, 52, 49, 32, 51, 46, 50, 46, 55, 32, 51, 46, 50, 32, 49, 46, 52, 50, 50, 32, 48, 32, 46, 49, 52, 56, 45, 46, 52, 56, 32, 49, 46, 51, 49, 54, 45, 49, 46, 48, 54, 55, 32, 50, 46, 53, 57, 52, 45, 49, 46, 56, 50, 54, 32, 51, 46, 57, 55, 55, 45, 49, 46, 54, 49, 56, 32, 52, 46, 51, 48, 56, 32, 50, 46, 55, 48, 52, 32, 52, 46, 51, 48, 56, 32, 52, 46, 48, 50, 53, 32, 48, 32, 51, 46, 57, 49, 56, 45, 46, 49, 50, 51, 32, 51, 46, 48, 53, 49, 32, 51, 46, 53, 48, 55, 45, 46, 54, 53, 52, 32, 50, 46, 55, 51, 54, 45, 46, 54, 54, 52, 32, 51, 46, 50, 54, 45, 46, 48, 55, 50, 32, 51, 46, 56, 53, 49, 46, 52, 53, 51, 46, 52, 53, 52, 32, 49, 46, 51, 48, 55, 46, 52, 48, 51, 32, 51, 46, 57, 55, 56, 45, 46, 50, 51, 54, 32, 50, 46, 48, 52, 45, 46, 52, 56, 55, 32, 50, 46, 51, 57, 56, 45, 46, 53, 50, 49, 32, 50, 46, 56, 55, 49, 45, 46, 50, 54, 56, 108, 46, 53, 52, 46, 50, 56, 57, 45, 46, 49, 52, 54, 32, 50, 46, 57, 51, 53, 99, 45, 46, 49, 52, 53, 32, 50, 46, 57, 51, 52, 45, 46, 49, 52, 52, 32, 50, 46, 57, 51, 54, 46, 51, 56, 56, 32, 51, 46, 52, 54, 57, 46, 50, 57, 51, 46, 50, 57, 51, 46, 55, 50, 50, 46, 53, 51, 51, 46, 57, 53, 50, 46, 53, 51, 51, 46, 50, 51, 32, 48, 32, 49, 46, 53, 53, 52, 45, 46, 53, 49, 54, 32, 50, 46, 57, 52, 51, 45, 49, 46, 49, 52, 55, 32, 51, 46, 52, 52, 55, 45, 49, 46, 53, 54, 53, 32, 51, 46, 52, 50, 53, 45, 49, 46, 53, 55, 56, 32, 52, 46, 48, 54, 49, 32, 50, 46, 50, 52, 54, 46, 53, 48, 52, 32, 51, 46, 48, 51, 49, 46, 55, 57, 56, 32, 51, 46, 53, 57, 52, 32, 49, 46, 56, 55, 52, 32, 51, 46, 53, 57, 52, 46, 50, 54, 55, 32, 48, 32, 49, 46, 52, 57, 52, 45, 46, 55, 50, 32, 50, 46, 55, 50, 56, 45, 49, 46, 54, 32, 50, 46, 49, 54, 55, 45, 49, 46, 53, 52, 54, 32, 50, 46, 55, 50, 57, 45, 49, 46, 55, 56, 56, 32, 51, 46, 51, 48, 54, 45, 49, 46, 52, 50, 49, 46, 49, 52, 57, 46, 48, 57, 52, 46, 55, 50, 55, 32, 49, 46, 51, 54, 52, 32, 49, 46, 50, 56, 52, 32, 50, 46, 56, 50, 50, 46, 56, 49, 57, 32, 50, 46, 49, 52, 52, 32, 49, 46, 49, 49, 57, 32, 50, 46, 55, 48, 50, 32, 49, 46, 53, 55, 53, 32, 50, 46, 57, 50, 46, 56, 54, 56, 46, 52, 49, 54, 32, 49, 46, 52, 48, 53, 46, 48, 56, 50, 32, 51, 46, 52, 52, 53, 45, 50, 46, 49, 52, 32, 50, 46, 52, 54, 51, 45, 50, 46, 54, 56, 51, 32, 50, 46, 53, 54, 52, 45, 50, 46, 54, 55, 32, 52, 46, 53, 55, 53, 46, 53, 56, 57, 32, 50, 46, 50, 50, 49, 32, 51, 46, 53, 57, 56, 32, 50, 46, 55, 57, 54, 32, 51, 46, 53, 57, 32, 53, 46, 48, 55, 51, 45, 46, 48, 55, 51, 32, 49, 46, 57, 54, 50, 45, 51, 46, 49, 53, 54, 32, 49, 46, 57, 51, 57, 45, 51, 46, 49, 53, 52, 32, 52, 46, 53, 57, 49, 45, 46, 51, 56, 52, 32, 49, 46, 55, 54, 49, 32, 49, 46, 56, 51, 56, 32, 50, 46, 49, 51, 54, 32, 50, 46, 49, 51, 49, 32, 50, 46, 55, 51, 32, 50, 46, 49, 51, 49, 46, 51, 55, 57, 32, 48, 32, 46, 56, 51, 50, 45, 46, 49, 52, 50, 32, 49, 46, 48, 48, 53, 45, 46, 51, 49, 54, 46, 49, 55, 52, 45, 46, 49, 55, 52, 46, 55, 53, 45, 49, 46, 52, 53, 57, 32, 49, 46, 50, 56, 45, 50, 46, 56, 53, 53, 46, 53, 51, 45, 49, 46, 51, 57, 55, 32, 49, 46, 48, 55, 57, 45, 50, 46, 54, 49, 51, 32, 49, 46, 50, 50, 49, 45, 50, 46, 55, 48, 51, 46, 53, 54, 49, 45, 46, 51, 53, 55, 32, 49, 46, 49, 52, 50, 45, 46, 49, 48, 54, 32, 51, 46, 51, 48, 54, 32, 49, 46, 52, 51, 32, 49, 46, 50, 55, 52, 46, 57, 48, 53, 32, 50, 46, 52, 55, 51, 32, 49, 46, 54, 32, 50, 46, 55, 53, 56, 32, 49, 46, 54, 32, 49, 46, 48, 53, 56, 32, 48, 32, 49, 46, 52, 52, 45, 46, 55, 53, 49, 32, 49, 46, 56, 56, 45, 51, 46, 55, 48, 51, 46, 51, 55, 54, 45, 50, 46, 53, 49, 55, 46, 52, 53, 50, 45, 50, 46, 55, 53, 56, 46, 57, 52, 55, 45, 51, 46, 48, 48, 57, 46, 52, 56, 55, 45, 46, 50, 52, 55, 46, 55, 55, 57, 45, 46, 49, 54, 52, 32, 51, 46, 48, 54, 51, 46, 56, 55, 51, 32, 49, 46, 51, 56, 57, 46, 54, 51, 32, 50, 46, 55, 49, 51, 32, 49, 46, 49, 52, 54, 32, 50, 46, 57, 52, 51, 32, 49, 46, 49, 52, 54, 46, 50, 51, 32, 48, 32, 46, 54, 54, 54, 45, 46, 50, 52, 55, 46, 57, 54, 55, 45, 46, 53, 52, 57, 108, 46, 53, 52, 57, 45, 46, 53, 52, 56, 45, 46, 49, 53, 49, 45, 50, 46, 56, 49, 53, 99, 45, 46, 49, 52, 52, 45, 50, 46, 54, 56, 56, 45, 46, 49, 51, 49, 45, 50, 46, 56, 51, 50, 46, 50, 57, 56, 45, 51, 46, 50, 50, 46, 52, 52, 49, 45, 46, 51, 57, 57, 46, 52, 56, 54, 45, 46, 51, 57, 55, 32, 50, 46, 57, 53, 50, 46, 49, 54, 54, 32, 50, 46, 57, 56, 54, 46, 54, 56, 50, 32, 51, 46, 53, 52, 51, 46, 55, 32, 52, 46, 49, 48, 52, 46, 49, 51, 57, 46, 53, 52, 56, 45, 46, 53, 52, 56, 46, 53, 52, 50, 45, 46, 54, 54, 56, 45, 46, 50, 48, 56, 45, 51, 46, 56, 51, 49, 45, 46, 56, 52, 49, 45, 51, 46, 53, 52, 56, 45, 46, 57, 53, 52, 45, 51, 46, 52, 50, 50, 32, 51, 46, 48, 56, 56, 45, 51, 46, 52, 50, 50, 32, 50, 46, 55, 53, 53, 32, 48, 32, 51, 46, 48, 54, 50, 45, 46, 48, 51, 57, 32, 51, 46, 52, 49, 51, 45, 46, 52, 50, 54, 46, 53, 56, 54, 45, 46, 54, 52, 56, 46, 52, 52, 55, 45, 49, 46, 51, 57, 45, 46, 55, 51, 50, 45, 51, 46, 57, 48, 51, 45, 46, 53, 57, 53, 45, 49, 46, 50, 54, 54, 45, 49, 46, 48, 55, 56, 45, 50, 46, 52, 49, 56, 45, 49, 46, 48, 55, 52, 45, 50, 46, 53, 54, 46, 48, 50, 45, 46, 55, 52, 55, 46, 54, 48, 55, 45, 49, 46, 48, 48, 50, 32, 51, 46, 51, 50, 45, 49, 46, 52, 52, 51, 32, 49, 46, 54, 54, 45, 46, 50, 54, 57, 32, 50, 46, 57, 48, 50, 45, 46, 53, 56, 49, 32, 51, 46, 49, 50, 55, 45, 46, 55, 56, 52, 46, 55, 53, 52, 45, 46, 54, 56, 49, 46, 52, 55, 55, 45, 49, 46, 53, 54, 55, 45, 49, 46, 50, 52, 52, 45, 51, 46, 57, 56, 45, 50, 46, 49, 53, 55, 45, 51, 46, 48, 50, 52, 45, 50, 46, 49, 52, 56, 45, 51, 46, 48, 53, 51, 32, 49, 46, 51, 48, 54, 45, 52, 46, 51, 50, 54, 32, 52, 46, 49, 51, 54, 45, 49, 46, 53, 50, 52, 32, 52, 46, 50, 53, 52, 45, 50, 46, 48, 51, 50, 32, 49, 46, 49, 53, 57, 45, 52, 46, 57, 55, 51, 45, 50, 46, 56, 54, 55, 45, 50, 46, 55, 50, 52, 45, 50, 46, 56, 54, 56, 45, 50, 46, 55, 48, 57, 46, 50, 55, 50, 45, 52, 46, 54, 51, 55, 32, 51, 46, 55, 57, 54, 45, 50, 46, 51, 51, 32, 51, 46, 56, 48, 50, 45, 50, 46, 56, 53, 53, 46, 48, 54, 55, 45, 53, 46, 49, 55, 51, 45, 51, 46, 50, 49, 50, 45, 49, 46, 57, 57, 51, 45, 51, 46, 50, 49, 45, 49, 46, 57, 54, 53, 45, 46, 51, 51, 49, 45, 52, 46, 54, 57, 57, 32, 51, 46, 48, 56, 56, 45, 50, 46, 57, 51, 52, 32, 51, 46, 48, 48, 52, 45, 51, 46, 51, 49, 56, 45, 49, 46, 48, 53, 55, 45, 52, 46, 56, 55, 49, 45, 51, 46, 53, 56, 52, 45, 49, 46, 51, 55, 49, 45, 51, 46, 53, 57, 53, 45, 49, 46, 52, 48, 53, 45, 49, 46, 52, 49, 55, 45, 52, 46, 51, 57, 52, 32, 49, 46, 50, 57, 55, 45, 49, 46, 55, 56, 32, 49, 46, 54, 49, 56, 45, 50, 46, 51, 55, 49, 32, 49, 46, 54, 49, 56, 45, 50, 46, 57, 56, 49, 32, 48, 45, 49, 46, 48, 54, 54, 45, 46, 52, 55, 56, 45, 49, 46, 51, 48, 53, 45, 51, 46, 54, 50, 50, 45, 49, 46, 56, 49, 51, 45, 50, 46, 54, 50, 55, 45, 46, 52, 50, 52, 45, 51, 46, 50, 48, 53, 45, 46, 54, 56, 50, 45, 51, 46, 50, 48, 53, 45, 49, 46, 52, 50, 57, 32, 48, 45, 46, 49, 52, 50, 46, 52, 56, 45, 49, 46, 50, 56, 53, 32, 49, 46, 48, 54, 55, 45, 50, 46, 53, 52, 50, 32, 49, 46, 49, 52, 57, 45, 50, 46, 52, 54, 49, 32, 49, 46, 51, 49, 45, 51, 46, 52, 52, 54, 46, 54, 54, 45, 52, 46, 48, 51, 53, 45, 46, 51, 52, 57, 45, 46, 51, 49, 54, 45, 46, 56, 49, 55, 45, 46, 51, 54, 49, 45, 51, 46, 51, 50, 49, 45, 46, 51, 50, 45, 50, 46, 54, 50, 46, 48, 52, 52, 45, 50, 46, 57, 53, 53, 46, 48, 48, 55, 45, 51, 46, 51, 49, 56, 45, 46, 51, 53, 56, 45, 46, 51, 57, 55, 45, 46, 51, 57, 57, 45, 46, 51, 57, 51, 45, 46, 52, 53, 53, 46, 50, 50, 55, 45, 51, 46, 48, 52, 50, 46, 55, 54, 45, 51, 46, 49, 55, 46, 55, 54, 51, 45, 51, 46, 50, 52, 55, 46, 49, 51, 56, 45, 51, 46, 56, 51, 52, 45, 46, 54, 51, 52, 45, 46, 53, 57, 54, 45, 49, 46, 48, 51, 45, 46, 53, 56, 54, 45, 51, 46, 57, 52, 49, 46, 48, 57, 57, 45, 50, 46, 49, 50, 49, 46, 53, 45, 50, 46, 52, 55, 50, 46, 53, 51, 51, 45, 50, 46, 57, 53, 52, 46, 50, 55, 53, 108, 45, 46, 53, 52, 55, 45, 46, 50, 57, 51, 46, 49, 53, 49, 45, 50, 46, 57, 50, 54, 46, 49, 53, 50, 45, 50, 46, 57, 50, 53, 45, 46, 53, 52, 55, 45, 46, 53, 52, 55, 99, 45, 46, 51, 48, 49, 45, 46, 51, 48, 49, 45, 46, 55, 50, 56, 45, 46, 53, 52, 55, 45, 46, 57, 53, 45, 46, 53, 52, 55, 45, 46, 50, 50, 49, 32, 48, 45, 49, 46, 53, 51, 56, 46, 53, 50, 51, 45, 50, 46, 57, 50, 54, 32, 49, 46, 49, 54, 49, 45, 50, 46, 51, 49, 56, 32, 49, 46, 48, 54, 55, 45, 50, 46, 53, 54, 55, 32, 49, 46, 49, 51, 56, 45, 51, 46, 48, 54, 56, 46, 56, 55, 54, 45, 46, 53, 45, 46, 50, 54, 50, 45, 46, 53, 56, 51, 45, 46, 53, 50, 45, 49, 46, 48, 49, 45, 51, 46, 49, 50, 55, 45, 46, 52, 57, 51, 45, 51, 46, 48, 49, 54, 45, 46, 55, 57, 56, 45, 51, 46, 54, 48, 51, 45, 49, 46, 56, 54, 57, 45, 51, 46, 54, 48, 51, 45, 46, 50, 53, 52, 32, 48, 45, 49, 46, 53, 49, 51, 46, 55, 53, 53, 45, 50, 46, 55, 57, 56, 32, 49, 46, 54, 55, 56, 45, 50, 46, 49, 49, 32, 49, 46, 53, 49, 54, 45, 50, 46, 51, 57, 51, 32, 49, 46, 54, 53, 57, 45, 50, 46, 57, 49, 57, 32, 49, 46, 52, 55, 54, 45, 46, 52, 51, 53, 45, 46, 49, 53, 50, 45, 46, 54, 56, 56, 45, 46, 52, 56, 51, 45, 46, 57, 57, 55, 45, 49, 46, 51, 48, 54, 45, 46, 50, 50, 57, 45, 46, 54, 48, 54, 45, 46, 54, 54, 55, 45, 49, 46, 55, 55, 52, 45, 46, 57, 55, 53, 45, 50, 46, 53, 57, 53, 45, 46, 54, 50, 50, 45, 49, 46, 54, 53, 54, 45, 46, 57, 54, 57, 45, 50, 46, 48, 50, 55, 45, 49, 46, 57, 48, 49, 45, 50, 46, 48, 50, 55, 45, 46, 53, 50, 32, 48, 45, 46, 57, 57, 49, 46, 51, 55, 52, 45, 50, 46, 54, 55, 57, 32, 50, 46, 49, 50, 55, 45, 50, 46, 54, 53, 51, 32, 50, 46, 55, 53, 54, 45, 50, 46, 54, 54, 51, 32, 50, 46, 55, 53, 53, 45, 52, 46, 54, 49, 52, 45, 46, 52, 52, 53, 45, 46, 55, 56, 45, 49, 46, 50, 55, 57, 45, 49, 46, 53, 57, 53, 45, 50, 46, 52, 50, 49, 45, 49, 46, 56, 49, 50, 45, 50, 46, 53, 51, 55, 45, 46, 52, 56, 56, 45, 46, 50, 54, 50, 45, 49, 46, 48, 54, 50, 45, 46, 50, 54, 49, 45, 49, 46, 53, 49, 49, 46, 48, 48, 50, 109, 50, 46, 52, 49, 56, 32, 57, 46, 54, 51, 53, 99, 50, 46, 51, 49, 49, 32, 49, 46, 54, 52, 53, 32, 49, 46, 48, 56, 50, 32, 53, 46, 53, 49, 50, 45, 49, 46, 55, 53, 50, 32, 53, 46, 53, 49, 50, 45, 50, 46, 55, 53, 32, 48, 45, 52, 46, 49, 51, 53, 45, 51, 46, 51, 49, 51, 45, 50, 46, 49, 55, 49, 45, 53, 46, 49, 57, 52, 32, 49, 46, 49, 48, 56, 45, 49, 46, 48, 54, 50, 32, 50, 46, 54, 57, 55, 45, 49, 46, 49, 57, 49, 32, 51, 46, 57, 50, 51, 45, 46, 51, 49, 56, 109, 45, 50, 46, 57, 48, 54, 32, 49, 48, 46, 50, 49, 52, 99, 49, 46, 53, 49, 53, 46, 53, 55, 54, 32, 50, 46, 49, 51, 55, 46, 50, 51, 32, 53, 46, 53, 57, 54, 45, 51, 46, 49, 48, 52, 108, 50, 46, 53, 57, 57, 45, 50, 46, 53, 48, 54, 32, 49, 46, 49, 46, 49, 52, 54, 99, 51, 46, 52, 53, 46, 52, 53, 56, 32, 49, 48, 46, 51, 49, 50, 32, 51, 46, 52, 55, 50, 32, 49, 52, 46, 50, 53, 53, 32, 54, 46, 50, 54, 49, 32, 51, 46, 54, 50, 51, 32, 50, 46, 53, 54, 52, 32, 56, 46, 52, 51, 56, 32, 55, 46, 55, 56, 54, 32, 49, 48, 46, 52, 57, 32, 49, 49, 46, 51, 55, 55, 108, 46, 52, 51, 57, 46, 55, 54, 57, 45, 49, 46, 57, 52, 52, 32, 52, 46, 51, 56, 99, 45, 49, 46, 48, 55, 32, 50, 46, 52, 48, 57, 45, 49, 46, 57, 52, 53, 32, 52, 46, 54, 51, 51, 45, 49, 46, 57, 52, 53, 32, 52, 46, 57, 52, 52, 32, 48, 32, 46, 55, 49, 55, 46, 52, 55, 32, 49, 46, 56, 53, 49, 46, 57, 50, 51, 32, 50, 46, 50, 50, 54, 46, 49, 57, 49, 46, 49, 53, 57, 32, 50, 46, 48, 48, 54, 32, 49, 46, 48, 51, 51, 32, 52, 46, 48, 51, 51, 32, 49, 46, 57, 52, 50, 108, 51, 46, 54, 56, 52, 32, 49, 46, 54, 53, 52, 46, 49, 52, 53, 46, 57, 51, 55, 99, 46, 49, 56, 55, 32, 49, 46, 50, 50, 49, 46, 50, 49, 50, 32, 52, 46, 50, 50, 46, 48, 52, 50, 32, 53, 46, 48, 55, 50, 108, 45, 46, 49, 51, 51, 46, 54, 54, 54, 104, 45, 50, 46, 49, 48, 51, 99, 45, 50, 46, 52, 51, 57, 32, 48, 45, 50, 46, 50, 53, 49, 45, 46, 50, 49, 56, 45, 50, 46, 51, 56, 51, 32, 50, 46, 55, 55, 52, 45, 46, 48, 57, 54, 32, 50, 46, 49, 54, 57, 45, 46, 54, 50, 32, 51, 46, 51, 54, 56, 45, 49, 46, 56, 49, 50, 32, 52, 46, 49, 52, 52, 45, 49, 46, 57, 52, 50, 32, 49, 46, 50, 54, 55, 45, 53, 46, 49, 52, 57, 32, 49, 46, 48, 51, 55, 45, 54, 46, 53, 48, 57, 45, 46, 52, 54, 54, 45, 46, 50, 48, 57, 45, 46, 50, 51, 49, 45, 46, 54, 49, 53, 45, 49, 46, 51, 57, 50, 45, 46, 57, 48, 51, 45, 50, 46, 53, 56, 49, 45, 46, 56, 52, 49, 45, 51, 46, 52, 55, 51, 45, 49, 46, 57, 55, 49, 45, 53, 46, 52, 50, 51, 45, 52, 46, 50, 52, 49, 45, 55, 46, 51, 50, 45, 46, 55, 49, 55, 45, 46, 53, 57, 57, 45, 49, 46, 51, 48, 51, 45, 49, 46, 49, 53, 56, 45, 49, 46, 51, 48, 51, 45, 49, 46, 50, 52, 51, 32, 48, 45, 46, 48, 56, 52, 46, 55, 56, 56, 45, 46, 55, 52, 56, 32, 49, 46, 55, 53, 50, 45, 49, 46, 52, 55, 51, 32, 51, 46, 53, 49, 45, 50, 46, 54, 52, 54, 32, 53, 46, 53, 50, 56, 45, 53, 46, 55, 50, 54, 32, 53, 46, 55, 53, 45, 56, 46, 55, 55, 55, 46, 52, 50, 51, 45, 53, 46, 56, 49, 57, 45, 52, 46, 50, 49, 51, 45, 49, 49, 46, 50, 52, 51, 45, 49, 49, 46, 49, 48, 57, 45, 49, 51, 46, 48, 48, 49, 45, 49, 46, 54, 51, 53, 45, 46, 52, 49, 55, 45, 50, 46, 51, 51, 51, 45, 46, 52, 51, 45, 50, 50, 46, 53, 54, 45, 46, 52, 51, 45, 49, 49, 46, 52, 56, 32, 48, 45, 50, 48, 46, 56, 55, 51, 45, 46, 48, 55, 53, 45, 50, 48, 46, 56, 55, 51, 45, 46, 49, 54, 54, 32, 48, 45, 46, 50, 49, 53, 32, 50, 46, 53, 53, 49, 45, 50, 46, 54, 57, 49, 32, 52, 46, 48, 53, 52, 45, 51, 46, 57, 51, 51, 32, 52, 46, 49, 50, 55, 45, 51, 46, 52, 49, 50, 32, 57, 46, 52, 56, 56, 45, 54, 46, 48, 57, 55, 32, 49, 53, 46, 48, 52, 45, 55, 46, 53, 51, 49, 108, 49, 46, 57, 50, 45, 46, 52, 57, 55, 32, 50, 46, 55, 50, 56, 32, 50, 46, 55, 54, 54, 99, 49, 46, 53, 48, 49, 32, 49, 46, 53, 50, 49, 32, 50, 46, 57, 55, 50, 32, 50, 46, 56, 53, 55, 32, 51, 46, 50, 54, 56, 32, 50, 46, 57, 55, 77, 50, 55, 46, 52, 51, 50, 32, 52, 56, 46, 53, 50, 54, 99, 49, 46, 50, 53, 55, 46, 56, 50, 51, 32, 49, 46, 55, 55, 50, 32, 50, 46, 56, 57, 49, 32, 49, 46, 48, 51, 32, 52, 46, 49, 51, 52, 45, 49, 46, 49, 52, 56, 32, 49, 46, 57, 50, 52, 45, 52, 46, 48, 53, 54, 32, 50, 46, 48, 48, 53, 45, 53, 46, 50, 48, 53, 46, 49, 52, 53, 45, 49, 46, 54, 55, 49, 45, 50, 46, 55, 48, 50, 32, 49, 46, 53, 52, 55, 45, 54, 46, 48, 48, 49, 32, 52, 46, 49, 55, 53, 45, 52, 46, 50, 55, 57, 109, 55, 52, 46, 48, 53, 46, 49, 48, 53, 99, 51, 46, 50, 56, 56, 32, 50, 46, 48, 48, 53, 46, 55, 52, 32, 54, 46, 57, 51, 55, 45, 50, 46, 55, 56, 32, 53, 46, 51, 56, 45, 50, 46, 51, 53, 45, 49, 46, 48, 52, 45, 50, 46, 52, 50, 53, 45, 52, 46, 50, 53, 50, 45, 46, 49, 50, 55, 45, 53, 46, 52, 50, 52, 46, 57, 53, 57, 45, 46, 52, 56, 57, 32, 50, 46, 48, 54, 49, 45, 46, 52, 55, 50, 32, 50, 46, 57, 48, 55, 46, 48, 52, 52, 77, 51, 55, 46, 49, 50, 32, 54, 48, 46, 57, 48, 55, 118, 49, 50, 46, 50, 54, 54, 72, 50, 54, 46, 50, 55, 54, 108, 45, 46, 52, 51, 45, 49, 46, 56, 54, 54, 99, 45, 46, 56, 52, 54, 45, 51, 46, 54, 55, 53, 45, 49, 46, 50, 48, 50, 45, 55, 46, 52, 55, 55, 45, 46, 57, 56, 57, 45, 49, 48, 46, 53, 57, 49, 108, 46, 49, 52, 57, 45, 50, 46, 49, 56, 56, 32, 51, 46, 55, 50, 56, 45, 49, 46, 54, 55, 50, 99, 50, 46, 51, 51, 57, 45, 49, 46, 48, 52, 56, 32, 51, 46, 56, 52, 51, 45, 49, 46, 56, 52, 55, 32, 52, 46, 48, 51, 55, 45, 50, 46, 49, 52, 52, 46, 56, 52, 56, 45, 49, 46, 50, 57, 51, 46, 55, 54, 55, 45, 50, 46, 50, 49, 55, 45, 46, 52, 50, 51, 45, 52, 46, 56, 52, 53, 108, 45, 46, 53, 53, 54, 45, 49, 46, 50, 50, 55, 104, 53, 46, 51, 50, 56, 118, 49, 50, 46, 50, 54, 55, 109, 51, 49, 46, 50, 50, 45, 49, 49, 46, 55, 51, 51, 99, 50, 46, 51, 50, 50, 46, 54, 48, 52, 32, 51, 46, 53, 52, 57, 32, 49, 46, 56, 51, 51, 32, 51, 46, 53, 53, 50, 32, 51, 46, 53, 53, 54, 46, 48, 48, 50, 32, 49, 46, 50, 54, 53, 45, 46, 54, 50, 53, 32, 50, 46, 48, 53, 57, 45, 50, 46, 49, 56, 32, 50, 46, 55, 54, 49, 45, 49, 46, 49, 48, 49, 46, 52, 57, 56, 45, 49, 46, 50, 55, 54, 46, 53, 49, 45, 56, 46, 50, 49, 57, 46, 53, 55, 56, 108, 45, 55, 46, 48, 57, 51, 46, 48, 54, 56, 118, 45, 55, 46, 50, 56, 52, 104, 54, 46, 51, 53, 53, 99, 52, 46, 57, 54, 52, 32, 48, 32, 54, 46, 54, 50, 53, 46, 48, 55, 32, 55, 46, 53, 56, 53, 46, 51, 50, 49, 109, 45, 50, 46, 51, 57, 54, 32, 49, 55, 46, 54, 48, 50, 99, 49, 46, 49, 53, 49, 46, 51, 50, 32, 50, 46, 53, 49, 50, 32, 49, 46, 51, 50, 32, 51, 46, 50, 49, 32, 50, 46, 51, 53, 57, 46, 55, 51, 51, 32, 49, 46, 48, 57, 50, 32, 49, 46, 49, 54, 50, 32, 50, 46, 53, 49, 50, 32, 50, 46, 49, 55, 56, 32, 55, 46, 50, 49, 54, 46, 56, 53, 56, 32, 51, 46, 57, 55, 54, 32, 49, 46, 52, 49, 32, 53, 46, 50, 55, 54, 32, 50, 46, 57, 53, 54, 32, 54, 46, 57, 54, 56, 32, 49, 46, 57, 49, 53, 32, 50, 46, 48, 57, 53, 32, 49, 46, 52, 55, 49, 32, 50, 46, 48, 49, 52, 32, 49, 49, 46, 48, 51, 55, 32, 50, 46, 48, 49, 52, 32, 52, 46, 53, 56, 49, 32, 48, 32, 56, 46, 51, 50, 56, 46, 48, 55, 51, 32, 56, 46, 51, 50, 56, 46, 49, 54, 51, 32, 48, 32, 46, 49, 54, 49, 45, 51, 46, 49, 53, 53, 32, 51, 46, 56, 57, 49, 45, 51, 46, 50, 57, 49, 32, 51, 46, 56, 57, 49, 45, 46, 48, 51, 57, 32, 48, 45, 49, 46, 54, 56, 55, 45, 46, 51, 52, 53, 45, 51, 46, 54, 54, 50, 45, 46, 55, 54, 55, 45, 53, 46, 53, 55, 55, 45, 49, 46, 49, 57, 49, 45, 53, 46, 55, 55, 56, 45, 49, 46, 48, 53, 49, 45, 55, 46, 48, 53, 56, 32, 52, 46, 57, 50, 54, 108, 45, 46, 56, 50, 51, 32, 51, 46, 56, 52, 45, 46, 55, 52, 51, 46, 51, 54, 54, 99, 45, 49, 46, 50, 52, 46, 54, 49, 50, 45, 53, 46, 50, 55, 32, 49, 46, 56, 55, 50, 45, 55, 46, 51, 53, 57, 32, 50, 46, 51, 48, 50, 45, 51, 46, 52, 53, 50, 46, 55, 49, 45, 55, 46, 50, 48, 57, 46, 57, 53, 45, 49, 48, 46, 53, 49, 49, 46, 54, 55, 49, 45, 53, 46, 54, 50, 57, 45, 46, 52, 55, 55, 45, 49, 51, 46, 48, 56, 51, 45, 50, 46, 54, 54, 49, 45, 49, 51, 46, 51, 55, 52, 45, 51, 46, 57, 50, 45, 46, 48, 54, 50, 45, 46, 50, 54, 55, 45, 46, 52, 51, 55, 45, 49, 46, 57, 57, 53, 45, 46, 56, 51, 50, 45, 51, 46, 56, 52, 49, 45, 46, 51, 57, 54, 45, 49, 46, 56, 52, 54, 45, 46, 56, 55, 55, 45, 51, 46, 53, 57, 55, 45, 49, 46, 48, 54, 57, 45, 51, 46, 56, 57, 49, 45, 46, 57, 50, 51, 45, 49, 46, 52, 48, 56, 45, 49, 46, 56, 57, 52, 45, 49, 46, 52, 57, 53, 45, 54, 46, 49, 54, 52, 45, 46, 53, 53, 45, 49, 46, 54, 49, 55, 46, 51, 53, 56, 45, 51, 46, 48, 50, 56, 46, 54, 53, 45, 51, 46, 49, 51, 54, 46, 54, 53, 45, 46, 50, 48, 51, 32, 48, 45, 51, 46, 50, 48, 52, 45, 51, 46, 52, 55, 45, 51, 46, 50, 48, 52, 45, 51, 46, 55, 48, 52, 32, 48, 45, 46, 48, 55, 51, 32, 55, 46, 49, 50, 56, 45, 46, 49, 53, 56, 32, 49, 53, 46, 56, 52, 45, 46, 49, 56, 56, 108, 49, 53, 46, 56, 52, 45, 46, 48, 53, 52, 46, 48, 53, 55, 45, 53, 46, 54, 50, 55, 99, 46, 48, 52, 45, 51, 46, 57, 55, 51, 45, 46, 48, 49, 53, 45, 53, 46, 55, 49, 52, 45, 46, 49, 56, 55, 45, 53, 46, 57, 50, 45, 46, 49, 57, 50, 45, 46, 50, 51, 50, 45, 49, 46, 50, 49, 52, 45, 46, 50, 57, 51, 45, 52, 46, 57, 49, 45, 46, 50, 57, 51, 72, 53, 52, 46, 52, 86, 54, 54, 46, 53, 54, 108, 53, 46, 51, 56, 55, 46, 48, 48, 49, 99, 50, 46, 57, 54, 50, 46, 48, 48, 49, 32, 53, 46, 55, 51, 51, 46, 48, 57, 56, 32, 54, 46, 49, 53, 55, 46, 50, 49, 53, 77, 52, 49, 46, 53, 51, 54, 32, 57, 50, 46, 51, 54, 53, 99, 50, 46, 53, 49, 57, 32, 49, 46, 53, 51, 53, 32, 49, 46, 51, 49, 49, 32, 53, 46, 53, 53, 55, 45, 49, 46, 54, 54, 56, 32, 53, 46, 53, 53, 52, 45, 51, 46, 48, 53, 53, 45, 46, 48, 48, 50, 45, 52, 46, 49, 56, 55, 45, 51, 46, 57, 56, 55, 45, 49, 46, 53, 56, 52, 45, 53, 46, 53, 55, 53, 46, 56, 54, 49, 45, 46, 53, 50, 53, 32, 50, 46, 51, 55, 52, 45, 46, 53, 49, 53, 32, 51, 46, 50, 53, 50, 46, 48, 50, 49, 109, 52, 54, 46, 49, 50, 54, 46, 49, 54, 56, 99, 49, 46, 50, 51, 53, 46, 57, 48, 53, 32, 49, 46, 54, 52, 54, 32, 50, 46, 55, 56, 56, 46, 56, 56, 49, 32, 52, 46, 48, 52, 50, 45, 50, 46, 48, 48, 57, 32, 51, 46, 50, 57, 53, 45, 55, 46, 48, 51, 51, 46, 54, 55, 54, 45, 53, 46, 51, 53, 53, 45, 50, 46, 55, 57, 49, 46, 56, 50, 53, 45, 49, 46, 55, 48, 51, 32, 51, 46, 48, 49, 56, 45, 50, 46, 51, 49, 55, 32, 52, 46, 52, 55, 52, 45, 49, 46, 50, 53, 49, 34, 32, 102, 105, 108, 108, 45, 114, 117, 108, 101, 61, 34, 101, 118, 101, 110, 111, 100, 100, 34, 32, 61442, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("search"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 115, 101, 97, 114, 99, 104, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 48, 46, 55, 49, 32, 49, 57, 46, 50, 57, 108, 45, 51, 46, 52, 45, 51, 46, 51, 57, 65, 55, 46, 57, 50, 32, 55, 46, 57, 50, 32, 48, 32, 48, 32, 48, 32, 49, 57, 32, 49, 49, 97, 56, 32, 56, 32, 48, 32, 49, 32, 48, 45, 56, 32, 56, 32, 55, 46, 57, 50, 32, 55, 46, 57, 50, 32, 48, 32, 48, 32, 48, 32, 52, 46, 57, 45, 49, 46, 54, 57, 108, 51, 46, 51, 57, 32, 51, 46, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 50, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 122, 77, 53, 32, 49, 49, 97, 54, 32, 54, 32, 48, 32, 49, 32, 49, 32, 54, 32, 54, 32, 54, 32, 54, 32, 48, 32, 48, 32, 49, 45, 54, 45, 54, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("settings"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 115, 101, 116, 116, 105, 110, 103, 115, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 99, 105, 114, 99, 108, 101, 32, 99, 120, 61, 34, 49, 50, 34, 32, 99, 121, 61, 34, 49, 50, 34, 32, 114, 61, 34, 49, 46, 53, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 49, 46, 56, 57, 32, 49, 48, 46, 51, 50, 76, 50, 49, 46, 49, 32, 55, 46, 56, 97, 50, 46, 50, 54, 32, 50, 46, 50, 54, 32, 48, 32, 48, 32, 48, 45, 50, 46, 56, 56, 45, 49, 46, 53, 49, 108, 45, 46, 51, 52, 46, 49, 49, 97, 49, 46, 55, 52, 32, 49, 46, 55, 52, 32, 48, 32, 48, 32, 49, 45, 49, 46, 53, 57, 45, 46, 50, 54, 108, 45, 46, 49, 49, 45, 46, 48, 56, 97, 49, 46, 55, 54, 32, 49, 46, 55, 54, 32, 48, 32, 48, 32, 49, 45, 46, 54, 57, 45, 49, 46, 52, 51, 118, 45, 46, 50, 56, 97, 50, 46, 51, 55, 32, 50, 46, 51, 55, 32, 48, 32, 48, 32, 48, 45, 46, 54, 56, 45, 49, 46, 54, 56, 32, 50, 46, 50, 54, 32, 50, 46, 50, 54, 32, 48, 32, 48, 32, 48, 45, 49, 46, 54, 45, 46, 54, 55, 104, 45, 50, 46, 53, 53, 97, 50, 46, 51, 50, 32, 50, 46, 51, 50, 32, 48, 32, 48, 32, 48, 45, 50, 46, 50, 57, 32, 50, 46, 51, 51, 118, 46, 50, 52, 97, 49, 46, 57, 52, 32, 49, 46, 57, 52, 32, 48, 32, 48, 32, 49, 45, 46, 55, 51, 32, 49, 46, 53, 49, 108, 45, 46, 49, 51, 46, 49, 97, 49, 46, 57, 51, 32, 49, 46, 57, 51, 32, 48, 32, 48, 32, 49, 45, 49, 46, 55, 56, 46, 50, 57, 32, 50, 46, 49, 52, 32, 50, 46, 49, 52, 32, 48, 32, 48, 32, 48, 45, 49, 46, 54, 56, 46, 49, 50, 32, 50, 46, 49, 56, 32, 50, 46, 49, 56, 32, 48, 32, 48, 32, 48, 45, 49, 46, 49, 50, 32, 49, 46, 51, 51, 108, 45, 46, 56, 50, 32, 50, 46, 54, 97, 50, 46, 51, 52, 32, 50, 46, 51, 52, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 56, 32, 50, 46, 57, 52, 104, 46, 49, 54, 97, 49, 46, 56, 51, 32, 49, 46, 56, 51, 32, 48, 32, 48, 32, 49, 32, 49, 46, 49, 50, 32, 49, 46, 50, 50, 108, 46, 48, 54, 46, 49, 54, 97, 50, 46, 48, 54, 32, 50, 46, 48, 54, 32, 48, 32, 48, 32, 49, 45, 46, 50, 51, 32, 49, 46, 56, 54, 32, 50, 46, 51, 55, 32, 50, 46, 51, 55, 32, 48, 32, 48, 32, 48, 32, 46, 52, 57, 32, 51, 46, 51, 108, 50, 46, 48, 55, 32, 49, 46, 53, 55, 97, 50, 46, 50, 53, 32, 50, 46, 50, 53, 32, 48, 32, 48, 32, 48, 32, 49, 46, 51, 53, 46, 52, 51, 65, 50, 32, 50, 32, 48, 32, 48, 32, 48, 32, 57, 32, 50, 50, 97, 50, 46, 50, 53, 32, 50, 46, 50, 53, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 55, 45, 49, 108, 46, 50, 51, 45, 46, 51, 51, 97, 49, 46, 56, 32, 49, 46, 56, 32, 48, 32, 48, 32, 49, 32, 49, 46, 52, 51, 45, 46, 55, 55, 32, 49, 46, 55, 53, 32, 49, 46, 55, 53, 32, 48, 32, 48, 32, 49, 32, 49, 46, 53, 46, 55, 56, 108, 46, 49, 50, 46, 49, 55, 97, 50, 46, 50, 52, 32, 50, 46, 50, 52, 32, 48, 32, 48, 32, 48, 32, 51, 46, 50, 50, 46, 53, 51, 76, 49, 57, 32, 49, 57, 46, 56, 54, 97, 50, 46, 51, 56, 32, 50, 46, 51, 56, 32, 48, 32, 48, 32, 48, 32, 46, 53, 45, 51, 46, 50, 51, 108, 45, 46, 50, 54, 45, 46, 51, 56, 65, 50, 32, 50, 32, 48, 32, 48, 32, 49, 32, 49, 57, 32, 49, 52, 46, 54, 97, 49, 46, 56, 57, 32, 49, 46, 56, 57, 32, 48, 32, 48, 32, 49, 32, 49, 46, 50, 49, 45, 49, 46, 50, 56, 108, 46, 50, 45, 46, 48, 55, 97, 50, 46, 51, 54, 32, 50, 46, 51, 54, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 56, 45, 50, 46, 57, 51, 122, 77, 49, 50, 32, 49, 53, 46, 53, 97, 51, 46, 53, 32, 51, 46, 53, 32, 48, 32, 49, 32, 49, 32, 51, 46, 53, 45, 51, 46, 53, 32, 51, 46, 53, 32, 51, 46, 53, 32, 48, 32, 48, 32, 49, 45, 51, 46, 53, 32, 51, 46, 53, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("share"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 115, 104, 97, 114, 101, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 56, 32, 49, 53, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 50, 46, 49, 46, 56, 54, 76, 56, 32, 49, 50, 46, 51, 52, 86, 49, 50, 118, 45, 46, 51, 51, 108, 55, 46, 57, 45, 51, 46, 53, 51, 65, 51, 32, 51, 32, 48, 32, 49, 32, 48, 32, 49, 53, 32, 54, 118, 46, 51, 52, 76, 55, 46, 49, 32, 57, 46, 56, 54, 97, 51, 32, 51, 32, 48, 32, 49, 32, 48, 32, 48, 32, 52, 46, 50, 56, 108, 55, 46, 57, 32, 51, 46, 53, 51, 86, 49, 56, 97, 51, 32, 51, 32, 48, 32, 49, 32, 48, 32, 51, 45, 51, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("shield"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 115, 104, 105, 101, 108, 100, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 50, 49, 46, 56, 53, 97, 50, 32, 50, 32, 48, 32, 48, 32, 49, 45, 49, 45, 46, 50, 53, 108, 45, 46, 51, 45, 46, 49, 55, 65, 49, 53, 46, 49, 55, 32, 49, 53, 46, 49, 55, 32, 48, 32, 48, 32, 49, 32, 51, 32, 56, 46, 50, 51, 118, 45, 46, 49, 52, 97, 50, 32, 50, 32, 48, 32, 48, 32, 49, 32, 49, 45, 49, 46, 55, 53, 108, 55, 45, 51, 46, 57, 52, 97, 50, 32, 50, 32, 48, 32, 48, 32, 49, 32, 50, 32, 48, 108, 55, 32, 51, 46, 57, 52, 97, 50, 32, 50, 32, 48, 32, 48, 32, 49, 32, 49, 32, 49, 46, 55, 53, 118, 46, 49, 52, 97, 49, 53, 46, 49, 55, 32, 49, 53, 46, 49, 55, 32, 48, 32, 48, 32, 49, 45, 55, 46, 55, 50, 32, 49, 51, 46, 50, 108, 45, 46, 51, 46, 49, 55, 97, 50, 32, 50, 32, 48, 32, 48, 32, 49, 45, 46, 57, 56, 46, 50, 53, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("shieldoff"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 115, 104, 105, 101, 108, 100, 45, 111, 102, 102, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 51, 46, 55, 51, 32, 54, 46, 53, 54, 65, 50, 32, 50, 32, 48, 32, 48, 32, 48, 32, 51, 32, 56, 46, 48, 57, 118, 46, 49, 52, 97, 49, 53, 46, 49, 55, 32, 49, 53, 46, 49, 55, 32, 48, 32, 48, 32, 48, 32, 55, 46, 55, 50, 32, 49, 51, 46, 50, 108, 46, 51, 46, 49, 55, 97, 50, 32, 50, 32, 48, 32, 48, 32, 48, 32, 50, 32, 48, 108, 46, 51, 45, 46, 49, 55, 97, 49, 53, 46, 50, 50, 32, 49, 53, 46, 50, 50, 32, 48, 32, 48, 32, 48, 32, 51, 45, 50, 46, 50, 55, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 56, 46, 56, 52, 32, 49, 54, 65, 49, 53, 46, 48, 56, 32, 49, 53, 46, 48, 56, 32, 48, 32, 48, 32, 48, 32, 50, 49, 32, 56, 46, 50, 51, 118, 45, 46, 49, 52, 97, 50, 32, 50, 32, 48, 32, 48, 32, 48, 45, 49, 45, 49, 46, 55, 53, 76, 49, 51, 32, 50, 46, 52, 97, 50, 32, 50, 32, 48, 32, 48, 32, 48, 45, 50, 32, 48, 76, 55, 46, 51, 50, 32, 52, 46, 52, 57, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 52, 46, 55, 49, 32, 51, 46, 50, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 32, 49, 46, 52, 50, 108, 49, 54, 32, 49, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 50, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("skull"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 48, 50, 44, 55, 54, 46, 57, 52, 67, 51, 54, 50, 46, 54, 49, 44, 51, 55, 46, 54, 51, 44, 51, 49, 48, 46, 55, 56, 44, 49, 54, 44, 50, 53, 54, 44, 49, 54, 104, 45, 46, 51, 55, 65, 50, 48, 56, 44, 50, 48, 56, 44, 48, 44, 48, 44, 48, 44, 52, 56, 44, 50, 50, 52, 86, 51, 50, 52, 46, 54, 55, 65, 55, 57, 46, 54, 50, 44, 55, 57, 46, 54, 50, 44, 48, 44, 48, 44, 48, 44, 57, 56, 46, 50, 57, 44, 51, 57, 57, 76, 49, 50, 50, 44, 52, 48, 56, 46, 52, 50, 97, 49, 53, 46, 57, 50, 44, 49, 53, 46, 57, 50, 44, 48, 44, 48, 44, 49, 44, 57, 46, 55, 53, 44, 49, 49, 46, 55, 50, 108, 49, 48, 44, 53, 48, 46, 49, 51, 65, 51, 50, 46, 48, 57, 44, 51, 50, 46, 48, 57, 44, 48, 44, 48, 44, 48, 44, 49, 55, 51, 46, 49, 50, 44, 52, 57, 54, 72, 49, 56, 52, 97, 56, 44, 56, 44, 48, 44, 48, 44, 48, 44, 56, 45, 56, 86, 52, 52, 56, 46, 52, 53, 99, 48, 45, 56, 46, 54, 49, 44, 54, 46, 54, 50, 45, 49, 54, 44, 49, 53, 46, 50, 51, 45, 49, 54, 46, 52, 51, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 50, 50, 52, 44, 52, 52, 56, 118, 52, 48, 97, 56, 44, 56, 44, 48, 44, 48, 44, 48, 44, 56, 44, 56, 104, 48, 97, 56, 44, 56, 44, 48, 44, 48, 44, 48, 44, 56, 45, 56, 86, 52, 52, 56, 46, 52, 53, 99, 48, 45, 56, 46, 54, 49, 44, 54, 46, 54, 50, 45, 49, 54, 44, 49, 53, 46, 50, 51, 45, 49, 54, 46, 52, 51, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 50, 55, 50, 44, 52, 52, 56, 118, 52, 48, 97, 56, 44, 56, 44, 48, 44, 48, 44, 48, 44, 56, 44, 56, 104, 48, 97, 56, 44, 56, 44, 48, 44, 48, 44, 48, 44, 56, 45, 56, 86, 52, 52, 56, 46, 52, 53, 99, 48, 45, 56, 46, 54, 49, 44, 54, 46, 54, 50, 45, 49, 54, 44, 49, 53, 46, 50, 51, 45, 49, 54, 46, 52, 51, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 51, 50, 48, 44, 52, 52, 56, 118, 52, 48, 97, 56, 44, 56, 44, 48, 44, 48, 44, 48, 44, 56, 44, 56, 104, 49, 48, 46, 56, 56, 97, 51, 50, 46, 48, 57, 44, 51, 50, 46, 48, 57, 44, 48, 44, 48, 44, 48, 44, 51, 49, 46, 51, 56, 45, 50, 53, 46, 55, 50, 108, 49, 48, 45, 53, 48, 46, 49, 52, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 51, 57, 48, 44, 52, 48, 56, 46, 52, 50, 76, 52, 49, 51, 46, 55, 49, 44, 51, 57, 57, 65, 55, 57, 46, 54, 50, 44, 55, 57, 46, 54, 50, 44, 48, 44, 48, 44, 48, 44, 52, 54, 52, 44, 51, 50, 52, 46, 54, 55, 118, 45, 57, 57, 67, 52, 54, 52, 44, 49, 54, 57, 46, 54, 55, 44, 52, 52, 50, 44, 49, 49, 54, 46, 56, 54, 44, 52, 48, 50, 44, 55, 54, 46, 57, 52, 90, 77, 49, 55, 49, 46, 54, 54, 44, 51, 51, 53, 46, 56, 56, 97, 53, 54, 44, 53, 54, 44, 48, 44, 49, 44, 49, 44, 53, 50, 46, 50, 50, 45, 53, 50, 46, 50, 50, 65, 53, 54, 44, 53, 54, 44, 48, 44, 48, 44, 49, 44, 49, 55, 49, 46, 54, 54, 44, 51, 51, 53, 46, 56, 56, 90, 77, 50, 56, 49, 44, 51, 57, 55, 46, 50, 53, 65, 49, 54, 46, 51, 55, 44, 49, 54, 46, 51, 55, 44, 48, 44, 48, 44, 49, 44, 50, 55, 49, 46, 55, 44, 52, 48, 48, 72, 50, 52, 48, 46, 51, 97, 49, 54, 46, 51, 55, 44, 49, 54, 46, 51, 55, 44, 48, 44, 48, 44, 49, 45, 57, 46, 50, 56, 45, 50, 46, 55, 53, 44, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 45, 54, 46, 54, 45, 49, 54, 46, 57, 108, 49, 53, 46, 57, 49, 45, 52, 55, 46, 54, 67, 50, 52, 51, 44, 51, 50, 54, 44, 50, 52, 55, 46, 50, 53, 44, 51, 50, 49, 44, 50, 53, 52, 44, 51, 50, 48, 46, 49, 51, 99, 56, 46, 50, 54, 45, 49, 44, 49, 52, 44, 50, 46, 56, 55, 44, 49, 55, 46, 54, 49, 44, 49, 50, 46, 50, 50, 108, 49, 54, 44, 52, 56, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 50, 56, 49, 44, 51, 57, 55, 46, 50, 53, 90, 109, 54, 54, 46, 54, 56, 45, 54, 49, 46, 51, 55, 97, 53, 54, 44, 53, 54, 44, 48, 44, 49, 44, 49, 44, 53, 50, 46, 50, 50, 45, 53, 50, 46, 50, 50, 65, 53, 54, 44, 53, 54, 44, 48, 44, 48, 44, 49, 44, 51, 52, 55, 46, 54, 54, 44, 51, 51, 53, 46, 56, 56, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("slash"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 115, 108, 97, 115, 104, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 50, 97, 49, 48, 32, 49, 48, 32, 48, 32, 49, 32, 48, 32, 49, 48, 32, 49, 48, 65, 49, 48, 32, 49, 48, 32, 48, 32, 48, 32, 48, 32, 49, 50, 32, 50, 122, 109, 56, 32, 49, 48, 97, 55, 46, 57, 50, 32, 55, 46, 57, 50, 32, 48, 32, 48, 32, 49, 45, 49, 46, 54, 57, 32, 52, 46, 57, 76, 55, 46, 49, 32, 53, 46, 54, 57, 65, 55, 46, 57, 50, 32, 55, 46, 57, 50, 32, 48, 32, 48, 32, 49, 32, 49, 50, 32, 52, 97, 56, 32, 56, 32, 48, 32, 48, 32, 49, 32, 56, 32, 56, 122, 77, 52, 32, 49, 50, 97, 55, 46, 57, 50, 32, 55, 46, 57, 50, 32, 48, 32, 48, 32, 49, 32, 49, 46, 54, 57, 45, 52, 46, 57, 76, 49, 54, 46, 57, 32, 49, 56, 46, 51, 49, 65, 55, 46, 57, 50, 32, 55, 46, 57, 50, 32, 48, 32, 48, 32, 49, 32, 49, 50, 32, 50, 48, 97, 56, 32, 56, 32, 48, 32, 48, 32, 49, 45, 56, 45, 56, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("soccer"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 50, 53, 54, 44, 52, 56, 67, 49, 52, 49, 46, 51, 49, 44, 52, 56, 44, 52, 56, 44, 49, 52, 49, 46, 51, 49, 44, 52, 56, 44, 50, 53, 54, 115, 57, 51, 46, 51, 49, 44, 50, 48, 56, 44, 50, 48, 56, 44, 50, 48, 56, 44, 50, 48, 56, 45, 57, 51, 46, 51, 49, 44, 50, 48, 56, 45, 50, 48, 56, 83, 51, 55, 48, 46, 54, 57, 44, 52, 56, 44, 50, 53, 54, 44, 52, 56, 90, 77, 51, 57, 57, 44, 51, 53, 50, 72, 51, 53, 51, 46, 55, 56, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 45, 54, 46, 57, 49, 45, 52, 108, 45, 49, 54, 46, 49, 52, 45, 50, 55, 46, 54, 56, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 45, 46, 56, 54, 45, 54, 108, 49, 52, 46, 56, 54, 45, 53, 57, 46, 57, 50, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 52, 46, 54, 53, 45, 53, 46, 52, 53, 108, 50, 56, 46, 49, 45, 49, 49, 46, 57, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 56, 46, 51, 52, 44, 49, 46, 51, 108, 52, 49, 46, 54, 51, 44, 51, 53, 46, 56, 50, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 50, 46, 54, 57, 44, 55, 46, 50, 54, 44, 49, 55, 52, 46, 55, 53, 44, 49, 55, 52, 46, 55, 53, 44, 48, 44, 48, 44, 49, 45, 50, 52, 46, 50, 56, 44, 54, 54, 46, 54, 56, 65, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 51, 57, 57, 44, 51, 53, 50, 90, 77, 49, 51, 52, 46, 53, 50, 44, 50, 51, 55, 46, 49, 51, 108, 50, 56, 46, 49, 44, 49, 49, 46, 57, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 52, 46, 54, 53, 44, 53, 46, 52, 53, 108, 49, 52, 46, 56, 54, 44, 53, 57, 46, 57, 50, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 45, 46, 56, 54, 44, 54, 76, 49, 54, 53, 46, 49, 51, 44, 51, 52, 56, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 45, 54, 46, 57, 49, 44, 52, 72, 49, 49, 51, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 45, 54, 46, 56, 50, 45, 51, 46, 56, 49, 44, 49, 55, 52, 46, 55, 53, 44, 49, 55, 52, 46, 55, 53, 44, 48, 44, 48, 44, 49, 45, 50, 52, 46, 50, 56, 45, 54, 54, 46, 54, 56, 44, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 50, 46, 54, 57, 45, 55, 46, 50, 54, 108, 52, 49, 46, 54, 51, 45, 51, 53, 46, 56, 50, 65, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 49, 51, 52, 46, 53, 50, 44, 50, 51, 55, 46, 49, 51, 90, 109, 50, 53, 54, 46, 57, 52, 45, 56, 55, 46, 50, 52, 45, 49, 56, 46, 48, 55, 44, 53, 49, 46, 51, 56, 65, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 51, 54, 57, 44, 50, 48, 54, 108, 45, 50, 57, 46, 53, 56, 44, 49, 50, 46, 53, 51, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 45, 56, 46, 50, 54, 45, 49, 46, 50, 52, 108, 45, 53, 54, 46, 50, 54, 45, 52, 55, 46, 49, 57, 65, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 50, 55, 50, 44, 49, 54, 52, 86, 49, 51, 48, 46, 52, 50, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 51, 46, 53, 54, 45, 54, 46, 54, 53, 108, 52, 50, 46, 56, 51, 45, 50, 56, 46, 53, 52, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 55, 46, 54, 54, 45, 46, 54, 55, 65, 49, 55, 54, 46, 57, 50, 44, 49, 55, 54, 46, 57, 50, 44, 48, 44, 48, 44, 49, 44, 51, 57, 48, 44, 49, 52, 50, 44, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 51, 57, 49, 46, 52, 54, 44, 49, 52, 57, 46, 56, 57, 90, 77, 49, 57, 51, 46, 54, 44, 57, 53, 46, 50, 51, 108, 52, 50, 46, 56, 52, 44, 50, 56, 46, 53, 52, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 51, 46, 53, 54, 44, 54, 46, 54, 53, 86, 49, 54, 52, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 45, 50, 46, 56, 54, 44, 54, 46, 49, 51, 108, 45, 53, 54, 46, 50, 54, 44, 52, 55, 46, 49, 57, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 45, 56, 46, 50, 54, 44, 49, 46, 50, 52, 76, 49, 52, 51, 44, 50, 48, 54, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 45, 52, 46, 52, 51, 45, 52, 46, 55, 50, 108, 45, 49, 56, 46, 48, 55, 45, 53, 49, 46, 51, 56, 65, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 49, 50, 50, 44, 49, 52, 50, 97, 49, 55, 54, 46, 57, 50, 44, 49, 55, 54, 46, 57, 50, 44, 48, 44, 48, 44, 49, 44, 54, 52, 45, 52, 55, 46, 52, 56, 65, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 49, 57, 51, 46, 54, 44, 57, 53, 46, 50, 51, 90, 109, 49, 55, 46, 51, 49, 44, 51, 50, 55, 46, 52, 54, 76, 49, 57, 49, 46, 49, 56, 44, 51, 55, 51, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 46, 53, 50, 45, 55, 108, 49, 53, 46, 49, 55, 45, 50, 54, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 54, 46, 57, 49, 45, 52, 104, 56, 52, 46, 52, 52, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 54, 46, 57, 49, 44, 52, 108, 49, 53, 46, 49, 56, 44, 50, 54, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 46, 53, 51, 44, 55, 108, 45, 49, 57, 46, 53, 57, 44, 52, 57, 46, 54, 55, 97, 56, 44, 56, 44, 48, 44, 48, 44, 49, 45, 53, 46, 54, 57, 44, 52, 46, 56, 55, 44, 49, 55, 54, 46, 53, 56, 44, 49, 55, 54, 46, 53, 56, 44, 48, 44, 48, 44, 49, 45, 55, 57, 44, 48, 65, 56, 44, 56, 44, 48, 44, 48, 44, 49, 44, 50, 49, 48, 46, 57, 49, 44, 52, 50, 50, 46, 54, 57, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("star"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 115, 116, 97, 114, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 116, 114, 97, 110, 115, 102, 111, 114, 109, 61, 34, 114, 111, 116, 97, 116, 101, 40, 57, 48, 32, 49, 50, 32, 49, 50, 41, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 55, 46, 53, 54, 32, 50, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 46, 52, 54, 45, 46, 49, 49, 76, 49, 50, 32, 49, 56, 46, 50, 50, 108, 45, 53, 46, 49, 32, 50, 46, 54, 55, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 49, 46, 52, 53, 45, 49, 46, 48, 54, 108, 49, 45, 53, 46, 54, 51, 45, 52, 46, 49, 50, 45, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 46, 50, 53, 45, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 46, 56, 49, 45, 46, 54, 56, 108, 53, 46, 55, 45, 46, 56, 51, 32, 50, 46, 53, 49, 45, 53, 46, 49, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 49, 46, 56, 32, 48, 108, 50, 46, 53, 52, 32, 53, 46, 49, 50, 32, 53, 46, 55, 46, 56, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 46, 56, 49, 46, 54, 56, 32, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 46, 50, 53, 32, 49, 108, 45, 52, 46, 49, 50, 32, 52, 32, 49, 32, 53, 46, 54, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 46, 52, 32, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 46, 54, 50, 46, 49, 56, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("terminal"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 51, 50, 44, 51, 50, 72, 56, 48, 65, 54, 52, 46, 48, 55, 44, 54, 52, 46, 48, 55, 44, 48, 44, 48, 44, 48, 44, 49, 54, 44, 57, 54, 86, 52, 49, 54, 97, 54, 52, 46, 48, 55, 44, 54, 52, 46, 48, 55, 44, 48, 44, 48, 44, 48, 44, 54, 52, 44, 54, 52, 72, 52, 51, 50, 97, 54, 52, 46, 48, 55, 44, 54, 52, 46, 48, 55, 44, 48, 44, 48, 44, 48, 44, 54, 52, 45, 54, 52, 86, 57, 54, 65, 54, 52, 46, 48, 55, 44, 54, 52, 46, 48, 55, 44, 48, 44, 48, 44, 48, 44, 52, 51, 50, 44, 51, 50, 90, 77, 57, 54, 44, 50, 53, 54, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 45, 49, 48, 45, 50, 56, 46, 52, 57, 76, 49, 53, 48, 46, 51, 57, 44, 49, 55, 54, 44, 56, 54, 44, 49, 50, 52, 46, 52, 57, 97, 49, 54, 44, 49, 54, 44, 48, 44, 49, 44, 49, 44, 50, 48, 45, 50, 53, 108, 56, 48, 44, 54, 52, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 48, 44, 50, 53, 108, 45, 56, 48, 44, 54, 52, 65, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 57, 54, 44, 50, 53, 54, 90, 109, 49, 54, 48, 44, 48, 72, 49, 57, 50, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 48, 45, 51, 50, 104, 54, 52, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 49, 44, 48, 44, 51, 50, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("thumbdown"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 32, 61442, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 48, 32, 48, 104, 50, 52, 118, 50, 52, 72, 48, 122, 34, 32, 102, 105, 108, 108, 61, 34, 110, 111, 110, 101, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 53, 32, 51, 72, 54, 99, 45, 46, 56, 51, 32, 48, 45, 49, 46, 53, 52, 46, 53, 45, 49, 46, 56, 52, 32, 49, 46, 50, 50, 108, 45, 51, 46, 48, 50, 32, 55, 46, 48, 53, 99, 45, 46, 48, 57, 46, 50, 51, 45, 46, 49, 52, 46, 52, 55, 45, 46, 49, 52, 46, 55, 51, 118, 50, 99, 48, 32, 49, 46, 49, 46, 57, 32, 50, 32, 50, 32, 50, 104, 54, 46, 51, 49, 108, 45, 46, 57, 53, 32, 52, 46, 53, 55, 45, 46, 48, 51, 46, 51, 50, 99, 48, 32, 46, 52, 49, 46, 49, 55, 46, 55, 57, 46, 52, 52, 32, 49, 46, 48, 54, 76, 57, 46, 56, 51, 32, 50, 51, 108, 54, 46, 53, 57, 45, 54, 46, 53, 57, 99, 46, 51, 54, 45, 46, 51, 54, 46, 53, 56, 45, 46, 56, 54, 46, 53, 56, 45, 49, 46, 52, 49, 86, 53, 99, 48, 45, 49, 46, 49, 45, 46, 57, 45, 50, 45, 50, 45, 50, 122, 109, 52, 32, 48, 118, 49, 50, 104, 52, 86, 51, 104, 45, 52, 122, 34, 32, 61442, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("tv"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 116, 118, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 56, 32, 54, 104, 45, 51, 46, 53, 57, 108, 50, 46, 51, 45, 50, 46, 50, 57, 97, 49, 32, 49, 32, 48, 32, 49, 32, 48, 45, 49, 46, 52, 50, 45, 49, 46, 52, 50, 76, 49, 50, 32, 53, 46, 53, 57, 108, 45, 51, 46, 50, 57, 45, 51, 46, 51, 97, 49, 32, 49, 32, 48, 32, 49, 32, 48, 45, 49, 46, 52, 50, 32, 49, 46, 52, 50, 76, 57, 46, 53, 57, 32, 54, 72, 54, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 51, 32, 51, 118, 49, 48, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 51, 32, 51, 104, 49, 50, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 51, 45, 51, 86, 57, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 51, 45, 51, 122, 109, 49, 32, 49, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 49, 32, 49, 72, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 49, 45, 49, 118, 45, 55, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 49, 45, 49, 104, 49, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 49, 32, 49, 32, 49, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("twitter"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 116, 119, 105, 116, 116, 101, 114, 34, 62, 60, 112, 111, 108, 121, 108, 105, 110, 101, 32, 112, 111, 105, 110, 116, 115, 61, 34, 48, 32, 48, 32, 50, 52, 32, 48, 32, 50, 52, 32, 50, 52, 32, 48, 32, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 56, 46, 48, 56, 32, 50, 48, 65, 49, 49, 46, 48, 55, 32, 49, 49, 46, 48, 55, 32, 48, 32, 48, 32, 48, 32, 49, 57, 46, 53, 50, 32, 57, 32, 56, 46, 48, 57, 32, 56, 46, 48, 57, 32, 48, 32, 48, 32, 48, 32, 50, 49, 32, 54, 46, 49, 54, 97, 46, 52, 52, 46, 52, 52, 32, 48, 32, 48, 32, 48, 45, 46, 54, 50, 45, 46, 53, 49, 32, 49, 46, 56, 56, 32, 49, 46, 56, 56, 32, 48, 32, 48, 32, 49, 45, 50, 46, 49, 54, 45, 46, 51, 56, 32, 51, 46, 56, 57, 32, 51, 46, 56, 57, 32, 48, 32, 48, 32, 48, 45, 53, 46, 53, 56, 45, 46, 49, 55, 65, 52, 46, 49, 51, 32, 52, 46, 49, 51, 32, 48, 32, 48, 32, 48, 32, 49, 49, 46, 52, 57, 32, 57, 67, 56, 46, 49, 52, 32, 57, 46, 50, 32, 53, 46, 56, 52, 32, 55, 46, 54, 49, 32, 52, 32, 53, 46, 52, 51, 97, 46, 52, 51, 46, 52, 51, 32, 48, 32, 48, 32, 48, 45, 46, 55, 53, 46, 50, 52, 32, 57, 46, 54, 56, 32, 57, 46, 54, 56, 32, 48, 32, 48, 32, 48, 32, 52, 46, 54, 32, 49, 48, 46, 48, 53, 65, 54, 46, 55, 51, 32, 54, 46, 55, 51, 32, 48, 32, 48, 32, 49, 32, 51, 46, 51, 56, 32, 49, 56, 97, 46, 52, 53, 46, 52, 53, 32, 48, 32, 48, 32, 48, 45, 46, 49, 52, 46, 56, 52, 65, 49, 49, 32, 49, 49, 32, 48, 32, 48, 32, 48, 32, 56, 46, 48, 56, 32, 50, 48, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("umbrella"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 117, 109, 98, 114, 101, 108, 108, 97, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 50, 65, 49, 48, 32, 49, 48, 32, 48, 32, 48, 32, 48, 32, 50, 32, 49, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 32, 49, 104, 56, 118, 54, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 54, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 50, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 49, 45, 50, 32, 48, 118, 45, 54, 104, 56, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 45, 49, 65, 49, 48, 32, 49, 48, 32, 48, 32, 48, 32, 48, 32, 49, 50, 32, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("university"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 32, 61442, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 48, 32, 48, 104, 50, 52, 118, 50, 52, 72, 48, 122, 34, 32, 102, 105, 108, 108, 61, 34, 110, 111, 110, 101, 34, 47, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 53, 32, 49, 51, 46, 49, 56, 118, 52, 76, 49, 50, 32, 50, 49, 108, 55, 45, 51, 46, 56, 50, 118, 45, 52, 76, 49, 50, 32, 49, 55, 108, 45, 55, 45, 51, 46, 56, 50, 122, 77, 49, 50, 32, 51, 76, 49, 32, 57, 108, 49, 49, 32, 54, 32, 57, 45, 52, 46, 57, 49, 86, 49, 55, 104, 50, 86, 57, 76, 49, 50, 32, 51, 122, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("video"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 118, 105, 100, 101, 111, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 49, 32, 55, 46, 49, 53, 97, 49, 46, 55, 32, 49, 46, 55, 32, 48, 32, 48, 32, 48, 45, 49, 46, 56, 53, 46, 51, 108, 45, 50, 46, 49, 53, 32, 50, 86, 56, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 51, 45, 51, 72, 53, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 51, 32, 51, 118, 56, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 51, 32, 51, 104, 57, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 51, 45, 51, 118, 45, 49, 46, 52, 53, 108, 50, 46, 49, 54, 32, 50, 97, 49, 46, 55, 52, 32, 49, 46, 55, 52, 32, 48, 32, 48, 32, 48, 32, 49, 46, 49, 54, 46, 52, 53, 32, 49, 46, 54, 56, 32, 49, 46, 54, 56, 32, 48, 32, 48, 32, 48, 32, 46, 54, 57, 45, 46, 49, 53, 32, 49, 46, 54, 32, 49, 46, 54, 32, 48, 32, 48, 32, 48, 32, 49, 45, 49, 46, 52, 56, 86, 56, 46, 54, 51, 65, 49, 46, 54, 32, 49, 46, 54, 32, 48, 32, 48, 32, 48, 32, 50, 49, 32, 55, 46, 49, 53, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("videooff"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 118, 105, 100, 101, 111, 45, 111, 102, 102, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 52, 46, 50, 50, 32, 49, 55, 46, 48, 53, 76, 52, 46, 56, 56, 32, 55, 46, 55, 49, 32, 51, 46, 49, 50, 32, 54, 32, 51, 32, 53, 46, 56, 65, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 50, 32, 56, 118, 56, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 32, 51, 32, 51, 104, 57, 97, 50, 46, 57, 52, 32, 50, 46, 57, 52, 32, 48, 32, 48, 32, 48, 32, 49, 46, 54, 54, 45, 46, 53, 49, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 49, 32, 55, 46, 49, 53, 97, 49, 46, 55, 32, 49, 46, 55, 32, 48, 32, 48, 32, 48, 45, 49, 46, 56, 53, 46, 51, 108, 45, 50, 46, 49, 53, 32, 50, 86, 56, 97, 51, 32, 51, 32, 48, 32, 48, 32, 48, 45, 51, 45, 51, 72, 55, 46, 56, 51, 108, 49, 46, 50, 57, 32, 49, 46, 50, 57, 32, 54, 46, 53, 57, 32, 54, 46, 53, 57, 32, 50, 32, 50, 32, 50, 32, 50, 97, 49, 46, 55, 51, 32, 49, 46, 55, 51, 32, 48, 32, 48, 32, 48, 32, 46, 54, 46, 49, 49, 32, 49, 46, 54, 56, 32, 49, 46, 54, 56, 32, 48, 32, 48, 32, 48, 32, 46, 54, 57, 45, 46, 49, 53, 32, 49, 46, 54, 32, 49, 46, 54, 32, 48, 32, 48, 32, 48, 32, 49, 45, 49, 46, 52, 56, 86, 56, 46, 54, 51, 97, 49, 46, 54, 32, 49, 46, 54, 32, 48, 32, 48, 32, 48, 45, 49, 45, 49, 46, 52, 56, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 55, 32, 49, 53, 46, 53, 57, 108, 45, 50, 45, 50, 76, 56, 46, 52, 49, 32, 55, 108, 45, 50, 45, 50, 45, 49, 46, 55, 45, 49, 46, 55, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 32, 49, 46, 52, 50, 108, 46, 53, 52, 46, 53, 51, 76, 53, 46, 53, 57, 32, 55, 108, 57, 46, 51, 52, 32, 57, 46, 51, 52, 32, 49, 46, 52, 54, 32, 49, 46, 52, 54, 32, 50, 46, 57, 32, 50, 46, 57, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 50, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("volumeoff"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 118, 111, 108, 117, 109, 101, 45, 111, 102, 102, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 54, 46, 57, 49, 32, 49, 52, 46, 48, 56, 108, 49, 46, 52, 52, 32, 49, 46, 52, 52, 97, 54, 32, 54, 32, 48, 32, 48, 32, 48, 45, 46, 48, 55, 45, 55, 46, 49, 53, 32, 49, 32, 49, 32, 48, 32, 49, 32, 48, 45, 49, 46, 53, 54, 32, 49, 46, 50, 54, 32, 52, 32, 52, 32, 48, 32, 48, 32, 49, 32, 46, 49, 57, 32, 52, 46, 52, 53, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 49, 32, 49, 50, 97, 54, 46, 53, 49, 32, 54, 46, 53, 49, 32, 48, 32, 48, 32, 49, 45, 49, 46, 55, 56, 32, 52, 46, 51, 57, 108, 49, 46, 52, 50, 32, 49, 46, 52, 50, 65, 56, 46, 53, 51, 32, 56, 46, 53, 51, 32, 48, 32, 48, 32, 48, 32, 50, 51, 32, 49, 50, 97, 56, 46, 55, 53, 32, 56, 46, 55, 53, 32, 48, 32, 48, 32, 48, 45, 51, 46, 51, 54, 45, 54, 46, 55, 55, 32, 49, 32, 49, 32, 48, 32, 49, 32, 48, 45, 49, 46, 50, 56, 32, 49, 46, 53, 52, 65, 54, 46, 56, 32, 54, 46, 56, 32, 48, 32, 48, 32, 49, 32, 50, 49, 32, 49, 50, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 53, 32, 49, 50, 46, 49, 55, 86, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 53, 55, 45, 46, 56, 51, 76, 57, 32, 54, 46, 50, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 52, 46, 55, 52, 32, 55, 46, 53, 55, 72, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 32, 49, 118, 54, 46, 56, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 32, 49, 104, 53, 108, 54, 46, 52, 49, 32, 52, 46, 52, 65, 49, 46, 48, 54, 32, 49, 46, 48, 54, 32, 48, 32, 48, 32, 48, 32, 49, 52, 32, 50, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 45, 49, 118, 45, 50, 46, 49, 55, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 52, 46, 55, 49, 32, 51, 46, 50, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 32, 49, 46, 52, 50, 108, 49, 54, 32, 49, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 50, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("volumeup"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 118, 111, 108, 117, 109, 101, 45, 117, 112, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 56, 46, 50, 56, 32, 56, 46, 51, 55, 97, 49, 32, 49, 32, 48, 32, 49, 32, 48, 45, 49, 46, 53, 54, 32, 49, 46, 50, 54, 32, 52, 32, 52, 32, 48, 32, 48, 32, 49, 32, 48, 32, 52, 46, 55, 52, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 55, 46, 53, 32, 49, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 56, 45, 46, 51, 55, 32, 54, 32, 54, 32, 48, 32, 48, 32, 48, 32, 48, 45, 55, 46, 50, 54, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 57, 46, 54, 52, 32, 53, 46, 50, 51, 97, 49, 32, 49, 32, 48, 32, 49, 32, 48, 45, 49, 46, 50, 56, 32, 49, 46, 53, 52, 65, 54, 46, 56, 32, 54, 46, 56, 32, 48, 32, 48, 32, 49, 32, 50, 49, 32, 49, 50, 97, 54, 46, 56, 32, 54, 46, 56, 32, 48, 32, 48, 32, 49, 45, 50, 46, 54, 52, 32, 53, 46, 50, 51, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 49, 51, 32, 49, 46, 52, 49, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 57, 32, 49, 57, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 54, 52, 45, 46, 50, 51, 65, 56, 46, 55, 53, 32, 56, 46, 55, 53, 32, 48, 32, 48, 32, 48, 32, 50, 51, 32, 49, 50, 97, 56, 46, 55, 53, 32, 56, 46, 55, 53, 32, 48, 32, 48, 32, 48, 45, 51, 46, 51, 54, 45, 54, 46, 55, 55, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 52, 46, 52, 55, 32, 51, 46, 49, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 32, 48, 76, 55, 32, 55, 46, 53, 55, 72, 50, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 32, 49, 118, 54, 46, 56, 54, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 32, 49, 104, 53, 108, 54, 46, 52, 49, 32, 52, 46, 52, 65, 49, 46, 48, 54, 32, 49, 46, 48, 54, 32, 48, 32, 48, 32, 48, 32, 49, 52, 32, 50, 49, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 45, 49, 86, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 53, 51, 45, 46, 56, 56, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("water"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 50, 54, 53, 46, 49, 50, 44, 54, 48, 46, 49, 50, 97, 49, 50, 44, 49, 50, 44, 48, 44, 48, 44, 48, 45, 49, 56, 46, 50, 51, 44, 48, 67, 50, 49, 53, 46, 50, 51, 44, 57, 55, 46, 49, 53, 44, 49, 49, 50, 44, 50, 50, 53, 46, 49, 55, 44, 49, 49, 50, 44, 51, 50, 48, 99, 48, 44, 56, 56, 46, 51, 55, 44, 53, 53, 46, 54, 52, 44, 49, 52, 52, 44, 49, 52, 52, 44, 49, 52, 52, 115, 49, 52, 52, 45, 53, 53, 46, 54, 51, 44, 49, 52, 52, 45, 49, 52, 52, 67, 52, 48, 48, 44, 50, 50, 53, 46, 49, 55, 44, 50, 57, 54, 46, 55, 55, 44, 57, 55, 46, 49, 53, 44, 50, 54, 53, 46, 49, 50, 44, 54, 48, 46, 49, 50, 90, 77, 50, 55, 50, 44, 52, 49, 50, 97, 49, 50, 44, 49, 50, 44, 48, 44, 48, 44, 49, 45, 49, 49, 46, 51, 52, 45, 49, 54, 44, 49, 49, 46, 56, 57, 44, 49, 49, 46, 56, 57, 44, 48, 44, 48, 44, 49, 44, 49, 49, 46, 52, 49, 45, 56, 65, 54, 48, 46, 48, 54, 44, 54, 48, 46, 48, 54, 44, 48, 44, 48, 44, 48, 44, 51, 51, 50, 44, 51, 50, 56, 46, 48, 55, 97, 49, 49, 46, 56, 57, 44, 49, 49, 46, 56, 57, 44, 48, 44, 48, 44, 49, 44, 56, 45, 49, 49, 46, 52, 49, 65, 49, 50, 44, 49, 50, 44, 48, 44, 48, 44, 49, 44, 51, 53, 54, 44, 51, 50, 56, 44, 56, 52, 46, 48, 57, 44, 56, 52, 46, 48, 57, 44, 48, 44, 48, 44, 49, 44, 50, 55, 50, 44, 52, 49, 50, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("wifi"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 119, 105, 102, 105, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 99, 105, 114, 99, 108, 101, 32, 99, 120, 61, 34, 49, 50, 34, 32, 99, 121, 61, 34, 49, 57, 34, 32, 114, 61, 34, 49, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 49, 52, 97, 53, 32, 53, 32, 48, 32, 48, 32, 48, 45, 51, 46, 52, 55, 32, 49, 46, 52, 32, 49, 32, 49, 32, 48, 32, 49, 32, 48, 32, 49, 46, 51, 57, 32, 49, 46, 52, 52, 32, 51, 46, 48, 56, 32, 51, 46, 48, 56, 32, 48, 32, 48, 32, 49, 32, 52, 46, 49, 54, 32, 48, 32, 49, 32, 49, 32, 48, 32, 49, 32, 48, 32, 49, 46, 51, 57, 45, 49, 46, 52, 52, 65, 53, 32, 53, 32, 48, 32, 48, 32, 48, 32, 49, 50, 32, 49, 52, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 32, 57, 97, 57, 32, 57, 32, 48, 32, 48, 32, 48, 45, 54, 46, 52, 55, 32, 50, 46, 55, 53, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 55, 32, 49, 51, 46, 49, 52, 97, 55, 32, 55, 32, 48, 32, 48, 32, 49, 32, 49, 48, 46, 48, 56, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 49, 46, 51, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 50, 45, 49, 46, 54, 57, 65, 57, 32, 57, 32, 48, 32, 48, 32, 48, 32, 49, 50, 32, 57, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 49, 46, 55, 50, 32, 55, 46, 57, 51, 97, 49, 52, 32, 49, 52, 32, 48, 32, 48, 32, 48, 45, 49, 57, 46, 52, 52, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 51, 56, 32, 49, 46, 52, 52, 32, 49, 50, 32, 49, 50, 32, 48, 32, 48, 32, 49, 32, 49, 54, 46, 54, 56, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 54, 57, 46, 50, 56, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 50, 45, 46, 51, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 48, 51, 45, 49, 46, 52, 49, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("wifioff"), vec![60, 115, 118, 103, 32, 61440, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 50, 52, 32, 50, 52, 34, 32, 61441, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 76, 97, 121, 101, 114, 32, 50, 34, 62, 60, 103, 32, 100, 97, 116, 97, 45, 110, 97, 109, 101, 61, 34, 119, 105, 102, 105, 45, 111, 102, 102, 34, 62, 60, 114, 101, 99, 116, 32, 119, 105, 100, 116, 104, 61, 34, 50, 52, 34, 32, 104, 101, 105, 103, 104, 116, 61, 34, 50, 52, 34, 32, 111, 112, 97, 99, 105, 116, 121, 61, 34, 48, 34, 47, 62, 60, 99, 105, 114, 99, 108, 101, 32, 99, 120, 61, 34, 49, 50, 34, 32, 99, 121, 61, 34, 49, 57, 34, 32, 114, 61, 34, 49, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 50, 46, 52, 52, 32, 49, 49, 108, 45, 49, 46, 57, 45, 49, 46, 56, 57, 45, 50, 46, 52, 54, 45, 50, 46, 52, 52, 45, 49, 46, 53, 53, 45, 49, 46, 53, 53, 45, 49, 46, 56, 50, 45, 49, 46, 56, 51, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 32, 49, 46, 52, 50, 108, 49, 46, 51, 56, 32, 49, 46, 51, 55, 32, 49, 46, 52, 54, 32, 49, 46, 52, 54, 32, 50, 46, 50, 51, 32, 50, 46, 50, 52, 32, 49, 46, 53, 53, 32, 49, 46, 53, 52, 32, 50, 46, 55, 52, 32, 50, 46, 55, 52, 32, 50, 46, 55, 57, 32, 50, 46, 56, 32, 51, 46, 56, 53, 32, 51, 46, 56, 53, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 52, 50, 32, 48, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 48, 45, 49, 46, 52, 50, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 50, 49, 46, 55, 50, 32, 55, 46, 57, 51, 65, 49, 51, 46, 57, 51, 32, 49, 51, 46, 57, 51, 32, 48, 32, 48, 32, 48, 32, 49, 50, 32, 52, 97, 49, 52, 46, 49, 32, 49, 52, 46, 49, 32, 48, 32, 48, 32, 48, 45, 52, 46, 52, 52, 46, 55, 51, 108, 49, 46, 54, 50, 32, 49, 46, 54, 50, 97, 49, 49, 46, 56, 57, 32, 49, 49, 46, 56, 57, 32, 48, 32, 48, 32, 49, 32, 49, 49, 46, 49, 54, 32, 51, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 54, 57, 46, 50, 56, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 50, 45, 46, 51, 49, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 45, 46, 48, 51, 45, 49, 46, 51, 57, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 51, 46, 56, 50, 32, 54, 46, 54, 53, 97, 49, 52, 46, 51, 50, 32, 49, 52, 46, 51, 50, 32, 48, 32, 48, 32, 48, 45, 49, 46, 53, 52, 32, 49, 46, 50, 56, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 49, 46, 51, 56, 32, 49, 46, 52, 52, 32, 49, 51, 46, 48, 57, 32, 49, 51, 46, 48, 57, 32, 48, 32, 48, 32, 49, 32, 49, 46, 54, 45, 49, 46, 50, 57, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 49, 55, 32, 49, 51, 46, 49, 52, 97, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 49, 46, 51, 32, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 46, 55, 50, 45, 49, 46, 54, 57, 65, 57, 32, 57, 32, 48, 32, 48, 32, 48, 32, 49, 50, 32, 57, 104, 45, 46, 49, 54, 108, 50, 46, 51, 53, 32, 50, 46, 51, 53, 65, 55, 32, 55, 32, 48, 32, 48, 32, 49, 32, 49, 55, 32, 49, 51, 46, 49, 52, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 55, 46, 52, 51, 32, 49, 48, 46, 50, 54, 97, 56, 46, 56, 32, 56, 46, 56, 32, 48, 32, 48, 32, 48, 45, 49, 46, 57, 32, 49, 46, 52, 57, 65, 49, 32, 49, 32, 48, 32, 48, 32, 48, 32, 55, 32, 49, 51, 46, 49, 52, 97, 55, 46, 51, 32, 55, 46, 51, 32, 48, 32, 48, 32, 49, 32, 50, 45, 49, 46, 52, 49, 122, 34, 32, 61442, 47, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 56, 46, 53, 51, 32, 49, 53, 46, 52, 97, 49, 32, 49, 32, 48, 32, 49, 32, 48, 32, 49, 46, 51, 57, 32, 49, 46, 52, 52, 32, 51, 46, 48, 54, 32, 51, 46, 48, 54, 32, 48, 32, 48, 32, 49, 32, 51, 46, 56, 52, 45, 46, 50, 53, 108, 45, 50, 46, 53, 50, 45, 50, 46, 53, 50, 97, 53, 32, 53, 32, 48, 32, 48, 32, 48, 45, 50, 46, 55, 49, 32, 49, 46, 51, 51, 122, 34, 32, 61442, 47, 62, 60, 47, 103, 62, 60, 47, 103, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("wine"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 53, 49, 50, 32, 53, 49, 50, 34, 62, 60, 112, 97, 116, 104, 32, 61442, 32, 100, 61, 34, 77, 52, 49, 52, 46, 53, 54, 44, 57, 52, 46, 57, 50, 86, 56, 48, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 45, 49, 54, 45, 49, 54, 72, 49, 49, 51, 46, 52, 52, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 45, 49, 54, 44, 49, 54, 86, 57, 52, 46, 57, 50, 99, 45, 49, 46, 52, 54, 44, 49, 49, 46, 51, 55, 45, 57, 46, 54, 53, 44, 57, 48, 46, 55, 52, 44, 51, 54, 46, 57, 51, 44, 49, 52, 52, 46, 54, 57, 44, 50, 52, 46, 56, 55, 44, 50, 56, 46, 56, 44, 54, 48, 46, 51, 54, 44, 52, 52, 46, 56, 53, 44, 49, 48, 53, 46, 54, 51, 44, 52, 55, 46, 56, 54, 86, 52, 49, 54, 72, 49, 54, 48, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 48, 44, 51, 50, 72, 51, 53, 50, 97, 49, 54, 44, 49, 54, 44, 48, 44, 48, 44, 48, 44, 48, 45, 51, 50, 72, 50, 55, 50, 86, 50, 56, 55, 46, 52, 55, 99, 52, 53, 46, 50, 55, 45, 51, 44, 56, 48, 46, 55, 54, 45, 49, 57, 46, 48, 54, 44, 49, 48, 53, 46, 54, 51, 45, 52, 55, 46, 56, 54, 67, 52, 50, 52, 46, 50, 49, 44, 49, 56, 53, 46, 54, 54, 44, 52, 49, 54, 44, 49, 48, 54, 46, 50, 57, 44, 52, 49, 52, 46, 53, 54, 44, 57, 52, 46, 57, 50, 90, 109, 45, 50, 56, 53, 46, 51, 44, 51, 46, 52, 49, 97, 49, 53, 46, 49, 52, 44, 49, 53, 46, 49, 52, 44, 48, 44, 48, 44, 48, 44, 46, 49, 56, 45, 50, 46, 51, 51, 72, 51, 56, 50, 46, 53, 54, 97, 49, 53, 46, 49, 52, 44, 49, 53, 46, 49, 52, 44, 48, 44, 48, 44, 48, 44, 46, 49, 56, 44, 50, 46, 51, 51, 44, 50, 48, 49, 46, 57, 49, 44, 50, 48, 49, 46, 57, 49, 44, 48, 44, 48, 44, 49, 44, 48, 44, 52, 53, 46, 54, 55, 72, 49, 50, 57, 46, 51, 50, 65, 50, 48, 52, 46, 50, 57, 44, 50, 48, 52, 46, 50, 57, 44, 48, 44, 48, 44, 49, 44, 49, 50, 57, 46, 50, 54, 44, 57, 56, 46, 51, 51, 90, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
-result.insert(into_v16("youtube"), vec![60, 115, 118, 103, 32, 61440, 32, 61441, 32, 61442, 32, 99, 108, 97, 115, 115, 61, 34, 98, 105, 32, 98, 105, 45, 121, 111, 117, 116, 117, 98, 101, 34, 32, 118, 105, 101, 119, 66, 111, 120, 61, 34, 48, 32, 48, 32, 49, 54, 32, 49, 54, 34, 62, 60, 112, 97, 116, 104, 32, 100, 61, 34, 77, 56, 46, 48, 53, 49, 32, 49, 46, 57, 57, 57, 104, 46, 48, 56, 57, 99, 46, 56, 50, 50, 46, 48, 48, 51, 32, 52, 46, 57, 56, 55, 46, 48, 51, 51, 32, 54, 46, 49, 49, 46, 51, 51, 53, 97, 50, 46, 48, 49, 32, 50, 46, 48, 49, 32, 48, 32, 48, 32, 49, 32, 49, 46, 52, 49, 53, 32, 49, 46, 52, 50, 99, 46, 49, 48, 49, 46, 51, 56, 46, 49, 55, 50, 46, 56, 56, 51, 46, 50, 50, 32, 49, 46, 52, 48, 50, 108, 46, 48, 49, 46, 49, 48, 52, 46, 48, 50, 50, 46, 50, 54, 46, 48, 48, 56, 46, 49, 48, 52, 99, 46, 48, 54, 53, 46, 57, 49, 52, 46, 48, 55, 51, 32, 49, 46, 55, 55, 46, 48, 55, 52, 32, 49, 46, 57, 53, 55, 118, 46, 48, 55, 53, 99, 45, 46, 48, 48, 49, 46, 49, 57, 52, 45, 46, 48, 49, 32, 49, 46, 49, 48, 56, 45, 46, 48, 56, 50, 32, 50, 46, 48, 54, 108, 45, 46, 48, 48, 56, 46, 49, 48, 53, 45, 46, 48, 48, 57, 46, 49, 48, 52, 99, 45, 46, 48, 53, 46, 53, 55, 50, 45, 46, 49, 50, 52, 32, 49, 46, 49, 52, 45, 46, 50, 51, 53, 32, 49, 46, 53, 53, 56, 97, 50, 46, 48, 48, 55, 32, 50, 46, 48, 48, 55, 32, 48, 32, 48, 32, 49, 45, 49, 46, 52, 49, 53, 32, 49, 46, 52, 50, 99, 45, 49, 46, 49, 54, 46, 51, 49, 50, 45, 53, 46, 53, 54, 57, 46, 51, 51, 52, 45, 54, 46, 49, 56, 46, 51, 51, 53, 104, 45, 46, 49, 52, 50, 99, 45, 46, 51, 48, 57, 32, 48, 45, 49, 46, 53, 56, 55, 45, 46, 48, 48, 54, 45, 50, 46, 57, 50, 55, 45, 46, 48, 53, 50, 108, 45, 46, 49, 55, 45, 46, 48, 48, 54, 45, 46, 48, 56, 55, 45, 46, 48, 48, 52, 45, 46, 49, 55, 49, 45, 46, 48, 48, 55, 45, 46, 49, 55, 49, 45, 46, 48, 48, 55, 99, 45, 49, 46, 49, 49, 45, 46, 48, 52, 57, 45, 50, 46, 49, 54, 55, 45, 46, 49, 50, 56, 45, 50, 46, 54, 53, 52, 45, 46, 50, 54, 97, 50, 46, 48, 48, 55, 32, 50, 46, 48, 48, 55, 32, 48, 32, 48, 32, 49, 45, 49, 46, 52, 49, 53, 45, 49, 46, 52, 49, 57, 99, 45, 46, 49, 49, 49, 45, 46, 52, 49, 55, 45, 46, 49, 56, 53, 45, 46, 57, 56, 54, 45, 46, 50, 51, 53, 45, 49, 46, 53, 53, 56, 76, 46, 48, 57, 32, 57, 46, 56, 50, 108, 45, 46, 48, 48, 56, 45, 46, 49, 48, 52, 65, 51, 49, 46, 52, 32, 51, 49, 46, 52, 32, 48, 32, 48, 32, 49, 32, 48, 32, 55, 46, 54, 56, 118, 45, 46, 49, 50, 51, 99, 46, 48, 48, 50, 45, 46, 50, 49, 53, 46, 48, 49, 45, 46, 57, 53, 56, 46, 48, 54, 52, 45, 49, 46, 55, 55, 56, 108, 46, 48, 48, 55, 45, 46, 49, 48, 51, 46, 48, 48, 51, 45, 46, 48, 53, 50, 46, 48, 48, 56, 45, 46, 49, 48, 52, 46, 48, 50, 50, 45, 46, 50, 54, 46, 48, 49, 45, 46, 49, 48, 52, 99, 46, 48, 52, 56, 45, 46, 53, 49, 57, 46, 49, 49, 57, 45, 49, 46, 48, 50, 51, 46, 50, 50, 45, 49, 46, 52, 48, 50, 97, 50, 46, 48, 48, 55, 32, 50, 46, 48, 48, 55, 32, 48, 32, 48, 32, 49, 32, 49, 46, 52, 49, 53, 45, 49, 46, 52, 50, 99, 46, 52, 56, 55, 45, 46, 49, 51, 32, 49, 46, 53, 52, 52, 45, 46, 50, 49, 32, 50, 46, 54, 53, 52, 45, 46, 50, 54, 108, 46, 49, 55, 45, 46, 48, 48, 55, 46, 49, 55, 50, 45, 46, 48, 48, 54, 46, 48, 56, 54, 45, 46, 48, 48, 51, 46, 49, 55, 49, 45, 46, 48, 48, 55, 65, 57, 57, 46, 55, 56, 56, 32, 57, 57, 46, 55, 56, 56, 32, 48, 32, 48, 32, 49, 32, 55, 46, 56, 53, 56, 32, 50, 104, 46, 49, 57, 51, 122, 77, 54, 46, 52, 32, 53, 46, 50, 48, 57, 118, 52, 46, 56, 49, 56, 108, 52, 46, 49, 53, 55, 45, 50, 46, 52, 48, 56, 76, 54, 46, 52, 32, 53, 46, 50, 48, 57, 122, 34, 47, 62, 60, 47, 115, 118, 103, 62]);
+result.insert("alerttriangle", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"alert-triangle\"><rect width=\"24\" height=\"24\" transform=\"rotate(90 12 12)\" opacity=\"0\"/><path d=\"M22.56 16.3L14.89 3.58a3.43 3.43 0 0 0-5.78 0L1.44 16.3a3 3 0 0 0-.05 3A3.37 3.37 0 0 0 4.33 21h15.34a3.37 3.37 0 0 0 2.94-1.66 3 3 0 0 0-.05-3.04zM12 17a1 1 0 1 1 1-1 1 1 0 0 1-1 1zm1-4a1 1 0 0 1-2 0V9a1 1 0 0 1 2 0z\" \u{f002}/></g></g></svg>");
+result.insert("arrowback", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"arrow-back\"><rect width=\"24\" height=\"24\" transform=\"rotate(90 12 12)\" opacity=\"0\"/><path d=\"M19 11H7.14l3.63-4.36a1 1 0 1 0-1.54-1.28l-5 6a1.19 1.19 0 0 0-.09.15c0 .05 0 .08-.07.13A1 1 0 0 0 4 12a1 1 0 0 0 .07.36c0 .05 0 .08.07.13a1.19 1.19 0 0 0 .09.15l5 6A1 1 0 0 0 10 19a1 1 0 0 0 .64-.23 1 1 0 0 0 .13-1.41L7.14 13H19a1 1 0 0 0 0-2z\" \u{f002}/></g></g></svg>");
+result.insert("arrowdown", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"arrow-down\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M18.77 13.36a1 1 0 0 0-1.41-.13L13 16.86V5a1 1 0 0 0-2 0v11.86l-4.36-3.63a1 1 0 1 0-1.28 1.54l6 5 .15.09.13.07a1 1 0 0 0 .72 0l.13-.07.15-.09 6-5a1 1 0 0 0 .13-1.41z\" \u{f002}/></g></g></svg>");
+result.insert("arrowforward", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"arrow-forward\"><rect width=\"24\" height=\"24\" transform=\"rotate(-90 12 12)\" opacity=\"0\"/><path d=\"M5 13h11.86l-3.63 4.36a1 1 0 0 0 1.54 1.28l5-6a1.19 1.19 0 0 0 .09-.15c0-.05.05-.08.07-.13A1 1 0 0 0 20 12a1 1 0 0 0-.07-.36c0-.05-.05-.08-.07-.13a1.19 1.19 0 0 0-.09-.15l-5-6A1 1 0 0 0 14 5a1 1 0 0 0-.64.23 1 1 0 0 0-.13 1.41L16.86 11H5a1 1 0 0 0 0 2z\" \u{f002}/></g></g></svg>");
+result.insert("arrowheaddown", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"arrowhead-down\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M17.37 12.39L12 16.71l-5.36-4.48a1 1 0 1 0-1.28 1.54l6 5a1 1 0 0 0 1.27 0l6-4.83a1 1 0 0 0 .15-1.41 1 1 0 0 0-1.41-.14z\" \u{f002}/><path d=\"M11.36 11.77a1 1 0 0 0 1.27 0l6-4.83a1 1 0 0 0 .15-1.41 1 1 0 0 0-1.41-.15L12 9.71 6.64 5.23a1 1 0 0 0-1.28 1.54z\" \u{f002}/></g></g></svg>");
+result.insert("arrowheadleft", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"arrowhead-left\"><rect width=\"24\" height=\"24\" transform=\"rotate(90 12 12)\" opacity=\"0\"/><path d=\"M11.64 5.23a1 1 0 0 0-1.41.13l-5 6a1 1 0 0 0 0 1.27l4.83 6a1 1 0 0 0 .78.37 1 1 0 0 0 .78-1.63L7.29 12l4.48-5.37a1 1 0 0 0-.13-1.4z\" \u{f002}/><path d=\"M14.29 12l4.48-5.37a1 1 0 0 0-1.54-1.28l-5 6a1 1 0 0 0 0 1.27l4.83 6a1 1 0 0 0 .78.37 1 1 0 0 0 .78-1.63z\" \u{f002}/></g></g></svg>");
+result.insert("arrowheadright", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"arrowhead-right\"><rect width=\"24\" height=\"24\" transform=\"rotate(-90 12 12)\" opacity=\"0\"/><path d=\"M18.78 11.37l-4.78-6a1 1 0 0 0-1.41-.15 1 1 0 0 0-.15 1.41L16.71 12l-4.48 5.37a1 1 0 0 0 .13 1.41A1 1 0 0 0 13 19a1 1 0 0 0 .77-.36l5-6a1 1 0 0 0 .01-1.27z\" \u{f002}/><path d=\"M7 5.37a1 1 0 0 0-1.61 1.26L9.71 12l-4.48 5.36a1 1 0 0 0 .13 1.41A1 1 0 0 0 6 19a1 1 0 0 0 .77-.36l5-6a1 1 0 0 0 0-1.27z\" \u{f002}/></g></g></svg>");
+result.insert("arrowheadup", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"arrowhead-up\"><rect width=\"24\" height=\"24\" transform=\"rotate(180 12 12)\" opacity=\"0\"/><path d=\"M6.63 11.61L12 7.29l5.37 4.48A1 1 0 0 0 18 12a1 1 0 0 0 .77-.36 1 1 0 0 0-.13-1.41l-6-5a1 1 0 0 0-1.27 0l-6 4.83a1 1 0 0 0-.15 1.41 1 1 0 0 0 1.41.14z\" \u{f002}/><path d=\"M12.64 12.23a1 1 0 0 0-1.27 0l-6 4.83a1 1 0 0 0-.15 1.41 1 1 0 0 0 1.41.15L12 14.29l5.37 4.48A1 1 0 0 0 18 19a1 1 0 0 0 .77-.36 1 1 0 0 0-.13-1.41z\" \u{f002}/></g></g></svg>");
+result.insert("arrowupward", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"arrow-upward\"><rect width=\"24\" height=\"24\" transform=\"rotate(180 12 12)\" opacity=\"0\"/><path d=\"M5.23 10.64a1 1 0 0 0 1.41.13L11 7.14V19a1 1 0 0 0 2 0V7.14l4.36 3.63a1 1 0 1 0 1.28-1.54l-6-5-.15-.09-.13-.07a1 1 0 0 0-.72 0l-.13.07-.15.09-6 5a1 1 0 0 0-.13 1.41z\" \u{f002}/></g></g></svg>");
+result.insert("at", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"at\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M13 2a10 10 0 0 0-5 19.1 10.15 10.15 0 0 0 4 .9 10 10 0 0 0 6.08-2.06 1 1 0 0 0 .19-1.4 1 1 0 0 0-1.41-.19A8 8 0 1 1 12.77 4 8.17 8.17 0 0 1 20 12.22v.68a1.71 1.71 0 0 1-1.78 1.7 1.82 1.82 0 0 1-1.62-1.88V8.4a1 1 0 0 0-1-1 1 1 0 0 0-1 .87 5 5 0 0 0-3.44-1.36A5.09 5.09 0 1 0 15.31 15a3.6 3.6 0 0 0 5.55.61A3.67 3.67 0 0 0 22 12.9v-.68A10.2 10.2 0 0 0 13 2zm-1.82 13.09A3.09 3.09 0 1 1 14.27 12a3.1 3.1 0 0 1-3.09 3.09z\" \u{f002}/></g></g></svg>");
+result.insert("attach", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"attach\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M9.29 21a6.23 6.23 0 0 1-4.43-1.88 6 6 0 0 1-.22-8.49L12 3.2A4.11 4.11 0 0 1 15 2a4.48 4.48 0 0 1 3.19 1.35 4.36 4.36 0 0 1 .15 6.13l-7.4 7.43a2.54 2.54 0 0 1-1.81.75 2.72 2.72 0 0 1-1.95-.82 2.68 2.68 0 0 1-.08-3.77l6.83-6.86a1 1 0 0 1 1.37 1.41l-6.83 6.86a.68.68 0 0 0 .08.95.78.78 0 0 0 .53.23.56.56 0 0 0 .4-.16l7.39-7.43a2.36 2.36 0 0 0-.15-3.31 2.38 2.38 0 0 0-3.27-.15L6.06 12a4 4 0 0 0 .22 5.67 4.22 4.22 0 0 0 3 1.29 3.67 3.67 0 0 0 2.61-1.06l7.39-7.43a1 1 0 1 1 1.42 1.41l-7.39 7.43A5.65 5.65 0 0 1 9.29 21z\" \u{f002}/></g></g></svg>");
+result.insert("beer", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M392,208H368v-5.74A63.93,63.93,0,0,0,321.65,96a111,111,0,0,0-27.59-47.29A108.62,108.62,0,0,0,216,16c-29.91,0-57.78,12.28-79,34.68a56,56,0,0,0-67.51,77.54A63.91,63.91,0,0,0,80,231.39V440a56.06,56.06,0,0,0,56,56H312a56.06,56.06,0,0,0,56-56v-8h24a72.08,72.08,0,0,0,72-72V280A72.08,72.08,0,0,0,392,208ZM176,416a16,16,0,0,1-32,0V256a16,16,0,0,1,32,0Zm64,0a16,16,0,0,1-32,0V256a16,16,0,0,1,32,0Zm64,0a16,16,0,0,1-32,0V256a16,16,0,0,1,32,0Zm16-224c-8.33,0-20.55-5.18-26.69-11.31A16,16,0,0,0,282,176H160a16,16,0,0,0-15,10.53C138.17,205.21,121.4,208,112,208a32,32,0,0,1,0-64c.09,0,9.12.34,16.4,5.8a16,16,0,1,0,19.2-25.6A63.69,63.69,0,0,0,112,112a63.55,63.55,0,0,0-14,1.57A24,24,0,0,1,120,80a23.78,23.78,0,0,1,19.38,9.84,51.35,51.35,0,0,1,4.71,7.9A16,16,0,0,0,176,96c0-6.77-3.61-15.17-10.76-25-.46-.63-1-1.25-1.45-1.86C178.39,55.44,196.64,48,216,48a76.86,76.86,0,0,1,55.23,23.18A80.2,80.2,0,0,1,292.61,142a16,16,0,0,0,12.73,18.71,16.29,16.29,0,0,0,3,.28,16,16,0,0,0,15.7-13A111.78,111.78,0,0,0,326,128.57,32,32,0,0,1,320,192ZM432,360a40,40,0,0,1-40,40H368V240h24a40,40,0,0,1,40,40Z\"/></svg>");
+result.insert("bell", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"bell\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M20.52 15.21l-1.8-1.81V8.94a6.86 6.86 0 0 0-5.82-6.88 6.74 6.74 0 0 0-7.62 6.67v4.67l-1.8 1.81A1.64 1.64 0 0 0 4.64 18H8v.34A3.84 3.84 0 0 0 12 22a3.84 3.84 0 0 0 4-3.66V18h3.36a1.64 1.64 0 0 0 1.16-2.79zM14 18.34A1.88 1.88 0 0 1 12 20a1.88 1.88 0 0 1-2-1.66V18h4z\" \u{f002}/></g></g></svg>");
+result.insert("belloff", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"bell-off\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M15.88 18.71l-.59-.59L14 16.78l-.07-.07L6.58 9.4 5.31 8.14a5.68 5.68 0 0 0 0 .59v4.67l-1.8 1.81A1.64 1.64 0 0 0 4.64 18H8v.34A3.84 3.84 0 0 0 12 22a3.88 3.88 0 0 0 4-3.22zM14 18.34A1.88 1.88 0 0 1 12 20a1.88 1.88 0 0 1-2-1.66V18h4z\" \u{f002}/><path d=\"M7.13 4.3l1.46 1.46 9.53 9.53 2 2 .31.3a1.58 1.58 0 0 0 .45-.6 1.62 1.62 0 0 0-.35-1.78l-1.8-1.81V8.94a6.86 6.86 0 0 0-5.83-6.88 6.71 6.71 0 0 0-5.32 1.61 6.88 6.88 0 0 0-.58.54z\" \u{f002}/><path d=\"M20.71 19.29L19.41 18l-2-2-9.52-9.53L6.42 5 4.71 3.29a1 1 0 0 0-1.42 1.42L5.53 7l1.75 1.7 7.31 7.3.07.07L16 17.41l.59.59 2.7 2.71a1 1 0 0 0 1.42 0 1 1 0 0 0 0-1.42z\" \u{f002}/></g></g></svg>");
+result.insert("bug", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M463.55,272.13H400v-48.2q0-4.32-.27-8.47c29.57-27.88,32.25-64.63,32.27-103,0-8.61-6.64-16-15.25-16.41A16,16,0,0,0,400,112c0,28-1.86,48.15-9.9,63.84-19.22-41.15-65.78-63.91-134.1-63.91-39.8,0-74.19,9.13-99.43,26.39-14.9,10.19-26.2,22.91-33.7,37.72C114,160.65,112,141,112,112.46c0-8.61-6.6-16-15.2-16.44A16,16,0,0,0,80,112c0,37.63,2.61,73.73,32.44,101.63q-.43,5.06-.44,10.3v48.2H48.45c-8.61,0-16,6.62-16.43,15.23a16,16,0,0,0,16,16.77h64V320a143.32,143.32,0,0,0,10.39,53.69C96.74,396.64,80.18,422,80,463.34c0,8.74,6.62,16.3,15.36,16.65A16,16,0,0,0,112,464c0-27.66,9.1-44.71,26.17-61.32A144.37,144.37,0,0,0,220,459.42a16,16,0,0,0,20-15.49V192.45c0-8.61,6.62-16,15.23-16.43A16,16,0,0,1,272,192V443.93a16,16,0,0,0,20,15.49,144.4,144.4,0,0,0,81.82-56.74c17,16.54,26.09,33.52,26.17,60.95A16.27,16.27,0,0,0,415.09,480,16,16,0,0,0,432,464c0-41.68-16.6-67.23-42.39-90.31A143.32,143.32,0,0,0,400,320V304.13h64a16,16,0,0,0,16-16.77C479.58,278.75,472.16,272.13,463.55,272.13Z\"/><path \u{f002} d=\"M321.39,104l.32.09c13.57,3.8,25.07-10.55,18.2-22.85A95.86,95.86,0,0,0,256.21,32h-.42A95.87,95.87,0,0,0,171.6,82.13c-6.84,12.58,5.14,27,18.84,22.86,19.71-6,41.79-9.06,65.56-9.06C280.09,95.93,302.09,98.65,321.39,104Z\"/></svg>");
+result.insert("bulb", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"bulb\"><rect width=\"24\" height=\"24\" transform=\"rotate(180 12 12)\" opacity=\"0\"/><path d=\"M12 7a5 5 0 0 0-3 9v4a2 2 0 0 0 2 2h2a2 2 0 0 0 2-2v-4a5 5 0 0 0-3-9z\" \u{f002}/><path d=\"M12 6a1 1 0 0 0 1-1V3a1 1 0 0 0-2 0v2a1 1 0 0 0 1 1z\" \u{f002}/><path d=\"M21 11h-2a1 1 0 0 0 0 2h2a1 1 0 0 0 0-2z\" \u{f002}/><path d=\"M5 11H3a1 1 0 0 0 0 2h2a1 1 0 0 0 0-2z\" \u{f002}/><path d=\"M7.66 6.42L6.22 5a1 1 0 0 0-1.39 1.47l1.44 1.39a1 1 0 0 0 .73.28 1 1 0 0 0 .72-.31 1 1 0 0 0-.06-1.41z\" \u{f002}/><path d=\"M19.19 5.05a1 1 0 0 0-1.41 0l-1.44 1.37a1 1 0 0 0 0 1.41 1 1 0 0 0 .72.31 1 1 0 0 0 .69-.28l1.44-1.39a1 1 0 0 0 0-1.42z\" \u{f002}/></g></g></svg>");
+result.insert("c", "<svg \u{f000} viewBox=\"0 0 128 128\" \u{f001}><path \u{f002} d=\"M117.5 33.5l.3-.2c-.6-1.1-1.5-2.1-2.4-2.6L67.1 2.9c-.8-.5-1.9-.7-3.1-.7-1.2 0-2.3.3-3.1.7l-48 27.9c-1.7 1-2.9 3.5-2.9 5.4v55.7c0 1.1.2 2.3.9 3.4l-.2.1c.5.8 1.2 1.5 1.9 1.9l48.2 27.9c.8.5 1.9.7 3.1.7 1.2 0 2.3-.3 3.1-.7l48-27.9c1.7-1 2.9-3.5 2.9-5.4V36.1c.1-.8 0-1.7-.4-2.6zM64 88.5c9.1 0 17.1-5 21.3-12.4l12.9 7.6c-6.8 11.8-19.6 19.8-34.2 19.8-21.8 0-39.5-17.7-39.5-39.5S42.2 24.5 64 24.5c14.7 0 27.5 8.1 34.3 20l-13 7.5C81.1 44.5 73.1 39.5 64 39.5c-13.5 0-24.5 11-24.5 24.5s11 24.5 24.5 24.5z\"/></svg>");
+result.insert("car", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M447.68,220.78a16,16,0,0,0-1-3.08l-37.78-88.16C400.19,109.17,379,96,354.89,96H157.11c-24.09,0-45.3,13.17-54,33.54L65.29,217.7A15.72,15.72,0,0,0,64,224V400a16,16,0,0,0,16,16h32a16,16,0,0,0,16-16V384H384v16a16,16,0,0,0,16,16h32a16,16,0,0,0,16-16V224A16.15,16.15,0,0,0,447.68,220.78ZM144,320a32,32,0,1,1,32-32A32,32,0,0,1,144,320Zm224,0a32,32,0,1,1,32-32A32,32,0,0,1,368,320ZM104.26,208l28.23-65.85C136.11,133.69,146,128,157.11,128H354.89c11.1,0,21,5.69,24.62,14.15L407.74,208Z\"/></svg>");
+result.insert("cart", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><circle \u{f002} cx=\"176\" cy=\"416\" r=\"32\"/><circle \u{f002} cx=\"400\" cy=\"416\" r=\"32\"/><path \u{f002} d=\"M456.8,120.78A23.92,23.92,0,0,0,438.24,112H133.89l-6.13-34.78A16,16,0,0,0,112,64H48a16,16,0,0,0,0,32H98.58l45.66,258.78A16,16,0,0,0,160,368H416a16,16,0,0,0,0-32H173.42l-5.64-32H409.44A24.07,24.07,0,0,0,433,284.71l28.8-144A24,24,0,0,0,456.8,120.78Z\"/></svg>");
+result.insert("chip", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path d=\"M352,128H160a32,32,0,0,0-32,32V352a32,32,0,0,0,32,32H352a32,32,0,0,0,32-32V160A32,32,0,0,0,352,128Zm0,216a8,8,0,0,1-8,8H168a8,8,0,0,1-8-8V168a8,8,0,0,1,8-8H344a8,8,0,0,1,8,8Z\" style=\"fill:none\"/><rect \u{f002} x=\"160\" y=\"160\" width=\"192\" height=\"192\" rx=\"8\" ry=\"8\"/><path \u{f002} d=\"M464,192a16,16,0,0,0,0-32H448V128a64.07,64.07,0,0,0-64-64H352V48a16,16,0,0,0-32,0V64H272V48a16,16,0,0,0-32,0V64H192V48a16,16,0,0,0-32,0V64H128a64.07,64.07,0,0,0-64,64v32H48a16,16,0,0,0,0,32H64v48H48a16,16,0,0,0,0,32H64v48H48a16,16,0,0,0,0,32H64v32a64.07,64.07,0,0,0,64,64h32v16a16,16,0,0,0,32,0V448h48v16a16,16,0,0,0,32,0V448h48v16a16,16,0,0,0,32,0V448h32a64.07,64.07,0,0,0,64-64V352h16a16,16,0,0,0,0-32H448V272h16a16,16,0,0,0,0-32H448V192ZM384,352a32,32,0,0,1-32,32H160a32,32,0,0,1-32-32V160a32,32,0,0,1,32-32H352a32,32,0,0,1,32,32Z\"/></svg>");
+result.insert("close", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"close\"><rect width=\"24\" height=\"24\" transform=\"rotate(180 12 12)\" opacity=\"0\"/><path d=\"M13.41 12l4.3-4.29a1 1 0 1 0-1.42-1.42L12 10.59l-4.29-4.3a1 1 0 0 0-1.42 1.42l4.3 4.29-4.3 4.29a1 1 0 0 0 0 1.42 1 1 0 0 0 1.42 0l4.29-4.3 4.29 4.3a1 1 0 0 0 1.42 0 1 1 0 0 0 0-1.42z\" \u{f002}/></g></g></svg>");
+result.insert("cpp", "<svg \u{f000} viewBox=\"0 0 128 128\" \u{f001}><path \u{f002} d=\"M117.5 33.5l.3-.2c-.6-1.1-1.5-2.1-2.4-2.6L67.1 2.9c-.8-.5-1.9-.7-3.1-.7-1.2 0-2.3.3-3.1.7l-48 27.9c-1.7 1-2.9 3.5-2.9 5.4v55.7c0 1.1.2 2.3.9 3.4l-.2.1c.5.8 1.2 1.5 1.9 1.9l48.2 27.9c.8.5 1.9.7 3.1.7 1.2 0 2.3-.3 3.1-.7l48-27.9c1.7-1 2.9-3.5 2.9-5.4V36.1c.1-.8 0-1.7-.4-2.6zM82 66v-4h5v-5h5v5h5v4h-5v5h-5v-5h-5zm3.3-14C81.1 44.5 73.1 39.5 64 39.5c-13.5 0-24.5 11-24.5 24.5s11 24.5 24.5 24.5c9.1 0 17.1-5 21.3-12.4l12.9 7.6c-6.8 11.8-19.6 19.8-34.2 19.8-21.8 0-39.5-17.7-39.5-39.5S42.2 24.5 64 24.5c14.7 0 27.5 8.1 34.3 20l-13 7.5zM115 66h-5v5h-4v-5h-6v-4h6v-5h4v5h5v4z\"/></svg>");
+result.insert("css", "<svg \u{f000} viewBox=\"0 0 128 128\" \u{f001}><path \u{f002} d=\"M8.76 1l10.055 112.883 45.118 12.58 45.244-12.626L119.24 1H8.76zm89.591 25.862l-3.347 37.605.01.203-.014.467v-.004l-2.378 26.294-.262 2.336L64 101.607v.001l-.022.019-28.311-7.888L33.75 72h13.883l.985 11.054 15.386 4.17-.004.008v-.002l15.443-4.229L81.075 65H48.792l-.277-3.043-.631-7.129L47.553 51h34.749l1.264-14H30.64l-.277-3.041-.63-7.131L29.401 23h69.281l-.331 3.862z\"/></svg>");
+result.insert("electricity", "<svg \u{f000} enable-background=\"new 0 0 24 24\" viewBox=\"0 0 24 24\" \u{f001} \u{f002}><g><rect fill=\"none\" height=\"24\" width=\"24\"/></g><g><path d=\"M14.69,2.21L4.33,11.49c-0.64,0.58-0.28,1.65,0.58,1.73L13,14l-4.85,6.76c-0.22,0.31-0.19,0.74,0.08,1.01h0 c0.3,0.3,0.77,0.31,1.08,0.02l10.36-9.28c0.64-0.58,0.28-1.65-0.58-1.73L11,10l4.85-6.76c0.22-0.31,0.19-0.74-0.08-1.01l0,0 C15.47,1.93,15,1.92,14.69,2.21z\" \u{f002}/></g></svg>");
+result.insert("eye", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"eye\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><circle cx=\"12\" cy=\"12\" r=\"1.5\" \u{f002}/><path d=\"M21.87 11.5c-.64-1.11-4.16-6.68-10.14-6.5-5.53.14-8.73 5-9.6 6.5a1 1 0 0 0 0 1c.63 1.09 4 6.5 9.89 6.5h.25c5.53-.14 8.74-5 9.6-6.5a1 1 0 0 0 0-1zm-9.87 4a3.5 3.5 0 1 1 3.5-3.5 3.5 3.5 0 0 1-3.5 3.5z\" \u{f002}/></g></g></svg>");
+result.insert("eyeoff", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"eye-off\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><circle cx=\"12\" cy=\"12\" r=\"1.5\" \u{f002}/><path d=\"M15.29 18.12L14 16.78l-.07-.07-1.27-1.27a4.07 4.07 0 0 1-.61.06A3.5 3.5 0 0 1 8.5 12a4.07 4.07 0 0 1 .06-.61l-2-2L5 7.87a15.89 15.89 0 0 0-2.87 3.63 1 1 0 0 0 0 1c.63 1.09 4 6.5 9.89 6.5h.25a9.48 9.48 0 0 0 3.23-.67z\" \u{f002}/><path d=\"M8.59 5.76l2.8 2.8A4.07 4.07 0 0 1 12 8.5a3.5 3.5 0 0 1 3.5 3.5 4.07 4.07 0 0 1-.06.61l2.68 2.68.84.84a15.89 15.89 0 0 0 2.91-3.63 1 1 0 0 0 0-1c-.64-1.11-4.16-6.68-10.14-6.5a9.48 9.48 0 0 0-3.23.67z\" \u{f002}/><path d=\"M20.71 19.29L19.41 18l-2-2-9.52-9.53L6.42 5 4.71 3.29a1 1 0 0 0-1.42 1.42L5.53 7l1.75 1.7 7.31 7.3.07.07L16 17.41l.59.59 2.7 2.71a1 1 0 0 0 1.42 0 1 1 0 0 0 0-1.42z\" \u{f002}/></g></g></svg>");
+result.insert("facebook", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"facebook\"><rect width=\"24\" height=\"24\" transform=\"rotate(180 12 12)\" opacity=\"0\"/><path d=\"M17 3.5a.5.5 0 0 0-.5-.5H14a4.77 4.77 0 0 0-5 4.5v2.7H6.5a.5.5 0 0 0-.5.5v2.6a.5.5 0 0 0 .5.5H9v6.7a.5.5 0 0 0 .5.5h3a.5.5 0 0 0 .5-.5v-6.7h2.62a.5.5 0 0 0 .49-.37l.72-2.6a.5.5 0 0 0-.48-.63H13V7.5a1 1 0 0 1 1-.9h2.5a.5.5 0 0 0 .5-.5z\" \u{f002}/></g></g></svg>");
+result.insert("fish", "<svg \u{f000} viewBox=\"0 0 512 512\" \u{f001}><path \u{f002} d=\"M512,256c0-16.54-14.27-46.76-45.61-74a207.06,207.06,0,0,0-60.28-36.12,3.15,3.15,0,0,0-3.93,1.56c-.15.29-.3.57-.47.86l-9.59,15.9a183.24,183.24,0,0,0,.07,183.78l.23.39,8.74,16a4,4,0,0,0,4.94,1.82C479.63,337.42,512,281.49,512,256Zm-93.92-.14a16,16,0,1,1,13.79-13.79A16,16,0,0,1,418.08,255.86Z\"/><path \u{f002} d=\"M335.45,256a214.8,214.8,0,0,1,29.08-108l.12-.21,4.62-7.67a4,4,0,0,0-2.59-6,284.29,284.29,0,0,0-39.26-5.39,7.94,7.94,0,0,1-4.29-1.6c-19.28-14.66-57.5-40.3-96.46-46.89a16,16,0,0,0-18,20.18l10.62,37.17a4,4,0,0,1-2.42,4.84c-36.85,13.69-68.59,38.75-91.74,57.85a8,8,0,0,1-10.06.06q-4.72-3.75-9.69-7.39C65.74,164,19.17,160.19,17.21,160.05A16,16,0,0,0,.38,179.45c.42,1.93,9.19,40.69,31.7,71.61a8.09,8.09,0,0,1,0,9.55C9.57,291.52.8,330.29.38,332.22a16,16,0,0,0,16.83,19.4c2-.14,48.53-4,88.12-32.88q4.85-3.56,9.47-7.22a8,8,0,0,1,10.06.07c23.25,19.19,55.05,44.28,92,58a4,4,0,0,1,2.42,4.83L208.62,411.6a16,16,0,0,0,18,20.18c17.16-2.9,51.88-12.86,96.05-46.83a8.15,8.15,0,0,1,4.36-1.65A287.36,287.36,0,0,0,366.25,378a4,4,0,0,0,2.69-5.83l-4.51-8.29A214.81,214.81,0,0,1,335.45,256Z\"/></svg>");
+result.insert("folder", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M408,96H252.11a23.89,23.89,0,0,1-13.31-4L211,73.41A55.77,55.77,0,0,0,179.89,64H104a56.06,56.06,0,0,0-56,56v24H464C464,113.12,438.88,96,408,96Z\"/><path \u{f002} d=\"M423.75,448H88.25a56,56,0,0,1-55.93-55.15L16.18,228.11l0-.28A48,48,0,0,1,64,176h384.1a48,48,0,0,1,47.8,51.83l0,.28L479.68,392.85A56,56,0,0,1,423.75,448ZM479.9,226.55h0Z\"/></svg>");
+result.insert("game", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M483.13,245.38C461.92,149.49,430,98.31,382.65,84.33A107.13,107.13,0,0,0,352,80c-13.71,0-25.65,3.34-38.28,6.88C298.5,91.15,281.21,96,256,96s-42.51-4.84-57.76-9.11C185.6,83.34,173.67,80,160,80a115.74,115.74,0,0,0-31.73,4.32c-47.1,13.92-79,65.08-100.52,161C4.61,348.54,16,413.71,59.69,428.83a56.62,56.62,0,0,0,18.64,3.22c29.93,0,53.93-24.93,70.33-45.34,18.53-23.1,40.22-34.82,107.34-34.82,59.95,0,84.76,8.13,106.19,34.82,13.47,16.78,26.2,28.52,38.9,35.91,16.89,9.82,33.77,12,50.16,6.37,25.82-8.81,40.62-32.1,44-69.24C497.82,331.27,493.86,293.86,483.13,245.38ZM208,240H176v32a16,16,0,0,1-32,0V240H112a16,16,0,0,1,0-32h32V176a16,16,0,0,1,32,0v32h32a16,16,0,0,1,0,32Zm84,4a20,20,0,1,1,20-20A20,20,0,0,1,292,244Zm44,44a20,20,0,1,1,20-19.95A20,20,0,0,1,336,288Zm0-88a20,20,0,1,1,20-20A20,20,0,0,1,336,200Zm44,44a20,20,0,1,1,20-20A20,20,0,0,1,380,244Z\"/></svg>");
+result.insert("gift", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"gift\"><rect width=\"24\" height=\"24\" transform=\"rotate(180 12 12)\" opacity=\"0\"/><path d=\"M4.64 15.27v4.82a.92.92 0 0 0 .92.91h5.62v-5.73z\" \u{f002}/><path d=\"M12.82 21h5.62a.92.92 0 0 0 .92-.91v-4.82h-6.54z\" \u{f002}/><path d=\"M20.1 7.09h-1.84a2.82 2.82 0 0 0 .29-1.23A2.87 2.87 0 0 0 15.68 3 4.21 4.21 0 0 0 12 5.57 4.21 4.21 0 0 0 8.32 3a2.87 2.87 0 0 0-2.87 2.86 2.82 2.82 0 0 0 .29 1.23H3.9c-.5 0-.9.59-.9 1.31v3.93c0 .72.4 1.31.9 1.31h7.28V7.09h1.64v6.55h7.28c.5 0 .9-.59.9-1.31V8.4c0-.72-.4-1.31-.9-1.31zm-11.78 0a1.23 1.23 0 1 1 0-2.45c1.4 0 2.19 1.44 2.58 2.45zm7.36 0H13.1c.39-1 1.18-2.45 2.58-2.45a1.23 1.23 0 1 1 0 2.45z\" \u{f002}/></g></g></svg>");
+result.insert("github", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><rect width=\"24\" height=\"24\" transform=\"rotate(180 12 12)\" opacity=\"0\"/><path d=\"M12 1A10.89 10.89 0 0 0 1 11.77 10.79 10.79 0 0 0 8.52 22c.55.1.75-.23.75-.52v-1.83c-3.06.65-3.71-1.44-3.71-1.44a2.86 2.86 0 0 0-1.22-1.58c-1-.66.08-.65.08-.65a2.31 2.31 0 0 1 1.68 1.11 2.37 2.37 0 0 0 3.2.89 2.33 2.33 0 0 1 .7-1.44c-2.44-.27-5-1.19-5-5.32a4.15 4.15 0 0 1 1.11-2.91 3.78 3.78 0 0 1 .11-2.84s.93-.29 3 1.1a10.68 10.68 0 0 1 5.5 0c2.1-1.39 3-1.1 3-1.1a3.78 3.78 0 0 1 .11 2.84A4.15 4.15 0 0 1 19 11.2c0 4.14-2.58 5.05-5 5.32a2.5 2.5 0 0 1 .75 2v2.95c0 .35.2.63.75.52A10.8 10.8 0 0 0 23 11.77 10.89 10.89 0 0 0 12 1\" data-name=\"github\" \u{f002}/></g></svg>");
+result.insert("glasses", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M464,184H453.1a78.72,78.72,0,0,0-16-7.18C419.5,171,396.26,168,368,168s-51.5,3-69.06,8.82c-14.06,4.69-20.25,9.86-22.25,11.87h0a47.94,47.94,0,0,0-41.36,0h0c-2-2-8.19-7.18-22.25-11.87C195.5,171,172.26,168,144,168s-51.5,3-69.06,8.82a78.72,78.72,0,0,0-16,7.18H48a16,16,0,0,0,0,32h.17c1,45.46,6.44,72.78,18.11,92.23a66.78,66.78,0,0,0,31.92,28c12.23,5.24,27.22,7.79,45.8,7.79,24.15,0,58.48-3.71,77.72-35.77,9.68-16.14,15.09-37.69,17.21-70.52A16,16,0,0,0,240,232a16,16,0,0,1,32,0,16,16,0,0,0,1.07,5.71c2.12,32.83,7.53,54.38,17.21,70.52a66.78,66.78,0,0,0,31.92,28c12.23,5.24,27.22,7.79,45.8,7.79,24.15,0,58.48-3.71,77.72-35.77,11.67-19.45,17.13-46.77,18.11-92.23H464a16,16,0,0,0,0-32Z\"/></svg>");
+result.insert("google", "<svg \u{f000} viewBox=\"0 0 128 128\" \u{f001}><path \u{f002} d=\"M44.59 4.21a63.28 63.28 0 004.33 120.9 67.6 67.6 0 0032.36.35 57.13 57.13 0 0025.9-13.46 57.44 57.44 0 0016-26.26 74.33 74.33 0 001.61-33.58H65.27v24.69h34.47a29.72 29.72 0 01-12.66 19.52 36.16 36.16 0 01-13.93 5.5 41.29 41.29 0 01-15.1 0A37.16 37.16 0 0144 95.74a39.3 39.3 0 01-14.5-19.42 38.31 38.31 0 010-24.63 39.25 39.25 0 019.18-14.91A37.17 37.17 0 0176.13 27a34.28 34.28 0 0113.64 8q5.83-5.8 11.64-11.63c2-2.09 4.18-4.08 6.15-6.22A61.22 61.22 0 0087.2 4.59a64 64 0 00-42.61-.38z\"/></svg>");
+result.insert("happy", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M414.39,97.61A224,224,0,1,0,97.61,414.39,224,224,0,1,0,414.39,97.61ZM184,208a24,24,0,1,1-24,24A23.94,23.94,0,0,1,184,208ZM351.67,314.17c-12,40.3-50.2,69.83-95.62,69.83s-83.62-29.53-95.72-69.83A8,8,0,0,1,168.16,304H343.85A8,8,0,0,1,351.67,314.17ZM328,256a24,24,0,1,1,24-24A23.94,23.94,0,0,1,328,256Z\"/></svg>");
+result.insert("hash", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"hash\"><rect width=\"24\" height=\"24\" transform=\"rotate(180 12 12)\" opacity=\"0\"/><path d=\"M20 14h-4.3l.73-4H20a1 1 0 0 0 0-2h-3.21l.69-3.81A1 1 0 0 0 16.64 3a1 1 0 0 0-1.22.82L14.67 8h-3.88l.69-3.81A1 1 0 0 0 10.64 3a1 1 0 0 0-1.22.82L8.67 8H4a1 1 0 0 0 0 2h4.3l-.73 4H4a1 1 0 0 0 0 2h3.21l-.69 3.81A1 1 0 0 0 7.36 21a1 1 0 0 0 1.22-.82L9.33 16h3.88l-.69 3.81a1 1 0 0 0 .84 1.19 1 1 0 0 0 1.22-.82l.75-4.18H20a1 1 0 0 0 0-2zM9.7 14l.73-4h3.87l-.73 4z\" \u{f002}/></g></g></svg>");
+result.insert("heart", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"heart\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M12 21a1 1 0 0 1-.71-.29l-7.77-7.78a5.26 5.26 0 0 1 0-7.4 5.24 5.24 0 0 1 7.4 0L12 6.61l1.08-1.08a5.24 5.24 0 0 1 7.4 0 5.26 5.26 0 0 1 0 7.4l-7.77 7.78A1 1 0 0 1 12 21z\" \u{f002}/></g></g></svg>");
+result.insert("home", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"home\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><rect x=\"10\" y=\"14\" width=\"4\" height=\"7\" \u{f002}/><path d=\"M20.42 10.18L12.71 2.3a1 1 0 0 0-1.42 0l-7.71 7.89A2 2 0 0 0 3 11.62V20a2 2 0 0 0 1.89 2H8v-9a1 1 0 0 1 1-1h6a1 1 0 0 1 1 1v9h3.11A2 2 0 0 0 21 20v-8.38a2.07 2.07 0 0 0-.58-1.44z\" \u{f002}/></g></g></svg>");
+result.insert("html", "<svg \u{f000} viewBox=\"0 0 128 128\" \u{f001}><path \u{f002} d=\"M9.032 2l10.005 112.093 44.896 12.401 45.02-12.387L118.968 2H9.032zm89.126 26.539l-.627 7.172L97.255 39H44.59l1.257 14h50.156l-.336 3.471-3.233 36.119-.238 2.27L64 102.609v.002l-.034.018-28.177-7.423L33.876 74h13.815l.979 10.919L63.957 89H64v-.546l15.355-3.875L80.959 67H33.261l-3.383-38.117L29.549 25h68.939l-.33 3.539z\"/></svg>");
+result.insert("image", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"image\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M18 3H6a3 3 0 0 0-3 3v12a3 3 0 0 0 3 3h12a3 3 0 0 0 3-3V6a3 3 0 0 0-3-3zM6 5h12a1 1 0 0 1 1 1v8.36l-3.2-2.73a2.77 2.77 0 0 0-3.52 0L5 17.7V6a1 1 0 0 1 1-1z\" \u{f002}/><circle cx=\"8\" cy=\"8.5\" r=\"1.5\" \u{f002}/></g></g></svg>");
+result.insert("info", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"info\"><rect width=\"24\" height=\"24\" transform=\"rotate(180 12 12)\" opacity=\"0\"/><path d=\"M12 2a10 10 0 1 0 10 10A10 10 0 0 0 12 2zm1 14a1 1 0 0 1-2 0v-5a1 1 0 0 1 2 0zm-1-7a1 1 0 1 1 1-1 1 1 0 0 1-1 1z\" \u{f002}/></g></g></svg>");
+result.insert("instagram", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M349.33,69.33a93.62,93.62,0,0,1,93.34,93.34V349.33a93.62,93.62,0,0,1-93.34,93.34H162.67a93.62,93.62,0,0,1-93.34-93.34V162.67a93.62,93.62,0,0,1,93.34-93.34H349.33m0-37.33H162.67C90.8,32,32,90.8,32,162.67V349.33C32,421.2,90.8,480,162.67,480H349.33C421.2,480,480,421.2,480,349.33V162.67C480,90.8,421.2,32,349.33,32Z\"/><path \u{f002} d=\"M377.33,162.67a28,28,0,1,1,28-28A27.94,27.94,0,0,1,377.33,162.67Z\"/><path \u{f002} d=\"M256,181.33A74.67,74.67,0,1,1,181.33,256,74.75,74.75,0,0,1,256,181.33M256,144A112,112,0,1,0,368,256,112,112,0,0,0,256,144Z\"/></svg>");
+result.insert("javascript", "<svg \u{f000} viewBox=\"0 0 128 128\" \u{f001}><path \u{f002} d=\"M2 1v125h125V1H2zm66.119 106.513c-1.845 3.749-5.367 6.212-9.448 7.401-6.271 1.44-12.269.619-16.731-2.059-2.986-1.832-5.318-4.652-6.901-7.901l9.52-5.83c.083.035.333.487.667 1.071 1.214 2.034 2.261 3.474 4.319 4.485 2.022.69 6.461 1.131 8.175-2.427 1.047-1.81.714-7.628.714-14.065C58.433 78.073 58.48 68 58.48 58h11.709c0 11 .06 21.418 0 32.152.025 6.58.596 12.446-2.07 17.361zm48.574-3.308c-4.07 13.922-26.762 14.374-35.83 5.176-1.916-2.165-3.117-3.296-4.26-5.795 4.819-2.772 4.819-2.772 9.508-5.485 2.547 3.915 4.902 6.068 9.139 6.949 5.748.702 11.531-1.273 10.234-7.378-1.333-4.986-11.77-6.199-18.873-11.531-7.211-4.843-8.901-16.611-2.975-23.335 1.975-2.487 5.343-4.343 8.877-5.235l3.688-.477c7.081-.143 11.507 1.727 14.756 5.355.904.916 1.642 1.904 3.022 4.045-3.772 2.404-3.76 2.381-9.163 5.879-1.154-2.486-3.069-4.046-5.093-4.724-3.142-.952-7.104.083-7.926 3.403-.285 1.023-.226 1.975.227 3.665 1.273 2.903 5.545 4.165 9.377 5.926 11.031 4.474 14.756 9.271 15.672 14.981.882 4.916-.213 8.105-.38 8.581z\"/></svg>");
+result.insert("key", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M218.1,167.17c0,13,0,25.6,4.1,37.4-43.1,50.6-156.9,184.3-167.5,194.5a20.17,20.17,0,0,0-6.7,15c0,8.5,5.2,16.7,9.6,21.3,6.6,6.9,34.8,33,40,28,15.4-15,18.5-19,24.8-25.2,9.5-9.3-1-28.3,2.3-36s6.8-9.2,12.5-10.4,15.8,2.9,23.7,3c8.3.1,12.8-3.4,19-9.2,5-4.6,8.6-8.9,8.7-15.6.2-9-12.8-20.9-3.1-30.4s23.7,6.2,34,5,22.8-15.5,24.1-21.6-11.7-21.8-9.7-30.7c.7-3,6.8-10,11.4-11s25,6.9,29.6,5.9c5.6-1.2,12.1-7.1,17.4-10.4,15.5,6.7,29.6,9.4,47.7,9.4,68.5,0,124-53.4,124-119.2S408.5,48,340,48,218.1,101.37,218.1,167.17ZM400,144a32,32,0,1,1-32-32A32,32,0,0,1,400,144Z\"/></svg>");
+result.insert("list", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"list\"><rect width=\"24\" height=\"24\" transform=\"rotate(180 12 12)\" opacity=\"0\"/><circle cx=\"4\" cy=\"7\" r=\"1\" \u{f002}/><circle cx=\"4\" cy=\"12\" r=\"1\" \u{f002}/><circle cx=\"4\" cy=\"17\" r=\"1\" \u{f002}/><rect x=\"7\" y=\"11\" width=\"14\" height=\"2\" rx=\".94\" ry=\".94\" \u{f002}/><rect x=\"7\" y=\"16\" width=\"14\" height=\"2\" rx=\".94\" ry=\".94\" \u{f002}/><rect x=\"7\" y=\"6\" width=\"14\" height=\"2\" rx=\".94\" ry=\".94\" \u{f002}/></g></g></svg>");
+result.insert("lock", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"lock\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><circle cx=\"12\" cy=\"15\" r=\"1\" \u{f002}/><path d=\"M17 8h-1V6.11a4 4 0 1 0-8 0V8H7a3 3 0 0 0-3 3v8a3 3 0 0 0 3 3h10a3 3 0 0 0 3-3v-8a3 3 0 0 0-3-3zm-7-1.89A2.06 2.06 0 0 1 12 4a2.06 2.06 0 0 1 2 2.11V8h-4zM12 18a3 3 0 1 1 3-3 3 3 0 0 1-3 3z\" \u{f002}/></g></g></svg>");
+result.insert("maximize", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"maximize\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M20.71 19.29l-3.4-3.39A7.92 7.92 0 0 0 19 11a8 8 0 1 0-8 8 7.92 7.92 0 0 0 4.9-1.69l3.39 3.4a1 1 0 0 0 1.42 0 1 1 0 0 0 0-1.42zM13 12h-1v1a1 1 0 0 1-2 0v-1H9a1 1 0 0 1 0-2h1V9a1 1 0 0 1 2 0v1h1a1 1 0 0 1 0 2z\" \u{f002}/></g></g></svg>");
+result.insert("menu", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"menu\"><rect width=\"24\" height=\"24\" transform=\"rotate(180 12 12)\" opacity=\"0\"/><rect \u{f002} x=\"3\" y=\"11\" width=\"18\" height=\"2\" rx=\".95\" ry=\".95\"/><rect \u{f002} x=\"3\" y=\"16\" width=\"18\" height=\"2\" rx=\".95\" ry=\".95\"/><rect \u{f002} x=\"3\" y=\"6\" width=\"18\" height=\"2\" rx=\".95\" ry=\".95\"/></g></g></svg>");
+result.insert("messagesquare", "<svg \u{f000}\" viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"message-square\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M19 3H5a3 3 0 0 0-3 3v15a1 1 0 0 0 .51.87A1 1 0 0 0 3 22a1 1 0 0 0 .51-.14L8 19.14a1 1 0 0 1 .55-.14H19a3 3 0 0 0 3-3V6a3 3 0 0 0-3-3zM8 12a1 1 0 1 1 1-1 1 1 0 0 1-1 1zm4 0a1 1 0 1 1 1-1 1 1 0 0 1-1 1zm4 0a1 1 0 1 1 1-1 1 1 0 0 1-1 1z\" \u{f002}/></g></g></svg>");
+result.insert("mic", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"mic\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M12 15a4 4 0 0 0 4-4V6a4 4 0 0 0-8 0v5a4 4 0 0 0 4 4z\" \u{f002}/><path d=\"M19 11a1 1 0 0 0-2 0 5 5 0 0 1-10 0 1 1 0 0 0-2 0 7 7 0 0 0 6 6.92V20H8.89a.89.89 0 0 0-.89.89v.22a.89.89 0 0 0 .89.89h6.22a.89.89 0 0 0 .89-.89v-.22a.89.89 0 0 0-.89-.89H13v-2.08A7 7 0 0 0 19 11z\" \u{f002}/></g></g></svg>");
+result.insert("micoff", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"mic-off\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M15.58 12.75A4 4 0 0 0 16 11V6a4 4 0 0 0-7.92-.75\" \u{f002}/><path d=\"M19 11a1 1 0 0 0-2 0 4.86 4.86 0 0 1-.69 2.48L17.78 15A7 7 0 0 0 19 11z\" \u{f002}/><path d=\"M12 15h.16L8 10.83V11a4 4 0 0 0 4 4z\" \u{f002}/><path d=\"M20.71 19.29l-16-16a1 1 0 0 0-1.42 1.42l16 16a1 1 0 0 0 1.42 0 1 1 0 0 0 0-1.42z\" \u{f002}/><path d=\"M15 20h-2v-2.08a7 7 0 0 0 1.65-.44l-1.6-1.6A4.57 4.57 0 0 1 12 16a5 5 0 0 1-5-5 1 1 0 0 0-2 0 7 7 0 0 0 6 6.92V20H9a1 1 0 0 0 0 2h6a1 1 0 0 0 0-2z\" \u{f002}/></g></g></svg>");
+result.insert("minimize", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"minimize\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M20.71 19.29l-3.4-3.39A7.92 7.92 0 0 0 19 11a8 8 0 1 0-8 8 7.92 7.92 0 0 0 4.9-1.69l3.39 3.4a1 1 0 0 0 1.42 0 1 1 0 0 0 0-1.42zM13 12H9a1 1 0 0 1 0-2h4a1 1 0 0 1 0 2z\" \u{f002}/></g></g></svg>");
+result.insert("music", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"music\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M19 15V4a1 1 0 0 0-.38-.78 1 1 0 0 0-.84-.2l-9 2A1 1 0 0 0 8 6v8.34a3.49 3.49 0 1 0 2 3.18 4.36 4.36 0 0 0 0-.52V6.8l7-1.55v7.09a3.49 3.49 0 1 0 2 3.17 4.57 4.57 0 0 0 0-.51z\" \u{f002}/></g></g></svg>");
+result.insert("navigation", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"navigation\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M20 20a.94.94 0 0 1-.55-.17l-6.9-4.56a1 1 0 0 0-1.1 0l-6.9 4.56a1 1 0 0 1-1.44-1.28l8-16a1 1 0 0 1 1.78 0l8 16a1 1 0 0 1-.23 1.2A1 1 0 0 1 20 20z\" \u{f002}/></g></g></svg>");
+result.insert("nuclear", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M258.9,48C141.92,46.42,46.42,141.92,48,258.9,49.56,371.09,140.91,462.44,253.1,464c117,1.6,212.48-93.9,210.88-210.88C462.44,140.91,371.09,49.56,258.9,48ZM429,239.92l-93.08-.1a2,2,0,0,1-1.95-1.57,80.08,80.08,0,0,0-27.44-44.17,2,2,0,0,1-.54-2.43l41.32-83.43a2,2,0,0,1,2.87-.81A176.2,176.2,0,0,1,431,237.71,2,2,0,0,1,429,239.92ZM208.2,260.38a48,48,0,1,1,43.42,43.42A48,48,0,0,1,208.2,260.38ZM164.65,108.22,206,191.65a2,2,0,0,1-.54,2.43A80.08,80.08,0,0,0,178,238.25a2,2,0,0,1-2,1.57l-93.08.1a2,2,0,0,1-2-2.21,176.2,176.2,0,0,1,80.82-130.3A2,2,0,0,1,164.65,108.22Zm-.37,295.34,56.31-74.09a2,2,0,0,1,2.43-.6,79.84,79.84,0,0,0,66,0,2,2,0,0,1,2.43.6l56.31,74.09a2,2,0,0,1-.54,2.92,175.65,175.65,0,0,1-182.36,0A2,2,0,0,1,164.28,403.56Z\"/></svg>");
+result.insert("options", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"options\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M7 14.18V3a1 1 0 0 0-2 0v11.18a3 3 0 0 0 0 5.64V21a1 1 0 0 0 2 0v-1.18a3 3 0 0 0 0-5.64z\" \u{f002}/><path d=\"M21 13a3 3 0 0 0-2-2.82V3a1 1 0 0 0-2 0v7.18a3 3 0 0 0 0 5.64V21a1 1 0 0 0 2 0v-5.18A3 3 0 0 0 21 13z\" \u{f002}/><path d=\"M15 5a3 3 0 1 0-4 2.82V21a1 1 0 0 0 2 0V7.82A3 3 0 0 0 15 5z\" \u{f002}/></g></g></svg>");
+result.insert("pen", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001} \u{f002}><path d=\"M0 0h24v24H0z\" fill=\"none\"/><path d=\"M3 17.25V21h3.75L17.81 9.94l-3.75-3.75L3 17.25zM20.71 7.04c.39-.39.39-1.02 0-1.41l-2.34-2.34c-.39-.39-1.02-.39-1.41 0l-1.83 1.83 3.75 3.75 1.83-1.83z\" \u{f002}/></svg>");
+result.insert("people", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M336,256c-20.56,0-40.44-9.18-56-25.84-15.13-16.25-24.37-37.92-26-61-1.74-24.62,5.77-47.26,21.14-63.76S312,80,336,80c23.83,0,45.38,9.06,60.7,25.52,15.47,16.62,23,39.22,21.26,63.63h0c-1.67,23.11-10.9,44.77-26,61C376.44,246.82,356.57,256,336,256Zm66-88h0Z\"/><path \u{f002} d=\"M467.83,432H204.18a27.71,27.71,0,0,1-22-10.67,30.22,30.22,0,0,1-5.26-25.79c8.42-33.81,29.28-61.85,60.32-81.08C264.79,297.4,299.86,288,336,288c36.85,0,71,9,98.71,26.05,31.11,19.13,52,47.33,60.38,81.55a30.27,30.27,0,0,1-5.32,25.78A27.68,27.68,0,0,1,467.83,432Z\"/><path \u{f002} d=\"M147,260c-35.19,0-66.13-32.72-69-72.93C76.58,166.47,83,147.42,96,133.45,108.86,119.62,127,112,147,112s38,7.66,50.93,21.57c13.1,14.08,19.5,33.09,18,53.52C213.06,227.29,182.13,260,147,260Z\"/><path \u{f002} d=\"M212.66,291.45c-17.59-8.6-40.42-12.9-65.65-12.9-29.46,0-58.07,7.68-80.57,21.62C40.93,316,23.77,339.05,16.84,366.88a27.39,27.39,0,0,0,4.79,23.36A25.32,25.32,0,0,0,41.72,400h111a8,8,0,0,0,7.87-6.57c.11-.63.25-1.26.41-1.88,8.48-34.06,28.35-62.84,57.71-83.82a8,8,0,0,0-.63-13.39C216.51,293.42,214.71,292.45,212.66,291.45Z\"/></svg>");
+result.insert("person", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"person\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M12 11a4 4 0 1 0-4-4 4 4 0 0 0 4 4z\" \u{f002}/><path d=\"M18 21a1 1 0 0 0 1-1 7 7 0 0 0-14 0 1 1 0 0 0 1 1z\" \u{f002}/></g></g></svg>");
+result.insert("phone", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"phone\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M17.4 22A15.42 15.42 0 0 1 2 6.6 4.6 4.6 0 0 1 6.6 2a3.94 3.94 0 0 1 .77.07 3.79 3.79 0 0 1 .72.18 1 1 0 0 1 .65.75l1.37 6a1 1 0 0 1-.26.92c-.13.14-.14.15-1.37.79a9.91 9.91 0 0 0 4.87 4.89c.65-1.24.66-1.25.8-1.38a1 1 0 0 1 .92-.26l6 1.37a1 1 0 0 1 .72.65 4.34 4.34 0 0 1 .19.73 4.77 4.77 0 0 1 .06.76A4.6 4.6 0 0 1 17.4 22z\" \u{f002}/></g></g></svg>");
+result.insert("pin", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"pin\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><circle cx=\"12\" cy=\"9.5\" r=\"1.5\" \u{f002}/><path d=\"M12 2a8 8 0 0 0-8 7.92c0 5.48 7.05 11.58 7.35 11.84a1 1 0 0 0 1.3 0C13 21.5 20 15.4 20 9.92A8 8 0 0 0 12 2zm0 11a3.5 3.5 0 1 1 3.5-3.5A3.5 3.5 0 0 1 12 13z\" \u{f002}/></g></g></svg>");
+result.insert("power", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"power\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M12 13a1 1 0 0 0 1-1V2a1 1 0 0 0-2 0v10a1 1 0 0 0 1 1z\" \u{f002}/><path d=\"M16.59 3.11a1 1 0 0 0-.92 1.78 8 8 0 1 1-7.34 0 1 1 0 1 0-.92-1.78 10 10 0 1 0 9.18 0z\" \u{f002}/></g></g></svg>");
+result.insert("python", "<svg \u{f000} viewBox=\"0 0 128 128\" \u{f001}><path \u{f002} d=\"M49.33 62h29.159C86.606 62 93 55.132 93 46.981V19.183c0-7.912-6.632-13.856-14.555-15.176-5.014-.835-10.195-1.215-15.187-1.191-4.99.023-9.612.448-13.805 1.191C37.098 6.188 35 10.758 35 19.183V30h29v4H23.776c-8.484 0-15.914 5.108-18.237 14.811-2.681 11.12-2.8 17.919 0 29.53C7.614 86.983 12.569 93 21.054 93H31V79.952C31 70.315 39.428 62 49.33 62zm-1.838-39.11c-3.026 0-5.478-2.479-5.478-5.545 0-3.079 2.451-5.581 5.478-5.581 3.015 0 5.479 2.502 5.479 5.581-.001 3.066-2.465 5.545-5.479 5.545zm74.789 25.921C120.183 40.363 116.178 34 107.682 34H97v12.981C97 57.031 88.206 65 78.489 65H49.33C41.342 65 35 72.326 35 80.326v27.8c0 7.91 6.745 12.564 14.462 14.834 9.242 2.717 17.994 3.208 29.051 0C85.862 120.831 93 116.549 93 108.126V97H64v-4h43.682c8.484 0 11.647-5.776 14.599-14.66 3.047-9.145 2.916-17.799 0-29.529zm-41.955 55.606c3.027 0 5.479 2.479 5.479 5.547 0 3.076-2.451 5.579-5.479 5.579-3.015 0-5.478-2.502-5.478-5.579 0-3.068 2.463-5.547 5.478-5.547z\"/></svg>");
+result.insert("question", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M256,64C150,64,64,150,64,256s86,192,192,192,192-86,192-192S362,64,256,64Zm-6,304a20,20,0,1,1,20-20A20,20,0,0,1,250,368Zm33.44-102C267.23,276.88,265,286.85,265,296a14,14,0,0,1-28,0c0-21.91,10.08-39.33,30.82-53.26C287.1,229.8,298,221.6,298,203.57c0-12.26-7-21.57-21.49-28.46-3.41-1.62-11-3.2-20.34-3.09-11.72.15-20.82,2.95-27.83,8.59C215.12,191.25,214,202.83,214,203a14,14,0,1,1-28-1.35c.11-2.43,1.8-24.32,24.77-42.8,11.91-9.58,27.06-14.56,45-14.78,12.7-.15,24.63,2,32.72,5.82C312.7,161.34,326,180.43,326,203.57,326,237.4,303.39,252.59,283.44,266Z\"/></svg>");
+result.insert("refresh", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"refresh\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M20.3 13.43a1 1 0 0 0-1.25.65A7.14 7.14 0 0 1 12.18 19 7.1 7.1 0 0 1 5 12a7.1 7.1 0 0 1 7.18-7 7.26 7.26 0 0 1 4.65 1.67l-2.17-.36a1 1 0 0 0-1.15.83 1 1 0 0 0 .83 1.15l4.24.7h.17a1 1 0 0 0 .34-.06.33.33 0 0 0 .1-.06.78.78 0 0 0 .2-.11l.09-.11c0-.05.09-.09.13-.15s0-.1.05-.14a1.34 1.34 0 0 0 .07-.18l.75-4a1 1 0 0 0-2-.38l-.27 1.45A9.21 9.21 0 0 0 12.18 3 9.1 9.1 0 0 0 3 12a9.1 9.1 0 0 0 9.18 9A9.12 9.12 0 0 0 21 14.68a1 1 0 0 0-.7-1.25z\" \u{f002}/></g></g></svg>");
+result.insert("rust", "<svg \u{f000} viewBox=\"0 0 128 128\" \u{f001}><path d=\"M62.271 10.88c-.189.11-.982 1.248-1.763 2.529-1.96 3.217-1.982 3.219-4.615.448-1.713-1.802-2.127-2.132-2.679-2.128-.359.002-.812.124-1.008.271-.195.147-.748 1.317-1.228 2.6-1.099 2.939-1.152 3.034-1.761 3.151-.375.071-1.097-.331-2.828-1.574-1.278-.919-2.532-1.67-2.786-1.67-1.054 0-1.351.576-1.853 3.593-.638 3.836-.616 3.823-4.074 2.252-1.396-.633-2.72-1.152-2.943-1.152-.223 0-.646.24-.939.533-.532.533-.533.535-.388 3.468l.146 2.936-.555.297c-.492.263-.831.231-3.009-.284-2.843-.671-3.443-.653-4.019.122l-.421.566.565 2.421c.31 1.331.609 2.613.665 2.848.055.234-.04.609-.212.832-.284.367-.586.4-3.217.36-4.453-.07-4.706.312-2.866 4.328.585 1.275 1.064 2.433 1.064 2.572 0 .734-.585 1.001-3.098 1.411-1.406.229-2.628.417-2.716.417-.088 0-.352.192-.586.426-.765.765-.548 1.483 1.187 3.932 2.161 3.05 2.157 3.061-1.413 4.427-4.06 1.553-4.142 1.936-1.051 4.868 2.879 2.73 2.882 2.69-.377 4.739-2.469 1.551-2.507 1.588-2.57 2.429-.076 1.023-.058 1.041 2.89 2.842 2.915 1.78 2.915 1.834.054 4.541-3.077 2.91-2.982 3.335 1.081 4.868 3.55 1.339 3.555 1.355 1.39 4.405-1.227 1.729-1.618 2.449-1.618 2.983 0 .999.52 1.254 3.627 1.776 2.617.441 3.2.7 3.2 1.422 0 .148-.48 1.316-1.067 2.594-1.826 3.977-1.618 4.308 2.704 4.308 4.025 0 3.918-.123 3.051 3.507-.654 2.736-.664 3.26-.072 3.851.453.454 1.307.403 3.978-.236 2.04-.487 2.398-.521 2.871-.268l.54.289-.146 2.935c-.145 2.934-.144 2.936.388 3.469.293.293.722.533.952.533.23 0 1.554-.516 2.943-1.147 3.447-1.565 3.425-1.578 4.061 2.246.504 3.031.798 3.594 1.874 3.594.267 0 1.494-.72 2.728-1.6 2.167-1.546 2.729-1.788 3.306-1.421.149.094.727 1.364 1.284 2.822.819 2.144 1.119 2.702 1.575 2.92.868.416 1.405.082 3.445-2.14 2.463-2.683 2.564-2.67 4.575.589 2.221 3.598 2.796 3.59 5.073-.073 1.962-3.156 1.939-3.154 4.591-.384 1.761 1.838 2.136 2.131 2.73 2.131.379 0 .832-.142 1.005-.316.174-.174.75-1.459 1.28-2.855.53-1.397 1.079-2.613 1.221-2.703.561-.357 1.142-.106 3.306 1.43 1.274.905 2.473 1.6 2.758 1.6 1.058 0 1.44-.751 1.88-3.703.376-2.517.452-2.758.947-3.009.487-.247.779-.164 3.063.873 1.389.63 2.713 1.146 2.943 1.146.23 0 .666-.247.967-.549l.549-.548-.151-2.815c-.144-2.688-.131-2.832.298-3.22.441-.399.486-.397 2.952.166 2.986.682 3.543.7 4.104.139.548-.548.542-.668-.208-3.831-.841-3.548-.954-3.422 3.088-3.422 2.755 0 3.062-.039 3.413-.426.586-.648.447-1.39-.732-3.903-.595-1.266-1.078-2.418-1.074-2.56.02-.747.607-1.002 3.32-1.443 1.66-.269 2.902-.581 3.127-.784.754-.681.477-1.567-1.244-3.98-2.157-3.024-2.148-3.053 1.306-4.326 4.136-1.524 4.254-2.032 1.159-4.973-2.867-2.724-2.868-2.709.272-4.637 3.796-2.33 3.802-2.855.067-5.173-3.212-1.993-3.21-1.965-.331-4.699 3.088-2.934 3.004-3.318-1.057-4.871-3.584-1.371-3.595-1.405-1.417-4.394 1.297-1.78 1.618-2.371 1.618-2.981 0-1.066-.478-1.305-3.622-1.813-2.627-.424-3.205-.682-3.205-1.429 0-.142.48-1.285 1.067-2.542 1.149-2.461 1.31-3.446.66-4.035-.349-.316-.817-.361-3.321-.32-2.62.044-2.955.007-3.318-.358-.397-.399-.393-.455.227-3.042.76-3.17.763-3.247.138-3.834-.634-.596-1.03-.586-3.941.099-2.121.5-2.472.533-2.954.275l-.547-.293.151-2.926.152-2.925-.547-.547c-.301-.301-.728-.547-.95-.547-.221 0-1.538.523-2.926 1.161-2.318 1.067-2.567 1.138-3.068.876-.5-.262-.583-.52-1.01-3.127-.493-3.016-.798-3.603-1.869-3.603-.254 0-1.513.755-2.798 1.678-2.11 1.516-2.393 1.659-2.919 1.476-.435-.152-.688-.483-.997-1.306-.229-.606-.667-1.774-.975-2.595-.622-1.656-.969-2.027-1.901-2.027-.52 0-.991.374-2.679 2.127-2.653 2.756-2.663 2.755-4.614-.445-.78-1.279-1.595-2.421-1.812-2.537-.488-.262-1.062-.261-1.511.002m2.418 9.635c2.311 1.645 1.082 5.512-1.752 5.512-2.75 0-4.135-3.313-2.171-5.194 1.108-1.062 2.697-1.191 3.923-.318m-2.906 10.214c1.515.576 2.137.23 5.596-3.104l2.599-2.506 1.1.146c3.45.458 10.312 3.472 14.255 6.261 3.623 2.564 8.438 7.786 10.49 11.377l.439.769-1.944 4.38c-1.07 2.409-1.945 4.633-1.945 4.944 0 .717.47 1.851.923 2.226.191.159 2.006 1.033 4.033 1.942l3.684 1.654.145.937c.187 1.221.212 4.22.042 5.072l-.133.666h-2.103c-2.439 0-2.251-.218-2.383 2.774-.096 2.169-.62 3.368-1.812 4.144-1.942 1.267-5.149 1.037-6.509-.466-.209-.231-.615-1.392-.903-2.581-.841-3.473-1.971-5.423-4.241-7.32-.717-.599-1.303-1.158-1.303-1.243 0-.084.788-.748 1.752-1.473 3.51-2.646 5.528-5.726 5.75-8.777.423-5.819-4.213-11.243-11.109-13.001-1.635-.417-2.333-.43-22.56-.43-11.48 0-20.873-.075-20.873-.166 0-.215 2.551-2.691 4.054-3.933 4.127-3.412 9.488-6.097 15.04-7.531l1.92-.497 2.728 2.766c1.501 1.521 2.972 2.857 3.268 2.97M27.432 48.526c1.257.823 1.772 2.891 1.03 4.134-1.148 1.924-4.056 2.005-5.205.145-1.671-2.702 1.547-6.001 4.175-4.279m74.05.105c3.288 2.005.74 6.937-2.78 5.38-2.35-1.04-2.425-4.252-.127-5.424.959-.489 2.061-.472 2.907.044M37.12 60.907v12.266H26.276l-.43-1.866c-.846-3.675-1.202-7.477-.989-10.591l.149-2.188 3.728-1.672c2.339-1.048 3.843-1.847 4.037-2.144.848-1.293.767-2.217-.423-4.845l-.556-1.227h5.328v12.267m31.22-11.733c2.322.604 3.549 1.833 3.552 3.556.002 1.265-.625 2.059-2.18 2.761-1.101.498-1.276.51-8.219.578l-7.093.068v-7.284h6.355c4.964 0 6.625.07 7.585.321m-2.396 17.602c1.151.32 2.512 1.32 3.21 2.359.733 1.092 1.162 2.512 2.178 7.216.858 3.976 1.41 5.276 2.956 6.968 1.915 2.095 1.471 2.014 11.037 2.014 4.581 0 8.328.073 8.328.163 0 .161-3.155 3.891-3.291 3.891-.039 0-1.687-.345-3.662-.767-5.577-1.191-5.778-1.051-7.058 4.926l-.823 3.84-.743.366c-1.24.612-5.27 1.872-7.359 2.302-3.452.71-7.209.95-10.511.671-5.629-.477-13.083-2.661-13.374-3.92-.062-.267-.437-1.995-.832-3.841-.396-1.846-.877-3.597-1.069-3.891-.923-1.408-1.894-1.495-6.164-.55-1.617.358-3.028.65-3.136.65-.203 0-3.204-3.47-3.204-3.704 0-.073 7.128-.158 15.84-.188l15.84-.054.057-5.627c.04-3.973-.015-5.714-.187-5.92-.192-.232-1.214-.293-4.91-.293H54.4V66.56l5.387.001c2.962.001 5.733.098 6.157.215M41.536 92.365c2.519 1.535 1.311 5.557-1.668 5.554-3.055-.002-4.187-3.987-1.584-5.575.861-.525 2.374-.515 3.252.021m46.126.168c1.235.905 1.646 2.788.881 4.042-2.009 3.295-7.033.676-5.355-2.791.825-1.703 3.018-2.317 4.474-1.251\" fill-rule=\"evenodd\" \u{f002}/></svg>");
+result.insert("search", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"search\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M20.71 19.29l-3.4-3.39A7.92 7.92 0 0 0 19 11a8 8 0 1 0-8 8 7.92 7.92 0 0 0 4.9-1.69l3.39 3.4a1 1 0 0 0 1.42 0 1 1 0 0 0 0-1.42zM5 11a6 6 0 1 1 6 6 6 6 0 0 1-6-6z\" \u{f002}/></g></g></svg>");
+result.insert("settings", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"settings\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><circle cx=\"12\" cy=\"12\" r=\"1.5\" \u{f002}/><path d=\"M21.89 10.32L21.1 7.8a2.26 2.26 0 0 0-2.88-1.51l-.34.11a1.74 1.74 0 0 1-1.59-.26l-.11-.08a1.76 1.76 0 0 1-.69-1.43v-.28a2.37 2.37 0 0 0-.68-1.68 2.26 2.26 0 0 0-1.6-.67h-2.55a2.32 2.32 0 0 0-2.29 2.33v.24a1.94 1.94 0 0 1-.73 1.51l-.13.1a1.93 1.93 0 0 1-1.78.29 2.14 2.14 0 0 0-1.68.12 2.18 2.18 0 0 0-1.12 1.33l-.82 2.6a2.34 2.34 0 0 0 1.48 2.94h.16a1.83 1.83 0 0 1 1.12 1.22l.06.16a2.06 2.06 0 0 1-.23 1.86 2.37 2.37 0 0 0 .49 3.3l2.07 1.57a2.25 2.25 0 0 0 1.35.43A2 2 0 0 0 9 22a2.25 2.25 0 0 0 1.47-1l.23-.33a1.8 1.8 0 0 1 1.43-.77 1.75 1.75 0 0 1 1.5.78l.12.17a2.24 2.24 0 0 0 3.22.53L19 19.86a2.38 2.38 0 0 0 .5-3.23l-.26-.38A2 2 0 0 1 19 14.6a1.89 1.89 0 0 1 1.21-1.28l.2-.07a2.36 2.36 0 0 0 1.48-2.93zM12 15.5a3.5 3.5 0 1 1 3.5-3.5 3.5 3.5 0 0 1-3.5 3.5z\" \u{f002}/></g></g></svg>");
+result.insert("share", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"share\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M18 15a3 3 0 0 0-2.1.86L8 12.34V12v-.33l7.9-3.53A3 3 0 1 0 15 6v.34L7.1 9.86a3 3 0 1 0 0 4.28l7.9 3.53V18a3 3 0 1 0 3-3z\" \u{f002}/></g></g></svg>");
+result.insert("shield", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"shield\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M12 21.85a2 2 0 0 1-1-.25l-.3-.17A15.17 15.17 0 0 1 3 8.23v-.14a2 2 0 0 1 1-1.75l7-3.94a2 2 0 0 1 2 0l7 3.94a2 2 0 0 1 1 1.75v.14a15.17 15.17 0 0 1-7.72 13.2l-.3.17a2 2 0 0 1-.98.25z\" \u{f002}/></g></g></svg>");
+result.insert("shieldoff", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"shield-off\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M3.73 6.56A2 2 0 0 0 3 8.09v.14a15.17 15.17 0 0 0 7.72 13.2l.3.17a2 2 0 0 0 2 0l.3-.17a15.22 15.22 0 0 0 3-2.27z\" \u{f002}/><path d=\"M18.84 16A15.08 15.08 0 0 0 21 8.23v-.14a2 2 0 0 0-1-1.75L13 2.4a2 2 0 0 0-2 0L7.32 4.49z\" \u{f002}/><path d=\"M4.71 3.29a1 1 0 0 0-1.42 1.42l16 16a1 1 0 0 0 1.42 0 1 1 0 0 0 0-1.42z\" \u{f002}/></g></g></svg>");
+result.insert("skull", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M402,76.94C362.61,37.63,310.78,16,256,16h-.37A208,208,0,0,0,48,224V324.67A79.62,79.62,0,0,0,98.29,399L122,408.42a15.92,15.92,0,0,1,9.75,11.72l10,50.13A32.09,32.09,0,0,0,173.12,496H184a8,8,0,0,0,8-8V448.45c0-8.61,6.62-16,15.23-16.43A16,16,0,0,1,224,448v40a8,8,0,0,0,8,8h0a8,8,0,0,0,8-8V448.45c0-8.61,6.62-16,15.23-16.43A16,16,0,0,1,272,448v40a8,8,0,0,0,8,8h0a8,8,0,0,0,8-8V448.45c0-8.61,6.62-16,15.23-16.43A16,16,0,0,1,320,448v40a8,8,0,0,0,8,8h10.88a32.09,32.09,0,0,0,31.38-25.72l10-50.14A16,16,0,0,1,390,408.42L413.71,399A79.62,79.62,0,0,0,464,324.67v-99C464,169.67,442,116.86,402,76.94ZM171.66,335.88a56,56,0,1,1,52.22-52.22A56,56,0,0,1,171.66,335.88ZM281,397.25A16.37,16.37,0,0,1,271.7,400H240.3a16.37,16.37,0,0,1-9.28-2.75,16,16,0,0,1-6.6-16.9l15.91-47.6C243,326,247.25,321,254,320.13c8.26-1,14,2.87,17.61,12.22l16,48A16,16,0,0,1,281,397.25Zm66.68-61.37a56,56,0,1,1,52.22-52.22A56,56,0,0,1,347.66,335.88Z\"/></svg>");
+result.insert("slash", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"slash\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M12 2a10 10 0 1 0 10 10A10 10 0 0 0 12 2zm8 10a7.92 7.92 0 0 1-1.69 4.9L7.1 5.69A7.92 7.92 0 0 1 12 4a8 8 0 0 1 8 8zM4 12a7.92 7.92 0 0 1 1.69-4.9L16.9 18.31A7.92 7.92 0 0 1 12 20a8 8 0 0 1-8-8z\" \u{f002}/></g></g></svg>");
+result.insert("soccer", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M256,48C141.31,48,48,141.31,48,256s93.31,208,208,208,208-93.31,208-208S370.69,48,256,48ZM399,352H353.78a8,8,0,0,1-6.91-4l-16.14-27.68a8,8,0,0,1-.86-6l14.86-59.92a8,8,0,0,1,4.65-5.45l28.1-11.9a8,8,0,0,1,8.34,1.3l41.63,35.82a8,8,0,0,1,2.69,7.26,174.75,174.75,0,0,1-24.28,66.68A8,8,0,0,1,399,352ZM134.52,237.13l28.1,11.9a8,8,0,0,1,4.65,5.45l14.86,59.92a8,8,0,0,1-.86,6L165.13,348a8,8,0,0,1-6.91,4H113a8,8,0,0,1-6.82-3.81,174.75,174.75,0,0,1-24.28-66.68,8,8,0,0,1,2.69-7.26l41.63-35.82A8,8,0,0,1,134.52,237.13Zm256.94-87.24-18.07,51.38A8,8,0,0,1,369,206l-29.58,12.53a8,8,0,0,1-8.26-1.24l-56.26-47.19A8,8,0,0,1,272,164V130.42a8,8,0,0,1,3.56-6.65l42.83-28.54a8,8,0,0,1,7.66-.67A176.92,176.92,0,0,1,390,142,8,8,0,0,1,391.46,149.89ZM193.6,95.23l42.84,28.54a8,8,0,0,1,3.56,6.65V164a8,8,0,0,1-2.86,6.13l-56.26,47.19a8,8,0,0,1-8.26,1.24L143,206a8,8,0,0,1-4.43-4.72l-18.07-51.38A8,8,0,0,1,122,142a176.92,176.92,0,0,1,64-47.48A8,8,0,0,1,193.6,95.23Zm17.31,327.46L191.18,373a8,8,0,0,1,.52-7l15.17-26a8,8,0,0,1,6.91-4h84.44a8,8,0,0,1,6.91,4l15.18,26a8,8,0,0,1,.53,7l-19.59,49.67a8,8,0,0,1-5.69,4.87,176.58,176.58,0,0,1-79,0A8,8,0,0,1,210.91,422.69Z\"/></svg>");
+result.insert("star", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"star\"><rect width=\"24\" height=\"24\" transform=\"rotate(90 12 12)\" opacity=\"0\"/><path d=\"M17.56 21a1 1 0 0 1-.46-.11L12 18.22l-5.1 2.67a1 1 0 0 1-1.45-1.06l1-5.63-4.12-4a1 1 0 0 1-.25-1 1 1 0 0 1 .81-.68l5.7-.83 2.51-5.13a1 1 0 0 1 1.8 0l2.54 5.12 5.7.83a1 1 0 0 1 .81.68 1 1 0 0 1-.25 1l-4.12 4 1 5.63a1 1 0 0 1-.4 1 1 1 0 0 1-.62.18z\" \u{f002}/></g></g></svg>");
+result.insert("terminal", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M432,32H80A64.07,64.07,0,0,0,16,96V416a64.07,64.07,0,0,0,64,64H432a64.07,64.07,0,0,0,64-64V96A64.07,64.07,0,0,0,432,32ZM96,256a16,16,0,0,1-10-28.49L150.39,176,86,124.49a16,16,0,1,1,20-25l80,64a16,16,0,0,1,0,25l-80,64A16,16,0,0,1,96,256Zm160,0H192a16,16,0,0,1,0-32h64a16,16,0,0,1,0,32Z\"/></svg>");
+result.insert("thumbdown", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001} \u{f002}><path d=\"M0 0h24v24H0z\" fill=\"none\"/><path d=\"M15 3H6c-.83 0-1.54.5-1.84 1.22l-3.02 7.05c-.09.23-.14.47-.14.73v2c0 1.1.9 2 2 2h6.31l-.95 4.57-.03.32c0 .41.17.79.44 1.06L9.83 23l6.59-6.59c.36-.36.58-.86.58-1.41V5c0-1.1-.9-2-2-2zm4 0v12h4V3h-4z\" \u{f002}/></svg>");
+result.insert("tv", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"tv\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M18 6h-3.59l2.3-2.29a1 1 0 1 0-1.42-1.42L12 5.59l-3.29-3.3a1 1 0 1 0-1.42 1.42L9.59 6H6a3 3 0 0 0-3 3v10a3 3 0 0 0 3 3h12a3 3 0 0 0 3-3V9a3 3 0 0 0-3-3zm1 13a1 1 0 0 1-1 1H6a1 1 0 0 1-1-1v-7a1 1 0 0 1 1-1h12a1 1 0 0 1 1 1z\" \u{f002}/></g></g></svg>");
+result.insert("twitter", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"twitter\"><polyline points=\"0 0 24 0 24 24 0 24\" opacity=\"0\"/><path d=\"M8.08 20A11.07 11.07 0 0 0 19.52 9 8.09 8.09 0 0 0 21 6.16a.44.44 0 0 0-.62-.51 1.88 1.88 0 0 1-2.16-.38 3.89 3.89 0 0 0-5.58-.17A4.13 4.13 0 0 0 11.49 9C8.14 9.2 5.84 7.61 4 5.43a.43.43 0 0 0-.75.24 9.68 9.68 0 0 0 4.6 10.05A6.73 6.73 0 0 1 3.38 18a.45.45 0 0 0-.14.84A11 11 0 0 0 8.08 20\" \u{f002}/></g></g></svg>");
+result.insert("umbrella", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"umbrella\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M12 2A10 10 0 0 0 2 12a1 1 0 0 0 1 1h8v6a3 3 0 0 0 6 0 1 1 0 0 0-2 0 1 1 0 0 1-2 0v-6h8a1 1 0 0 0 1-1A10 10 0 0 0 12 2z\" \u{f002}/></g></g></svg>");
+result.insert("university", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001} \u{f002}><path d=\"M0 0h24v24H0z\" fill=\"none\"/><path \u{f002} d=\"M5 13.18v4L12 21l7-3.82v-4L12 17l-7-3.82zM12 3L1 9l11 6 9-4.91V17h2V9L12 3z\"/></svg>");
+result.insert("video", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"video\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M21 7.15a1.7 1.7 0 0 0-1.85.3l-2.15 2V8a3 3 0 0 0-3-3H5a3 3 0 0 0-3 3v8a3 3 0 0 0 3 3h9a3 3 0 0 0 3-3v-1.45l2.16 2a1.74 1.74 0 0 0 1.16.45 1.68 1.68 0 0 0 .69-.15 1.6 1.6 0 0 0 1-1.48V8.63A1.6 1.6 0 0 0 21 7.15z\" \u{f002}/></g></g></svg>");
+result.insert("videooff", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"video-off\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M14.22 17.05L4.88 7.71 3.12 6 3 5.8A3 3 0 0 0 2 8v8a3 3 0 0 0 3 3h9a2.94 2.94 0 0 0 1.66-.51z\" \u{f002}/><path d=\"M21 7.15a1.7 1.7 0 0 0-1.85.3l-2.15 2V8a3 3 0 0 0-3-3H7.83l1.29 1.29 6.59 6.59 2 2 2 2a1.73 1.73 0 0 0 .6.11 1.68 1.68 0 0 0 .69-.15 1.6 1.6 0 0 0 1-1.48V8.63a1.6 1.6 0 0 0-1-1.48z\" \u{f002}/><path d=\"M17 15.59l-2-2L8.41 7l-2-2-1.7-1.71a1 1 0 0 0-1.42 1.42l.54.53L5.59 7l9.34 9.34 1.46 1.46 2.9 2.91a1 1 0 0 0 1.42 0 1 1 0 0 0 0-1.42z\" \u{f002}/></g></g></svg>");
+result.insert("volumeoff", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"volume-off\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><path d=\"M16.91 14.08l1.44 1.44a6 6 0 0 0-.07-7.15 1 1 0 1 0-1.56 1.26 4 4 0 0 1 .19 4.45z\" \u{f002}/><path d=\"M21 12a6.51 6.51 0 0 1-1.78 4.39l1.42 1.42A8.53 8.53 0 0 0 23 12a8.75 8.75 0 0 0-3.36-6.77 1 1 0 1 0-1.28 1.54A6.8 6.8 0 0 1 21 12z\" \u{f002}/><path d=\"M15 12.17V4a1 1 0 0 0-1.57-.83L9 6.2z\" \u{f002}/><path d=\"M4.74 7.57H2a1 1 0 0 0-1 1v6.86a1 1 0 0 0 1 1h5l6.41 4.4A1.06 1.06 0 0 0 14 21a1 1 0 0 0 1-1v-2.17z\" \u{f002}/><path d=\"M4.71 3.29a1 1 0 0 0-1.42 1.42l16 16a1 1 0 0 0 1.42 0 1 1 0 0 0 0-1.42z\" \u{f002}/></g></g></svg>");
+result.insert("volumeup", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"volume-up\"><rect width=\"24\" height=\"24\" opacity=\"0\" \u{f002}/><path d=\"M18.28 8.37a1 1 0 1 0-1.56 1.26 4 4 0 0 1 0 4.74A1 1 0 0 0 17.5 16a1 1 0 0 0 .78-.37 6 6 0 0 0 0-7.26z\" \u{f002}/><path d=\"M19.64 5.23a1 1 0 1 0-1.28 1.54A6.8 6.8 0 0 1 21 12a6.8 6.8 0 0 1-2.64 5.23 1 1 0 0 0-.13 1.41A1 1 0 0 0 19 19a1 1 0 0 0 .64-.23A8.75 8.75 0 0 0 23 12a8.75 8.75 0 0 0-3.36-6.77z\" \u{f002}/><path d=\"M14.47 3.12a1 1 0 0 0-1 0L7 7.57H2a1 1 0 0 0-1 1v6.86a1 1 0 0 0 1 1h5l6.41 4.4A1.06 1.06 0 0 0 14 21a1 1 0 0 0 1-1V4a1 1 0 0 0-.53-.88z\" \u{f002}/></g></g></svg>");
+result.insert("water", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M265.12,60.12a12,12,0,0,0-18.23,0C215.23,97.15,112,225.17,112,320c0,88.37,55.64,144,144,144s144-55.63,144-144C400,225.17,296.77,97.15,265.12,60.12ZM272,412a12,12,0,0,1-11.34-16,11.89,11.89,0,0,1,11.41-8A60.06,60.06,0,0,0,332,328.07a11.89,11.89,0,0,1,8-11.41A12,12,0,0,1,356,328,84.09,84.09,0,0,1,272,412Z\"/></svg>");
+result.insert("wifi", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"wifi\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><circle cx=\"12\" cy=\"19\" r=\"1\" \u{f002}/><path d=\"M12 14a5 5 0 0 0-3.47 1.4 1 1 0 1 0 1.39 1.44 3.08 3.08 0 0 1 4.16 0 1 1 0 1 0 1.39-1.44A5 5 0 0 0 12 14z\" \u{f002}/><path d=\"M12 9a9 9 0 0 0-6.47 2.75A1 1 0 0 0 7 13.14a7 7 0 0 1 10.08 0 1 1 0 0 0 .71.3 1 1 0 0 0 .72-1.69A9 9 0 0 0 12 9z\" \u{f002}/><path d=\"M21.72 7.93a14 14 0 0 0-19.44 0 1 1 0 0 0 1.38 1.44 12 12 0 0 1 16.68 0 1 1 0 0 0 .69.28 1 1 0 0 0 .72-.31 1 1 0 0 0-.03-1.41z\" \u{f002}/></g></g></svg>");
+result.insert("wifioff", "<svg \u{f000} viewBox=\"0 0 24 24\" \u{f001}><g data-name=\"Layer 2\"><g data-name=\"wifi-off\"><rect width=\"24\" height=\"24\" opacity=\"0\"/><circle cx=\"12\" cy=\"19\" r=\"1\" \u{f002}/><path d=\"M12.44 11l-1.9-1.89-2.46-2.44-1.55-1.55-1.82-1.83a1 1 0 0 0-1.42 1.42l1.38 1.37 1.46 1.46 2.23 2.24 1.55 1.54 2.74 2.74 2.79 2.8 3.85 3.85a1 1 0 0 0 1.42 0 1 1 0 0 0 0-1.42z\" \u{f002}/><path d=\"M21.72 7.93A13.93 13.93 0 0 0 12 4a14.1 14.1 0 0 0-4.44.73l1.62 1.62a11.89 11.89 0 0 1 11.16 3 1 1 0 0 0 .69.28 1 1 0 0 0 .72-.31 1 1 0 0 0-.03-1.39z\" \u{f002}/><path d=\"M3.82 6.65a14.32 14.32 0 0 0-1.54 1.28 1 1 0 0 0 1.38 1.44 13.09 13.09 0 0 1 1.6-1.29z\" \u{f002}/><path d=\"M17 13.14a1 1 0 0 0 .71.3 1 1 0 0 0 .72-1.69A9 9 0 0 0 12 9h-.16l2.35 2.35A7 7 0 0 1 17 13.14z\" \u{f002}/><path d=\"M7.43 10.26a8.8 8.8 0 0 0-1.9 1.49A1 1 0 0 0 7 13.14a7.3 7.3 0 0 1 2-1.41z\" \u{f002}/><path d=\"M8.53 15.4a1 1 0 1 0 1.39 1.44 3.06 3.06 0 0 1 3.84-.25l-2.52-2.52a5 5 0 0 0-2.71 1.33z\" \u{f002}/></g></g></svg>");
+result.insert("wine", "<svg \u{f000} \u{f001} viewBox=\"0 0 512 512\"><path \u{f002} d=\"M414.56,94.92V80a16,16,0,0,0-16-16H113.44a16,16,0,0,0-16,16V94.92c-1.46,11.37-9.65,90.74,36.93,144.69,24.87,28.8,60.36,44.85,105.63,47.86V416H160a16,16,0,0,0,0,32H352a16,16,0,0,0,0-32H272V287.47c45.27-3,80.76-19.06,105.63-47.86C424.21,185.66,416,106.29,414.56,94.92Zm-285.3,3.41a15.14,15.14,0,0,0,.18-2.33H382.56a15.14,15.14,0,0,0,.18,2.33,201.91,201.91,0,0,1,0,45.67H129.32A204.29,204.29,0,0,1,129.26,98.33Z\"/></svg>");
+result.insert("youtube", "<svg \u{f000} \u{f001} \u{f002} class=\"bi bi-youtube\" viewBox=\"0 0 16 16\"><path d=\"M8.051 1.999h.089c.822.003 4.987.033 6.11.335a2.01 2.01 0 0 1 1.415 1.42c.101.38.172.883.22 1.402l.01.104.022.26.008.104c.065.914.073 1.77.074 1.957v.075c-.001.194-.01 1.108-.082 2.06l-.008.105-.009.104c-.05.572-.124 1.14-.235 1.558a2.007 2.007 0 0 1-1.415 1.42c-1.16.312-5.569.334-6.18.335h-.142c-.309 0-1.587-.006-2.927-.052l-.17-.006-.087-.004-.171-.007-.171-.007c-1.11-.049-2.167-.128-2.654-.26a2.007 2.007 0 0 1-1.415-1.419c-.111-.417-.185-.986-.235-1.558L.09 9.82l-.008-.104A31.4 31.4 0 0 1 0 7.68v-.123c.002-.215.01-.958.064-1.778l.007-.103.003-.052.008-.104.022-.26.01-.104c.048-.519.119-1.023.22-1.402a2.007 2.007 0 0 1 1.415-1.42c.487-.13 1.544-.21 2.654-.26l.17-.007.172-.006.086-.003.171-.007A99.788 99.788 0 0 1 7.858 2h.193zM6.4 5.209v4.818l4.157-2.408L6.4 5.209z\"/></svg>");
 
     result
 }
 
 
-fn format_xmlns() -> Vec<u16> {
-    vec![]
+fn format_xmlns() -> String {
+    String::from("xmlns=\"http://www.w3.org/2000/svg\"")
 }
 
 
-fn format_size(n: usize) -> Vec<u16> {
-    into_v16(&format!("width=\"{}\" height=\"{}\"", n, n))
+fn format_size(n: usize) -> String {
+    format!("width=\"{}\" height=\"{}\"", n, n)
 }
 
 
-fn format_color(r: u8, g: u8, b: u8) -> Vec<u16> {
-    into_v16(&format!("fill=\"rgb({}, {}, {})\"", r, g, b))
-}
+// `None` follows the color of the text
+fn format_color(color: Option<(u8, u8, u8)>) -> String {
 
+    match color {
+        Some((r, g, b)) => format!("fill=\"rgb({}, {}, {})\"", r, g, b),
+        None => String::from("fill=\"currentColor\"")
+    }
 
-fn into_v16(s: &str) -> Vec<u16> {
-    String::from(s).encode_utf16().collect()
 }
 
 
-pub fn format(icon: &Vec<u16>, size: usize, r: u8, g: u8, b: u8) -> Vec<u16> {
+pub fn format(icon: &str, size: usize, color: Option<(u8, u8, u8)>) -> String {
 
-    let mut result = Vec::with_capacity(icon.len() + 60);
+    let mut result = String::with_capacity(icon.len() + 60);
 
-    for c in icon.iter() {
+    for c in icon.chars() {
 
-        if *c < XMLNS {
-            result.push(*c);
+        if c == COLOR {
+            result.push_str(&format_color(color));
         }
 
-        else if *c == COLOR {
-
-            for color_char in format_color(r, g, b) {
-                result.push(color_char);
-            }
-
+        else if c == SIZE {
+            result.push_str(&format_size(size));
         }
 
-        else if *c == SIZE {
-
-            for size_char in format_size(size) {
-                result.push(size_char);
-            }
-
+        else if c == XMLNS {
+            result.push_str(&format_xmlns());
         }
 
-        else if *c == XMLNS {
-
-            for xmlns_char in format_xmlns() {
-                result.push(xmlns_char);
-            }
-
+        else {
+            result.push(c);
         }
 
     }
@@ -161,9 +150,9 @@ pub fn format(icon: &Vec<u16>, size: usize, r: u8, g: u8, b: u8) -> Vec<u16> {
     result
 }
 
-const COLOR: u16 = 61442;
-const SIZE: u16 = 61441;
-const XMLNS: u16 = 61440;
+const COLOR: char = '\u{f002}';
+const SIZE: char = '\u{f001}';
+const XMLNS: char = '\u{f000}';
 
 /*
 MIT License
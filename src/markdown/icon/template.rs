@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 
 // this file is auto-generated by a Python file!
-pub fn data() -> HashMap<Vec<u16>, Vec<u16>> {
+pub fn data() -> HashMap<&'static str, &'static str> {
 
     let mut result = HashMap::with_capacity(100);
 
@@ -12,58 +12,47 @@ pub fn data() -> HashMap<Vec<u16>, Vec<u16>> {
 }
 
 
-fn format_xmlns() -> Vec<u16> {
-    vec![]
+fn format_xmlns() -> String {
+    String::from("xmlns=\"http://www.w3.org/2000/svg\"")
 }
 
 
-fn format_size(n: usize) -> Vec<u16> {
-    into_v16(&format!("width=\"{}\" height=\"{}\"", n, n))
+fn format_size(n: usize) -> String {
+    format!("width=\"{}\" height=\"{}\"", n, n)
 }
 
 
-fn format_color(r: u8, g: u8, b: u8) -> Vec<u16> {
-    into_v16(&format!("fill=\"rgb({}, {}, {})\"", r, g, b))
-}
+// `None` follows the color of the text
+fn format_color(color: Option<(u8, u8, u8)>) -> String {
 
+    match color {
+        Some((r, g, b)) => format!("fill=\"rgb({}, {}, {})\"", r, g, b),
+        None => String::from("fill=\"currentColor\"")
+    }
 
-fn into_v16(s: &str) -> Vec<u16> {
-    String::from(s).encode_utf16().collect()
 }
 
 
-pub fn format(icon: &Vec<u16>, size: usize, r: u8, g: u8, b: u8) -> Vec<u16> {
+pub fn format(icon: &str, size: usize, color: Option<(u8, u8, u8)>) -> String {
 
-    let mut result = Vec::with_capacity(icon.len() + 60);
+    let mut result = String::with_capacity(icon.len() + 60);
 
-    for c in icon.iter() {
+    for c in icon.chars() {
 
-        if *c < XMLNS {
-            result.push(*c);
+        if c == COLOR {
+            result.push_str(&format_color(color));
         }
 
-        else if *c == COLOR {
-
-            for color_char in format_color(r, g, b) {
-                result.push(color_char);
-            }
-
+        else if c == SIZE {
+            result.push_str(&format_size(size));
         }
 
-        else if *c == SIZE {
-
-            for size_char in format_size(size) {
-                result.push(size_char);
-            }
-
+        else if c == XMLNS {
+            result.push_str(&format_xmlns());
         }
 
-        else if *c == XMLNS {
-
-            for xmlns_char in format_xmlns() {
-                result.push(xmlns_char);
-            }
-
+        else {
+            result.push(c);
         }
 
     }
@@ -1,9 +1,15 @@
+use crate::markdown::abbreviation::{Abbreviations, render_abbreviations};
+use crate::markdown::attribute::{Attributes, parse_attributes};
 use crate::markdown::line::{Line, LineType};
+use crate::utils::{drop_while, remove_special_characters, take_and_drop_while};
 use self::decoration::render_code_spans;
+use crate::option::RenderOption;
 
 mod decoration;
+mod emoji;
 mod link;
 pub mod tag;
+pub mod span;
 
 
 /*
@@ -14,6 +20,7 @@ I can't figure out nice way to deal with multiple nested inline elements. I can'
 *inlines are rendered sequentially, in this order*
 
 code span
+emoji
 link
 image
 italic
@@ -26,31 +33,75 @@ alignment
 color
 size
 box
+abbreviation
 --- implemented so far
 svg
 footnote
 */
 
 
-pub fn render_inlines(lines: Vec<Line>) -> Vec<Line> {
+pub fn render_inlines(lines: Vec<Line>, option: &RenderOption, abbreviations: &Abbreviations) -> Vec<Line> {
 
-    lines.into_iter().map(render_inline).collect()
+    lines.into_iter().map(|line| render_inline(line, option, abbreviations)).collect()
 }
 
 
-pub fn render_inline(mut line: Line) -> Line {
+pub fn render_inline(mut line: Line, option: &RenderOption, abbreviations: &Abbreviations) -> Line {
     
     match line.line_type {
         LineType::FencedCode | LineType::CodeFence | LineType::ThematicBreak
         | LineType::Empty | LineType::TableDelimiter | LineType::RenderedTable
-        | LineType::Tag => line,
+        | LineType::RenderedList | LineType::RenderedBlockquote | LineType::RenderedDefinitionList
+        | LineType::RenderedHeader | LineType::Tag => line,
 
-        LineType::Paragraph | LineType::Header | LineType::Blockquote(_)
-        | LineType::OrderedList | LineType::UnorderedList => {
-            let new_content = render_code_spans(&line.content);
-            line.content = new_content;
+        LineType::Header => render_header(line, option),
+
+        LineType::Paragraph | LineType::Blockquote(_)
+        | LineType::OrderedList | LineType::UnorderedList | LineType::Definition => {
+            let mut new_content = String::with_capacity(line.content.len() * 2);
+            render_code_spans(&line.content, option, &mut new_content);
+
+            if !abbreviations.is_empty() {
+                line.content = String::with_capacity(new_content.len() * 2);
+                render_abbreviations(&new_content, abbreviations, &mut line.content);
+            }
+
+            else {
+                line.content = new_content;
+            }
 
             line
         }
     }
-}
\ No newline at end of file
+}
+
+
+// `## Title {#id .class}` -> `<h2 id="id" class="class">Title</h2>`
+// the id is made of the title unless it's given, and abbreviations are not rendered in headings, so that they're not in the ids
+fn render_header(mut line: Line, option: &RenderOption) -> Line {
+
+    let (sharps, sharps_removed) = take_and_drop_while(&line.content, '#');
+    let level = sharps.len();
+    let title = drop_while(sharps_removed, ' ');
+
+    let (title, mut attributes) = match parse_attributes(title) {
+        Some((begin, attributes)) => (title[..begin].trim_end(), attributes),
+        None => (title, Attributes::default())
+    };
+
+    let mut rendered_title = String::with_capacity(title.len() * 2);
+    render_code_spans(title, option, &mut rendered_title);
+
+    if !option.heading_ids {
+        attributes.id = None;
+    }
+
+    else if attributes.id.is_none() {
+        attributes.id = Some(remove_special_characters(&rendered_title));
+    }
+
+    line.content = format!("<h{}{}>{}</h{}>", level, attributes.render(&[]), rendered_title, level);
+    line.line_type = LineType::RenderedHeader;
+
+    line
+}
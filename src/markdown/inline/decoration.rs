@@ -1,29 +1,26 @@
-use crate::consts::*;
 use crate::markdown::escape::prevent_backslash_escape;
 use crate::markdown::inline::link::render_link;
 use crate::markdown::inline::tag::render_tag;
+use crate::option::RenderOption;
 
 
-pub fn render_code_spans(content: &Vec<u16>) -> Vec<u16> {
+pub fn render_code_spans(content: &str, option: &RenderOption, buffer: &mut String) {
 
     let mut is_inside_code = false;
     let mut last_index = 0;
-    let mut result = vec![];
 
-    for (ind, c) in content.iter().enumerate() {
+    for (ind, c) in content.bytes().enumerate() {
 
-        if *c == U16_BACKTICK {
+        if c == b'`' {
 
             if is_inside_code {
-                result.push(vec![
-                    vec![U16_LESS_THAN, U16_SMALL_C, U16_SMALL_O, U16_SMALL_D, U16_SMALL_E, U16_SPACE, U16_SMALL_C, U16_SMALL_L, U16_SMALL_A, U16_SMALL_S, U16_SMALL_S, U16_EQUAL, U16_DOUBLE_QUOTE, U16_SMALL_S, U16_SMALL_H, U16_SMALL_O, U16_SMALL_R, U16_SMALL_T, U16_DOUBLE_QUOTE, U16_GREATER_THAN],
-                    prevent_backslash_escape(&content[last_index..ind].to_vec()),
-                    vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_C, U16_SMALL_O, U16_SMALL_D, U16_SMALL_E, U16_GREATER_THAN],
-                ].concat());
+                buffer.push_str(&format!("<code class=\"{}short\">", option.class_prefix));
+                buffer.push_str(&prevent_backslash_escape(&content[last_index..ind]));
+                buffer.push_str("</code>");
             }
 
             else {
-                result.push(render_link(&content[last_index..ind].to_vec()));
+                render_link(&content[last_index..ind], option, buffer);
             }
 
             last_index = ind + 1;
@@ -36,32 +33,31 @@ pub fn render_code_spans(content: &Vec<u16>) -> Vec<u16> {
         last_index -= 1;
     }
 
-    result.push(render_link(&content[last_index..].to_vec()));
-    result.concat()
+    render_link(&content[last_index..], option, buffer);
 }
 
 
 // <em>
-pub fn render_italic(content: &Vec<u16>) -> Vec<u16> {
+pub fn render_italic(content: &str, option: &RenderOption, buffer: &mut String) {
 
     let mut is_inside_emphasis = false;
     let mut last_index = 0;
-    let mut result = vec![];
+    let bytes = content.as_bytes();
 
     for curr_index in 0..content.len() {
 
-        if is_italic_delimiter(content, curr_index) {
+        if is_italic_delimiter(bytes, curr_index) {
 
             // <em> ends
-            if is_inside_emphasis && content[curr_index - 1] != U16_SPACE {
-                result.push(vec![U16_LESS_THAN, U16_SMALL_E, U16_SMALL_M, U16_GREATER_THAN]);
-                result.push(render_bold(&content[last_index..curr_index].to_vec()));
-                result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_E, U16_SMALL_M, U16_GREATER_THAN]);
+            if is_inside_emphasis && bytes[curr_index - 1] != b' ' {
+                buffer.push_str("<em>");
+                render_bold(&content[last_index..curr_index], option, buffer);
+                buffer.push_str("</em>");
             }
 
             // <em> begins
-            else if curr_index + 1 < content.len() && content[curr_index + 1] != U16_SPACE {
-                result.push(render_bold(&content[last_index..curr_index].to_vec()));
+            else if curr_index + 1 < content.len() && bytes[curr_index + 1] != b' ' {
+                render_bold(&content[last_index..curr_index], option, buffer);
             }
 
             else {
@@ -78,42 +74,42 @@ pub fn render_italic(content: &Vec<u16>) -> Vec<u16> {
         last_index -= 1;
     }
 
-    result.push(render_bold(&content[last_index..].to_vec()));
-    result.concat()
+    render_bold(&content[last_index..], option, buffer);
 }
 
 
-fn is_italic_delimiter(content: &Vec<u16>, index: usize) -> bool {
+pub fn is_italic_delimiter(content: &[u8], index: usize) -> bool {
 
-    content[index] == U16_ASTERISK && (
-        index == 0 || content[index - 1] != U16_ASTERISK
+    content[index] == b'*' && (
+        index == 0 || content[index - 1] != b'*'
     ) && (
-        index == content.len() - 1 || content[index + 1] != U16_ASTERISK
+        index == content.len() - 1 || content[index + 1] != b'*'
     )
 }
 
 
 // <strong>
-fn render_bold(content: &Vec<u16>) -> Vec<u16> {
+fn render_bold(content: &str, option: &RenderOption, buffer: &mut String) {
 
     let mut is_inside_bold = false;
     let mut last_index = 0;
-    let mut result = vec![];
+    let bytes = content.as_bytes();
 
     for curr_index in 0..content.len() {
 
-        if is_bold_delimiter(content, curr_index) {
+        // `***` doesn't close the `**` it begins with
+        if is_bold_delimiter(bytes, curr_index) && curr_index >= last_index {
 
             // <strong> ends
-            if is_inside_bold && content[curr_index - 1] != U16_SPACE {
-                result.push(vec![U16_LESS_THAN, U16_SMALL_S, U16_SMALL_T, U16_SMALL_R, U16_SMALL_O, U16_SMALL_N, U16_SMALL_G, U16_GREATER_THAN]);
-                result.push(render_del(&content[last_index..curr_index].to_vec()));
-                result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_S, U16_SMALL_T, U16_SMALL_R, U16_SMALL_O, U16_SMALL_N, U16_SMALL_G, U16_GREATER_THAN]);
+            if is_inside_bold && bytes[curr_index - 1] != b' ' {
+                buffer.push_str("<strong>");
+                render_del(&content[last_index..curr_index], option, buffer);
+                buffer.push_str("</strong>");
             }
 
             // <strong> begins
-            else if curr_index + 2 < content.len() && content[curr_index + 2] != U16_SPACE {
-                result.push(render_del(&content[last_index..curr_index].to_vec()));
+            else if curr_index + 2 < content.len() && bytes[curr_index + 2] != b' ' {
+                render_del(&content[last_index..curr_index], option, buffer);
             }
 
             else {
@@ -130,38 +126,38 @@ fn render_bold(content: &Vec<u16>) -> Vec<u16> {
         last_index -= 2;
     }
 
-    result.push(render_del(&content[last_index..].to_vec()));
-    result.concat()
+    render_del(&content[last_index..], option, buffer);
 }
 
 
-fn is_bold_delimiter(content: &Vec<u16>, index: usize) -> bool {
+pub fn is_bold_delimiter(content: &[u8], index: usize) -> bool {
 
-    content[index] == U16_ASTERISK && index + 1 < content.len() && content[index + 1] == U16_ASTERISK
+    content[index] == b'*' && index + 1 < content.len() && content[index + 1] == b'*'
 }
 
 
 // <del>
-fn render_del(content: &Vec<u16>) -> Vec<u16> {
+fn render_del(content: &str, option: &RenderOption, buffer: &mut String) {
 
     let mut is_inside_del = false;
     let mut last_index = 0;
-    let mut result = vec![];
+    let bytes = content.as_bytes();
 
     for curr_index in 0..content.len() {
 
-        if is_del_delimiter(content, curr_index) {
+        // `~~~` doesn't close the `~~` it begins with
+        if is_del_delimiter(bytes, curr_index) && curr_index >= last_index {
 
             // <del> ends
-            if is_inside_del && content[curr_index - 1] != U16_SPACE {
-                result.push(vec![U16_LESS_THAN, U16_SMALL_D, U16_SMALL_E, U16_SMALL_L, U16_GREATER_THAN]);
-                result.push(render_underline(&content[last_index..curr_index].to_vec()));
-                result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_D, U16_SMALL_E, U16_SMALL_L, U16_GREATER_THAN]);
+            if is_inside_del && bytes[curr_index - 1] != b' ' {
+                buffer.push_str("<del>");
+                render_underline(&content[last_index..curr_index], option, buffer);
+                buffer.push_str("</del>");
             }
 
             // <del> begins
-            else if curr_index + 2 < content.len() && content[curr_index + 2] != U16_SPACE {
-                result.push(render_underline(&content[last_index..curr_index].to_vec()));
+            else if curr_index + 2 < content.len() && bytes[curr_index + 2] != b' ' {
+                render_underline(&content[last_index..curr_index], option, buffer);
             }
 
             else {
@@ -178,36 +174,40 @@ fn render_del(content: &Vec<u16>) -> Vec<u16> {
         last_index -= 2;
     }
 
-    result.push(render_underline(&content[last_index..].to_vec()));
-    result.concat()
+    render_underline(&content[last_index..], option, buffer);
 }
 
 
-fn is_del_delimiter(content: &Vec<u16>, index: usize) -> bool {
+pub fn is_del_delimiter(content: &[u8], index: usize) -> bool {
 
-    content[index] == U16_TILDE && index + 1 < content.len() && content[index + 1] == U16_TILDE
+    content[index] == b'~' && index + 1 < content.len() && content[index + 1] == b'~'
 }
 
 
 // <u>
-fn render_underline(content: &Vec<u16>) -> Vec<u16> {
+fn render_underline(content: &str, option: &RenderOption, buffer: &mut String) {
     
     let mut is_inside_underline = false;
     let mut last_index = 0;
-    let mut result = vec![];
+    let bytes = content.as_bytes();
 
     for curr_index in 0..content.len() {
 
+        // `~_~` doesn't close the `~_` it begins with
+        if curr_index < last_index {
+            continue;
+        }
+
         // <u> ends
-        if is_inside_underline && is_underline_end(content, curr_index) {
-            result.push(vec![U16_LESS_THAN, U16_SMALL_U, U16_GREATER_THAN]);
-            result.push(render_subscript(&content[last_index..curr_index].to_vec()));
-            result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_U, U16_GREATER_THAN]);
+        if is_inside_underline && is_underline_end(bytes, curr_index) {
+            buffer.push_str("<u>");
+            render_subscript(&content[last_index..curr_index], option, buffer);
+            buffer.push_str("</u>");
         }
 
         // <u> begins
-        else if !is_inside_underline && is_underline_begin(content, curr_index) {
-            result.push(render_subscript(&content[last_index..curr_index].to_vec()));
+        else if !is_inside_underline && is_underline_begin(bytes, curr_index) {
+            render_subscript(&content[last_index..curr_index], option, buffer);
         }
 
         else {
@@ -222,46 +222,45 @@ fn render_underline(content: &Vec<u16>) -> Vec<u16> {
         last_index -= 2;
     }
 
-    result.push(render_subscript(&content[last_index..].to_vec()));
-    result.concat()
+    render_subscript(&content[last_index..], option, buffer);
 }
 
 
 // content[index] == `~`
-fn is_underline_begin(content: &Vec<u16>, index: usize) -> bool {
+pub fn is_underline_begin(content: &[u8], index: usize) -> bool {
 
-    content[index] == U16_TILDE && index + 2 < content.len() && content[index + 1] == U16_UNDERBAR && content[index + 2] != U16_SPACE
+    content[index] == b'~' && index + 2 < content.len() && content[index + 1] == b'_' && content[index + 2] != b' '
 }
 
 
 // content[index] == `_`
-fn is_underline_end(content: &Vec<u16>, index: usize) -> bool {
+pub fn is_underline_end(content: &[u8], index: usize) -> bool {
 
-    content[index] == U16_UNDERBAR && index + 1 < content.len() && content[index + 1] == U16_TILDE && index > 0 && content[index - 1] != U16_SPACE
+    content[index] == b'_' && index + 1 < content.len() && content[index + 1] == b'~' && index > 0 && content[index - 1] != b' '
 }
 
 
 // <sub>
-fn render_subscript(content: &Vec<u16>) -> Vec<u16> {
+fn render_subscript(content: &str, option: &RenderOption, buffer: &mut String) {
 
     let mut is_inside_subscript = false;
     let mut last_index = 0;
-    let mut result = vec![];
+    let bytes = content.as_bytes();
 
     for curr_index in 0..content.len() {
 
-        if is_subscript_delimiter(content, curr_index) {
+        if is_subscript_delimiter(bytes, curr_index) {
 
             // <sub> ends
-            if is_inside_subscript && content[curr_index - 1] != U16_SPACE && content[curr_index - 1] != U16_UNDERBAR {
-                result.push(vec![U16_LESS_THAN, U16_SMALL_S, U16_SMALL_U, U16_SMALL_B, U16_GREATER_THAN]);
-                result.push(render_superscript(&content[last_index..curr_index].to_vec()));
-                result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_S, U16_SMALL_U, U16_SMALL_B, U16_GREATER_THAN]);
+            if is_inside_subscript && bytes[curr_index - 1] != b' ' && bytes[curr_index - 1] != b'_' {
+                buffer.push_str("<sub>");
+                render_superscript(&content[last_index..curr_index], option, buffer);
+                buffer.push_str("</sub>");
             }
 
             // <sub> begins
-            else if curr_index + 1 < content.len() && content[curr_index + 1] != U16_SPACE && content[curr_index + 1] != U16_UNDERBAR {
-                result.push(render_superscript(&content[last_index..curr_index].to_vec()));
+            else if curr_index + 1 < content.len() && bytes[curr_index + 1] != b' ' && bytes[curr_index + 1] != b'_' {
+                render_superscript(&content[last_index..curr_index], option, buffer);
             }
 
             else {
@@ -272,7 +271,7 @@ fn render_subscript(content: &Vec<u16>) -> Vec<u16> {
             is_inside_subscript = !is_inside_subscript;
         }
 
-        if is_inside_subscript && content[curr_index] == U16_SPACE {
+        if is_inside_subscript && bytes[curr_index] == b' ' {
             last_index -= 1;
             is_inside_subscript = false;
         }
@@ -283,42 +282,41 @@ fn render_subscript(content: &Vec<u16>) -> Vec<u16> {
         last_index -= 1;
     }
 
-    result.push(render_superscript(&content[last_index..].to_vec()));
-    result.concat()
+    render_superscript(&content[last_index..], option, buffer);
 }
 
 
-fn is_subscript_delimiter(content: &Vec<u16>, index: usize) -> bool {
+pub fn is_subscript_delimiter(content: &[u8], index: usize) -> bool {
 
-    content[index] == U16_TILDE && (
-        index == 0 || content[index - 1] != U16_TILDE
+    content[index] == b'~' && (
+        index == 0 || content[index - 1] != b'~'
     ) && (
-        index == content.len() - 1 || content[index + 1] != U16_TILDE
+        index == content.len() - 1 || content[index + 1] != b'~'
     )
 }
 
 
 // <sup>
-fn render_superscript(content: &Vec<u16>) -> Vec<u16> {
+fn render_superscript(content: &str, option: &RenderOption, buffer: &mut String) {
 
     let mut is_inside_superscript = false;
     let mut last_index = 0;
-    let mut result = vec![];
+    let bytes = content.as_bytes();
 
     for curr_index in 0..content.len() {
 
-        if is_superscript_delimiter(content, curr_index) {
+        if is_superscript_delimiter(bytes, curr_index) {
 
             // <sup> ends
-            if is_inside_superscript && content[curr_index - 1] != U16_SPACE {
-                result.push(vec![U16_LESS_THAN, U16_SMALL_S, U16_SMALL_U, U16_SMALL_P, U16_GREATER_THAN]);
-                result.push(render_tag(&content[last_index..curr_index].to_vec()));
-                result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_S, U16_SMALL_U, U16_SMALL_P, U16_GREATER_THAN]);
+            if is_inside_superscript && bytes[curr_index - 1] != b' ' {
+                buffer.push_str("<sup>");
+                render_tag(&content[last_index..curr_index], option, buffer);
+                buffer.push_str("</sup>");
             }
 
             // <sup> begins
-            else if curr_index + 1 < content.len() && content[curr_index + 1] != U16_SPACE {
-                result.push(render_tag(&content[last_index..curr_index].to_vec()));
+            else if curr_index + 1 < content.len() && bytes[curr_index + 1] != b' ' {
+                render_tag(&content[last_index..curr_index], option, buffer);
             }
 
             else {
@@ -329,7 +327,7 @@ fn render_superscript(content: &Vec<u16>) -> Vec<u16> {
             is_inside_superscript = !is_inside_superscript;
         }
 
-        if is_inside_superscript && content[curr_index] == U16_SPACE {
+        if is_inside_superscript && bytes[curr_index] == b' ' {
             last_index -= 1;
             is_inside_superscript = false;
         }
@@ -340,16 +338,15 @@ fn render_superscript(content: &Vec<u16>) -> Vec<u16> {
         last_index -= 1;
     }
 
-    result.push(render_tag(&content[last_index..].to_vec()));
-    result.concat()
+    render_tag(&content[last_index..], option, buffer);
 }
 
 
-fn is_superscript_delimiter(content: &Vec<u16>, index: usize) -> bool {
+pub fn is_superscript_delimiter(content: &[u8], index: usize) -> bool {
 
-    content[index] == U16_CARET && (
-        index == 0 || content[index - 1] != U16_CARET
+    content[index] == b'^' && (
+        index == 0 || content[index - 1] != b'^'
     ) && (
-        index == content.len() - 1 || content[index + 1] != U16_CARET
+        index == content.len() - 1 || content[index + 1] != b'^'
     )
 }
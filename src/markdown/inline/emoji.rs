@@ -0,0 +1,106 @@
+use crate::option::{ElementStyle, RenderOption};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+
+lazy_static! {
+    static ref EMOJI: HashMap<&'static str, &'static str> = [
+        ("smile", "😄"), ("smiley", "😃"), ("grinning", "😀"), ("grin", "😁"), ("laughing", "😆"),
+        ("joy", "😂"), ("rofl", "🤣"), ("wink", "😉"), ("blush", "😊"), ("innocent", "😇"),
+        ("heart_eyes", "😍"), ("kissing_heart", "😘"), ("yum", "😋"), ("stuck_out_tongue", "😛"), ("sunglasses", "😎"),
+        ("smirk", "😏"), ("thinking", "🤔"), ("neutral_face", "😐"), ("expressionless", "😑"), ("unamused", "😒"),
+        ("roll_eyes", "🙄"), ("grimacing", "😬"), ("relieved", "😌"), ("pensive", "😔"), ("sleepy", "😪"),
+        ("sleeping", "😴"), ("mask", "😷"), ("nerd_face", "🤓"), ("confused", "😕"), ("worried", "😟"),
+        ("slightly_smiling_face", "🙂"), ("upside_down_face", "🙃"), ("astonished", "😲"), ("flushed", "😳"), ("scream", "😱"),
+        ("cry", "😢"), ("sob", "😭"), ("sweat_smile", "😅"), ("sweat", "😓"), ("angry", "😠"),
+        ("rage", "😡"), ("skull", "💀"), ("poop", "💩"), ("clown_face", "🤡"), ("ghost", "👻"),
+        ("alien", "👽"), ("robot", "🤖"), ("see_no_evil", "🙈"), ("wave", "👋"), ("ok_hand", "👌"),
+        ("+1", "👍"), ("thumbsup", "👍"), ("-1", "👎"), ("thumbsdown", "👎"), ("clap", "👏"),
+        ("raised_hands", "🙌"), ("pray", "🙏"), ("muscle", "💪"), ("point_up", "☝️"), ("point_right", "👉"),
+        ("v", "✌️"), ("eyes", "👀"), ("brain", "🧠"), ("heart", "❤️"), ("broken_heart", "💔"),
+        ("sparkling_heart", "💖"), ("star", "⭐"), ("star2", "🌟"), ("sparkles", "✨"), ("zap", "⚡"),
+        ("fire", "🔥"), ("boom", "💥"), ("100", "💯"), ("tada", "🎉"), ("confetti_ball", "🎊"),
+        ("gift", "🎁"), ("trophy", "🏆"), ("medal_sports", "🏅"), ("rocket", "🚀"), ("airplane", "✈️"),
+        ("sunny", "☀️"), ("cloud", "☁️"), ("umbrella", "☔"), ("snowflake", "❄️"), ("rainbow", "🌈"),
+        ("earth_asia", "🌏"), ("moon", "🌙"), ("seedling", "🌱"), ("evergreen_tree", "🌲"), ("cherry_blossom", "🌸"),
+        ("rose", "🌹"), ("four_leaf_clover", "🍀"), ("apple", "🍎"), ("coffee", "☕"), ("beer", "🍺"),
+        ("pizza", "🍕"), ("cake", "🍰"), ("cat", "🐱"), ("dog", "🐶"), ("crab", "🦀"),
+        ("bug", "🐛"), ("snake", "🐍"), ("penguin", "🐧"), ("octocat", "🐙"), ("books", "📚"),
+        ("memo", "📝"), ("pencil2", "✏️"), ("bulb", "💡"), ("mag", "🔍"), ("lock", "🔒"),
+        ("unlock", "🔓"), ("key", "🔑"), ("hammer", "🔨"), ("wrench", "🔧"), ("gear", "⚙️"),
+        ("package", "📦"), ("link", "🔗"), ("email", "📧"), ("bell", "🔔"), ("calendar", "📆"),
+        ("chart_with_upwards_trend", "📈"), ("computer", "💻"), ("keyboard", "⌨️"), ("iphone", "📱"), ("warning", "⚠️"),
+        ("no_entry", "⛔"), ("x", "❌"), ("heavy_check_mark", "✔️"), ("white_check_mark", "✅"), ("question", "❓"),
+        ("exclamation", "❗"), ("construction", "🚧"), ("recycle", "♻️"), ("hourglass", "⌛"), ("stopwatch", "⏱️"),
+        ("arrow_right", "➡️"), ("arrow_left", "⬅️"), ("arrow_up", "⬆️"), ("arrow_down", "⬇️"), ("new", "🆕"),
+    ].into_iter().collect();
+}
+
+
+// `:smile:` -> `😄`, unknown shortcodes are rendered as they are
+// `RenderOption::custom_emoji` is looked up first, then `RenderOption::emoji` and the bundled shortcodes
+pub fn render_emoji(content: &str, option: &RenderOption, buffer: &mut String) {
+
+    let mut last_index = 0;
+    let mut curr_index = 0;
+
+    while curr_index < content.len() {
+
+        if let Some((end_index, emoji)) = get_shortcode(content, curr_index, option) {
+            buffer.push_str(&content[last_index..curr_index]);
+            buffer.push_str(&emoji);
+            last_index = end_index + 1;
+            curr_index = last_index;
+            continue;
+        }
+
+        // the closing `:` may begin another shortcode, ex) `10:30:smile:`
+        curr_index += 1;
+    }
+
+    buffer.push_str(&content[last_index..]);
+}
+
+
+// (index of the closing `:`, emoji) if a known shortcode begins at `content[index]`
+pub fn get_shortcode(content: &str, index: usize, option: &RenderOption) -> Option<(usize, String)> {
+
+    let bytes = content.as_bytes();
+
+    // `\:smile:` is not a shortcode
+    if bytes[index] != b':' || (index > 0 && bytes[index - 1] == b'\\') {
+        return None;
+    }
+
+    let name_len = bytes[index + 1..].iter().take_while(|c| is_shortcode_character(**c)).count();
+    let end_index = index + name_len + 1;
+
+    if name_len > 0 && end_index < bytes.len() && bytes[end_index] == b':' {
+        get_emoji(&content[index + 1..end_index], option).map(|emoji| (end_index, emoji))
+    }
+
+    else {
+        None
+    }
+
+}
+
+
+fn get_emoji(name: &str, option: &RenderOption) -> Option<String> {
+
+    // <img class="emoji" src="/ferris.png" alt=":ferris:">
+    if let Some(src) = option.custom_emoji.get(name) {
+
+        return Some(match option.element_style {
+            ElementStyle::CssClass => format!("<img class=\"{}emoji\" src=\"{}\" alt=\":{}:\">", option.class_prefix, src, name),
+            ElementStyle::InlineStyle => format!("<img style=\"height:1.2em;vertical-align:middle\" src=\"{}\" alt=\":{}:\">", src, name)
+        });
+    }
+
+    option.emoji.get(name).cloned().or_else(|| EMOJI.get(name).map(|emoji| emoji.to_string()))
+}
+
+
+fn is_shortcode_character(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_' || c == b'+' || c == b'-'
+}
@@ -1,69 +1,101 @@
 use crate::utils::{get_bracket_end_index, get_parenthesis_end_index, remove_special_characters};
 use crate::markdown::escape::undo_html_escapes_safely;
 use crate::markdown::inline::decoration::render_italic;
-use crate::consts::*;
+use crate::markdown::inline::emoji::render_emoji;
+use crate::option::RenderOption;
 
 
-pub fn render_link(content: &Vec<u16>) -> Vec<u16> {
+// `[text](reference)`, `content[index]` is `[`
+pub struct Link {
+    // index of `]`
+    pub text_end_index: usize,
+    // index of `)`
+    pub end_index: usize,
+    pub reference: String,
+    // `![text](reference)`
+    pub is_image: bool
+}
+
 
-    let mut result = Vec::with_capacity(content.len());
+// emoji shortcodes are rendered here, so that `:`s in urls are not shortcodes
+pub fn render_link(content: &str, option: &RenderOption, buffer: &mut String) {
+
+    let mut result = String::with_capacity(content.len());
     let mut curr_index = 0;
+    let mut last_index = 0;
 
     while curr_index < content.len() {
 
-        if is_valid_link(content, curr_index) {
-            let link_end_index = get_bracket_end_index(content, curr_index).unwrap();
-
-            // TODO: security check
-            let ref_begin_index = link_end_index + 1;
-            let ref_end_index = get_parenthesis_end_index(content, ref_begin_index).unwrap();
-
-            let link = if curr_index > 0 && content[curr_index - 1] == U16_FACTORIAL {
-                result.pop();  // `!`
-
-                vec![
-                    vec![U16_LESS_THAN, U16_SMALL_I, U16_SMALL_M, U16_SMALL_G, U16_SPACE, U16_SMALL_S, U16_SMALL_R, U16_SMALL_C, U16_EQUAL, U16_DOUBLE_QUOTE],
-                    undo_html_escapes_safely(&content[ref_begin_index + 1..ref_end_index].to_vec()),
-                    vec![U16_DOUBLE_QUOTE, U16_SPACE, U16_SMALL_A, U16_SMALL_L, U16_SMALL_T, U16_EQUAL, U16_DOUBLE_QUOTE],
-                    remove_special_characters(&content[curr_index + 1..link_end_index].to_vec()),
-                    vec![U16_DOUBLE_QUOTE, U16_SPACE, U16_SMALL_T, U16_SMALL_I, U16_SMALL_T, U16_SMALL_L, U16_SMALL_E, U16_EQUAL, U16_DOUBLE_QUOTE],
-                    remove_special_characters(&content[curr_index + 1..link_end_index].to_vec()),
-                    vec![U16_DOUBLE_QUOTE, U16_GREATER_THAN]
-                ].concat()
-            } else {
-                vec![
-                    vec![U16_LESS_THAN, U16_SMALL_A, U16_SPACE, U16_SMALL_H, U16_SMALL_R, U16_SMALL_E, U16_SMALL_F, U16_EQUAL, U16_DOUBLE_QUOTE],
-                    undo_html_escapes_safely(&content[ref_begin_index + 1..ref_end_index].to_vec()),
-                    vec![U16_DOUBLE_QUOTE, U16_GREATER_THAN],
-                    content[curr_index + 1..link_end_index].to_vec(),
-                    vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_A, U16_GREATER_THAN]
-                ].concat()
-            };
-
-            result = vec![result, link].concat();
-            curr_index = ref_end_index;
+        // TODO: security check
+        if let Some(link) = get_link(content, curr_index) {
+            let link_text = &content[curr_index + 1..link.text_end_index];
+
+            if link.is_image {
+                render_emoji(&content[last_index..curr_index - 1], option, &mut result);  // without `!`
+                result.push_str(&render_image(link_text, &link.reference));
+            }
+
+            else {
+                render_emoji(&content[last_index..curr_index], option, &mut result);
+                result.push_str(&render_anchor(&link.reference));
+                render_emoji(link_text, option, &mut result);
+                result.push_str("</a>");
+            }
+
+            curr_index = link.end_index + 1;
+            last_index = curr_index;
         }
 
         else {
-            result.push(content[curr_index]);
+            curr_index += 1;
         }
 
-        curr_index += 1;
     }
 
-    render_italic(&result)
+    render_emoji(&content[last_index..], option, &mut result);
+
+    render_italic(&result, option, buffer);
+}
+
+
+pub fn get_link(content: &str, index: usize) -> Option<Link> {
+
+    if !is_valid_link(content, index) {
+        return None;
+    }
+
+    let text_end_index = get_bracket_end_index(content, index).unwrap();
+    let end_index = get_parenthesis_end_index(content, text_end_index + 1).unwrap();
+
+    Some(Link {
+        text_end_index,
+        end_index,
+        reference: undo_html_escapes_safely(&content[text_end_index + 2..end_index]),
+        is_image: index > 0 && content.as_bytes()[index - 1] == b'!'
+    })
 }
 
 
-fn is_valid_link(content: &Vec<u16>, index: usize) -> bool {
+pub fn render_image(link_text: &str, link_ref: &str) -> String {
+
+    let alt = remove_special_characters(link_text);
+
+    format!("<img src=\"{}\" alt=\"{}\" title=\"{}\">", link_ref, alt, alt)
+}
+
 
-    content[index] == U16_LEFT_SQUARE_BRACKET && match get_bracket_end_index(content, index) {
+// the opening tag, the link text follows it
+pub fn render_anchor(link_ref: &str) -> String {
+    format!("<a href=\"{}\">", link_ref)
+}
+
+
+fn is_valid_link(content: &str, index: usize) -> bool {
+
+    content.as_bytes()[index] == b'[' && match get_bracket_end_index(content, index) {
         None => false,
         Some(i) => {
-            i + 1 < content.len() && content[i + 1] == U16_LEFT_PARENTHESIS && match get_parenthesis_end_index(content, i + 1) {
-                None => false,
-                Some(_) => true
-            }
+            i + 1 < content.len() && content.as_bytes()[i + 1] == b'(' && get_parenthesis_end_index(content, i + 1).is_some()
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,481 @@
+use crate::markdown::attribute::parse_attributes;
+use crate::markdown::diagnostic::Span;
+use crate::markdown::inline::decoration::{
+    is_italic_delimiter, is_bold_delimiter, is_del_delimiter, is_underline_begin, is_underline_end,
+    is_subscript_delimiter, is_superscript_delimiter
+};
+use crate::markdown::inline::emoji::get_shortcode;
+use crate::markdown::inline::link::{get_link, render_anchor, render_image};
+use crate::markdown::inline::tag::{is_tag, is_valid_tag};
+use crate::markdown::line::{Line, LineType};
+use crate::option::RenderOption;
+use crate::utils::{drop_while, get_bracket_end_index, take_and_drop_while};
+
+/*
+Inline elements are rendered into strings, so they don't have their own nodes.
+This module walks the unrendered contents the same way `render_code_spans` and the functions it calls do, and records where each element comes from.
+The decorations are rendered after links and emoji, so they're found in `Linked`, which is the same string that `render_italic` gets.
+*/
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InlineKind {
+    CodeSpan,
+    Link,
+    Image,
+    Emoji,
+    Italic,
+    Bold,
+    Del,
+    Underline,
+    Subscript,
+    Superscript,
+    Tag
+}
+
+
+// the span covers the delimiters, ex) `**bold**`, `[text](url)` and `:smile:`
+#[derive(Clone, Debug, PartialEq)]
+pub struct InlineSpan {
+    pub kind: InlineKind,
+    pub span: Span
+}
+
+
+// `lines` must not be rendered yet, so that the spans are correct
+pub fn collect_inline_spans(lines: &[Line], option: &RenderOption, spans: &mut Vec<InlineSpan>) {
+
+    for ln in lines.iter() {
+
+        // (kind, begin, end) of `ln.content`
+        let mut found = vec![];
+
+        match ln.line_type {
+            LineType::Paragraph | LineType::Blockquote(_)
+            | LineType::OrderedList | LineType::UnorderedList | LineType::Definition => {
+                collect_code_spans(&ln.content, 0, option, &mut found);
+            }
+
+            // the same title as `render_header`
+            LineType::Header => {
+                let (_, sharps_removed) = take_and_drop_while(&ln.content, '#');
+                let title = drop_while(sharps_removed, ' ');
+                let title_begin = ln.content.len() - title.len();
+
+                let title = match parse_attributes(title) {
+                    Some((begin, _)) => title[..begin].trim_end(),
+                    None => title
+                };
+
+                collect_code_spans(title, title_begin, option, &mut found);
+            }
+
+            // unknown tags are in paragraphs, the same as `render_independent_tag`
+            LineType::Tag => {
+
+                if is_valid_tag(&ln.content, 0) {
+                    found.push((InlineKind::Tag, 0, get_bracket_end_index(&ln.content, 0).unwrap() + 1));
+                }
+
+                else {
+                    collect_code_spans(&ln.content, 0, option, &mut found);
+                }
+
+            }
+
+            _ => {}
+        }
+
+        spans.extend(found.into_iter().map(
+            |(kind, begin, end)| InlineSpan { kind, span: ln.span_of(begin, end) }
+        ));
+    }
+
+}
+
+
+// `content[0]` is `base` of the line, like the other `collect_*` functions
+fn collect_code_spans(content: &str, base: usize, option: &RenderOption, found: &mut Vec<(InlineKind, usize, usize)>) {
+
+    let mut is_inside_code = false;
+    let mut last_index = 0;
+
+    for (ind, c) in content.bytes().enumerate() {
+
+        if c == b'`' {
+
+            if is_inside_code {
+                found.push((InlineKind::CodeSpan, base + last_index - 1, base + ind + 1));
+            }
+
+            else {
+                collect_links(&content[last_index..ind], base + last_index, option, found);
+            }
+
+            last_index = ind + 1;
+            is_inside_code = !is_inside_code;
+        }
+
+    }
+
+    if is_inside_code {
+        last_index -= 1;
+    }
+
+    collect_links(&content[last_index..], base + last_index, option, found);
+}
+
+
+// `render_link`
+fn collect_links(content: &str, base: usize, option: &RenderOption, found: &mut Vec<(InlineKind, usize, usize)>) {
+
+    let mut linked = Linked {
+        text: String::with_capacity(content.len()),
+        sources: Vec::with_capacity(content.len())
+    };
+    let mut curr_index = 0;
+    let mut last_index = 0;
+
+    while curr_index < content.len() {
+
+        if let Some(link) = get_link(content, curr_index) {
+            let link_text = &content[curr_index + 1..link.text_end_index];
+
+            if link.is_image {
+                linked.push_emoji(&content[last_index..curr_index - 1], base + last_index, option, found);
+                linked.push_generated(&render_image(link_text, &link.reference), base + curr_index - 1);
+                found.push((InlineKind::Image, base + curr_index - 1, base + link.end_index + 1));
+            }
+
+            else {
+                linked.push_emoji(&content[last_index..curr_index], base + last_index, option, found);
+                linked.push_generated(&render_anchor(&link.reference), base + curr_index);
+                linked.push_emoji(link_text, base + curr_index + 1, option, found);
+                linked.push_generated("</a>", base + link.text_end_index);
+                found.push((InlineKind::Link, base + curr_index, base + link.end_index + 1));
+            }
+
+            curr_index = link.end_index + 1;
+            last_index = curr_index;
+        }
+
+        else {
+            curr_index += 1;
+        }
+
+    }
+
+    linked.push_emoji(&content[last_index..], base + last_index, option, found);
+
+    collect_italic(&linked, 0, linked.text.len(), found);
+}
+
+
+// the string that `render_link` gives to `render_italic`, with where each byte comes from
+// the bytes of rendered links and emoji come from the beginning of the element, or `]` for `</a>`
+struct Linked {
+    text: String,
+    sources: Vec<usize>
+}
+
+
+impl Linked {
+
+    // `render_emoji`
+    fn push_emoji(&mut self, content: &str, base: usize, option: &RenderOption, found: &mut Vec<(InlineKind, usize, usize)>) {
+
+        let mut last_index = 0;
+        let mut curr_index = 0;
+
+        while curr_index < content.len() {
+
+            if let Some((end_index, emoji)) = get_shortcode(content, curr_index, option) {
+                self.push_source(&content[last_index..curr_index], base + last_index);
+                self.push_generated(&emoji, base + curr_index);
+                found.push((InlineKind::Emoji, base + curr_index, base + end_index + 1));
+                last_index = end_index + 1;
+                curr_index = last_index;
+                continue;
+            }
+
+            curr_index += 1;
+        }
+
+        self.push_source(&content[last_index..], base + last_index);
+    }
+
+    fn push_source(&mut self, content: &str, base: usize) {
+        self.text.push_str(content);
+        self.sources.extend(base..base + content.len());
+    }
+
+    fn push_generated(&mut self, html: &str, source: usize) {
+        self.text.push_str(html);
+        self.sources.extend(std::iter::repeat_n(source, html.len()));
+    }
+
+    // `begin..end` of `text`
+    fn push(&self, kind: InlineKind, begin: usize, end: usize, found: &mut Vec<(InlineKind, usize, usize)>) {
+        found.push((kind, self.sources[begin], self.sources[end - 1] + 1));
+    }
+
+}
+
+
+// `render_italic`
+fn collect_italic(linked: &Linked, begin: usize, end: usize, found: &mut Vec<(InlineKind, usize, usize)>) {
+
+    let bytes = &linked.text.as_bytes()[begin..end];
+    let mut is_inside_emphasis = false;
+    let mut last_index = 0;
+
+    for curr_index in 0..bytes.len() {
+
+        if is_italic_delimiter(bytes, curr_index) {
+
+            if is_inside_emphasis && bytes[curr_index - 1] != b' ' {
+                linked.push(InlineKind::Italic, begin + last_index - 1, begin + curr_index + 1, found);
+                collect_bold(linked, begin + last_index, begin + curr_index, found);
+            }
+
+            else if curr_index + 1 < bytes.len() && bytes[curr_index + 1] != b' ' {
+                collect_bold(linked, begin + last_index, begin + curr_index, found);
+            }
+
+            else {
+                continue;
+            }
+
+            last_index = curr_index + 1;
+            is_inside_emphasis = !is_inside_emphasis;
+        }
+
+    }
+
+    if is_inside_emphasis {
+        last_index -= 1;
+    }
+
+    collect_bold(linked, begin + last_index, end, found);
+}
+
+
+// `render_bold`
+fn collect_bold(linked: &Linked, begin: usize, end: usize, found: &mut Vec<(InlineKind, usize, usize)>) {
+
+    let bytes = &linked.text.as_bytes()[begin..end];
+    let mut is_inside_bold = false;
+    let mut last_index = 0;
+
+    for curr_index in 0..bytes.len() {
+
+        if is_bold_delimiter(bytes, curr_index) && curr_index >= last_index {
+
+            if is_inside_bold && bytes[curr_index - 1] != b' ' {
+                linked.push(InlineKind::Bold, begin + last_index - 2, begin + curr_index + 2, found);
+                collect_del(linked, begin + last_index, begin + curr_index, found);
+            }
+
+            else if curr_index + 2 < bytes.len() && bytes[curr_index + 2] != b' ' {
+                collect_del(linked, begin + last_index, begin + curr_index, found);
+            }
+
+            else {
+                continue;
+            }
+
+            last_index = curr_index + 2;
+            is_inside_bold = !is_inside_bold;
+        }
+
+    }
+
+    if is_inside_bold {
+        last_index -= 2;
+    }
+
+    collect_del(linked, begin + last_index, end, found);
+}
+
+
+// `render_del`
+fn collect_del(linked: &Linked, begin: usize, end: usize, found: &mut Vec<(InlineKind, usize, usize)>) {
+
+    let bytes = &linked.text.as_bytes()[begin..end];
+    let mut is_inside_del = false;
+    let mut last_index = 0;
+
+    for curr_index in 0..bytes.len() {
+
+        if is_del_delimiter(bytes, curr_index) && curr_index >= last_index {
+
+            if is_inside_del && bytes[curr_index - 1] != b' ' {
+                linked.push(InlineKind::Del, begin + last_index - 2, begin + curr_index + 2, found);
+                collect_underline(linked, begin + last_index, begin + curr_index, found);
+            }
+
+            else if curr_index + 2 < bytes.len() && bytes[curr_index + 2] != b' ' {
+                collect_underline(linked, begin + last_index, begin + curr_index, found);
+            }
+
+            else {
+                continue;
+            }
+
+            last_index = curr_index + 2;
+            is_inside_del = !is_inside_del;
+        }
+
+    }
+
+    if is_inside_del {
+        last_index -= 2;
+    }
+
+    collect_underline(linked, begin + last_index, end, found);
+}
+
+
+// `render_underline`
+fn collect_underline(linked: &Linked, begin: usize, end: usize, found: &mut Vec<(InlineKind, usize, usize)>) {
+
+    let bytes = &linked.text.as_bytes()[begin..end];
+    let mut is_inside_underline = false;
+    let mut last_index = 0;
+
+    for curr_index in 0..bytes.len() {
+
+        if curr_index < last_index {
+            continue;
+        }
+
+        if is_inside_underline && is_underline_end(bytes, curr_index) {
+            linked.push(InlineKind::Underline, begin + last_index - 2, begin + curr_index + 2, found);
+            collect_subscript(linked, begin + last_index, begin + curr_index, found);
+        }
+
+        else if !is_inside_underline && is_underline_begin(bytes, curr_index) {
+            collect_subscript(linked, begin + last_index, begin + curr_index, found);
+        }
+
+        else {
+            continue;
+        }
+
+        is_inside_underline = !is_inside_underline;
+        last_index = curr_index + 2;
+    }
+
+    if is_inside_underline {
+        last_index -= 2;
+    }
+
+    collect_subscript(linked, begin + last_index, end, found);
+}
+
+
+// `render_subscript`
+fn collect_subscript(linked: &Linked, begin: usize, end: usize, found: &mut Vec<(InlineKind, usize, usize)>) {
+
+    let bytes = &linked.text.as_bytes()[begin..end];
+    let mut is_inside_subscript = false;
+    let mut last_index = 0;
+
+    for curr_index in 0..bytes.len() {
+
+        if is_subscript_delimiter(bytes, curr_index) {
+
+            if is_inside_subscript && bytes[curr_index - 1] != b' ' && bytes[curr_index - 1] != b'_' {
+                linked.push(InlineKind::Subscript, begin + last_index - 1, begin + curr_index + 1, found);
+                collect_superscript(linked, begin + last_index, begin + curr_index, found);
+            }
+
+            else if curr_index + 1 < bytes.len() && bytes[curr_index + 1] != b' ' && bytes[curr_index + 1] != b'_' {
+                collect_superscript(linked, begin + last_index, begin + curr_index, found);
+            }
+
+            else {
+                continue;
+            }
+
+            last_index = curr_index + 1;
+            is_inside_subscript = !is_inside_subscript;
+        }
+
+        if is_inside_subscript && bytes[curr_index] == b' ' {
+            last_index -= 1;
+            is_inside_subscript = false;
+        }
+
+    }
+
+    if is_inside_subscript {
+        last_index -= 1;
+    }
+
+    collect_superscript(linked, begin + last_index, end, found);
+}
+
+
+// `render_superscript`
+fn collect_superscript(linked: &Linked, begin: usize, end: usize, found: &mut Vec<(InlineKind, usize, usize)>) {
+
+    let bytes = &linked.text.as_bytes()[begin..end];
+    let mut is_inside_superscript = false;
+    let mut last_index = 0;
+
+    for curr_index in 0..bytes.len() {
+
+        if is_superscript_delimiter(bytes, curr_index) {
+
+            if is_inside_superscript && bytes[curr_index - 1] != b' ' {
+                linked.push(InlineKind::Superscript, begin + last_index - 1, begin + curr_index + 1, found);
+                collect_tags(linked, begin + last_index, begin + curr_index, found);
+            }
+
+            else if curr_index + 1 < bytes.len() && bytes[curr_index + 1] != b' ' {
+                collect_tags(linked, begin + last_index, begin + curr_index, found);
+            }
+
+            else {
+                continue;
+            }
+
+            last_index = curr_index + 1;
+            is_inside_superscript = !is_inside_superscript;
+        }
+
+        if is_inside_superscript && bytes[curr_index] == b' ' {
+            last_index -= 1;
+            is_inside_superscript = false;
+        }
+
+    }
+
+    if is_inside_superscript {
+        last_index -= 1;
+    }
+
+    collect_tags(linked, begin + last_index, end, found);
+}
+
+
+// `render_tag`
+fn collect_tags(linked: &Linked, begin: usize, end: usize, found: &mut Vec<(InlineKind, usize, usize)>) {
+
+    let content = &linked.text[begin..end];
+    let mut curr_index = 0;
+
+    while curr_index < content.len() {
+
+        if is_tag(content, curr_index) && is_valid_tag(content, curr_index) {
+            let end_index = get_bracket_end_index(content, curr_index).unwrap();
+            linked.push(InlineKind::Tag, begin + curr_index, begin + end_index + 1, found);
+            curr_index = end_index + 1;
+            continue;
+        }
+
+        curr_index += 1;
+    }
+
+}
@@ -1,50 +1,43 @@
-use crate::consts::*;
+use crate::markdown::diagnostic::Diagnostic;
+use crate::markdown::icon::{get_icon, get_icon_image, is_icon_name};
 use crate::markdown::line::{Line, LineType};
-use crate::utils::{get_bracket_end_index, lowercase};
+use crate::option::{ElementStyle, RenderOption};
+use crate::utils::get_bracket_end_index;
 use std::str::FromStr;
 
 
-pub fn render_independent_tag(lines: &Vec<Line>) -> Vec<Line> {
+pub fn render_independent_tag(lines: Vec<Line>, option: &RenderOption) -> Vec<Line> {
 
-    let mut result = Vec::with_capacity(lines.len());
+    lines.into_iter().map(
+        |mut ln| {
 
-    for ln in lines.iter() {
+            if ln.line_type == LineType::Tag {
 
-        if ln.line_type == LineType::Tag {
-            let mut new_line = ln.clone();
+                match parse_tag(&ln.content, 0, option) {
+                    Err(_) => {ln.line_type = LineType::Paragraph;}
+                    Ok(c) => {ln.content = c;}
+                }
 
-            match parse_tag(&ln.content, 0) {
-                Err(_) => {new_line.line_type = LineType::Paragraph;}
-                Ok(c) => {new_line.content = c;}
             }
 
-            result.push(new_line);
-        }
-
-        else {
-            result.push(ln.clone());
+            ln
         }
-
-    }
-
-    result
+    ).collect()
 }
 
 
-pub fn render_tag(content: &Vec<u16>) -> Vec<u16> {
+pub fn render_tag(content: &str, option: &RenderOption, buffer: &mut String) {
 
-    let mut result = vec![];
     let mut curr_index = 0;
     let mut last_index = 0;
 
     while curr_index < content.len() {
 
         if is_tag(content, curr_index) {
-            let tag = parse_tag(content, curr_index);
 
-            if tag.is_ok() {
-                result.push(content[last_index..curr_index].to_vec());
-                result.push(tag.unwrap());
+            if let Ok(tag) = parse_tag(content, curr_index, option) {
+                buffer.push_str(&content[last_index..curr_index]);
+                buffer.push_str(&tag);
                 last_index = get_bracket_end_index(content, curr_index).unwrap() + 1;
                 curr_index = last_index;
                 continue;
@@ -56,16 +49,73 @@ pub fn render_tag(content: &Vec<u16>) -> Vec<u16> {
     }
 
     if last_index < curr_index {
-        result.push(content[last_index..].to_vec());
+        buffer.push_str(&content[last_index..]);
     }
 
-    result.concat()
 }
 
 
-pub fn is_tag(content: &Vec<u16>, index: usize) -> bool {
+// `lines` must not be rendered yet, so that the spans are correct
+pub fn check_tags(lines: &[Line], diagnostics: &mut Vec<Diagnostic>) {
+
+    for ln in lines.iter() {
+
+        // contents of lists, blockquotes, tables and definition lists are checked when they're parsed
+        if ln.line_type == LineType::FencedCode || ln.line_type == LineType::RenderedList || ln.line_type == LineType::RenderedBlockquote
+            || ln.line_type == LineType::RenderedTable || ln.line_type == LineType::RenderedDefinitionList {
+            continue;
+        }
+
+        let content = ln.content.as_bytes();
+        let mut is_inside_code = false;
+        let mut curr_index = 0;
+
+        while curr_index < content.len() {
+
+            if content[curr_index] == b'`' {
+                is_inside_code = !is_inside_code;
+            }
+
+            else if !is_inside_code && content[curr_index..].starts_with(b"[[") {
+
+                if is_tag(&ln.content, curr_index) {
+                    let end_index = get_bracket_end_index(&ln.content, curr_index).unwrap();
+                    let tag = ln.content[curr_index + 2..end_index - 1].chars().filter(|c| *c != ' ').collect::<String>().to_lowercase();
+
+                    if is_icon(&tag) {
 
-    content[index] == U16_LEFT_SQUARE_BRACKET && index + 1 < content.len() && content[index + 1] == U16_LEFT_SQUARE_BRACKET && {
+                        if let Err(e) = check_icon_arguments(&tag) {
+                            diagnostics.push(Diagnostic::error(ln.span_of(curr_index, end_index + 1), e));
+                        }
+
+                    }
+
+                    curr_index = end_index;
+                }
+
+                else {
+                    diagnostics.push(Diagnostic::warning(ln.span_of(curr_index, curr_index + 2), String::from("unterminated tag")));
+
+                    // `[[[`
+                    while curr_index + 1 < content.len() && content[curr_index + 1] == b'[' {
+                        curr_index += 1;
+                    }
+
+                }
+
+            }
+
+            curr_index += 1;
+        }
+
+    }
+
+}
+
+
+pub fn is_tag(content: &str, index: usize) -> bool {
+
+    content.as_bytes()[index..].starts_with(b"[[") && {
 
         let end1 = match get_bracket_end_index(content, index) {
             None => {return false;}
@@ -82,60 +132,93 @@ pub fn is_tag(content: &Vec<u16>, index: usize) -> bool {
 }
 
 
-fn parse_tag(content: &Vec<u16>, index: usize) -> Result<Vec<u16>, ()> {
+// `content[index..]` must be a tag
+pub fn is_valid_tag(content: &str, index: usize) -> bool {
+    parse_tag(content, index, &RenderOption::default()).is_ok()
+}
+
+
+// `parse_tag` for gfm documents, where alignment classes have no stylesheet and inline svgs are stripped
+pub fn export_tag(content: &str, index: usize) -> Result<String, ()> {
+
+    let end_index = get_bracket_end_index(content, index + 1).unwrap();
+    let name = content[index + 2..end_index].chars().filter(|c| *c != ' ').collect::<String>().to_lowercase();
+
+    // <div align="center">
+    if is_alignment_name(&name) {
+        return Ok(format!("<div align=\"{}\">", name));
+    }
+
+    if is_icon(&name) {
+        return export_icon(&name).ok_or(());
+    }
+
+    parse_tag(content, index, &RenderOption::default())
+}
+
+
+// `RenderOption::class_prefix` is prepended to the classes, ex) `hmd-box`
+// with `ElementStyle::InlineStyle`, the rules of `default_stylesheet` are inlined instead
+fn parse_tag(content: &str, index: usize, option: &RenderOption) -> Result<String, ()> {
 
     let end_index = get_bracket_end_index(content, index + 1).unwrap();
-    let content = content[index + 2..end_index].iter().filter(|c| *c != &U16_SPACE).map(|c| lowercase(*c)).collect::<Vec<u16>>();
+    let content = content[index + 2..end_index].chars().filter(|c| *c != ' ').collect::<String>().to_lowercase();
 
-    if content.len() == 0 {
+    if content.is_empty() {
         return Err(());
     }
 
     // <span class="font_red">
     // <span class="font_big">
     if is_color_name(&content) || is_size_name(&content) {
-        return Ok(vec![
-            vec![U16_LESS_THAN, U16_SMALL_S, U16_SMALL_P, U16_SMALL_A, U16_SMALL_N, U16_SPACE, U16_SMALL_C, U16_SMALL_L, U16_SMALL_A, U16_SMALL_S, U16_SMALL_S, U16_EQUAL, U16_DOUBLE_QUOTE, U16_SMALL_F, U16_SMALL_O, U16_SMALL_N, U16_SMALL_T, U16_UNDERBAR],
-            content,
-            vec![U16_DOUBLE_QUOTE, U16_GREATER_THAN]
-        ].concat());
+
+        return Ok(match option.element_style {
+            ElementStyle::CssClass => format!("<span class=\"{}font_{}\">", option.class_prefix, content),
+            ElementStyle::InlineStyle => match get_color_rgb(&content) {
+                Some((r, g, b)) => format!("<span style=\"color:rgb({}, {}, {})\">", r, g, b),
+                None => format!("<span style=\"font-size:{}\">", get_font_size(&content))
+            }
+        });
     }
 
     // <div class="align_center">
     if is_alignment_name(&content) {
-        return Ok(vec![
-            vec![U16_LESS_THAN, U16_SMALL_D, U16_SMALL_I, U16_SMALL_V, U16_SPACE, U16_SMALL_C, U16_SMALL_L, U16_SMALL_A, U16_SMALL_S, U16_SMALL_S, U16_EQUAL, U16_DOUBLE_QUOTE, U16_SMALL_A, U16_SMALL_L, U16_SMALL_I, U16_SMALL_G, U16_SMALL_N, U16_UNDERBAR],
-            content,
-            vec![U16_DOUBLE_QUOTE, U16_GREATER_THAN]
-        ].concat());
+
+        return Ok(match option.element_style {
+            ElementStyle::CssClass => format!("<div class=\"{}align_{}\">", option.class_prefix, content),
+            ElementStyle::InlineStyle => format!("<div style=\"text-align:{}\">", content)
+        });
     }
 
     // <div class="box">
     if is_box_name(&content) {
-        return Ok(vec![U16_LESS_THAN, U16_SMALL_D, U16_SMALL_I, U16_SMALL_V, U16_SPACE, U16_SMALL_C, U16_SMALL_L, U16_SMALL_A, U16_SMALL_S, U16_SMALL_S, U16_EQUAL, U16_DOUBLE_QUOTE, U16_SMALL_B, U16_SMALL_O, U16_SMALL_X, U16_DOUBLE_QUOTE, U16_GREATER_THAN]);
+
+        return Ok(match option.element_style {
+            ElementStyle::CssClass => format!("<div class=\"{}box\">", option.class_prefix),
+            ElementStyle::InlineStyle => String::from("<div style=\"border:1px solid rgb(128, 128, 128);border-radius:4px;margin:0.5em 0;padding:0.5em 1em\">")
+        });
     }
 
     if is_blank_name(&content) {
-        return Ok(vec![U16_AMPERSAND, U16_SMALL_N, U16_SMALL_B, U16_SMALL_S, U16_SMALL_P, U16_SEMI_COLON]);
+        return Ok(String::from("&nbsp;"));
     }
 
     if is_icon(&content) {
 
-        match parse_icon(&content) {
-            Some(s) => {return Ok(s);}
-            _ => {}
+        if let Some(s) = parse_icon(&content) {
+            return Ok(s);
         }
 
     }
 
-    if content[0] == U16_SLASH {
+    if let Some(name) = content.strip_prefix('/') {
 
-        if is_color_name(&content[1..]) || is_size_name(&content[1..]) {
-            return Ok(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_S, U16_SMALL_P, U16_SMALL_A, U16_SMALL_N, U16_GREATER_THAN]);
+        if is_color_name(name) || is_size_name(name) {
+            return Ok(String::from("</span>"));
         }
 
-        if is_alignment_name(&content[1..]) || is_box_name(&content[1..]) {
-            return Ok(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_D, U16_SMALL_I, U16_SMALL_V, U16_GREATER_THAN]);
+        if is_alignment_name(name) || is_box_name(name) {
+            return Ok(String::from("</div>"));
         }
 
     }
@@ -145,62 +228,68 @@ fn parse_tag(content: &Vec<u16>, index: usize) -> Result<Vec<u16>, ()> {
 
 // TODO: make a `color` module
 // define all colors there
-// `color_names.contains(string)` would be much better!
-fn is_color_name(string: &[u16]) -> bool {
-    string == &vec![U16_SMALL_A, U16_SMALL_Q, U16_SMALL_U, U16_SMALL_A] ||
-    string == &vec![U16_SMALL_B, U16_SMALL_L, U16_SMALL_U, U16_SMALL_E] ||
-    string == &vec![U16_SMALL_G, U16_SMALL_R, U16_SMALL_E, U16_SMALL_E, U16_SMALL_N] ||
-    string == &vec![U16_SMALL_L, U16_SMALL_I, U16_SMALL_M, U16_SMALL_E] ||
-    string == &vec![U16_SMALL_O, U16_SMALL_R, U16_SMALL_A, U16_SMALL_N, U16_SMALL_G, U16_SMALL_E] ||
-    string == &vec![U16_SMALL_R, U16_SMALL_E, U16_SMALL_D] ||
-    string == &vec![U16_SMALL_V, U16_SMALL_I, U16_SMALL_O, U16_SMALL_L, U16_SMALL_E, U16_SMALL_T] ||
-    string == &vec![U16_SMALL_W, U16_SMALL_H, U16_SMALL_I, U16_SMALL_T, U16_SMALL_E] ||
-    string == &vec![U16_SMALL_Y, U16_SMALL_E, U16_SMALL_L, U16_SMALL_L, U16_SMALL_O, U16_SMALL_W]
+fn is_color_name(string: &str) -> bool {
+    ["aqua", "blue", "green", "lime", "orange", "red", "violet", "white", "yellow"].contains(&string)
+}
+
+fn get_color_rgb(string: &str) -> Option<(u8, u8, u8)> {
+
+    match string {
+        "aqua" => Some((64, 192, 192)),
+        "blue" => Some((32, 128, 255)),
+        "green" => Some((32, 192, 32)),
+        "lime" => Some((0, 255, 0)),
+        "orange" => Some((255, 165, 0)),
+        "red" => Some((192, 32, 32)),
+        "violet" => Some((187, 134, 252)),
+        "white" => Some((255, 255, 255)),
+        "yellow" => Some((192, 192, 32)),
+        _ => None
+    }
+
+}
+
+// the same as `.font_big`, `.font_medium` and `.font_small` of `default_stylesheet`
+fn get_font_size(string: &str) -> &'static str {
+
+    match string {
+        "big" => "1.5em",
+        "medium" => "1.2em",
+        _ => "0.8em"
+    }
+
 }
 
-fn is_size_name(string: &[u16]) -> bool {
-    string == &vec![U16_SMALL_B, U16_SMALL_I, U16_SMALL_G] ||
-    string == &vec![U16_SMALL_S, U16_SMALL_M, U16_SMALL_A, U16_SMALL_L, U16_SMALL_L] ||
-    string == &vec![U16_SMALL_M, U16_SMALL_E, U16_SMALL_D, U16_SMALL_I, U16_SMALL_U, U16_SMALL_M]
+fn is_size_name(string: &str) -> bool {
+    ["big", "small", "medium"].contains(&string)
 }
 
-fn is_alignment_name(string: &[u16]) -> bool {
-    string == &vec![U16_SMALL_L, U16_SMALL_E, U16_SMALL_F, U16_SMALL_T] ||
-    string == &vec![U16_SMALL_R, U16_SMALL_I, U16_SMALL_G, U16_SMALL_H, U16_SMALL_T] ||
-    string == &vec![U16_SMALL_C, U16_SMALL_E, U16_SMALL_N, U16_SMALL_T, U16_SMALL_E, U16_SMALL_R]
+fn is_alignment_name(string: &str) -> bool {
+    ["left", "right", "center"].contains(&string)
 }
 
-fn is_box_name(string: &[u16]) -> bool {
-    string.len() == 3 && string[0] == U16_SMALL_B && string[1] == U16_SMALL_O && string[2] == U16_SMALL_X
+fn is_box_name(string: &str) -> bool {
+    string == "box"
 }
 
-fn is_blank_name(string: &[u16]) -> bool {
-    string.len() == 5 && string[0] == U16_SMALL_B && string[1] == U16_SMALL_L && string[2] == U16_SMALL_A && string[3] == U16_SMALL_N && string[4] == U16_SMALL_K
+fn is_blank_name(string: &str) -> bool {
+    string == "blank"
 }
 
 // if true, it's possibly a valid icon
 // if not, it can never be an icon
-fn is_icon(string: &[u16]) -> bool {
-    string.len() > 5 && string[0] == U16_SMALL_I && string[1] == U16_SMALL_C && string[2] == U16_SMALL_O && string[3] == U16_SMALL_N && string[4] == U16_EQUAL
+fn is_icon(string: &str) -> bool {
+    string.len() > 5 && string.starts_with("icon=")
 }
 
 // `[[a = b, c = d, e = f]]` -> vec![(`a`, `b`), (`c`, `d`), (`e`, `f`)]
-fn parse_arguments(content: &[u16]) -> Vec<(Vec<u16>, Vec<u16>)> {  // Vec<(key, value)>
-    content.split(
-        |c|
-        *c == U16_COMMA
-    ).filter_map(
+fn parse_arguments(content: &str) -> Vec<(&str, &str)> {  // Vec<(key, value)>
+    content.split(',').filter_map(
         |arg| {
-            let arg_split = arg.split(
-                |c|
-                *c == U16_EQUAL
-            ).map(
-                |word|
-                word.to_vec()
-            ).collect::<Vec<Vec<u16>>>();
+            let arg_split = arg.split('=').collect::<Vec<&str>>();
 
             if arg_split.len() == 2 {
-                Some((arg_split[0].clone(), arg_split[1].clone()))
+                Some((arg_split[0], arg_split[1]))
             } else {
                 None
             }
@@ -208,7 +297,61 @@ fn parse_arguments(content: &[u16]) -> Vec<(Vec<u16>, Vec<u16>)> {  // Vec<(key,
     ).collect()
 }
 
-fn parse_icon(content: &[u16]) -> Option<Vec<u16>> {
+// `[[icon = rust, size = 32, color = red]]`
+fn check_icon_arguments(content: &str) -> Result<(), String> {
+
+    for arg in content.split(',') {
+
+        let (key, value) = match arg.split_once('=') {
+            Some((key, value)) if !value.contains('=') => (key, value),
+            _ => { return Err(format!("invalid icon argument `{}`, expected `key = value`", arg)); }
+        };
+
+        match key {
+            "icon" if !is_icon_name(value) => {
+                return Err(format!("unknown icon `{}`", value));
+            }
+            "size" if usize::from_str(value).is_err() => {
+                return Err(format!("icon size should be a number, not `{}`", value));
+            }
+            "color" if !is_color_name(value) => {
+                return Err(format!("unknown icon color `{}`", value));
+            }
+            "icon" | "size" | "color" => {}
+            _ => {
+                return Err(format!("unknown icon argument `{}`", key));
+            }
+        }
+
+    }
+
+    Ok(())
+}
+
+fn parse_icon(content: &str) -> Option<String> {
+
+    let icon = parse_icon_arguments(content)?;
+
+    get_icon(icon.name, icon.size, icon.color)
+}
+
+// `to_gfm` embeds icons as images, which don't follow the color of the text, so they're gray by default
+fn export_icon(content: &str) -> Option<String> {
+
+    let icon = parse_icon_arguments(content)?;
+
+    get_icon_image(icon.name, icon.size, icon.color.unwrap_or((128, 128, 128)))
+}
+
+struct IconArguments<'a> {
+    name: &'a str,
+    size: usize,
+
+    // `None` if it's not given
+    color: Option<(u8, u8, u8)>
+}
+
+fn parse_icon_arguments(content: &str) -> Option<IconArguments<'_>> {
 
     let mut curr_icon = None;
     let mut curr_size = None;
@@ -217,49 +360,32 @@ fn parse_icon(content: &[u16]) -> Option<Vec<u16>> {
 
     // if the same arg is given twice, the later one is applied
     // I'm not raising an error for that
-    for (key, value) in args.iter() {
-
-        if key == &vec![U16_SMALL_I, U16_SMALL_C, U16_SMALL_O, U16_SMALL_N] {
-            curr_icon = Some(value.clone());
-        }
-
-        else if key == &vec![U16_SMALL_S, U16_SMALL_I, U16_SMALL_Z, U16_SMALL_E] {
-            curr_size = Some(value.clone());
-        }
+    for (key, value) in args.into_iter() {
 
-        else if key == &vec![U16_SMALL_C, U16_SMALL_O, U16_SMALL_L, U16_SMALL_O, U16_SMALL_R] {
-            curr_color = Some(value.clone());
-        }
-
-        else {
-            return None;
+        match key {
+            "icon" => { curr_icon = Some(value); }
+            "size" => { curr_size = Some(value); }
+            "color" => { curr_color = Some(value); }
+            _ => { return None; }
         }
 
     }
 
-    let curr_icon = if curr_icon.is_none() {
-        return None
-    } else {
-        curr_icon.unwrap()
-    };
+    let curr_icon = curr_icon?;
 
-    let curr_size = if curr_size.is_none() {
-        24
-    } else {
-        match i32::from_str(&String::from_utf16_lossy(&curr_size.unwrap())) {
+    let curr_size = match curr_size {
+        None => 24,
+        Some(size) => match usize::from_str(size) {
             Err(_) => {return None}
             Ok(n) => n
         }
     };
 
+    // `currentColor` by default, so that icons are visible on both light and dark pages
     let curr_color = match curr_color {
-        None => vec![U16_SMALL_W, U16_SMALL_H, U16_SMALL_I, U16_SMALL_T, U16_SMALL_E],
-        Some(s) => if is_color_name(&s) {
-            s.clone()
-        } else {
-            return None
-        }
+        None => None,
+        Some(s) => Some(get_color_rgb(s)?)
     };
 
-    panic!("Not Implemented Yet!")
-}
\ No newline at end of file
+    Some(IconArguments { name: curr_icon, size: curr_size, color: curr_color })
+}
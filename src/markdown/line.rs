@@ -1,6 +1,8 @@
-use crate::consts::*;
-use crate::utils::*;
+use crate::markdown::escape::{escape_htmls, escape_backslashes, undo_html_escapes};
+use crate::markdown::diagnostic::Span;
+use crate::markdown::list::demote_stray_bullets;
 use crate::markdown::predicates::get_type;
+use std::fmt;
 
 
 #[derive(Clone, Debug, PartialEq)]
@@ -12,36 +14,44 @@ pub enum LineType {
     TableDelimiter,
     FencedCode,
     RenderedTable,
+    RenderedList,
+    RenderedBlockquote,
+    RenderedDefinitionList,
+    RenderedHeader,
     Blockquote(usize),
     UnorderedList,
     Tag,
     OrderedList,
+    Definition,
     Empty
 }
 
 
 #[derive(Clone)]
 pub struct Line {
-    pub content: Vec<u16>,
+    pub content: String,
     pub indent: usize,
-    pub line_type: LineType
+    pub line_type: LineType,
+
+    // where `content` comes from, merged lines cover all of them
+    pub span: Span
 }
 
 
 impl Line {
 
-    pub fn from_raw(raw: &Vec<u16>) -> Line {
+    pub fn from_raw(raw: &str) -> Line {
 
         let mut indent = 0;
         let mut index = 0;
 
-        while index < raw.len() {
+        for c in raw.bytes() {
 
-            if raw[index] == U16_SPACE {
+            if c == b' ' {
                 indent += 1;
             }
 
-            else if raw[index] == U16_TAB {
+            else if c == b'\t' {
                 indent += 4;
             }
 
@@ -53,100 +63,161 @@ impl Line {
         }
 
         Line {
-            content: raw[index..].to_vec(),
+            content: raw[index..].to_string(),
             indent,
-            line_type: LineType::Paragraph
+            line_type: LineType::Paragraph,
+            span: Span::default()
         }
     }
 
-    pub fn into_raw(&self) -> Vec<u16> {
-
-        vec![vec![U16_SPACE;self.indent], self.content.clone()].concat()
-    }
+    pub fn to_raw(&self) -> String {
 
-    pub fn render(&self) -> Vec<u16> {
+        let mut result = String::with_capacity(self.indent + self.content.len());
 
-        match self.line_type {
-            LineType::Paragraph => self.render_paragraph(),
-            LineType::Header => self.render_header(),
-            LineType::ThematicBreak => vec![U16_LESS_THAN, U16_SMALL_H, U16_SMALL_R, U16_SPACE, U16_SLASH, U16_GREATER_THAN],
-            LineType::Empty => vec![],
-            LineType::FencedCode => self.render_fenced_code(),
-            LineType::Blockquote(_) => self.render_as_it_is(),
-            LineType::OrderedList => self.render_as_it_is(),
-            LineType::Tag => self.render_as_it_is(),
-            LineType::UnorderedList => self.render_as_it_is(),
-            LineType::RenderedTable => self.render_as_it_is(),
-            _ => panic!("{:?}", self.line_type)
+        for _ in 0..self.indent {
+            result.push(' ');
         }
 
+        result.push_str(&self.content);
+        result
     }
 
-    fn render_paragraph(&self) -> Vec<u16> {
+    // `begin..end` of `content` in the source
+    // `content` must be the one from `code_to_lines`, which is html-escaped but not rendered
+    pub fn span_of(&self, begin: usize, end: usize) -> Span {
 
-        vec![
-            vec![U16_LESS_THAN, U16_SMALL_P, U16_GREATER_THAN],
-            self.content.clone(),
-            vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_P, U16_GREATER_THAN],
-        ].concat()
-    }
+        let before = undo_html_escapes(&self.content[..begin]);
+        let inside = undo_html_escapes(&self.content[begin..end]);
 
-    fn render_as_it_is(&self) -> Vec<u16> {
-        self.content.clone()
+        Span {
+            line: self.span.line,
+            column: self.span.column + before.chars().count(),
+            offset: self.span.offset + before.len(),
+            len: inside.len()
+        }
     }
 
-    fn render_header(&self) -> Vec<u16> {
+    pub fn render(&self, buffer: &mut String) {
 
-        let (sharps, sharps_removed) = take_and_drop_while(&self.content, U16_SHARP);
-        let indents_removed = drop_while(&sharps_removed, U16_SPACE);
+        match self.line_type {
+            LineType::Paragraph => self.render_paragraph(buffer),
+            LineType::ThematicBreak => buffer.push_str("<hr />"),
+            LineType::Empty => {},
+            LineType::FencedCode => self.render_as_it_is(buffer),
+            LineType::Blockquote(_) => self.render_as_it_is(buffer),
+            LineType::OrderedList => self.render_as_it_is(buffer),
+            LineType::Tag => self.render_as_it_is(buffer),
+            LineType::UnorderedList => self.render_as_it_is(buffer),
+            LineType::RenderedTable => self.render_as_it_is(buffer),
+            LineType::RenderedList => self.render_as_it_is(buffer),
+            LineType::RenderedBlockquote => self.render_as_it_is(buffer),
+            LineType::RenderedDefinitionList => self.render_as_it_is(buffer),
+            LineType::RenderedHeader => self.render_as_it_is(buffer),
+            _ => panic!("{:?}", self.line_type)
+        }
 
-        vec![
-            vec![U16_LESS_THAN, U16_SMALL_H, sharps.len() as u16 + 48, U16_SPACE, U16_SMALL_I, U16_SMALL_D, U16_EQUAL, U16_DOUBLE_QUOTE],
-            remove_special_characters(&indents_removed),
-            vec![U16_DOUBLE_QUOTE, U16_GREATER_THAN],
-            indents_removed,
-            vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_H, sharps.len() as u16 + 48, U16_GREATER_THAN]
-        ].concat()
     }
 
-    fn render_fenced_code(&self) -> Vec<u16> {
+    fn render_paragraph(&self, buffer: &mut String) {
+        buffer.push_str("<p>");
+        buffer.push_str(&self.content);
+        buffer.push_str("</p>");
+    }
 
-        vec![
-            vec![U16_LESS_THAN, U16_SMALL_P, U16_SMALL_R, U16_SMALL_E, U16_GREATER_THAN, U16_LESS_THAN, U16_SMALL_C, U16_SMALL_O, U16_SMALL_D, U16_SMALL_E, U16_GREATER_THAN],
-            self.content.clone(),
-            vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_C, U16_SMALL_O, U16_SMALL_D, U16_SMALL_E, U16_GREATER_THAN, U16_LESS_THAN, U16_SLASH, U16_SMALL_P, U16_SMALL_R, U16_SMALL_E, U16_GREATER_THAN]
-        ].concat()
+    fn render_as_it_is(&self, buffer: &mut String) {
+        buffer.push_str(&self.content);
     }
 
 }
 
 
-pub fn code_to_lines(code: &Vec<u16>) -> Vec<Line> {
+// lines are html-escaped one by one, so that the document is never copied as a whole
+pub fn code_to_lines(code: &str) -> Vec<Line> {
+
+    let mut offset = 0;
+
+    let mut lines = code.split('\n').enumerate().map(
+        |(ind, ln)| {
+            let mut untyped = Line::from_raw(&escape_htmls(ln));
 
-    code.split(
-        |c|
-        *c == U16_NEWLINE
-    ).map(
-        |ln| {
-            let mut untyped = Line::from_raw(&ln.to_vec());
+            // indents are not escaped, so they have the same length in `ln`
+            let indent_len = ln.len() - ln.trim_start_matches([' ', '\t']).len();
+
+            untyped.span = Span {
+                line: ind + 1,
+                column: indent_len + 1,
+                offset: offset + indent_len,
+                len: ln.len() - indent_len
+            };
+            offset += ln.len() + 1;
 
             let line_type = get_type(&untyped);
             untyped.line_type = line_type;
 
             untyped
         }
-    ).collect::<Vec<Line>>()
+    ).collect::<Vec<Line>>();
+
+    demote_stray_bullets(&mut lines);
+
+    lines
 }
 
 
-pub fn render_lines(lines: &Vec<Line>) -> Vec<u16> {
+// blocks inside a blockquote or a table cell
+// paragraphs are wrapped in `<p>` only if they're separated by empty lines, like lists
+// `tight_end` follows the paragraphs that are not wrapped
+pub fn render_blocks(blocks: &[Line], tight_end: &str, buffer: &mut String) {
+
+    let begin = blocks.iter().position(|block| block.line_type != LineType::Empty).unwrap_or(blocks.len());
+    let end = blocks.iter().rposition(|block| block.line_type != LineType::Empty).map_or(begin, |ind| ind + 1);
+    let blocks = &blocks[begin..end];
 
-    let mut result = Vec::with_capacity(lines.len() * 2);
+    let is_loose = blocks.iter().any(|block| block.line_type == LineType::Empty);
+
+    for block in blocks.iter() {
+
+        if block.line_type == LineType::Paragraph && !is_loose {
+            buffer.push_str(&block.content);
+            buffer.push_str(tight_end);
+        }
+
+        else {
+            block.render(buffer);
+        }
+
+    }
+
+}
+
+
+pub fn render_lines(lines: &[Line]) -> String {
+
+    let mut result = String::with_capacity(lines.iter().map(|ln| ln.content.len() + 8).sum());
+
+    // writing to a `String` never fails
+    write_lines(lines, &mut result).unwrap();
+
+    result
+}
+
+
+// lines are written one by one, so that the rendered html is never joined into a single string
+// `lines` are already parsed, so it's not a streaming parser
+pub fn write_lines<W: fmt::Write>(lines: &[Line], writer: &mut W) -> fmt::Result {
+
+    let mut curr_line = String::new();
+    let mut escaped_line = String::new();
 
     for ln in lines.iter() {
-        result.push(ln.render());
-        result.push(vec![U16_NEWLINE]);
+        ln.render(&mut curr_line);
+        escape_backslashes(&curr_line, &mut escaped_line);
+        escaped_line.push('\n');
+        writer.write_str(&escaped_line)?;
+
+        curr_line.clear();
+        escaped_line.clear();
     }
 
-    result.concat()
-}
\ No newline at end of file
+    Ok(())
+}
@@ -0,0 +1,358 @@
+use crate::markdown::blockquote::get_blockquote_content;
+use crate::markdown::diagnostic::Diagnostic;
+use crate::markdown::line::{Line, LineType, code_to_lines};
+use crate::markdown::predicates::get_type;
+
+/*
+Places where hmd silently differs from gfm, see `Things that are missing` and `Things that are different` in README.md.
+They're all warnings: the document is rendered, but not the way a gfm writer would expect.
+*/
+
+
+pub fn lint(content: &str) -> Vec<Diagnostic> {
+
+    let lines = code_to_lines(content);
+
+    // the line checks see the contents of blockquotes
+    let unquoted = lines.iter().map(remove_blockquote_markers).collect::<Vec<Line>>();
+
+    let mut result = vec![];
+    let mut is_inside_fence = false;
+
+    // fences in list items are indented
+    let mut is_inside_list = false;
+
+    // lazy lines are in the fence until the blockquote ends
+    let mut is_inside_quote_fence = false;
+
+    for (ind, ln) in lines.iter().enumerate() {
+
+        match ln.line_type {
+            LineType::Blockquote(_) => {
+
+                if get_blockquote_content(&ln.content).starts_with("```") {
+                    is_inside_quote_fence = !is_inside_quote_fence;
+                    continue;
+                }
+
+            }
+            LineType::Paragraph => {}
+            _ => {
+                is_inside_quote_fence = false;
+            }
+        }
+
+        if is_inside_quote_fence {
+            continue;
+        }
+
+        if ln.content.starts_with("```") && (ln.indent == 0 || is_inside_list) {
+            is_inside_fence = !is_inside_fence;
+            check_multi_backticks(ln, true, &mut result);
+            continue;
+        }
+
+        if is_inside_fence {
+            continue;
+        }
+
+        let ln = &unquoted[ind];
+        let prev = if ind > 0 { Some(&unquoted[ind - 1]) } else { None };
+
+        if ln.line_type == LineType::UnorderedList || ln.line_type == LineType::OrderedList {
+            is_inside_list = true;
+        }
+
+        else if ln.line_type != LineType::Empty && ln.indent == 0 && (ln.line_type != LineType::Paragraph || prev.is_some_and(|prev| prev.line_type == LineType::Empty)) {
+            is_inside_list = false;
+        }
+
+        check_spaced_thematic_break(ln, &mut result);
+        check_star_bullet(ln, &mut result);
+        check_ordered_list_number(ln, &mut result);
+        check_setext_heading(ln, prev, &mut result);
+        check_tilde_fence(ln, &mut result);
+        check_multi_backticks(ln, false, &mut result);
+        check_underbar_emphasis(ln, &mut result);
+        check_raw_html(ln, &mut result);
+        check_html_entity(ln, &mut result);
+    }
+
+    result
+}
+
+
+// `> > * item` -> `* item`
+fn remove_blockquote_markers(line: &Line) -> Line {
+
+    if !matches!(line.line_type, LineType::Blockquote(_)) {
+        return line.clone();
+    }
+
+    let mut result = Line::from_raw(get_blockquote_content(&line.content));
+
+    result.span = line.span_of(line.content.len() - result.content.len(), line.content.len());
+    result.line_type = get_type(&result);
+
+    result
+}
+
+
+// `* * *`, `- - -`: gfm allows spaces between the characters, but hmd doesn't
+fn check_spaced_thematic_break(line: &Line, diagnostics: &mut Vec<Diagnostic>) {
+
+    if !is_spaced_thematic_break(line) {
+        return;
+    }
+
+    let marker = line.content.as_bytes()[0] as char;
+
+    diagnostics.push(Diagnostic::warning(
+        line.span_of(0, line.content.trim_end().len()),
+        format!("thematic breaks cannot contain spaces, use `{}` instead", marker.to_string().repeat(3))
+    ));
+
+}
+
+
+fn is_spaced_thematic_break(line: &Line) -> bool {
+
+    if line.indent > 3 || line.line_type == LineType::ThematicBreak {
+        return false;
+    }
+
+    match line.content.as_bytes().first() {
+        Some(marker) if b"*-_".contains(marker) => {
+            line.content.bytes().all(|c| c == *marker || c == b' ' || c == b'\t')
+            && line.content.bytes().filter(|c| c == marker).count() > 2
+        }
+        _ => false
+    }
+
+}
+
+
+// `* item`
+fn check_star_bullet(line: &Line, diagnostics: &mut Vec<Diagnostic>) {
+
+    if line.line_type != LineType::ThematicBreak && !is_spaced_thematic_break(line) && line.content.starts_with("* ") {
+        diagnostics.push(Diagnostic::warning(
+            line.span_of(0, 1),
+            String::from("`*` is not a list bullet, use `-` instead")
+        ));
+    }
+
+}
+
+
+// `1) item`
+fn check_ordered_list_number(line: &Line, diagnostics: &mut Vec<Diagnostic>) {
+
+    let digits = line.content.bytes().take_while(|c| c.is_ascii_digit()).count();
+
+    if digits == 0 || line.line_type == LineType::OrderedList {
+        return;
+    }
+
+    if line.content.as_bytes()[digits..].starts_with(b") ") {
+        diagnostics.push(Diagnostic::warning(
+            line.span_of(0, digits + 1),
+            format!("`{}` is not a list bullet, use `{}.` instead", &line.content[..digits + 1], &line.content[..digits])
+        ));
+    }
+
+}
+
+
+// `===` or `---` under a paragraph
+fn check_setext_heading(line: &Line, prev: Option<&Line>, diagnostics: &mut Vec<Diagnostic>) {
+
+    let is_paragraph = match prev {
+        Some(prev) => prev.line_type == LineType::Paragraph,
+        None => false
+    };
+
+    let underline = line.content.trim_end();
+
+    let is_underline = line.indent < 4 && !underline.is_empty() && (
+        underline.bytes().all(|c| c == b'=') || underline.bytes().all(|c| c == b'-')
+    );
+
+    if is_paragraph && is_underline {
+        diagnostics.push(Diagnostic::warning(
+            line.span,
+            String::from("setext headings are not supported, use `#` instead")
+        ));
+    }
+
+}
+
+
+// `~~~`
+fn check_tilde_fence(line: &Line, diagnostics: &mut Vec<Diagnostic>) {
+
+    if line.indent < 4 && line.content.starts_with("~~~") {
+        diagnostics.push(Diagnostic::warning(
+            line.span_of(0, line.content.len() - line.content.trim_start_matches('~').len()),
+            String::from("code fences should use backticks, not tildes")
+        ));
+    }
+
+}
+
+
+// ``code``
+// backticks in code fences are counted too, because only the first 3 of them make a fence
+fn check_multi_backticks(line: &Line, is_fence: bool, diagnostics: &mut Vec<Diagnostic>) {
+
+    let content = line.content.as_bytes();
+    let mut curr_index = if is_fence { 3 } else { 0 };
+
+    while curr_index < content.len() {
+
+        if content[curr_index] == b'`' {
+            let run = content[curr_index..].iter().take_while(|c| **c == b'`').count();
+
+            if run > 1 {
+                diagnostics.push(Diagnostic::warning(
+                    line.span_of(curr_index, curr_index + run),
+                    String::from("code spans use a single backtick, multiple backticks are rendered as they are")
+                ));
+            }
+
+            curr_index += run;
+        }
+
+        else {
+            curr_index += 1;
+        }
+
+    }
+
+}
+
+
+// `_emphasis_`, `__strong__`
+fn check_underbar_emphasis(line: &Line, diagnostics: &mut Vec<Diagnostic>) {
+
+    let content = line.content.as_bytes();
+
+    for_each_outside_code_spans(line, |index| {
+
+        if content[index] != b'_' || is_escaped(content, index) || (index > 0 && (content[index - 1].is_ascii_alphanumeric() || content[index - 1] == b'_' || content[index - 1] == b'~')) {
+            return;
+        }
+
+        let run = content[index..].iter().take_while(|c| **c == b'_').count();
+
+        if index + run >= content.len() || content[index + run] == b' ' {
+            return;
+        }
+
+        // the closing `_`s: not after a space, not followed by an alphanumeric, and not `_~` of underlines
+        let closing = (index + run + 1..content.len()).find(
+            |i| content[*i] == b'_' && content[*i - 1] != b' ' && content[*i - 1] != b'_' && !is_escaped(content, *i)
+            && content[*i..].starts_with(&content[index..index + run])
+            && match content.get(*i + run) {
+                None => true,
+                Some(c) => !c.is_ascii_alphanumeric() && *c != b'_' && *c != b'~'
+            }
+        );
+
+        if let Some(closing) = closing {
+            diagnostics.push(Diagnostic::warning(
+                line.span_of(index, closing + run),
+                String::from("`_` is not an emphasis delimiter, use `*` instead")
+            ));
+        }
+
+    });
+
+}
+
+
+// `<div>`, `</div>`, `<!-- -->`
+fn check_raw_html(line: &Line, diagnostics: &mut Vec<Diagnostic>) {
+
+    let content = line.content.as_bytes();
+
+    for_each_outside_code_spans(line, |index| {
+
+        // `<` is already escaped
+        if !content[index..].starts_with(b"&lt;") {
+            return;
+        }
+
+        let is_tag = match content.get(index + 4) {
+            Some(c) => c.is_ascii_alphabetic() || *c == b'/' || *c == b'!',
+            None => false
+        };
+
+        if is_tag {
+            let end_index = match line.content[index..].find('>') {
+                Some(i) => index + i + 1,
+                None => index + 4
+            };
+
+            diagnostics.push(Diagnostic::warning(
+                line.span_of(index, end_index),
+                String::from("raw html is not supported, it's rendered as a text")
+            ));
+        }
+
+    });
+
+}
+
+
+// `&nbsp;`, `&#60;`
+fn check_html_entity(line: &Line, diagnostics: &mut Vec<Diagnostic>) {
+
+    let content = line.content.as_bytes();
+
+    for_each_outside_code_spans(line, |index| {
+
+        // `&` is already escaped
+        if !content[index..].starts_with(b"&amp;") {
+            return;
+        }
+
+        let name_begin = if content.get(index + 5) == Some(&b'#') { index + 6 } else { index + 5 };
+        let name_len = content[name_begin..].iter().take_while(|c| c.is_ascii_alphanumeric()).count();
+
+        if name_len > 0 && content.get(name_begin + name_len) == Some(&b';') {
+            diagnostics.push(Diagnostic::warning(
+                line.span_of(index, name_begin + name_len + 1),
+                String::from("html entities are not supported, type the character as it is")
+            ));
+        }
+
+    });
+
+}
+
+
+// `\_`, but not `\\_`
+fn is_escaped(content: &[u8], index: usize) -> bool {
+    content[..index].iter().rev().take_while(|c| **c == b'\\').count() % 2 == 1
+}
+
+
+// calls `f` with byte indexes of `line.content` that are not in code spans
+fn for_each_outside_code_spans<F: FnMut(usize)>(line: &Line, mut f: F) {
+
+    let mut is_inside_code = false;
+
+    for (index, c) in line.content.bytes().enumerate() {
+
+        if c == b'`' {
+            is_inside_code = !is_inside_code;
+        }
+
+        else if !is_inside_code {
+            f(index);
+        }
+
+    }
+
+}
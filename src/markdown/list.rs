@@ -1,32 +1,43 @@
 use crate::markdown::line::{Line, LineType};
-use crate::consts::*;
+use crate::markdown::predicates::get_type;
+use crate::option::{ElementStyle, RenderOption};
 
 
-enum ListOrder {
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ListOrder {
     None, Num, AlphaLow, AlphaUpper, RomanLow, RomanUpper
 }
 
 
 impl ListOrder {
 
-    fn opening_tag(&self) -> Vec<u16> {
+    // `start` is the value of the first bullet, ex) 3 for `3.`, `c.` and `iii.`
+    fn opening_tag(&self, start: usize) -> String {
 
-        match self {
-            ListOrder::None => vec![U16_LESS_THAN, U16_SMALL_U, U16_SMALL_L, U16_GREATER_THAN],
-            ListOrder::Num => vec![U16_LESS_THAN, U16_SMALL_O, U16_SMALL_L, U16_SPACE, U16_SMALL_T, U16_SMALL_Y, U16_SMALL_P, U16_SMALL_E, U16_EQUAL, U16_DOUBLE_QUOTE, U16_1, U16_DOUBLE_QUOTE, U16_GREATER_THAN],
-            ListOrder::AlphaLow => vec![U16_LESS_THAN, U16_SMALL_O, U16_SMALL_L, U16_SPACE, U16_SMALL_T, U16_SMALL_Y, U16_SMALL_P, U16_SMALL_E, U16_EQUAL, U16_DOUBLE_QUOTE, U16_SMALL_A, U16_DOUBLE_QUOTE, U16_GREATER_THAN],
-            ListOrder::AlphaUpper => vec![U16_LESS_THAN, U16_SMALL_O, U16_SMALL_L, U16_SPACE, U16_SMALL_T, U16_SMALL_Y, U16_SMALL_P, U16_SMALL_E, U16_EQUAL, U16_DOUBLE_QUOTE, U16_BIG_A, U16_DOUBLE_QUOTE, U16_GREATER_THAN],
-            ListOrder::RomanLow => vec![U16_LESS_THAN, U16_SMALL_O, U16_SMALL_L, U16_SPACE, U16_SMALL_T, U16_SMALL_Y, U16_SMALL_P, U16_SMALL_E, U16_EQUAL, U16_DOUBLE_QUOTE, U16_SMALL_I, U16_DOUBLE_QUOTE, U16_GREATER_THAN],
-            ListOrder::RomanUpper => vec![U16_LESS_THAN, U16_SMALL_O, U16_SMALL_L, U16_SPACE, U16_SMALL_T, U16_SMALL_Y, U16_SMALL_P, U16_SMALL_E, U16_EQUAL, U16_DOUBLE_QUOTE, U16_BIG_I, U16_DOUBLE_QUOTE, U16_GREATER_THAN],
+        let tag = match self {
+            ListOrder::None => { return String::from("<ul>"); }
+            ListOrder::Num => "<ol type=\"1\"",
+            ListOrder::AlphaLow => "<ol type=\"a\"",
+            ListOrder::AlphaUpper => "<ol type=\"A\"",
+            ListOrder::RomanLow => "<ol type=\"i\"",
+            ListOrder::RomanUpper => "<ol type=\"I\"",
+        };
+
+        if start == 1 {
+            format!("{}>", tag)
+        }
+
+        else {
+            format!("{} start=\"{}\">", tag, start)
         }
 
     }
 
-    fn closing_tag(&self) -> Vec<u16> {
+    fn closing_tag(&self) -> &'static str {
 
         match self {
-            ListOrder::None => vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_U, U16_SMALL_L, U16_GREATER_THAN],
-            _ => vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_O, U16_SMALL_L, U16_GREATER_THAN],
+            ListOrder::None => "</ul>",
+            _ => "</ol>",
         }
     
     }
@@ -36,30 +47,26 @@ impl ListOrder {
 
 impl Line {
 
-    fn get_list_ordering(&self) -> ListOrder {
+    // (ordering, start)
+    fn get_list_ordering(&self) -> (ListOrder, usize) {
 
         match self.line_type {
-            LineType::UnorderedList => ListOrder::None,
+            LineType::UnorderedList => (ListOrder::None, 1),
             LineType::OrderedList => {
-                match self.content[0] {
-                    U16_1 => ListOrder::Num,
-                    U16_SMALL_A => ListOrder::AlphaLow,
-                    U16_BIG_A => ListOrder::AlphaUpper,
-                    U16_SMALL_I => ListOrder::RomanLow,
-                    U16_BIG_I => ListOrder::RomanUpper,
-                    _ => panic!()
-                }
+                let (ordering, start, _) = parse_ordered_bullet(&self.content).unwrap();
+
+                (ordering, start)
             }
             _ => panic!()
         }
 
     }
 
-    fn get_list_content(&self) -> Vec<u16> {
+    fn get_list_content(&self) -> &str {
 
         match self.line_type {
-            LineType::UnorderedList => self.content[2..].to_vec(),
-            LineType::OrderedList => self.content[3..].to_vec(),
+            LineType::UnorderedList => &self.content[2..],
+            LineType::OrderedList => &self.content[parse_ordered_bullet(&self.content).unwrap().2 + 2..],
             _ => panic!()
         }
 
@@ -68,151 +75,463 @@ impl Line {
 }
 
 
-pub fn merge_list(lines: &Vec<Line>) -> Vec<Line> {
+// contents of list items are blocks, `parse_blocks` renders them
+pub fn merge_list(lines: Vec<Line>, option: &RenderOption, parse_blocks: &mut dyn FnMut(Vec<Line>) -> Vec<Line>) -> Vec<Line> {
 
-    let mut current_list = vec![];
+    let mut current_list = CurrentList::default();
+    let mut empty_lines = vec![];
     let mut result = Vec::with_capacity(lines.len());
 
-    for ln in lines.iter() {
+    for ln in lines.into_iter() {
 
-        match ln.line_type {
-            LineType::UnorderedList | LineType::OrderedList => {
-                current_list.push(ln.clone());
-            },
-            LineType::Paragraph => {
+        if current_list.items.is_empty() {
 
-                if current_list.len() > 0 {
-                    current_list.push(ln.clone());
-                }
+            if is_list(&ln) {
+                current_list.push_item(ln);
+            }
 
-                else {
-                    result.push(ln.clone());
-                }
+            else {
+                result.push(ln);
+            }
 
-            },
-            _ => {
+            continue;
+        }
 
-                if current_list.len() > 0 {
-                    result.push(construct_list(current_list));
-                    current_list = vec![];
-                }
+        if ln.line_type == LineType::Empty {
+            empty_lines.push(ln);
+            continue;
+        }
+
+        let is_after_empty = !empty_lines.is_empty();
+
+        // an empty line between sibling items of the same kind makes the list loose, ex) `-`s or `1.`s
+        // https://github.github.com/gfm/#loose
+        if is_list(&ln) && ln.indent <= current_list.base_indent && (!is_after_empty || is_same_kind(&current_list.items[0][0], &ln)) {
+            current_list.is_loose |= is_after_empty;
+            empty_lines.clear();
+            current_list.push_item(ln);
+        }
+
+        else if is_list_continued(&ln, current_list.base_indent, current_list.content_indent, is_after_empty) {
+            current_list.is_last_lazy = ln.line_type == LineType::Paragraph && ln.indent < current_list.content_indent;
+
+            let item = current_list.items.last_mut().unwrap();
+            item.append(&mut empty_lines);
+            item.push(ln);
+        }
+
+        else {
 
-                result.push(ln.clone());
+            // `| a |` above `|---|` is the head of a table, not a lazy continuation
+            let table_head = match ln.line_type {
+                LineType::TableDelimiter if current_list.is_last_lazy && !is_after_empty => current_list.items.last_mut().unwrap().pop(),
+                _ => None
+            };
+
+            result.push(construct_list(std::mem::take(&mut current_list), option, parse_blocks));
+            result.extend(table_head);
+            result.append(&mut empty_lines);
+
+            if is_list(&ln) {
+                current_list.push_item(ln);
+            }
+
+            else {
+                result.push(ln);
             }
 
         }
 
     }
 
-    if current_list.len() > 0 {
-        result.push(construct_list(current_list));
+    if !current_list.items.is_empty() {
+        result.push(construct_list(current_list, option, parse_blocks));
     }
 
+    result.append(&mut empty_lines);
+
     result
 }
 
 
-fn construct_list(mut lines: Vec<Line>) -> Line {
+#[derive(Default)]
+struct CurrentList {
+    items: Vec<Vec<Line>>,
+
+    // indentations of the bullet and the contents of the last item
+    base_indent: usize,
+    content_indent: usize,
+
+    is_last_lazy: bool,
+
+    // there's an empty line between the items
+    is_loose: bool
+}
+
+
+impl CurrentList {
 
-    lines = merge_paragraph_to_list(lines);
-    let (_, content) = construct_list_recursive(&lines, 0, lines[0].indent, lines[0].get_list_ordering());
+    fn push_item(&mut self, line: Line) {
+        self.base_indent = line.indent;
+        self.content_indent = line.indent + line.content.len() - line.get_list_content().len();
+        self.is_last_lazy = false;
+        self.items.push(vec![line]);
+    }
+
+}
+
+
+pub fn is_list(line: &Line) -> bool {
+    line.line_type == LineType::UnorderedList || line.line_type == LineType::OrderedList
+}
+
+
+// `-` and `-`, `1.` and `3.`, `a.` and `b.`, but not `-` and `1.`
+pub fn is_same_kind(item: &Line, other: &Line) -> bool {
+    is_list(other) && item.get_list_ordering().0 == other.get_list_ordering().0
+}
+
+
+// whether a non-empty `line` belongs to the last item of a list
+// `base_indent` and `content_indent` are the indentations of the bullet and the contents of the item
+// after an empty line, only the lines indented as much as the contents are in the item
+// sibling bullets are not in the item, but they're still in the list, see `merge_list`
+pub fn is_list_continued(line: &Line, base_indent: usize, content_indent: usize, is_after_empty: bool) -> bool {
+
+    match line.line_type {
+        LineType::UnorderedList | LineType::OrderedList => line.indent > base_indent,
+
+        // laziness
+        LineType::Paragraph => !is_after_empty || line.indent >= content_indent,
+        _ => line.indent >= content_indent
+    }
+
+}
+
+
+fn construct_list(list: CurrentList, option: &RenderOption, parse_blocks: &mut dyn FnMut(Vec<Line>) -> Vec<Line>) -> Line {
+
+    let CurrentList { items, is_loose, .. } = list;
+
+    let last_item = &items[items.len() - 1];
+    let span = items[0][0].span.join(&last_item[last_item.len() - 1].span);
+    let (ordering, start) = items[0][0].get_list_ordering();
+
+    let items = items.into_iter().map(|item| parse_blocks(dedent_item(item))).collect::<Vec<Vec<Line>>>();
+
+    // https://github.github.com/gfm/#loose
+    let is_loose = is_loose || items.iter().any(|blocks| blocks.iter().any(|block| block.line_type == LineType::Empty));
+
+    let mut content = ordering.opening_tag(start);
+
+    for blocks in items.iter() {
+        content.push_str("<li>");
+
+        for (ind, block) in blocks.iter().enumerate() {
+
+            if block.line_type == LineType::Paragraph {
+
+                if is_loose {
+                    content.push_str("<p>");
+                }
+
+                if ind == 0 {
+                    render_task_list(&block.content, option, &mut content);
+                }
+
+                else {
+                    content.push_str(&block.content);
+                }
+
+                if is_loose {
+                    content.push_str("</p>");
+                }
+
+            }
+
+            else {
+                block.render(&mut content);
+            }
+
+        }
+
+        content.push_str("</li>");
+    }
+
+    content.push_str(ordering.closing_tag());
 
     Line {
-        line_type: LineType::UnorderedList,
+        line_type: LineType::RenderedList,
         content,
-        indent: 0
+        indent: 0,
+        span
     }
 }
 
 
-fn merge_paragraph_to_list(mut lines: Vec<Line>) -> Vec<Line> {
+// the bullet is removed from the first line, and the contents of the item are dedented
+// nested lists may be less indented than the contents, ex) `  i.` under `1.`, and lazy continuations are even less
+fn dedent_item(mut item: Vec<Line>) -> Vec<Line> {
 
-    let mut result = Vec::with_capacity(lines.len());
+    let first = &item[0];
+    let bullet_len = first.content.len() - first.get_list_content().len();
+    let content_indent = item[1..].iter().filter(|ln| is_list(ln)).map(|ln| ln.indent).fold(first.indent + bullet_len, usize::min);
 
-    for ind in 0..lines.len() {
+    // the first line is always a paragraph, ex) `- # not a heading`
+    item[0] = Line {
+        content: first.get_list_content().to_string(),
+        indent: 0,
+        line_type: LineType::Paragraph,
+        span: first.span_of(bullet_len, first.content.len())
+    };
 
-        if lines[ind].line_type == LineType::Paragraph {
-            continue;
-        }
+    for ln in item[1..].iter_mut() {
 
-        if ind + 1 < lines.len() && lines[ind + 1].line_type == LineType::Paragraph {
-            lines[ind].content = vec![
-                lines[ind].content.clone(),
-                vec![U16_SPACE],
-                lines[ind + 1].content.clone()
-            ].concat();
+        if ln.line_type != LineType::Empty {
+            ln.indent -= ln.indent.min(content_indent);
+            ln.line_type = get_type(ln);
         }
 
-        result.push(lines[ind].clone());
     }
 
-    result
+    demote_stray_bullets(&mut item);
+
+    item
 }
 
 
-fn construct_list_recursive(lines: &Vec<Line>, begin_index: usize, curr_indent: usize, ordering: ListOrder) -> (usize, Vec<u16>) {
+// `e. g. text`, `A. Smith wrote`: sentences beginning with an initial are paragraphs, not lists
+// an alphabetic bullet is a list item only if the previous or the next item continues its sequence, ex) `a.` and `b.`
+// `lines` must be typed with `get_type`, and it has to run again whenever they're typed again
+pub fn demote_stray_bullets(lines: &mut [Line]) {
+
+    for index in 0..lines.len() {
+
+        match lines[index].line_type {
+            LineType::OrderedList => {}
+            _ => { continue; }
+        }
+
+        let (is_upper, value) = match parse_ordered_bullet(&lines[index].content) {
+            Some((ListOrder::AlphaLow, value, _)) => (false, value),
+            Some((ListOrder::AlphaUpper, value, _)) => (true, value),
+            _ => { continue; }
+        };
 
-    let mut result = vec![];
-    let mut index = begin_index;
-    result.push(ordering.opening_tag());
+        let is_sequence = |sibling: Option<usize>, expected: usize| match sibling {
+            Some(sibling) => get_letter_bullet(&lines[sibling].content) == Some((is_upper, expected)),
+            None => false
+        };
 
-    while index < lines.len() {
-        let ln = &lines[index];
+        let is_continued = is_sequence(find_sibling(lines, index, true), value - 1)
+            || is_sequence(find_sibling(lines, index, false), value + 1);
 
-        if ln.indent < curr_indent {
-            result.push(ordering.closing_tag());
-            return (index, result.concat());
+        if !is_continued {
+            lines[index].line_type = LineType::Paragraph;
         }
 
-        else if ln.indent > curr_indent {
-            result.pop();  // `</li>`
-            let (next_index, content) = construct_list_recursive(lines, index, ln.indent, ln.get_list_ordering());
+    }
+
+}
+
 
-            index = next_index;
-            result.push(content);
-            result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_L, U16_SMALL_I, U16_GREATER_THAN]);
+// the closest item of the same level, skipping empty lines, the contents of items and lazy lines
+fn find_sibling(lines: &[Line], index: usize, is_backward: bool) -> Option<usize> {
+
+    let indent = lines[index].indent;
+    let mut curr_index = index;
+
+    loop {
+        curr_index = if is_backward { curr_index.checked_sub(1)? } else { curr_index + 1 };
+
+        let ln = lines.get(curr_index)?;
+        let is_lazy = ln.line_type == LineType::Paragraph && curr_index > 0 && lines[curr_index - 1].line_type != LineType::Empty;
+
+        if ln.line_type == LineType::Empty || ln.indent > indent || is_lazy {
             continue;
         }
 
-        else {
-            result.push(vec![U16_LESS_THAN, U16_SMALL_L, U16_SMALL_I, U16_GREATER_THAN]);
-            result.push(render_task_list(ln.get_list_content()));
-            result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_L, U16_SMALL_I, U16_GREATER_THAN]);
+        if ln.line_type == LineType::OrderedList && ln.indent == indent {
+            return Some(curr_index);
         }
 
-        index += 1;
+        return None;
+    }
+
+}
+
+
+// `c.` -> (false, 3), `I.` -> (true, 9), roman numerals are read as letters here
+fn get_letter_bullet(content: &str) -> Option<(bool, usize)> {
+
+    let c = *content.as_bytes().first()?;
+
+    if content.as_bytes().get(1..3) != Some(b". ") {
+        None
+    }
+
+    else if c.is_ascii_lowercase() {
+        Some((false, (c - b'a') as usize + 1))
+    }
+
+    else if c.is_ascii_uppercase() {
+        Some((true, (c - b'A') as usize + 1))
     }
 
-    result.push(ordering.closing_tag());
+    else {
+        None
+    }
 
-    (lines.len(), result.concat())
 }
 
+
+// `.checked_box`, `.unchecked_box` and `.checkmark` of `default_stylesheet`, for `ElementStyle::InlineStyle`
+const CHECKBOX_STYLE: &str = "border-radius:3px;display:inline-block;height:1em;margin-right:0.4em;position:relative;vertical-align:middle;width:1em;";
+const CHECKMARK_STYLE: &str = "border:solid rgb(255, 255, 255);border-width:0 2px 2px 0;height:0.6em;left:0.3em;position:absolute;top:0.05em;transform:rotate(45deg);width:0.3em";
+
+
 // `line` parameter is a content of a list
 // <div class="checked_box"><span class="checkmark"></span></div>
 // <div class="unchecked_box"></div>
-fn render_task_list(line: Vec<u16>) -> Vec<u16> {
+fn render_task_list(line: &str, option: &RenderOption, buffer: &mut String) {
     
-    if is_task_list(&line) {
-        vec![
-            vec![U16_LESS_THAN, U16_SMALL_D, U16_SMALL_I, U16_SMALL_V, U16_SPACE, U16_SMALL_C, U16_SMALL_L, U16_SMALL_A, U16_SMALL_S, U16_SMALL_S, U16_EQUAL, U16_DOUBLE_QUOTE],
-            if line[1] == U16_SPACE {
-                vec![U16_SMALL_U, U16_SMALL_N, U16_SMALL_C, U16_SMALL_H, U16_SMALL_E, U16_SMALL_C, U16_SMALL_K, U16_SMALL_E, U16_SMALL_D, U16_UNDERBAR, U16_SMALL_B, U16_SMALL_O, U16_SMALL_X, U16_DOUBLE_QUOTE, U16_GREATER_THAN]
-            } else {
-                vec![U16_SMALL_C, U16_SMALL_H, U16_SMALL_E, U16_SMALL_C, U16_SMALL_K, U16_SMALL_E, U16_SMALL_D, U16_UNDERBAR, U16_SMALL_B, U16_SMALL_O, U16_SMALL_X, U16_DOUBLE_QUOTE, U16_GREATER_THAN, U16_LESS_THAN, U16_SMALL_S, U16_SMALL_P, U16_SMALL_A, U16_SMALL_N, U16_SPACE, U16_SMALL_C, U16_SMALL_L, U16_SMALL_A, U16_SMALL_S, U16_SMALL_S, U16_EQUAL, U16_DOUBLE_QUOTE, U16_SMALL_C, U16_SMALL_H, U16_SMALL_E, U16_SMALL_C, U16_SMALL_K, U16_SMALL_M, U16_SMALL_A, U16_SMALL_R, U16_SMALL_K, U16_DOUBLE_QUOTE, U16_GREATER_THAN, U16_LESS_THAN, U16_SLASH, U16_SMALL_S, U16_SMALL_P, U16_SMALL_A, U16_SMALL_N, U16_GREATER_THAN]
-            },
-            vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_D, U16_SMALL_I, U16_SMALL_V, U16_GREATER_THAN],
-            line[3..].to_vec()
-        ].concat()
+    if is_task_list(line) {
+
+        let is_checked = line.as_bytes()[1] != b' ';
+
+        match option.element_style {
+            ElementStyle::CssClass if is_checked => {
+                buffer.push_str(&format!("<div class=\"{}checked_box\"><span class=\"{}checkmark\"></span></div>", option.class_prefix, option.class_prefix));
+            }
+            ElementStyle::CssClass => {
+                buffer.push_str(&format!("<div class=\"{}unchecked_box\"></div>", option.class_prefix));
+            }
+            ElementStyle::InlineStyle if is_checked => {
+                buffer.push_str(&format!("<div style=\"{}background-color:rgb(47, 129, 247);border:2px solid rgb(47, 129, 247)\"><span style=\"{}\"></span></div>", CHECKBOX_STYLE, CHECKMARK_STYLE));
+            }
+            ElementStyle::InlineStyle => {
+                buffer.push_str(&format!("<div style=\"{}border:2px solid rgb(128, 128, 128)\"></div>", CHECKBOX_STYLE));
+            }
+        }
+
+        buffer.push_str(&line[3..]);
     }
 
     else {
-        line
+        buffer.push_str(line);
     }
 
 }
 
 
-fn is_task_list(line: &Vec<u16>) -> bool {
-    line.len() > 3 && line[0] == U16_LEFT_SQUARE_BRACKET && (line[1] == U16_SPACE || line[1] == U16_SMALL_X || line[1] == U16_BIG_X) && line[2] == U16_RIGHT_SQUARE_BRACKET && line[3] == U16_SPACE
-}
\ No newline at end of file
+pub fn is_task_list(line: &str) -> bool {
+    let line = line.as_bytes();
+
+    line.len() > 3 && line[0] == b'[' && (line[1] == b' ' || line[1] == b'x' || line[1] == b'X') && line[2] == b']' && line[3] == b' '
+}
+
+
+// `3.`, `c.`, `IV.` -> (ordering, value, length of the bullet without `.`)
+// `i.` and `I.` are roman numerals, and the other single letters are alphabets
+// it only looks at the line, `demote_stray_bullets` decides whether an alphabetic bullet is really a list item
+pub fn parse_ordered_bullet(content: &str) -> Option<(ListOrder, usize, usize)> {
+
+    let bullet_len = content.find(". ")?;
+    let bullet = &content[..bullet_len];
+
+    if bullet_len == 0 || bullet_len > 9 {
+        None
+    }
+
+    else if bullet.bytes().all(|c| c.is_ascii_digit()) {
+        Some((ListOrder::Num, bullet.parse().ok()?, bullet_len))
+    }
+
+    else if bullet_len == 1 && bullet != "i" && bullet != "I" {
+        let c = bullet.as_bytes()[0];
+
+        if c.is_ascii_lowercase() {
+            Some((ListOrder::AlphaLow, (c - b'a') as usize + 1, 1))
+        }
+
+        else if c.is_ascii_uppercase() {
+            Some((ListOrder::AlphaUpper, (c - b'A') as usize + 1, 1))
+        }
+
+        else {
+            None
+        }
+
+    }
+
+    else if bullet.bytes().all(|c| c.is_ascii_uppercase()) {
+        parse_roman(&bullet.to_lowercase()).map(|n| (ListOrder::RomanUpper, n, bullet_len))
+    }
+
+    else {
+        parse_roman(bullet).map(|n| (ListOrder::RomanLow, n, bullet_len))
+    }
+
+}
+
+
+// lowercase only, `iiii` and `ic` are not roman numerals
+// only `i`, `v` and `x` are used, up to `xxxix`: `mix.` and `dix.` are words, not 1009 and 509
+fn parse_roman(numeral: &str) -> Option<usize> {
+
+    let mut result: usize = 0;
+    let mut prev = 0;
+
+    for c in numeral.bytes().rev() {
+        let value = match c {
+            b'i' => 1,
+            b'v' => 5,
+            b'x' => 10,
+            b'l' => 50,
+            b'c' => 100,
+            b'd' => 500,
+            b'm' => 1000,
+            _ => { return None; }
+        };
+
+        // `iiiiiiv` would be negative
+        if value < prev {
+            result = result.checked_sub(value)?;
+        }
+
+        else {
+            result += value;
+            prev = value;
+        }
+
+    }
+
+    if result > 0 && result < 40 && to_roman(result) == numeral {
+        Some(result)
+    }
+
+    else {
+        None
+    }
+
+}
+
+
+fn to_roman(mut n: usize) -> String {
+
+    let mut result = String::new();
+
+    for (value, numeral) in [
+        (1000, "m"), (900, "cm"), (500, "d"), (400, "cd"), (100, "c"), (90, "xc"),
+        (50, "l"), (40, "xl"), (10, "x"), (9, "ix"), (5, "v"), (4, "iv"), (1, "i")
+    ] {
+
+        while n >= value {
+            result.push_str(numeral);
+            n -= value;
+        }
+
+    }
+
+    result
+}
@@ -1,13 +1,12 @@
 use crate::markdown::line::{Line, LineType};
-use crate::consts::*;
 
 
-pub fn merge_paragraph(lines: &Vec<Line>) -> Vec<Line> {
+pub fn merge_paragraph(lines: Vec<Line>) -> Vec<Line> {
 
     let mut curr_paragraph = vec![];
     let mut result = Vec::with_capacity(lines.len());
 
-    for ln in lines.iter() {
+    for ln in lines.into_iter() {
 
         match ln.line_type {
             LineType::Paragraph => {
@@ -15,53 +14,52 @@ pub fn merge_paragraph(lines: &Vec<Line>) -> Vec<Line> {
             }
             _ => {
 
-                if curr_paragraph.len() > 0 {
-                    result.push(construct_paragraph(curr_paragraph));
-                    curr_paragraph = vec![];
+                if !curr_paragraph.is_empty() {
+                    result.push(construct_paragraph(&curr_paragraph));
+                    curr_paragraph.clear();
                 }
 
-                result.push(ln.clone());
+                result.push(ln);
 
             }
         }
 
     }
 
-    if curr_paragraph.len() > 0 {
-        result.push(construct_paragraph(curr_paragraph));
+    if !curr_paragraph.is_empty() {
+        result.push(construct_paragraph(&curr_paragraph));
     }
 
     result
 }
 
 
-fn construct_paragraph(paragraph: Vec<&Line>) -> Line {
+fn construct_paragraph(paragraph: &[Line]) -> Line {
 
-    let mut contents = Vec::with_capacity(paragraph.len() * 2);
+    let mut content = String::with_capacity(paragraph.iter().map(|p| p.content.len() + 1).sum());
 
-    if paragraph.len() > 0 {
+    for (ind, p) in paragraph.iter().enumerate() {
 
-        for p in paragraph.iter() {
-            contents.push(p.content.clone());
+        if ind > 0 {
+            let prev = &paragraph[ind - 1].content;
 
-            if p.content.len() > 2
-            && p.content[p.content.len() - 1] == U16_SPACE
-            && p.content[p.content.len() - 2] == U16_SPACE  {
-                contents.push(vec![U16_LESS_THAN, U16_SMALL_B, U16_SMALL_R, U16_SPACE, U16_SLASH, U16_GREATER_THAN]);
+            if prev.len() > 2 && prev.ends_with("  ") {
+                content.push_str("<br />");
             }
 
             else {
-                contents.push(vec![U16_SPACE]);
+                content.push(' ');
             }
 
         }
 
-        contents.pop();
+        content.push_str(&p.content);
     }
 
     Line {
-        content: contents.concat(),
+        content,
         indent: 0,
-        line_type: LineType::Paragraph
+        line_type: LineType::Paragraph,
+        span: paragraph[0].span.join(&paragraph[paragraph.len() - 1].span)
     }
-}
\ No newline at end of file
+}
@@ -1,8 +1,8 @@
 use crate::utils::*;
-use crate::consts::*;
 use crate::markdown::inline::tag::*;
 use crate::markdown::line::{Line, LineType};
 use crate::markdown::blockquote::count_blockquote;
+use crate::markdown::list::parse_ordered_bullet;
 
 
 pub fn get_type(line: &Line) -> LineType {
@@ -43,6 +43,10 @@ pub fn get_type(line: &Line) -> LineType {
         LineType::OrderedList
     }
 
+    else if is_definition(line) {
+        LineType::Definition
+    }
+
     else {
         LineType::Paragraph
     }
@@ -54,9 +58,9 @@ fn is_header(line: &Line) -> bool {
 
     line.indent == 0
     && {
-        let (pre, post) = take_and_drop_while(&line.content, U16_SHARP);
+        let (pre, post) = take_and_drop_while(&line.content, '#');
 
-        pre.len() > 0 && pre.len() < 7 && post[0] == U16_SPACE && drop_while(&post, U16_SPACE).len() > 0
+        !pre.is_empty() && pre.len() < 7 && post.starts_with(' ') && !drop_while(post, ' ').is_empty()
     }
 }
 
@@ -64,48 +68,54 @@ fn is_header(line: &Line) -> bool {
 fn is_thematic_break(line: &Line) -> bool {
 
     line.indent < 4 && line.content.len() > 2 && (
-        line.content[0] == U16_ASTERISK ||
-        line.content[0] == U16_MINUS ||
-        line.content[0] == U16_UNDERBAR
+        line.content.starts_with('*') ||
+        line.content.starts_with('-') ||
+        line.content.starts_with('_')
     ) && {
-        let (pre, post) = take_and_drop_while(&line.content, line.content[0]);
+        let (pre, post) = take_and_drop_while(&line.content, line.content.as_bytes()[0] as char);
 
-        pre.len() > 2 && post.iter().filter(|c| *c != &U16_SPACE && *c != &U16_TAB).collect::<Vec<&u16>>().len() == 0
+        pre.len() > 2 && post.bytes().all(|c| c == b' ' || c == b'\t')
     }
 }
 
 
 fn is_code_fence(line: &Line) -> bool {
 
-    line.indent == 0 && line.content.len() > 2 && &line.content[0..3] == &[U16_BACKTICK, U16_BACKTICK, U16_BACKTICK]
+    line.indent == 0 && line.content.starts_with("```")
 }
 
 
+// `>` and `> ` are empty lines in blockquotes
 fn is_blockquote(line: &Line) -> bool {
 
-    line.indent < 4 && line.content.len() > 2 && line.content[0] == U16_GREATER_THAN && {
-        let content = drop_while(&line.content, U16_GREATER_THAN);
+    line.indent < 4 && line.content.starts_with('>') && {
+        let content = drop_while(&line.content, '>');
 
-        content.len() > 0 && content[0] == U16_SPACE
+        content.is_empty() || content.starts_with(' ')
     }
 }
 
 
 fn is_unordered_list(line: &Line) -> bool {
 
-    line.content.len() > 2 && line.content[0] == U16_MINUS && line.content[1] == U16_SPACE
+    line.content.len() > 2 && line.content.starts_with("- ")
 }
 
 
 fn is_ordered_list(line: &Line) -> bool {
 
-    line.content.len() > 3 && (
-        line.content[0] == U16_1 ||
-        line.content[0] == U16_SMALL_A ||
-        line.content[0] == U16_SMALL_I ||
-        line.content[0] == U16_BIG_A ||
-        line.content[0] == U16_BIG_I
-    ) && line.content[1] == U16_DOT && line.content[2] == U16_SPACE
+    match parse_ordered_bullet(&line.content) {
+        Some((_, _, bullet_len)) => line.content.len() > bullet_len + 2,
+        None => false
+    }
+
+}
+
+
+// `: definition`, the contents may be indented more, ex) `:   definition`
+fn is_definition(line: &Line) -> bool {
+
+    line.indent < 4 && line.content.starts_with(": ") && !line.content[2..].trim().is_empty()
 }
 
 
@@ -121,22 +131,14 @@ fn is_independent_tag(line: &Line) -> bool {
 
 fn is_table_delimiter(line: &Line) -> bool {
 
-    if line.content.len() == 0 || line.content[0] != U16_VERTICAL_BAR || line.content[line.content.len() - 1] != U16_VERTICAL_BAR {
+    if !line.content.starts_with('|') || !line.content.ends_with('|') {
         return false;
     }
 
-    for c in line.content.iter() {
-
-        if *c != U16_COLON && *c != U16_MINUS && *c != U16_VERTICAL_BAR && *c != U16_SPACE {
-            return false;
-        }
-
-    }
-
-    true
+    line.content.bytes().all(|c| c == b':' || c == b'-' || c == b'|' || c == b' ')
 }
 
 
 fn is_empty(line: &Line) -> bool {
-    line.content.len() == 0
-}
\ No newline at end of file
+    line.content.is_empty()
+}
@@ -0,0 +1,50 @@
+use crate::option::ColorScheme;
+
+
+// the variables of a color scheme, followed by the rules for every class that hmd emits
+// `class_prefix` is prepended to the class selectors, like `RenderOption::class_prefix`
+pub fn stylesheet(color_scheme: ColorScheme, class_prefix: &str) -> String {
+
+    let variables = match color_scheme {
+        ColorScheme::Light => include_str!("stylesheet/light.css"),
+        ColorScheme::Dark => include_str!("stylesheet/dark.css")
+    };
+
+    format!("{}\n{}", variables, prefix_classes(include_str!("stylesheet/base.css"), class_prefix))
+}
+
+
+// `.box, td.line_no::before {` -> `.hmd-box, td.hmd-line_no::before {`
+// declarations and comments are not changed, ex) `0.5em`
+fn prefix_classes(css: &str, class_prefix: &str) -> String {
+
+    let mut result = String::with_capacity(css.len() * 2);
+    let mut is_inside_block = false;
+    let mut is_inside_comment = false;
+    let mut prev = ' ';
+
+    for c in css.chars() {
+
+        if is_inside_comment {
+            is_inside_comment = !(prev == '*' && c == '/');
+        }
+
+        else if prev == '/' && c == '*' {
+            is_inside_comment = true;
+        }
+
+        else if c == '{' || c == '}' {
+            is_inside_block = c == '{';
+        }
+
+        result.push(c);
+
+        if c == '.' && !is_inside_block && !is_inside_comment {
+            result.push_str(class_prefix);
+        }
+
+        prev = c;
+    }
+
+    result
+}
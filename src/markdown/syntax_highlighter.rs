@@ -1,63 +1,158 @@
 use crate::markdown::escape::undo_html_escapes;
 use crate::markdown::line::{Line, LineType};
-use crate::consts::*;
-use syntect::parsing::SyntaxSet;
+use crate::option::{CodeHighlight, RenderOption, DEFAULT_THEME};
+use syntect::parsing::{SyntaxSet, SyntaxReference, ParseState, ScopeStack};
 use syntect::easy::HighlightLines;
 use syntect::highlighting::{ThemeSet, Theme};
-use syntect::html::{IncludeBackground, append_highlighted_html_for_styled_line};
+use syntect::html::{ClassStyle, IncludeBackground, append_highlighted_html_for_styled_line, css_for_theme_with_class_style, line_tokens_to_classed_spans};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
 
-pub struct SyntaxHighlighter {
-    syntaxes: SyntaxSet,
-    theme: Theme
+// loading them takes much longer than rendering most documents, so they're loaded only once
+lazy_static! {
+    static ref DEFAULT_SYNTAXES: SyntaxSet = SyntaxSet::load_defaults_nonewlines();
+    static ref DEFAULT_THEMES: ThemeSet = ThemeSet::load_defaults();
 }
 
-impl SyntaxHighlighter {
-    pub fn new() -> SyntaxHighlighter {
-        let syntax_set = SyntaxSet::load_defaults_nonewlines();
-        let theme_set = ThemeSet::load_defaults();
-        let theme = theme_set.themes["base16-eighties.dark"].clone();
+pub struct SyntaxHighlighter<'a> {
+    syntaxes: &'a SyntaxSet,
+    theme: &'a Theme,
+    code_highlight: CodeHighlight,
+    language_aliases: &'a HashMap<String, String>
+}
+
+impl<'a> SyntaxHighlighter<'a> {
+    pub fn new(option: &'a RenderOption) -> SyntaxHighlighter<'a> {
+        let syntax_set = match option.syntaxes() {
+            Some(syntaxes) => syntaxes,
+            None => &DEFAULT_SYNTAXES
+        };
+
+        let theme = match option.custom_theme(&option.theme) {
+            Some(theme) => theme,
+            None => match DEFAULT_THEMES.themes.get(&option.theme) {
+                Some(theme) => theme,
+                None => &DEFAULT_THEMES.themes[DEFAULT_THEME]
+            }
+        };
 
         SyntaxHighlighter {
             syntaxes: syntax_set,
-            theme
+            theme,
+            code_highlight: option.code_highlight,
+            language_aliases: &option.language_aliases
         }
     }
 
     pub fn highlight_syntax(&self, lines: Vec<Line>, syntax: &str) -> Vec<Line> {
 
-        let syntax_rule = match self.syntaxes.find_syntax_by_token(syntax) {
+        let syntax_rule = match self.find_syntax(syntax) {
             None => {return lines;}
             Some(s) => s
         };
 
-        let mut highlighter = HighlightLines::new(syntax_rule, &self.theme);
-        let mut output = String::new();
+        match self.code_highlight {
+            CodeHighlight::InlineStyle => self.highlight_with_styles(lines, syntax_rule),
+            CodeHighlight::CssClass => self.highlight_with_classes(lines, syntax_rule)
+        }
+
+    }
 
+    fn find_syntax(&self, syntax: &str) -> Option<&SyntaxReference> {
+
+        let syntax = match self.language_aliases.get(syntax) {
+            Some(alias) => alias.as_str(),
+            None => syntax
+        };
+
+        self.syntaxes.find_syntax_by_token(syntax).or_else(
+            || self.syntaxes.find_syntax_by_name(syntax)
+        )
+
+    }
+
+    fn highlight_with_styles(&self, lines: Vec<Line>, syntax_rule: &SyntaxReference) -> Vec<Line> {
+
+        let mut highlighter = HighlightLines::new(syntax_rule, self.theme);
+        let mut result = Vec::with_capacity(lines.len());
+
+        // the syntaxes are `nonewlines` ones, so the lines are highlighted without `\n`
         for ln in lines.iter() {
-            let mut raw_line = undo_html_escapes(&ln.into_raw());
-            raw_line.push(U16_NEWLINE);
+            let curr_line = undo_html_escapes(&ln.to_raw());
+            let styled_line = highlighter.highlight(&curr_line, self.syntaxes);
+            let mut output = String::new();
 
-            let curr_line = &String::from_utf16_lossy(&raw_line);
-            let styled_line = highlighter.highlight(curr_line, &self.syntaxes);
             append_highlighted_html_for_styled_line(
                 &styled_line[..],
                 IncludeBackground::No,
                 &mut output
             );
+
+            result.push(Line {
+                line_type: LineType::Paragraph,
+                content: output,
+                indent: 0,
+                span: ln.span
+            });
         }
 
-        let result = output.encode_utf16().collect::<Vec<u16>>();
+        result
+    }
 
-        result.split(
-            |c|
-            *c == U16_NEWLINE
-        ).map(
-            |ln| Line {
-                line_type: LineType::Paragraph,
-                content: ln.to_vec(),
-                indent: 0
+    // Each line is closed by itself: spans left open at the end of a line are closed there and reopened on the next line.
+    fn highlight_with_classes(&self, lines: Vec<Line>, syntax_rule: &SyntaxReference) -> Vec<Line> {
+
+        let mut parse_state = ParseState::new(syntax_rule);
+        let mut scope_stack = ScopeStack::new();
+        let mut result = Vec::with_capacity(lines.len());
+
+        for ln in lines.iter() {
+            let curr_line = undo_html_escapes(&ln.to_raw());
+            let ops = parse_state.parse_line(&curr_line, self.syntaxes);
+            let mut output = String::new();
+
+            for scope in scope_stack.as_slice() {
+                output.push_str(&format!("<span class=\"{}\">", scope.build_string().replace('.', " ")));
             }
-        ).collect::<Vec<Line>>()
+
+            let (spans, _) = line_tokens_to_classed_spans(&curr_line, &ops[..], ClassStyle::Spaced, &mut scope_stack);
+            output.push_str(&spans);
+            output.push_str(&"</span>".repeat(scope_stack.len()));
+
+            result.push(Line {
+                line_type: LineType::Paragraph,
+                content: output,
+                indent: 0,
+                span: ln.span
+            });
+        }
+
+        result
     }
 
 }
+
+
+pub fn has_theme(theme_name: &str, option: &RenderOption) -> bool {
+    option.custom_theme(theme_name).is_some() || DEFAULT_THEMES.themes.contains_key(theme_name)
+}
+
+
+// the themes loaded with `RenderOption::load_theme_dir` come first
+pub fn theme_names(option: &RenderOption) -> Vec<String> {
+
+    let mut result = option.custom_theme_names();
+
+    result.extend(DEFAULT_THEMES.themes.keys().filter(|name| option.custom_theme(name).is_none()).cloned());
+
+    result
+}
+
+
+// the stylesheet for `CodeHighlight::CssClass`
+// a theme loaded with `RenderOption::load_theme_dir` is looked up before the bundled ones, the same as `SyntaxHighlighter::new`
+pub fn theme_css(theme_name: &str, option: &RenderOption) -> Option<String> {
+    option.custom_theme(theme_name).or_else(|| DEFAULT_THEMES.themes.get(theme_name)).map(
+        |theme| css_for_theme_with_class_style(theme, ClassStyle::Spaced)
+    )
+}
@@ -1,6 +1,9 @@
-use crate::markdown::line::{Line, LineType};
-use crate::markdown::inline::render_inline;
-use crate::consts::*;
+use crate::markdown::diagnostic::Diagnostic;
+use crate::markdown::line::{Line, LineType, render_blocks};
+use crate::markdown::list::demote_stray_bullets;
+use crate::markdown::predicates::get_type;
+use crate::option::{RenderOption, TableAlignmentStyle};
+use std::ops::Range;
 
 
 #[derive(Debug)]
@@ -11,139 +14,387 @@ enum TableAlignment {
 
 impl TableAlignment {
 
-    fn render(&self) -> Vec<u16> {
-        match self {
-            TableAlignment::Center => vec![U16_LESS_THAN, U16_SMALL_T, U16_SMALL_D, U16_SPACE, U16_SMALL_C, U16_SMALL_L, U16_SMALL_A, U16_SMALL_S, U16_SMALL_S, U16_EQUAL, U16_DOUBLE_QUOTE, U16_SMALL_A, U16_SMALL_L, U16_SMALL_I, U16_SMALL_G, U16_SMALL_N, U16_UNDERBAR, U16_SMALL_C, U16_SMALL_E, U16_SMALL_N, U16_SMALL_T, U16_SMALL_E, U16_SMALL_R, U16_DOUBLE_QUOTE, U16_GREATER_THAN],
-            TableAlignment::Left => vec![U16_LESS_THAN, U16_SMALL_T, U16_SMALL_D, U16_SPACE, U16_SMALL_C, U16_SMALL_L, U16_SMALL_A, U16_SMALL_S, U16_SMALL_S, U16_EQUAL, U16_DOUBLE_QUOTE, U16_SMALL_A, U16_SMALL_L, U16_SMALL_I, U16_SMALL_G, U16_SMALL_N, U16_UNDERBAR, U16_SMALL_L, U16_SMALL_E, U16_SMALL_F, U16_SMALL_T, U16_DOUBLE_QUOTE, U16_GREATER_THAN],
-            TableAlignment::Right => vec![U16_LESS_THAN, U16_SMALL_T, U16_SMALL_D, U16_SPACE, U16_SMALL_C, U16_SMALL_L, U16_SMALL_A, U16_SMALL_S, U16_SMALL_S, U16_EQUAL, U16_DOUBLE_QUOTE, U16_SMALL_A, U16_SMALL_L, U16_SMALL_I, U16_SMALL_G, U16_SMALL_N, U16_UNDERBAR, U16_SMALL_R, U16_SMALL_I, U16_SMALL_G, U16_SMALL_H, U16_SMALL_T, U16_DOUBLE_QUOTE, U16_GREATER_THAN],
+    // `<td class="align_left" colspan="2">`, `<th style="text-align:left">`
+    fn render(&self, tag: &str, colspan: usize, style: TableAlignmentStyle, class_prefix: &str) -> String {
+
+        let alignment = match self {
+            TableAlignment::Center => "center",
+            TableAlignment::Left => "left",
+            TableAlignment::Right => "right",
+        };
+
+        let attribute = match style {
+            TableAlignmentStyle::CssClass => format!("class=\"{}align_{}\"", class_prefix, alignment),
+            TableAlignmentStyle::InlineStyle => format!("style=\"text-align:{}\"", alignment)
+        };
+
+        if colspan > 1 {
+            format!("<{} {} colspan=\"{}\">", tag, attribute, colspan)
+        }
+
+        else {
+            format!("<{} {}>", tag, attribute)
         }
+
     }
 
 }
 
 
-pub fn merge_table(lines: &Vec<Line>) -> Vec<Line> {
+// renders lines of a cell with the diagnostics of the table
+pub type ParseBlocks<'a> = dyn FnMut(Vec<Line>, &mut Vec<Diagnostic>) -> Vec<Line> + 'a;
+
+
+// a row beginning with `+|` continues the row above it, so that cells can have multiple lines
+// contents of cells are blocks, `parse_blocks` renders them
+pub fn merge_table(lines: Vec<Line>, option: &RenderOption, parse_blocks: &mut ParseBlocks, diagnostics: &mut Vec<Diagnostic>) -> Vec<Line> {
 
     let mut result = Vec::with_capacity(lines.len());
     let mut is_inside_table = false;
     let mut curr_table = vec![];
 
-    for (ind, ln) in lines.iter().enumerate() {
+    for ln in lines.into_iter() {
 
         if is_inside_table {
 
-            if is_valid_table_row(ln) {
-                curr_table.push(ln.clone());
+            // the head cannot be continued
+            // a continued row may leave the other cells out, ex) `+| more |`, so its cells are not counted
+            if is_valid_table_row(&ln) || (is_continued_row(&ln) && curr_table.len() > 2) {
+
+                if !is_continued_row(&ln) {
+                    check_cell_count(&curr_table[0], &ln, diagnostics);
+                }
+
+                curr_table.push(ln);
+            }
+
+            else if is_table_caption(&ln) {
+                result.push(construct_table(&curr_table, Some(&ln), option, parse_blocks, diagnostics));
+                curr_table.clear();
+                is_inside_table = false;
             }
 
             else {
-                result.push(construct_table(curr_table));
-                result.push(ln.clone());
-                curr_table = vec![];
+                result.push(construct_table(&curr_table, None, option, parse_blocks, diagnostics));
+                result.push(ln);
+                curr_table.clear();
                 is_inside_table = false;
             }
 
         }
 
-        else {
-
-            if ln.line_type == LineType::TableDelimiter {
+        else if ln.line_type == LineType::TableDelimiter {
 
-                if ind > 0 && is_valid_table_row(&lines[ind - 1]) && row_to_cells(&ln.content).len() == row_to_cells(&lines[ind - 1].content).len() {
+            match result.last() {
+                Some(prev) if is_valid_table_row(prev) && raw_row_to_cells(&ln.content).len() == raw_row_to_cells(&prev.content).len() => {
                     curr_table.push(result.pop().unwrap());
-                    curr_table.push(ln.clone());
+                    curr_table.push(ln);
                     is_inside_table = true;
                 }
-
-                else {
-                    let mut ln_ = ln.clone();
-                    ln_.line_type = LineType::Paragraph;
-                    ln_ = render_inline(ln_);
-                    result.push(ln_);
+                _ => {
+                    let mut ln = ln;
+                    ln.line_type = LineType::Paragraph;
+                    result.push(ln);
                 }
-
             }
 
-            else {
-                result.push(ln.clone());
-            }
+        }
 
+        else {
+            result.push(ln);
         }
 
     }
 
     if is_inside_table {
-        result.push(construct_table(curr_table));
+        result.push(construct_table(&curr_table, None, option, parse_blocks, diagnostics));
     }
 
     result
 }
 
 
-fn construct_table(lines: Vec<Line>) -> Line {
+// `caption` is the line right below the table, `Table: caption`
+fn construct_table(lines: &[Line], caption: Option<&Line>, option: &RenderOption, parse_blocks: &mut ParseBlocks, diagnostics: &mut Vec<Diagnostic>) -> Line {
 
-    let table_head = row_to_cells(&lines[0].content);
-    let table_body = lines[2..].iter().map(|ln| row_to_cells(&ln.content)).collect::<Vec<Vec<Vec<u16>>>>();
-    let mut result = vec![];
-    let alignments = row_to_cells(&lines[1].content).iter().map(|cell| parse_column_alignment(cell)).collect::<Vec<TableAlignment>>();
+    let table_head = render_row(&lines[0..1], parse_blocks, diagnostics);
+    let alignments = raw_row_to_cells(&lines[1].content).iter().map(|cell| parse_column_alignment(cell)).collect::<Vec<TableAlignment>>();
+    let mut result = String::with_capacity(lines.iter().map(|ln| ln.content.len() * 2).sum());
 
-    result.push(vec![U16_LESS_THAN, U16_SMALL_T, U16_SMALL_A, U16_SMALL_B, U16_SMALL_L, U16_SMALL_E, U16_GREATER_THAN]);
-    result.push(vec![U16_LESS_THAN, U16_SMALL_T, U16_SMALL_H, U16_SMALL_E, U16_SMALL_A, U16_SMALL_D, U16_GREATER_THAN]);
+    result.push_str("<table>");
 
-    for th in table_head.iter() {
-        result.push(vec![U16_LESS_THAN, U16_SMALL_T, U16_SMALL_H, U16_GREATER_THAN]);
-        result.push(th.clone());
-        result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_T, U16_SMALL_H, U16_GREATER_THAN]);
+    if let Some(caption) = caption {
+        let begin = caption.content.len() - caption.content["Table:".len()..].trim_start().len();
+        let end = caption.content.trim_end().len();
+
+        result.push_str("<caption>");
+        render_blocks(&parse_blocks(vec![cell_line(caption, begin..end)], diagnostics), "", &mut result);
+        result.push_str("</caption>");
+    }
+
+    // `<col>`s are aligned too, so that stylesheets can select columns
+    if option.table_colgroup {
+        result.push_str("<colgroup>");
+
+        for alignment in alignments.iter() {
+            result.push_str(&alignment.render("col", 1, option.table_alignment_style, &option.class_prefix));
+        }
+
+        result.push_str("</colgroup>");
     }
 
-    result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_T, U16_SMALL_H, U16_SMALL_E, U16_SMALL_A, U16_SMALL_D, U16_GREATER_THAN]);
-    result.push(vec![U16_LESS_THAN, U16_SMALL_T, U16_SMALL_B, U16_SMALL_O, U16_SMALL_D, U16_SMALL_Y, U16_GREATER_THAN]);
+    result.push_str("<thead>");
+    render_cells("th", table_head, &alignments, option, &mut result);
+    result.push_str("</thead><tbody>");
 
-    for tr in table_body.iter() {
-        result.push(vec![U16_LESS_THAN, U16_SMALL_T, U16_SMALL_R, U16_GREATER_THAN]);
+    let mut row_begin = 2;
 
-        for (ind, td) in tr.iter().enumerate() {
+    while row_begin < lines.len() {
+        let row_end = lines[row_begin + 1..].iter().position(|ln| !is_continued_row(ln)).map_or(lines.len(), |ind| row_begin + 1 + ind);
+        let cells = render_row(&lines[row_begin..row_end], parse_blocks, diagnostics);
 
-            if ind == alignments.len() {
-                break;
+        result.push_str("<tr>");
+        render_cells("td", cells, &alignments, option, &mut result);
+        result.push_str("</tr>");
+
+        row_begin = row_end;
+    }
+
+    result.push_str("</tbody></table>");
+
+    let last_line = caption.unwrap_or(&lines[lines.len() - 1]);
+
+    Line {
+        content: result,
+        indent: 0,
+        line_type: LineType::RenderedTable,
+        span: lines[0].span.join(&last_line.span)
+    }
+}
+
+
+// `cells` are (rendered content, colspan)
+fn render_cells(tag: &str, mut cells: Vec<(String, usize)>, alignments: &[TableAlignment], option: &RenderOption, buffer: &mut String) {
+
+    let mut column = cells.iter().map(|(_, colspan)| colspan).sum::<usize>();
+
+    // missing cells are filled with empty ones, like gfm
+    while column < alignments.len() {
+        cells.push((String::new(), 1));
+        column += 1;
+    }
+
+    column = 0;
+
+    // extra cells are not rendered
+    for (content, colspan) in cells.iter() {
+
+        if column >= alignments.len() {
+            break;
+        }
+
+        let colspan = (*colspan).min(alignments.len() - column);
+
+        buffer.push_str(&alignments[column].render(tag, colspan, option.table_alignment_style, &option.class_prefix));
+        buffer.push_str(content);
+        buffer.push_str(&format!("</{}>", tag));
+
+        column += colspan;
+    }
+
+}
+
+
+// `rows[0]` is a row, and `rows[1..]` are its continuations
+// (rendered content, colspan)
+fn render_row(rows: &[Line], parse_blocks: &mut ParseBlocks, diagnostics: &mut Vec<Diagnostic>) -> Vec<(String, usize)> {
+
+    let cells = merge_cells(&rows[0].content, row_to_cells(&rows[0]));
+    let continued_cells = rows[1..].iter().map(row_to_cells).collect::<Vec<Vec<Range<usize>>>>();
+    let mut result = Vec::with_capacity(cells.len());
+    let mut column = 0;
+
+    for (cell, colspan) in cells.into_iter() {
+        let mut cell_lines = vec![(&rows[0], cell)];
+
+        // lines of a merged cell are below its first column
+        for (row, cells) in rows[1..].iter().zip(continued_cells.iter()) {
+
+            if let Some(cell) = cells.get(column) {
+                cell_lines.push((row, cell.clone()));
             }
 
-            result.push(alignments[ind].render());
-            result.push(td.clone());
-            result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_T, U16_SMALL_D, U16_GREATER_THAN]);
         }
 
-        result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_T, U16_SMALL_R, U16_GREATER_THAN]);
+        // a cell of a single-line row is a paragraph, ex) `| # not a heading |`
+        let cell_lines = if rows.len() == 1 {
+            vec![cell_line(&rows[0], cell_lines[0].1.clone())]
+        }
+
+        else {
+            cell_to_lines(&cell_lines)
+        };
+
+        let mut content = String::new();
+        render_blocks(&parse_blocks(cell_lines, diagnostics), "", &mut content);
+
+        result.push((content, colspan));
+        column += colspan;
     }
 
-    result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_T, U16_SMALL_B, U16_SMALL_O, U16_SMALL_D, U16_SMALL_Y, U16_GREATER_THAN]);
-    result.push(vec![U16_LESS_THAN, U16_SLASH, U16_SMALL_T, U16_SMALL_A, U16_SMALL_B, U16_SMALL_L, U16_SMALL_E, U16_GREATER_THAN]);
+    result
+}
+
+
+// lines of a multi-line cell are dedented and trimmed, then typed again
+fn cell_to_lines(cell_lines: &[(&Line, Range<usize>)]) -> Vec<Line> {
+
+    let indent = cell_lines.iter().map(
+        |(row, cell)| &row.content[cell.clone()]
+    ).filter(
+        |content| !content.trim().is_empty()
+    ).map(
+        |content| Line::from_raw(content).indent
+    ).min().unwrap_or(0);
+
+    let mut lines = cell_lines.iter().map(
+        |(row, cell)| {
+            let content = row.content[cell.clone()].trim_end();
+            let mut line = Line::from_raw(content);
+            let end = cell.start + content.len();
+
+            line.indent -= line.indent.min(indent);
+            line.span = row.span_of(end - line.content.len(), end);
+            line.line_type = get_type(&line);
+
+            line
+        }
+    ).collect::<Vec<Line>>();
+
+    demote_stray_bullets(&mut lines);
+
+    lines
+}
+
+
+fn cell_line(row: &Line, cell: Range<usize>) -> Line {
 
     Line {
-        content: result.concat(),
+        content: row.content[cell.clone()].to_string(),
         indent: 0,
-        line_type: LineType::RenderedTable
+        line_type: LineType::Paragraph,
+        span: row.span_of(cell.start, cell.end)
     }
 }
 
 
-fn row_to_cells(row: &Vec<u16>) -> Vec<Vec<u16>> {
+// extra cells are not rendered, and missing cells are empty
+fn check_cell_count(head: &Line, row: &Line, diagnostics: &mut Vec<Diagnostic>) {
 
-    let result = row.split(|c| *c == U16_VERTICAL_BAR).map(|c| c.to_vec()).collect::<Vec<Vec<u16>>>();
+    let head_count = row_to_cells(head).len();
+    let row_count = row_to_cells(row).len();
 
-    result[1..result.len() - 1].to_vec()
+    if head_count != row_count {
+        diagnostics.push(Diagnostic::warning(
+            row.span,
+            format!(
+                "the table has {} column{}, but this row has {} cell{}",
+                head_count, if head_count == 1 { "" } else { "s" },
+                row_count, if row_count == 1 { "" } else { "s" }
+            )
+        ));
+    }
+
+}
+
+
+// `\|` and `|`s in code spans are not delimiters
+pub fn raw_row_to_cells(row: &str) -> Vec<&str> {
+    cell_ranges(row).into_iter().map(|cell| &row[cell]).collect()
+}
+
+
+// `+|` of a continued row is not a part of its cells
+fn row_to_cells(row: &Line) -> Vec<Range<usize>> {
+
+    let offset = if is_continued_row(row) { 1 } else { 0 };
+
+    cell_ranges(&row.content[offset..]).into_iter().map(|cell| cell.start + offset..cell.end + offset).collect()
 }
 
 
-fn parse_column_alignment(content: &Vec<u16>) -> TableAlignment {
+fn cell_ranges(row: &str) -> Vec<Range<usize>> {
+
+    let bytes = row.as_bytes();
+    let mut result = vec![];
+
+    // the leading `|` is not a part of the first cell
+    let mut last_index = if row.starts_with('|') { 1 } else { 0 };
+    let mut curr_index = last_index;
+
+    while curr_index < bytes.len() {
+
+        match bytes[curr_index] {
+            b'\\' => {
+                curr_index += 2;
+            }
+            b'`' => {
+                curr_index = row[curr_index + 1..].find('`').map_or(curr_index + 1, |end| curr_index + end + 2);
+            }
+            b'|' => {
+                result.push(last_index..curr_index);
+                last_index = curr_index + 1;
+                curr_index += 1;
+            }
+            _ => {
+                curr_index += 1;
+            }
+        }
+
+    }
+
+    result
+}
+
+
+// a cell with only `<` is merged into the cell on its left
+// (cell, colspan)
+fn merge_cells(row: &str, cells: Vec<Range<usize>>) -> Vec<(Range<usize>, usize)> {
+
+    let mut result: Vec<(Range<usize>, usize)> = Vec::with_capacity(cells.len());
+
+    for cell in cells.into_iter() {
+
+        match result.last_mut() {
+            Some((_, colspan)) if row[cell.clone()].trim() == "&lt;" => {
+                *colspan += 1;
+            }
+            _ => {
+                result.push((cell, 1));
+            }
+        }
+
+    }
+
+    result
+}
+
+
+// `Table: caption`
+fn is_table_caption(line: &Line) -> bool {
+    line.line_type == LineType::Paragraph && line.content.starts_with("Table: ")
+}
+
+
+fn parse_column_alignment(content: &str) -> TableAlignment {
 
     if content.len() < 2 {
         TableAlignment::Left
     }
 
-    else if content[0] == U16_COLON {
+    else if content.starts_with(':') {
 
-        if content[content.len() - 1] == U16_COLON {
+        if content.ends_with(':') {
             TableAlignment::Center
         }
 
@@ -153,7 +404,7 @@ fn parse_column_alignment(content: &Vec<u16>) -> TableAlignment {
 
     }
 
-    else if content[content.len() - 1] == U16_COLON {
+    else if content.ends_with(':') {
         TableAlignment::Right
     }
 
@@ -164,8 +415,16 @@ fn parse_column_alignment(content: &Vec<u16>) -> TableAlignment {
 }
 
 
-fn is_valid_table_row(line: &Line) -> bool {
+pub fn is_valid_table_row(line: &Line) -> bool {
     (line.line_type == LineType::Paragraph || line.line_type == LineType::TableDelimiter)
     && line.content.len() > 2
-    && line.content[0] == U16_VERTICAL_BAR && line.content[line.content.len() - 1] == U16_VERTICAL_BAR
-}
\ No newline at end of file
+    && line.content.starts_with('|') && line.content.ends_with('|') && !line.content.ends_with("\\|")
+}
+
+
+// `+| a |`
+pub fn is_continued_row(line: &Line) -> bool {
+    line.line_type == LineType::Paragraph
+    && line.content.len() > 3
+    && line.content.starts_with("+|") && line.content.ends_with('|') && !line.content.ends_with("\\|")
+}
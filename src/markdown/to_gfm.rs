@@ -0,0 +1,606 @@
+use crate::markdown::abbreviation::parse_abbreviation;
+use crate::markdown::attribute::parse_attributes;
+use crate::markdown::blockquote::get_blockquote_content;
+use crate::markdown::code_fence::get_fence_language;
+use crate::markdown::diagnostic::{Diagnostic, Span};
+use crate::markdown::inline::tag::{export_tag, is_tag};
+use crate::markdown::line::{Line, LineType};
+use crate::markdown::list::{ListOrder, demote_stray_bullets, parse_ordered_bullet};
+use crate::markdown::predicates::get_type;
+use crate::markdown::table::raw_row_to_cells;
+use crate::utils::get_bracket_end_index;
+
+/*
+Lowers hmd into gfm, line by line. The extensions become inline html, which gfm and most CommonMark engines pass through.
+
+- `~_u_~`, `~sub~`, `^sup^` -> `<u>`, `<sub>`, `<sup>`
+- `[[box]]`, `[[red]]`, `[[big]]` -> the html `render` emits, `[[center]]` -> `<div align="center">`
+- `[[icon = ...]]` -> `<img>` of a data uri, with the name of the icon as its alt text
+- ```rust, line_num(1), title(a.rs) -> ```rust
+- `c.`, `iv.` bullets -> `3.`, `4.`, with a warning
+
+Some characters mean nothing in hmd but something in gfm, so they're escaped: raw html, html entities, `_`, `* ` at the beginning of a paragraph, and more.
+*/
+
+
+pub fn to_gfm(content: &str) -> (String, Vec<Diagnostic>) {
+
+    let mut exporter = Exporter::new(content);
+
+    for index in 0..exporter.lines.len() {
+        exporter.export_line(index);
+    }
+
+    exporter.diagnostics.sort_by_key(|d| d.span.offset);
+
+    (exporter.result.join("\n"), exporter.diagnostics)
+}
+
+
+struct Exporter<'a> {
+    lines: Vec<&'a str>,
+
+    // the types of `lines`, `demote_stray_bullets` needs the lines around
+    line_types: Vec<LineType>,
+
+    // byte offset of each line in the source
+    offsets: Vec<usize>,
+
+    result: Vec<String>,
+    diagnostics: Vec<Diagnostic>,
+
+    // fences in list items are indented
+    fence_indent: Option<usize>,
+    is_inside_list: bool,
+
+    // lazy lines are in the fence until the blockquote ends
+    is_inside_quote_fence: bool,
+    prev_type: LineType,
+
+    // html blocks of gfm swallow the following lines until an empty line
+    needs_empty_line: bool
+}
+
+
+impl<'a> Exporter<'a> {
+
+    fn new(content: &'a str) -> Exporter<'a> {
+
+        let lines = content.split('\n').collect::<Vec<&str>>();
+        let mut offsets = Vec::with_capacity(lines.len());
+        let mut offset = 0;
+
+        for ln in lines.iter() {
+            offsets.push(offset);
+            offset += ln.len() + 1;
+        }
+
+        let mut typed = lines.iter().map(
+            |raw| {
+                let mut ln = Line::from_raw(raw);
+                ln.line_type = get_type(&ln);
+
+                ln
+            }
+        ).collect::<Vec<Line>>();
+
+        demote_stray_bullets(&mut typed);
+
+        Exporter {
+            result: Vec::with_capacity(lines.len()),
+            line_types: typed.into_iter().map(|ln| ln.line_type).collect(),
+            lines,
+            offsets,
+            diagnostics: vec![],
+            fence_indent: None,
+            is_inside_list: false,
+            is_inside_quote_fence: false,
+            prev_type: LineType::Empty,
+            needs_empty_line: false
+        }
+    }
+
+    fn export_line(&mut self, index: usize) {
+
+        let raw = self.lines[index];
+        let mut ln = Line::from_raw(raw);
+        ln.line_type = self.line_types[index].clone();
+
+        if let Some(fence_indent) = self.fence_indent {
+
+            // any line beginning with 3 backticks closes a fence in hmd
+            if ln.content.starts_with("```") && ln.indent <= fence_indent {
+                self.result.push(format!("{}```", &raw[..raw.len() - ln.content.len()]));
+                self.fence_indent = None;
+            }
+
+            else {
+                self.result.push(raw.to_string());
+            }
+
+            return;
+        }
+
+        match ln.line_type {
+            LineType::Blockquote(_) => {
+                let quoted = get_blockquote_content(&ln.content);
+
+                if quoted.starts_with("```") {
+                    let markers = &raw[..raw.len() - quoted.len()];
+
+                    if self.is_inside_quote_fence {
+                        self.result.push(format!("{}```", markers));
+                    }
+
+                    else {
+                        self.result.push(format!("{}```{}", markers, get_fence_language(quoted).unwrap_or_default()));
+                    }
+
+                    self.is_inside_quote_fence = !self.is_inside_quote_fence;
+                    self.prev_type = ln.line_type;
+
+                    return;
+                }
+
+            }
+            LineType::Paragraph => {}
+            _ => {
+                self.is_inside_quote_fence = false;
+            }
+        }
+
+        if self.is_inside_quote_fence {
+            self.result.push(raw.to_string());
+            self.prev_type = ln.line_type;
+
+            return;
+        }
+
+        if self.needs_empty_line && ln.line_type != LineType::Empty {
+            self.result.push(String::new());
+        }
+
+        self.needs_empty_line = false;
+
+        if ln.line_type == LineType::UnorderedList || ln.line_type == LineType::OrderedList {
+            self.is_inside_list = true;
+        }
+
+        else if ln.line_type != LineType::Empty && ln.indent == 0 && (ln.line_type != LineType::Paragraph || self.prev_type == LineType::Empty) {
+            self.is_inside_list = false;
+        }
+
+        if ln.content.starts_with("```") && (ln.indent == 0 || self.is_inside_list) {
+            self.result.push(format!("{}```{}", &raw[..raw.len() - ln.content.len()], get_fence_language(&ln.content).unwrap_or_default()));
+            self.fence_indent = Some(ln.indent);
+            self.prev_type = LineType::CodeFence;
+
+            return;
+        }
+
+        match ln.line_type {
+            LineType::Empty => {
+                self.result.push(raw.to_string());
+            }
+
+            // `---` under a paragraph is a setext heading in gfm
+            LineType::ThematicBreak => {
+
+                if self.prev_type == LineType::Paragraph && ln.content.starts_with('-') {
+                    self.result.push(String::new());
+                }
+
+                self.result.push(raw.to_string());
+            }
+            LineType::Tag => {
+
+                match export_tag(&ln.content, 0) {
+                    Ok(tag) => {
+
+                        if self.prev_type != LineType::Empty {
+                            self.result.push(String::new());
+                        }
+
+                        self.result.push(tag);
+                        self.needs_empty_line = true;
+                    }
+                    Err(_) => {
+                        ln.line_type = LineType::Paragraph;
+                        self.export_text_line(index, &ln);
+                    }
+                }
+
+            }
+            _ => {
+                self.export_text_line(index, &ln);
+            }
+        }
+
+        self.prev_type = ln.line_type;
+    }
+
+    fn export_text_line(&mut self, index: usize, ln: &Line) {
+
+        let raw = self.lines[index];
+        let mut begin = raw.len() - ln.content.len();
+        let mut end = raw.len();
+        let mut result = raw[..begin].to_string();
+        let content = ln.content.as_bytes();
+
+        match ln.line_type {
+            LineType::Blockquote(depth) => {
+                result.push_str(&ln.content[..depth]);
+                begin += depth;
+            }
+            LineType::UnorderedList => {
+                result.push_str("- ");
+                begin += 2;
+            }
+            LineType::OrderedList => {
+                let (ordering, start, bullet_len) = parse_ordered_bullet(&ln.content).unwrap();
+
+                if ordering != ListOrder::Num {
+                    self.diagnostics.push(Diagnostic::warning(
+                        self.span(index, begin, begin + bullet_len + 1),
+                        format!("gfm has no `{}` bullets, it's exported as `{}.`", &ln.content[..bullet_len + 1], start)
+                    ));
+                }
+
+                result.push_str(&format!("{}. ", start));
+                begin += bullet_len + 2;
+            }
+            // `{#id .class}` of headings are removed
+            LineType::Header => {
+
+                if let Some((attribute_begin, _)) = parse_attributes(&raw[begin..]) {
+                    self.diagnostics.push(Diagnostic::warning(
+                        self.span(index, begin + attribute_begin, raw.trim_end().len()),
+                        String::from("gfm has no heading attributes, they're removed")
+                    ));
+
+                    end = begin + raw[begin..begin + attribute_begin].trim_end().len();
+                }
+
+            }
+            LineType::Definition => {
+                self.diagnostics.push(Diagnostic::warning(
+                    self.span(index, begin, begin + 1),
+                    String::from("gfm has no definition lists, `: ` lines are exported as they are")
+                ));
+            }
+            LineType::Paragraph => {
+                let digits = content.iter().take_while(|c| c.is_ascii_digit()).count();
+
+                // gfm would read it as a row of the table
+                if content.starts_with(b"Table: ") && index > 0 && is_table_row(self.lines[index - 1]) {
+                    self.diagnostics.push(Diagnostic::warning(
+                        self.span(index, begin, raw.len()),
+                        String::from("gfm has no table captions, it's exported as a paragraph")
+                    ));
+                    self.result.push(String::new());
+                }
+
+                if parse_abbreviation(ln).is_some() {
+                    self.diagnostics.push(Diagnostic::warning(
+                        self.span(index, begin, raw.len()),
+                        String::from("gfm has no abbreviations, the definition is exported as a paragraph")
+                    ));
+                }
+
+                if is_table_row(raw) {
+                    self.check_merged_cells(index, begin, &ln.content);
+                }
+
+                // gfm would read `+` as the first cell of a row
+                else if content.starts_with(b"+|") && is_table_row(&raw.trim()[1..]) {
+                    self.diagnostics.push(Diagnostic::warning(
+                        self.span(index, begin, begin + 2),
+                        String::from("gfm has no multi-line cells, `+|` rows are exported as they are")
+                    ));
+                }
+
+                // `2. `, `3) ` are lists in gfm
+                if digits > 0 && digits < 10 && content.len() > digits + 1
+                    && (content[digits] == b'.' || content[digits] == b')') && content[digits + 1] == b' ' {
+                    result.push_str(&ln.content[..digits]);
+                    result.push('\\');
+                    begin += digits;
+                }
+
+                // `* `, `+ `, `>quote`, `~~~`, and `===` of setext headings
+                else if content.starts_with(b"* ") || content.starts_with(b"+ ") || content.starts_with(b">")
+                    || content.starts_with(b"~~~") || (self.prev_type == LineType::Paragraph && content.iter().all(|c| *c == b'=')) {
+                    result.push('\\');
+                }
+
+            }
+            _ => {}
+        }
+
+        self.export_inlines(index, begin, end, &mut result);
+        self.result.push(result);
+    }
+
+    fn export_inlines(&mut self, index: usize, begin: usize, end: usize, buffer: &mut String) {
+
+        let content = &self.lines[index][begin..end];
+        let bytes = content.as_bytes();
+
+        // where `</u>`, `</sub>` and `</sup>` go
+        let mut underline_end = None;
+        let mut subscript_end = None;
+        let mut superscript_end = None;
+
+        let mut curr_index = 0;
+
+        while curr_index < bytes.len() {
+
+            if underline_end == Some(curr_index) {
+                buffer.push_str("</u>");
+                underline_end = None;
+                curr_index += 2;
+                continue;
+            }
+
+            if subscript_end == Some(curr_index) {
+                buffer.push_str("</sub>");
+                subscript_end = None;
+                curr_index += 1;
+                continue;
+            }
+
+            if superscript_end == Some(curr_index) {
+                buffer.push_str("</sup>");
+                superscript_end = None;
+                curr_index += 1;
+                continue;
+            }
+
+            match bytes[curr_index] {
+
+                // code spans are the same, but pipes in tables are escaped even in code spans
+                b'`' => {
+                    let end_index = match content[curr_index + 1..].find('`') {
+                        Some(i) => curr_index + i + 2,
+                        None => curr_index + 1
+                    };
+
+                    if is_table_row(self.lines[index]) {
+                        buffer.push_str(&content[curr_index..end_index].replace('|', "\\|"));
+                    }
+
+                    else {
+                        buffer.push_str(&content[curr_index..end_index]);
+                    }
+
+                    curr_index = end_index;
+                }
+
+                // `\q` is `q` in hmd, but `\q` in gfm
+                b'\\' => {
+
+                    match bytes.get(curr_index + 1) {
+                        Some(c) if c.is_ascii_punctuation() => {
+                            buffer.push_str(&content[curr_index..curr_index + 2]);
+                            curr_index += 2;
+                        }
+                        _ => {
+                            curr_index += 1;
+                        }
+                    }
+
+                }
+                b'~' if underline_end.is_none() && bytes.get(curr_index + 1) == Some(&b'_') => {
+
+                    match get_underline_end(bytes, curr_index) {
+                        Some(end_index) => {
+                            buffer.push_str("<u>");
+                            underline_end = Some(end_index);
+                            curr_index += 2;
+                        }
+                        None => {
+                            buffer.push_str("\\~\\_");
+                            curr_index += 2;
+                        }
+                    }
+
+                }
+                b'~' if bytes.get(curr_index + 1) == Some(&b'~') => {
+                    let run = bytes[curr_index..].iter().take_while(|c| **c == b'~').count();
+
+                    buffer.push_str(&content[curr_index..curr_index + run]);
+                    curr_index += run;
+                }
+                b'~' => {
+
+                    match get_script_end(bytes, curr_index) {
+                        Some(end_index) if subscript_end.is_none() => {
+                            buffer.push_str("<sub>");
+                            subscript_end = Some(end_index);
+                        }
+
+                        // a single `~` might be a strikethrough in gfm
+                        _ => {
+                            buffer.push_str("\\~");
+                        }
+                    }
+
+                    curr_index += 1;
+                }
+                b'^' => {
+
+                    match get_script_end(bytes, curr_index) {
+                        Some(end_index) if superscript_end.is_none() => {
+                            buffer.push_str("<sup>");
+                            superscript_end = Some(end_index);
+                        }
+                        _ => {
+                            buffer.push('^');
+                        }
+                    }
+
+                    curr_index += 1;
+                }
+                b'[' if is_tag(content, curr_index) => {
+                    let end_index = get_bracket_end_index(content, curr_index).unwrap() + 1;
+
+                    match export_tag(content, curr_index) {
+                        Ok(tag) => {
+                            buffer.push_str(&tag);
+                        }
+                        Err(_) => {
+                            buffer.push_str(&content[curr_index..end_index]);
+                        }
+                    }
+
+                    curr_index = end_index;
+                }
+
+                // `<` and `&` are plain texts in hmd
+                b'<' => {
+
+                    match bytes.get(curr_index + 1) {
+                        Some(c) if c.is_ascii_alphabetic() || *c == b'/' || *c == b'!' || *c == b'?' => {
+                            buffer.push_str("&lt;");
+                        }
+                        _ => {
+                            buffer.push('<');
+                        }
+                    }
+
+                    curr_index += 1;
+                }
+                b'&' => {
+
+                    if is_entity(&bytes[curr_index + 1..]) {
+                        buffer.push_str("&amp;");
+                    }
+
+                    else {
+                        buffer.push('&');
+                    }
+
+                    curr_index += 1;
+                }
+
+                // `_` between alphanumerics is never an emphasis in gfm
+                b'_' => {
+                    let is_intraword = curr_index > 0 && bytes[curr_index - 1].is_ascii_alphanumeric()
+                        && curr_index + 1 < bytes.len() && bytes[curr_index + 1].is_ascii_alphanumeric();
+
+                    if !is_intraword {
+                        buffer.push('\\');
+                    }
+
+                    buffer.push('_');
+                    curr_index += 1;
+                }
+                _ => {
+                    let c = content[curr_index..].chars().next().unwrap();
+                    buffer.push(c);
+                    curr_index += c.len_utf8();
+                }
+            }
+
+        }
+
+    }
+
+    // `| a | < |`
+    fn check_merged_cells(&mut self, index: usize, begin: usize, row: &str) {
+
+        let mut offset = begin + 1;
+
+        for cell in raw_row_to_cells(row).iter() {
+
+            if cell.trim() == "<" {
+                let marker = offset + cell.len() - cell.trim_start().len();
+
+                self.diagnostics.push(Diagnostic::warning(
+                    self.span(index, marker, marker + 1),
+                    String::from("gfm has no merged cells, `<` is exported as it is")
+                ));
+            }
+
+            offset += cell.len() + 1;
+        }
+
+    }
+
+    fn span(&self, index: usize, begin: usize, end: usize) -> Span {
+
+        let ln = self.lines[index];
+
+        Span {
+            line: index + 1,
+            column: ln[..begin].chars().count() + 1,
+            offset: self.offsets[index] + begin,
+            len: end - begin
+        }
+    }
+
+}
+
+
+// the indentation is spaces and tabs, the same as `Line::from_raw`
+fn is_table_row(line: &str) -> bool {
+
+    let line = line.trim_start_matches([' ', '\t']).trim_end();
+
+    line.len() > 2 && line.starts_with('|') && line.ends_with('|')
+}
+
+
+// `~_u_~`, returns the index of `_~`
+fn get_underline_end(bytes: &[u8], index: usize) -> Option<usize> {
+
+    if bytes.get(index + 2).is_none_or(|c| *c == b' ') {
+        return None;
+    }
+
+    (index + 3..bytes.len() - 1).find(
+        |i| bytes[*i] == b'_' && bytes[*i + 1] == b'~' && bytes[*i - 1] != b' '
+    )
+}
+
+
+// `~sub~`, `^sup^`, returns the index of the closing delimiter
+// they may not contain any space
+fn get_script_end(bytes: &[u8], index: usize) -> Option<usize> {
+
+    let delimiter = bytes[index];
+
+    match bytes.get(index + 1) {
+        Some(c) if *c != b' ' && *c != b'_' && *c != delimiter => {}
+        _ => { return None; }
+    }
+
+    for (i, c) in bytes.iter().enumerate().skip(index + 1) {
+
+        if *c == b' ' {
+            return None;
+        }
+
+        if *c == delimiter {
+            return if bytes.get(i + 1) == Some(&delimiter) || bytes[i - 1] == b'_' { None } else { Some(i) };
+        }
+
+    }
+
+    None
+}
+
+
+// `nbsp;`, `#60;`, `#x3c;`
+fn is_entity(bytes: &[u8]) -> bool {
+
+    let name = match bytes {
+        [b'#', b'x' | b'X', rest @ ..] => rest,
+        [b'#', rest @ ..] => rest,
+        _ => bytes
+    };
+
+    let name_len = name.iter().take_while(|c| c.is_ascii_alphanumeric()).count();
+
+    name_len > 0 && name.get(name_len) == Some(&b';')
+}
@@ -0,0 +1,165 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use syntect::highlighting::{Theme, ThemeSet};
+use syntect::parsing::SyntaxSet;
+
+pub const DEFAULT_THEME: &str = "base16-eighties.dark";
+
+
+// how fenced code blocks are highlighted
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CodeHighlight {
+    // `<span style="color:...">`, colors come from `RenderOption::theme`
+    InlineStyle,
+    // `<span class="...">`, colors come from a stylesheet, see `syntax_theme_css`
+    CssClass
+}
+
+
+// how `line_num(n)` renders line numbers of fenced codes
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineNumberLayout {
+    // `<span class="line_no">n</span>` at the beginning of each line
+    Inline,
+    // a row per line, the number is `data-line-number` of `td.line_no`, to be rendered by CSS
+    Table,
+    // `<span class="line">` per line, numbered by the CSS counter `line_no`
+    Counter
+}
+
+
+// how table cells are aligned
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TableAlignmentStyle {
+    // `class="align_left"`, alignments come from a stylesheet
+    CssClass,
+    // `style="text-align:left"`, for pages without hmd's stylesheet
+    InlineStyle
+}
+
+
+// how extra tags and task list checkboxes are styled
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ElementStyle {
+    // `class="font_red"`, styles come from `default_stylesheet`
+    CssClass,
+    // `style="color:rgb(192, 32, 32)"`, for email clients and feed readers that strip stylesheets
+    InlineStyle
+}
+
+
+// colors of `default_stylesheet`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorScheme {
+    Light,
+    Dark
+}
+
+
+#[derive(Clone, Debug)]
+pub struct RenderOption {
+    // name of a bundled syntect theme or a theme loaded with `load_theme_dir`
+    // unknown names fall back to `DEFAULT_THEME`, with a warning from `render_with_diagnostics`
+    // it's ignored when `code_highlight` is `CssClass`
+    pub theme: String,
+    pub code_highlight: CodeHighlight,
+    // fence language -> syntax, ex) `"hmd" -> "markdown"`, `"zig" -> "Zig"`
+    // the value is looked up by token (name or extension) first, then by name
+    pub language_aliases: HashMap<String, String>,
+    // wraps fenced codes in a container with the language name and a *Copy* button
+    // the button copies the code as it's written, without line numbers
+    pub code_copy_button: bool,
+    pub line_number_layout: LineNumberLayout,
+    pub table_alignment_style: TableAlignmentStyle,
+    pub element_style: ElementStyle,
+    // emits a `<colgroup>` with an aligned `<col>` per column
+    pub table_colgroup: bool,
+    // prepended to the classes in the rendered html, ex) `"hmd-"` renders `class="hmd-box"`
+    // classes of `CodeHighlight::CssClass` are not prefixed, they belong to `syntax_theme_css`
+    pub class_prefix: String,
+    // shortcode -> emoji, ex) `"shipit" -> "🐿️"`, it's looked up before the bundled shortcodes like `:smile:`
+    pub emoji: HashMap<String, String>,
+    // shortcode -> image url, ex) `"ferris" -> "/img/ferris.png"`, `:ferris:` is rendered as `<img class="emoji">`
+    pub custom_emoji: HashMap<String, String>,
+    // term -> title, ex) `"HTML" -> "Hyper Text Markup Language"`, terms in texts are rendered as `<abbr title="...">`
+    // `*[HTML]: ...` lines in the document are added to them
+    pub abbreviations: HashMap<String, String>,
+    // `false` removes the ids of headings, including the ones given with `{#id}`
+    pub heading_ids: bool,
+
+    // `None` means syntect's default syntaxes
+    syntaxes: Option<SyntaxSet>,
+    custom_themes: BTreeMap<String, Theme>
+}
+
+
+impl Default for RenderOption {
+
+    fn default() -> Self {
+        RenderOption {
+            theme: DEFAULT_THEME.to_string(),
+            code_highlight: CodeHighlight::InlineStyle,
+            language_aliases: HashMap::new(),
+            code_copy_button: false,
+            line_number_layout: LineNumberLayout::Inline,
+            table_alignment_style: TableAlignmentStyle::CssClass,
+            element_style: ElementStyle::CssClass,
+            table_colgroup: false,
+            class_prefix: String::new(),
+            emoji: HashMap::new(),
+            custom_emoji: HashMap::new(),
+            abbreviations: HashMap::new(),
+            heading_ids: true,
+            syntaxes: None,
+            custom_themes: BTreeMap::new()
+        }
+    }
+
+}
+
+
+impl RenderOption {
+
+    // loads every `.sublime-syntax` file in `dir` (recursively), on top of the default syntaxes
+    // it's expensive, so load them once and reuse the option
+    pub fn load_syntax_dir<P: AsRef<Path>>(&mut self, dir: P) -> Result<(), String> {
+
+        let syntaxes = match self.syntaxes.take() {
+            Some(syntaxes) => syntaxes,
+            None => SyntaxSet::load_defaults_nonewlines()
+        };
+
+        // the lines are highlighted without trailing newlines
+        let mut builder = syntaxes.into_builder();
+        let result = builder.add_from_folder(&dir, false).map_err(
+            |e| format!("cannot load syntaxes from `{}`: {}", dir.as_ref().display(), e)
+        );
+
+        self.syntaxes = Some(builder.build());
+        result
+    }
+
+    // loads every `.tmTheme` file in `dir`, a theme is named after its file name without the extension
+    pub fn load_theme_dir<P: AsRef<Path>>(&mut self, dir: P) -> Result<(), String> {
+
+        let theme_set = ThemeSet::load_from_folder(&dir).map_err(
+            |e| format!("cannot load themes from `{}`: {}", dir.as_ref().display(), e)
+        )?;
+
+        self.custom_themes.extend(theme_set.themes);
+        Ok(())
+    }
+
+    pub(crate) fn syntaxes(&self) -> Option<&SyntaxSet> {
+        self.syntaxes.as_ref()
+    }
+
+    pub(crate) fn custom_theme(&self, name: &str) -> Option<&Theme> {
+        self.custom_themes.get(name)
+    }
+
+    pub(crate) fn custom_theme_names(&self) -> Vec<String> {
+        self.custom_themes.keys().cloned().collect()
+    }
+
+}